@@ -0,0 +1,878 @@
+use std::slice;
+
+use indexmap::map::{self, Slice};
+
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    Unexpected, VariantAccess, Visitor,
+};
+use serde::forward_to_deserialize_any;
+use swon_parol::diagnostics::line_column;
+use swon_tree::{
+    Cst,
+    spanned::{Location, SpannedContent, SpannedValue, SpannedVariant},
+};
+use swon_value::value::{KeyCmpValue, Value};
+
+use crate::Error;
+
+/// The key that `$variant` is given as, unless [DeserializeOptions::tag] names another.
+const VARIANT: &str = "$variant";
+
+/// How `$variant` is given to enums other than externally tagged ones. serde tells their tag and
+/// content keys only to the enums themselves, so the keys used in a document are named here.
+#[derive(Debug, Clone, Default)]
+pub struct DeserializeOptions {
+    /// The tag key of internally tagged enums, `#[serde(tag = "type")]`, which `$variant` is
+    /// given as.
+    pub tag: Option<&'static str>,
+    /// The tag and content keys of adjacently tagged enums, `#[serde(tag = "t", content = "c")]`.
+    /// A struct with exactly these fields reads a variant as its tag and content.
+    pub adjacent: Option<(&'static str, &'static str)>,
+}
+
+/// Deserialize an instance of `T` from a SWON document.
+///
+/// | SWON | serde |
+/// |------|-------|
+/// | map, section, block | map or struct |
+/// | array, tuple | sequence, tuple or tuple struct |
+/// | null | `None` or unit |
+/// | typed string, code | string, dropping the type name or the language |
+/// | variant `$variant = "name"` | externally tagged or untagged enum, internally or adjacently tagged enum with the keys in [DeserializeOptions], and a map with the `$variant` key otherwise |
+/// | string | unit variant |
+///
+/// Errors about a value are located at the line and column of the key that holds it.
+pub fn from_str<T: DeserializeOwned>(input: &str) -> Result<T, Error> {
+    from_str_with_options(input, &DeserializeOptions::default())
+}
+
+/// Deserialize an instance of `T` from a SWON document, giving `$variant` to enums as `options`
+/// name.
+pub fn from_str_with_options<T: DeserializeOwned>(
+    input: &str,
+    options: &DeserializeOptions,
+) -> Result<T, Error> {
+    let cst = swon_parol::parse(input, "input.swon")?;
+    let document = cst.spanned_values(input).map_err(|error| {
+        // Duplicate keys and the like are located at the node they are found at
+        match error.token_start(&cst) {
+            Some(start) => {
+                let (line, column) = line_column(input, start);
+                Error::Located {
+                    message: error.to_string(),
+                    line,
+                    column,
+                }
+            }
+            None => Error::Values(error),
+        }
+    })?;
+    let source = Source {
+        input,
+        cst: &cst,
+        options,
+    };
+    T::deserialize(ValueDeserializer {
+        value: &document.root,
+        source: &source,
+    })
+}
+
+struct Source<'a> {
+    input: &'a str,
+    cst: &'a Cst,
+    options: &'a DeserializeOptions,
+}
+
+impl Source<'_> {
+    /// Add the line and column of `location` to an error that has none.
+    fn locate(&self, error: Error, location: Option<Location>) -> Error {
+        let Error::Custom(message) = error else {
+            return error;
+        };
+        match location.and_then(|location| location.token_start(self.cst)) {
+            Some(start) => {
+                let (line, column) = line_column(self.input, start);
+                Error::Located {
+                    message,
+                    line,
+                    column,
+                }
+            }
+            None => Error::Custom(message),
+        }
+    }
+
+    /// Locate an error of `value` at its key, or at the value itself if it has no key.
+    fn locate_value(&self, error: Error, value: &SpannedValue) -> Error {
+        self.locate(error, value.key.or(value.value))
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ValueDeserializer<'a> {
+    value: &'a SpannedValue,
+    source: &'a Source<'a>,
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer<'_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let value = match &self.value.content {
+            SpannedContent::Map(entries) => {
+                return visitor.visit_map(EntryAccess::new(None, entries.as_slice(), self.source));
+            }
+            SpannedContent::Variant(variant) => {
+                let entries = entries(&variant.content);
+                return visitor.visit_map(EntryAccess::new(Some(variant), entries, self.source));
+            }
+            SpannedContent::Array(elements) | SpannedContent::Tuple(elements) => {
+                return visitor.visit_seq(ElementAccess {
+                    elements: elements.iter(),
+                    source: self.source,
+                });
+            }
+            SpannedContent::Value(value) => value,
+        };
+        match value {
+            Value::Null | Value::Unit => visitor.visit_unit(),
+            Value::Bool(value) => visitor.visit_bool(*value),
+            Value::I64(value) => visitor.visit_i64(*value),
+            Value::U64(value) => visitor.visit_u64(*value),
+            Value::F32(value) => visitor.visit_f32(*value),
+            Value::F64(value) => visitor.visit_f64(*value),
+            Value::String(value) => visitor.visit_string(value.clone()),
+            Value::TypedString(typed) => visitor.visit_string(typed.value.clone()),
+            Value::Code(code) => visitor.visit_string(code.content.clone()),
+            Value::Path(_) => Err(de::Error::invalid_type(Unexpected::Other("path"), &visitor)),
+            Value::Array(_) | Value::Tuple(_) | Value::Map(_) | Value::Variant(_) => {
+                unreachable!("the spanned document splits containers out of values")
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match &self.value.content {
+            SpannedContent::Value(Value::Null) => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match &self.value.content {
+            SpannedContent::Map(entries) if entries.is_empty() => visitor.visit_unit(),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        // Adjacently tagged enums take their tag and content as a sequence
+        if let (SpannedContent::Variant(variant), &[tag, content]) = (&self.value.content, fields)
+            && self.source.options.adjacent == Some((tag, content))
+        {
+            return visitor.visit_seq(AdjacentAccess {
+                variant,
+                index: 0,
+                source: self.source,
+            });
+        }
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let (tag, location, content) = match &self.value.content {
+            SpannedContent::Variant(variant) => (
+                variant.tag.to_string(),
+                Some(variant.tag_location),
+                Some(&*variant.content),
+            ),
+            SpannedContent::Value(Value::String(tag)) => (tag.clone(), self.value.value, None),
+            // `{ Circle = { radius = 1.0 } }`
+            SpannedContent::Map(entries) => match entries.first() {
+                Some((KeyCmpValue::String(tag), content)) if entries.len() == 1 => {
+                    (tag.clone(), content.key, Some(content))
+                }
+                _ => return Err(de::Error::invalid_type(Unexpected::Map, &visitor)),
+            },
+            _ => return Err(de::Error::invalid_type(unexpected(self.value), &visitor)),
+        };
+        visitor.visit_enum(VariantDeserializer {
+            tag,
+            location,
+            content,
+            source: self.source,
+        })
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        unit_struct seq tuple tuple_struct map identifier
+    }
+}
+
+/// The entries of a map, or of the content of a variant after its `$variant` entry.
+struct EntryAccess<'a> {
+    variant: Option<&'a SpannedVariant>,
+    entries: map::Iter<'a, KeyCmpValue, SpannedValue>,
+    /// The value of the key given last
+    pending: Option<Pending<'a>>,
+    source: &'a Source<'a>,
+}
+
+enum Pending<'a> {
+    Tag(&'a SpannedVariant),
+    Value(&'a SpannedValue),
+}
+
+impl<'a> EntryAccess<'a> {
+    fn new(
+        variant: Option<&'a SpannedVariant>,
+        entries: &'a Slice<KeyCmpValue, SpannedValue>,
+        source: &'a Source<'a>,
+    ) -> Self {
+        Self {
+            variant,
+            entries: entries.iter(),
+            pending: None,
+            source,
+        }
+    }
+}
+
+impl<'de> MapAccess<'de> for EntryAccess<'_> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        if let Some(variant) = self.variant.take() {
+            self.pending = Some(Pending::Tag(variant));
+            return seed
+                .deserialize(TagKeyDeserializer {
+                    key: self.source.options.tag.unwrap_or(VARIANT),
+                })
+                .map(Some)
+                .map_err(|error| self.source.locate(error, Some(variant.tag_location)));
+        }
+        let Some((key, value)) = self.entries.next() else {
+            return Ok(None);
+        };
+        self.pending = Some(Pending::Value(value));
+        seed.deserialize(KeyDeserializer { key })
+            .map(Some)
+            .map_err(|error| self.source.locate_value(error, value))
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        match self.pending.take() {
+            Some(Pending::Tag(variant)) => seed
+                .deserialize(tag_deserializer(variant))
+                .map_err(|error| self.source.locate(error, Some(variant.tag_location))),
+            Some(Pending::Value(value)) => seed
+                .deserialize(ValueDeserializer {
+                    value,
+                    source: self.source,
+                })
+                .map_err(|error| self.source.locate_value(error, value)),
+            None => Err(de::Error::custom("value is asked before its key")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len() + usize::from(self.variant.is_some()))
+    }
+}
+
+/// The elements of an array or a tuple.
+struct ElementAccess<'a> {
+    elements: slice::Iter<'a, SpannedValue>,
+    source: &'a Source<'a>,
+}
+
+impl<'de> SeqAccess<'de> for ElementAccess<'_> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        let Some(value) = self.elements.next() else {
+            return Ok(None);
+        };
+        seed.deserialize(ValueDeserializer {
+            value,
+            source: self.source,
+        })
+        .map(Some)
+        .map_err(|error| self.source.locate_value(error, value))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.elements.len())
+    }
+}
+
+/// The tag and the content of a variant, as an adjacently tagged enum reads them.
+struct AdjacentAccess<'a> {
+    variant: &'a SpannedVariant,
+    index: usize,
+    source: &'a Source<'a>,
+}
+
+impl<'de> SeqAccess<'de> for AdjacentAccess<'_> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        self.index += 1;
+        let element = match self.index {
+            1 => seed.deserialize(tag_deserializer(self.variant)),
+            // A unit variant is read from a variant without entries
+            2 if entries(&self.variant.content).is_empty() => seed.deserialize(UnitDeserializer),
+            2 => seed.deserialize(ValueDeserializer {
+                value: &self.variant.content,
+                source: self.source,
+            }),
+            _ => return Ok(None),
+        };
+        element
+            .map(Some)
+            .map_err(|error| self.source.locate(error, Some(self.variant.tag_location)))
+    }
+}
+
+/// A variant as an externally tagged enum reads it. `content` is `None` for a variant written as
+/// a string.
+struct VariantDeserializer<'a> {
+    tag: String,
+    location: Option<Location>,
+    content: Option<&'a SpannedValue>,
+    source: &'a Source<'a>,
+}
+
+impl<'de, 'a> EnumAccess<'de> for VariantDeserializer<'a> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self), Error> {
+        let tag = seed
+            .deserialize(IntoDeserializer::<Error>::into_deserializer(
+                self.tag.clone(),
+            ))
+            .map_err(|error| self.source.locate(error, self.location))?;
+        Ok((tag, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for VariantDeserializer<'_> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        match self.content {
+            None => Ok(()),
+            Some(content) => de::Deserializer::deserialize_unit(
+                ValueDeserializer {
+                    value: content,
+                    source: self.source,
+                },
+                UnitVisitor,
+            ),
+        }
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(self.content()?)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_seq(self.content()?, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_map(self.content()?, visitor)
+    }
+}
+
+impl<'a> VariantDeserializer<'a> {
+    fn content(&self) -> Result<ValueDeserializer<'a>, Error> {
+        match self.content {
+            Some(value) => Ok(ValueDeserializer {
+                value,
+                source: self.source,
+            }),
+            None => Err(de::Error::invalid_type(
+                Unexpected::UnitVariant,
+                &"a variant with content",
+            )),
+        }
+    }
+}
+
+struct UnitVisitor;
+
+impl Visitor<'_> for UnitVisitor {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a unit variant")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+}
+
+/// The content of a variant without entries, which is either a unit or an empty map.
+struct UnitDeserializer;
+
+impl<'de> de::Deserializer<'de> for UnitDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_map(de::value::MapDeserializer::new(
+            std::iter::empty::<((), ())>(),
+        ))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_map(visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct seq tuple tuple_struct enum identifier ignored_any
+    }
+}
+
+/// The key of the `$variant` entry.
+struct TagKeyDeserializer {
+    key: &'static str,
+}
+
+impl<'de> de::Deserializer<'de> for TagKeyDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_str(self.key)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct seq tuple tuple_struct map struct enum identifier
+        ignored_any
+    }
+}
+
+/// A map key, which is given as a string to identifiers and strings.
+struct KeyDeserializer<'a> {
+    key: &'a KeyCmpValue,
+}
+
+impl<'de> de::Deserializer<'de> for KeyDeserializer<'_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.key {
+            KeyCmpValue::Null | KeyCmpValue::Unit => visitor.visit_unit(),
+            KeyCmpValue::Bool(key) => visitor.visit_bool(*key),
+            KeyCmpValue::I64(key) => visitor.visit_i64(*key),
+            KeyCmpValue::U64(key) => visitor.visit_u64(*key),
+            KeyCmpValue::String(key) => visitor.visit_string(key.clone()),
+            KeyCmpValue::Tuple(tuple) => visitor.visit_seq(KeyElementAccess {
+                elements: tuple.0.iter(),
+            }),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.key {
+            KeyCmpValue::Bool(key) => visitor.visit_string(key.to_string()),
+            KeyCmpValue::I64(key) => visitor.visit_string(key.to_string()),
+            KeyCmpValue::U64(key) => visitor.visit_string(key.to_string()),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.key {
+            KeyCmpValue::String(key) => visitor.visit_enum(key.clone().into_deserializer()),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char bytes byte_buf option unit
+        unit_struct seq tuple tuple_struct map struct ignored_any
+    }
+}
+
+/// The elements of a tuple key.
+struct KeyElementAccess<'a> {
+    elements: slice::Iter<'a, KeyCmpValue>,
+}
+
+impl<'de> SeqAccess<'de> for KeyElementAccess<'_> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        self.elements
+            .next()
+            .map(|key| seed.deserialize(KeyDeserializer { key }))
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.elements.len())
+    }
+}
+
+fn entries(value: &SpannedValue) -> &Slice<KeyCmpValue, SpannedValue> {
+    match &value.content {
+        SpannedContent::Map(entries) => entries.as_slice(),
+        _ => Slice::new(),
+    }
+}
+
+fn tag_deserializer(variant: &SpannedVariant) -> de::value::StringDeserializer<Error> {
+    variant.tag.to_string().into_deserializer()
+}
+
+fn unexpected(value: &SpannedValue) -> Unexpected<'_> {
+    match &value.content {
+        SpannedContent::Map(_) | SpannedContent::Variant(_) => Unexpected::Map,
+        SpannedContent::Array(_) | SpannedContent::Tuple(_) => Unexpected::Seq,
+        SpannedContent::Value(value) => match value {
+            Value::Null | Value::Unit => Unexpected::Unit,
+            Value::Bool(value) => Unexpected::Bool(*value),
+            Value::I64(value) => Unexpected::Signed(*value),
+            Value::U64(value) => Unexpected::Unsigned(*value),
+            Value::F32(value) => Unexpected::Float(*value as f64),
+            Value::F64(value) => Unexpected::Float(*value),
+            Value::String(value) => Unexpected::Str(value),
+            Value::TypedString(typed) => Unexpected::Str(&typed.value),
+            Value::Code(code) => Unexpected::Str(&code.content),
+            Value::Path(_) => Unexpected::Other("path"),
+            Value::Array(_) | Value::Tuple(_) => Unexpected::Seq,
+            Value::Map(_) | Value::Variant(_) => Unexpected::Map,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Config {
+        name: String,
+        port: u16,
+        ratio: f64,
+        tags: Vec<String>,
+        pair: (i32, String),
+        limit: Option<u32>,
+        timeout: Option<u32>,
+        labels: BTreeMap<String, String>,
+        server: Server,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Server {
+        host: String,
+        url: String,
+    }
+
+    #[test]
+    fn test_from_str() {
+        let input = r#"
+name: web
+port = 8080
+ratio = 1
+tags = ["a", "b"]
+pair = (1, "x")
+limit = null
+timeout = 30
+
+@ labels
+app: web
+
+@ server
+host: localhost
+url = url"https://swon.dev"
+"#;
+        assert_eq!(
+            from_str::<Config>(input).unwrap(),
+            Config {
+                name: "web".into(),
+                port: 8080,
+                ratio: 1.0,
+                tags: vec!["a".into(), "b".into()],
+                pair: (1, "x".into()),
+                limit: None,
+                timeout: Some(30),
+                labels: BTreeMap::from([("app".into(), "web".into())]),
+                server: Server {
+                    host: "localhost".into(),
+                    url: "https://swon.dev".into(),
+                },
+            }
+        );
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    enum External {
+        Circle { radius: f64 },
+        Point(Vec<i64>),
+        Empty,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(tag = "type", deny_unknown_fields)]
+    enum Internal {
+        Circle { radius: f64 },
+        Empty,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(tag = "t", content = "c", deny_unknown_fields)]
+    enum Adjacent {
+        Circle { radius: f64 },
+        Empty,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(untagged)]
+    enum Untagged {
+        Circle { radius: f64 },
+        Square { side: f64 },
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Shapes<T> {
+        a: T,
+        b: T,
+    }
+
+    const SHAPES: &str = r#"
+@ a
+$variant: Circle
+radius = 1.5
+
+@ b
+$variant: Empty
+"#;
+
+    const BAD_KEY: &str = r#"
+@ a
+$variant: Circle
+radus = 1.5
+
+@ b
+$variant: Empty
+"#;
+
+    #[test]
+    fn test_external() {
+        assert_eq!(
+            from_str::<Shapes<External>>(SHAPES).unwrap(),
+            Shapes {
+                a: External::Circle { radius: 1.5 },
+                b: External::Empty,
+            }
+        );
+
+        // Externally tagged forms without `$variant`
+        let input = "a = { Point = [1, 2] }\nb = \"Empty\"\n";
+        assert_eq!(
+            from_str::<Shapes<External>>(input).unwrap(),
+            Shapes {
+                a: External::Point(vec![1, 2]),
+                b: External::Empty,
+            }
+        );
+
+        let error = from_str::<Shapes<External>>(BAD_KEY).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unknown field `radus`, expected `radius` at line 4 column 1"
+        );
+    }
+
+    #[test]
+    fn test_internal() {
+        let options = DeserializeOptions {
+            tag: Some("type"),
+            ..Default::default()
+        };
+        assert_eq!(
+            from_str_with_options::<Shapes<Internal>>(SHAPES, &options).unwrap(),
+            Shapes {
+                a: Internal::Circle { radius: 1.5 },
+                b: Internal::Empty,
+            }
+        );
+
+        // serde buffers the content before reading it, so the error is at the section
+        let error = from_str_with_options::<Shapes<Internal>>(BAD_KEY, &options).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unknown field `radus`, expected `radius` at line 2 column 3"
+        );
+
+        // Without the tag key, the enum doesn't find its tag
+        let error = from_str::<Shapes<Internal>>(SHAPES).unwrap_err();
+        assert_eq!(error.to_string(), "missing field `type` at line 2 column 3");
+    }
+
+    #[test]
+    fn test_adjacent() {
+        let options = DeserializeOptions {
+            adjacent: Some(("t", "c")),
+            ..Default::default()
+        };
+        assert_eq!(
+            from_str_with_options::<Shapes<Adjacent>>(SHAPES, &options).unwrap(),
+            Shapes {
+                a: Adjacent::Circle { radius: 1.5 },
+                b: Adjacent::Empty,
+            }
+        );
+
+        let error = from_str_with_options::<Shapes<Adjacent>>(BAD_KEY, &options).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unknown field `radus`, expected `radius` at line 4 column 1"
+        );
+
+        let error = from_str::<Shapes<Adjacent>>(SHAPES).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid value: string \"$variant\", expected \"t\" or \"c\" at line 3 column 1"
+        );
+    }
+
+    #[test]
+    fn test_untagged() {
+        let input = "a = { $variant = \"Circle\", radius = 1.5 }\nb = { side = 2.0 }\n";
+        assert_eq!(
+            from_str::<Shapes<Untagged>>(input).unwrap(),
+            Shapes {
+                a: Untagged::Circle { radius: 1.5 },
+                b: Untagged::Square { side: 2.0 },
+            }
+        );
+
+        let input = "a = { radius = 1.5 }\nb = { sides = 2.0 }\n";
+        let error = from_str::<Shapes<Untagged>>(input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "data did not match any variant of untagged enum Untagged at line 2 column 1"
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let message = |input: &str| from_str::<Config>(input).unwrap_err().to_string();
+        let input = r#"name: web
+port = 70000
+"#;
+        assert_eq!(
+            message(input),
+            "invalid value: integer `70000`, expected u16 at line 2 column 1"
+        );
+
+        let input = r#"
+@ server
+host = 1
+"#;
+        assert_eq!(
+            message(input),
+            "invalid type: integer `1`, expected a string at line 3 column 1"
+        );
+
+        let input = "@ server\nhost: localhost\n";
+        assert_eq!(message(input), "missing field `url` at line 1 column 3");
+
+        let error = from_str::<Shapes<External>>("a = { $variant = \"Oval\" }\n").unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Located {
+                line: 1,
+                column: 7,
+                ..
+            }
+        ));
+        assert!(matches!(from_str::<Config>("a = "), Err(Error::Parse(_))));
+
+        let input = "@ server\nurl = \"a\"\nurl = \"b\"\n";
+        assert_eq!(message(input), "Key is already assigned at line 3 column 1");
+    }
+}
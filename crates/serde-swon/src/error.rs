@@ -0,0 +1,31 @@
+use std::fmt::Display;
+
+use swon_parol::ParseError;
+use swon_tree::value_visitor::ValueVisitorError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Parse(#[from] Box<ParseError>),
+    /// An error in the values of the document that has no location. Duplicate keys and the like
+    /// are [Error::Located].
+    #[error(transparent)]
+    Values(#[from] ValueVisitorError),
+    /// A value that doesn't fit the type, located at the key that holds it.
+    #[error("{message} at line {line} column {column}")]
+    Located {
+        message: String,
+        line: usize,
+        column: usize,
+    },
+    /// An error that has no location, like one about the document root.
+    #[error("{0}")]
+    Custom(String),
+}
+
+impl serde::de::Error for Error {
+    fn custom<T: Display>(message: T) -> Self {
+        Error::Custom(message.to_string())
+    }
+}
@@ -1,14 +1,7 @@
-pub fn add(left: usize, right: usize) -> usize {
-    left + right
-}
+//! Serde support for SWON.
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+mod de;
+mod error;
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
+pub use de::{DeserializeOptions, from_str, from_str_with_options};
+pub use error::Error;
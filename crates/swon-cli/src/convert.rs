@@ -0,0 +1,444 @@
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, BufWriter, Read as _, Write};
+use std::path::{Path, PathBuf};
+
+use clap::{Args, ValueEnum};
+use swon_json::{FromJsonOptions, ToJsonOptions, from_json, to_json};
+use swon_parol::diagnostics::line_column;
+use swon_tree::{Cst, spanned::SpannedDocument};
+use swon_value::value::{Path as ValuePath, PathSegment};
+use swon_yaml::{FromYamlOptions, from_yaml, to_yaml};
+
+use crate::fmt::collect_files;
+use crate::{report_parse_error, report_values_error};
+
+#[derive(Args)]
+pub struct Convert {
+    /// Format of the input. Detected from the extension of the first input, else SWON if the
+    /// output format is known
+    #[arg(long, value_enum)]
+    from: Option<Format>,
+    /// Format of the output. Detected from the extension of the output, else SWON if the input
+    /// format is known
+    #[arg(long, value_enum)]
+    to: Option<Format>,
+    /// Input files or directories, or `-` for stdin. Only SWON converted to JSON Lines or YAML is
+    /// read from several inputs
+    #[arg(default_value = "-")]
+    inputs: Vec<PathBuf>,
+    /// Output file, or the directory to write one SWON document per record or YAML document to.
+    /// Stdout by default
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    /// Write the output even if it loses information, and report the losses as warnings
+    #[arg(long)]
+    allow_lossy: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Swon,
+    Json,
+    /// JSON Lines, one JSON value per line
+    Jsonl,
+    Toml,
+    Yaml,
+}
+
+impl Format {
+    /// The format of a file from its extension.
+    fn detect(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "swon" => Some(Format::Swon),
+            "json" => Some(Format::Json),
+            "jsonl" | "ndjson" => Some(Format::Jsonl),
+            "toml" => Some(Format::Toml),
+            "yaml" | "yml" => Some(Format::Yaml),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Format::Swon => "SWON",
+            Format::Json => "JSON",
+            Format::Jsonl => "JSON Lines",
+            Format::Toml => "TOML",
+            Format::Yaml => "YAML",
+        }
+    }
+}
+
+/// Convert the inputs of `args`, and return whether all of them succeeded without losing
+/// information, unless losses are allowed.
+pub fn run(args: &Convert) -> bool {
+    let from = args
+        .from
+        .or_else(|| args.inputs.first().and_then(|input| Format::detect(input)));
+    let to = args
+        .to
+        .or_else(|| args.output.as_deref().and_then(Format::detect));
+    // One side of a conversion is always SWON
+    let (from, to) = match (from, to) {
+        (Some(from), Some(to)) => (from, to),
+        (Some(Format::Swon), None) => {
+            eprintln!("Can't detect the output format, so it needs --to <FORMAT>");
+            return false;
+        }
+        (Some(from), None) => (from, Format::Swon),
+        (None, Some(Format::Swon)) => {
+            eprintln!("Can't detect the input format, so it needs --from <FORMAT>");
+            return false;
+        }
+        (None, Some(to)) => (Format::Swon, to),
+        (None, None) => {
+            eprintln!("Can't detect the formats, so it needs --from <FORMAT> or --to <FORMAT>");
+            return false;
+        }
+    };
+    match (from, to) {
+        (Format::Swon, Format::Swon) => {
+            eprintln!("The input and output formats must differ");
+            false
+        }
+        (Format::Jsonl, Format::Swon) => jsonl_to_swon(args),
+        (from, Format::Swon) => to_swon(args, from),
+        (Format::Swon, to) => from_swon(args, to),
+        (from, to) => {
+            eprintln!(
+                "{} can't be converted to {} directly, convert it to SWON first",
+                from.name(),
+                to.name()
+            );
+            false
+        }
+    }
+}
+
+/// Write each line of the input as `<record>.swon` in the output directory, numbered from 1.
+/// Records are converted one at a time, so memory use is bounded by the largest record rather
+/// than the stream.
+fn jsonl_to_swon(args: &Convert) -> bool {
+    let [input] = args.inputs.as_slice() else {
+        eprintln!("JSON Lines is read from one input");
+        return false;
+    };
+    let Some(dir) = &args.output else {
+        eprintln!("Converting to SWON writes one document per record, so it needs --output <DIR>");
+        return false;
+    };
+    if let Err(error) = fs::create_dir_all(dir) {
+        eprintln!("Error creating {}: {error}", dir.display());
+        return false;
+    }
+    let (name, mut reader): (_, Box<dyn BufRead>) = if input.as_os_str() == "-" {
+        ("<stdin>".to_string(), Box::new(std::io::stdin().lock()))
+    } else {
+        match File::open(input) {
+            Ok(file) => (input.display().to_string(), Box::new(BufReader::new(file))),
+            Err(error) => {
+                eprintln!("Error reading {}: {error}", input.display());
+                return false;
+            }
+        }
+    };
+
+    let options = FromJsonOptions::default();
+    let mut ok = true;
+    let mut line = String::new();
+    let mut record = 0;
+    for number in 1.. {
+        line.clear();
+        match reader.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(error) => {
+                eprintln!("Error reading {name}:{number}: {error}");
+                return false;
+            }
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+        record += 1;
+        let swon = serde_json::from_str(&line)
+            .map_err(|error| error.to_string())
+            .and_then(|json| from_json(&json, &options).map_err(|error| error.to_string()));
+        let swon = match swon {
+            Ok(swon) => swon,
+            Err(error) => {
+                eprintln!("Error in {name}:{number}: {error}");
+                ok = false;
+                continue;
+            }
+        };
+        let path = dir.join(format!("{record}.swon"));
+        if let Err(error) = fs::write(&path, swon) {
+            eprintln!("Error writing {}: {error}", path.display());
+            return false;
+        }
+    }
+    ok
+}
+
+/// Convert a JSON, TOML or YAML input to SWON. A YAML stream of several documents is written as
+/// `<document>.swon` in the output directory, numbered from 1.
+fn to_swon(args: &Convert, from: Format) -> bool {
+    let [input] = args.inputs.as_slice() else {
+        eprintln!("{} is read from one input", from.name());
+        return false;
+    };
+    let Some((name, contents)) = read_input(input) else {
+        return false;
+    };
+    let documents = match from {
+        Format::Json => serde_json::from_str(&contents)
+            .map_err(|error| error.to_string())
+            .and_then(|json| {
+                from_json(&json, &FromJsonOptions::default()).map_err(|error| error.to_string())
+            })
+            .map(|swon| vec![swon]),
+        Format::Toml => swon_toml::from_toml_str(&contents)
+            .map(|swon| vec![swon])
+            .map_err(|error| error.to_string()),
+        Format::Yaml => {
+            from_yaml(&contents, &FromYamlOptions::default()).map_err(|error| error.to_string())
+        }
+        Format::Swon | Format::Jsonl => unreachable!("converted by the caller"),
+    };
+    let documents = match documents {
+        Ok(documents) => documents,
+        Err(error) => {
+            eprintln!("Error in {name}: {error}");
+            return false;
+        }
+    };
+    if let [] | [_] = documents.as_slice() {
+        let mut output = Output::new(args.output.as_deref());
+        let document = documents.first().map_or("", String::as_str);
+        return output.write(document) && output.finish();
+    }
+    let Some(dir) = &args.output else {
+        eprintln!(
+            "{name} holds {} documents, so converting it to SWON needs --output <DIR>",
+            documents.len()
+        );
+        return false;
+    };
+    if let Err(error) = fs::create_dir_all(dir) {
+        eprintln!("Error creating {}: {error}", dir.display());
+        return false;
+    }
+    for (index, document) in documents.iter().enumerate() {
+        let path = dir.join(format!("{}.swon", index + 1));
+        if let Err(error) = fs::write(&path, document) {
+            eprintln!("Error writing {}: {error}", path.display());
+            return false;
+        }
+    }
+    true
+}
+
+/// Convert the SWON documents of the inputs to `to`, as lines of JSON Lines or documents of a
+/// YAML stream. JSON and TOML are written from one document.
+fn from_swon(args: &Convert, to: Format) -> bool {
+    let mut ok = true;
+    let mut files = vec![];
+    for input in &args.inputs {
+        if input.as_os_str() == "-" {
+            files.push(input.clone());
+        } else if let Err(error) = collect_files(input, &mut files) {
+            eprintln!("Error reading {}: {error}", input.display());
+            ok = false;
+        }
+    }
+    if matches!(to, Format::Json | Format::Toml) && files.len() > 1 {
+        eprintln!("{} is written from one input", to.name());
+        return false;
+    }
+
+    let mut output = Output::new(args.output.as_deref());
+    let mut written = false;
+    for file in files {
+        let Some(text) = convert_swon(&file, to, args.allow_lossy) else {
+            ok = false;
+            continue;
+        };
+        if to == Format::Yaml && std::mem::replace(&mut written, true) {
+            ok &= output.write("---\n");
+        }
+        if !output.write(&text) {
+            return false;
+        }
+    }
+    output.finish() && ok
+}
+
+/// Convert the SWON document at `path` to `to`, and report the information it loses.
+fn convert_swon(path: &Path, to: Format, allow_lossy: bool) -> Option<String> {
+    let (name, contents) = read_input(path)?;
+    let cst = match swon_parol::parse(&contents, &name) {
+        Ok(cst) => cst,
+        Err(error) => {
+            report_parse_error(&error.error, &contents, &name);
+            return None;
+        }
+    };
+    let document = match cst.spanned_values(&contents) {
+        Ok(document) => document,
+        Err(error) => {
+            report_values_error(&error, &cst, &contents, &name);
+            return None;
+        }
+    };
+    let source = Source {
+        name: &name,
+        contents: &contents,
+        cst: &cst,
+        document: &document,
+    };
+
+    let converted = match to {
+        Format::Json | Format::Jsonl => {
+            to_json(&document.clone().into_values(), &ToJsonOptions::default())
+                .map(|json| {
+                    let text = if to == Format::Json {
+                        format!("{:#}\n", json.value)
+                    } else {
+                        format!("{}\n", json.value)
+                    };
+                    let losses = json.losses.into_iter();
+                    let losses = losses.map(|loss| (loss.path, loss.kind.to_string()));
+                    (text, losses.collect())
+                })
+                .map_err(|error| (error.path.clone(), error.to_string()))
+        }
+        Format::Toml => swon_toml::to_toml(&contents, &cst, &document)
+            .map(|toml| {
+                let losses = toml.losses.into_iter();
+                let losses = losses.map(|loss| (loss.path, loss.kind.to_string()));
+                (toml.document.to_string(), losses.collect())
+            })
+            .map_err(|error| (error.path.clone(), error.to_string())),
+        Format::Yaml => to_yaml(&document.clone().into_values())
+            .map(|yaml| {
+                let losses = yaml.losses.into_iter();
+                let losses = losses.map(|loss| (loss.path, loss.kind.to_string()));
+                (yaml.text, losses.collect())
+            })
+            .map_err(|error| (error.path.clone(), error.to_string())),
+        Format::Swon => unreachable!("converted by the caller"),
+    };
+    let (text, losses): (String, Vec<(ValuePath, String)>) = match converted {
+        Ok(converted) => converted,
+        Err((path, message)) => {
+            eprintln!("Error in {}: {message}", source.locate(&path.0));
+            return None;
+        }
+    };
+    let severity = if allow_lossy { "Warning" } else { "Error" };
+    for (path, message) in &losses {
+        eprintln!("{severity} in {}: {message}", source.locate(&path.0));
+    }
+    if !losses.is_empty() && !allow_lossy {
+        eprintln!(
+            "{name} can't be converted to {} without losing information, pass --allow-lossy to \
+             convert it anyway",
+            to.name()
+        );
+        return None;
+    }
+    Some(text)
+}
+
+/// A parsed SWON document, for locating the values of diagnostics.
+struct Source<'a> {
+    name: &'a str,
+    contents: &'a str,
+    cst: &'a Cst,
+    document: &'a SpannedDocument,
+}
+
+impl Source<'_> {
+    /// `name:line:column` of the key or the value at `path`, or of its nearest ancestor that is
+    /// written in the document.
+    fn locate(&self, path: &[PathSegment]) -> String {
+        let start = (0..=path.len()).rev().find_map(|len| {
+            let value = self.document.get(&path[..len])?;
+            value.key.or(value.value)?.token_start(self.cst)
+        });
+        match start {
+            Some(start) => {
+                let (line, column) = line_column(self.contents, start);
+                format!("{}:{line}:{column}", self.name)
+            }
+            None => self.name.to_string(),
+        }
+    }
+}
+
+/// The output file or stdout. A file is created on the first write, so a failed conversion
+/// leaves no file behind.
+struct Output<'a> {
+    path: Option<&'a Path>,
+    writer: Option<BufWriter<Box<dyn Write>>>,
+}
+
+impl<'a> Output<'a> {
+    fn new(path: Option<&'a Path>) -> Self {
+        Self { path, writer: None }
+    }
+
+    fn write(&mut self, text: &str) -> bool {
+        let writer = match &mut self.writer {
+            Some(writer) => writer,
+            None => {
+                let writer: Box<dyn Write> = match self.path {
+                    Some(path) => match File::create(path) {
+                        Ok(file) => Box::new(file),
+                        Err(error) => {
+                            eprintln!("Error writing {}: {error}", path.display());
+                            return false;
+                        }
+                    },
+                    None => Box::new(std::io::stdout().lock()),
+                };
+                self.writer.insert(BufWriter::new(writer))
+            }
+        };
+        if let Err(error) = writer.write_all(text.as_bytes()) {
+            eprintln!("Error writing output: {error}");
+            return false;
+        }
+        true
+    }
+
+    fn finish(self) -> bool {
+        if let Some(mut writer) = self.writer
+            && let Err(error) = writer.flush()
+        {
+            eprintln!("Error writing output: {error}");
+            return false;
+        }
+        true
+    }
+}
+
+/// Read the file at `path`, or stdin for `-`, with its name for diagnostics.
+fn read_input(path: &Path) -> Option<(String, String)> {
+    if path.as_os_str() == "-" {
+        let mut contents = String::new();
+        if let Err(error) = std::io::stdin().read_to_string(&mut contents) {
+            eprintln!("Error reading stdin: {error}");
+            return None;
+        }
+        return Some(("<stdin>".to_string(), contents));
+    }
+    match fs::read_to_string(path) {
+        Ok(contents) => Some((path.display().to_string(), contents)),
+        Err(error) => {
+            eprintln!("Error reading {}: {error}", path.display());
+            None
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use std::fs;
+use std::io::{Read as _, Write as _};
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+use similar::TextDiff;
+use swon_fmt::{DocumentConfig, FormatOptions, document_config, fmt_with_options};
+
+use crate::{report_parse_error, report_values_error};
+
+#[derive(Args)]
+pub struct Fmt {
+    /// Files or directories to format, or `-` to format stdin to stdout
+    #[arg(required = true)]
+    paths: Vec<PathBuf>,
+    /// Check that the files are formatted without writing them, and fail if any is not
+    #[arg(long)]
+    check: bool,
+    /// Print a unified diff of the changes instead of writing them
+    #[arg(long)]
+    diff: bool,
+    /// Config file with the format options
+    #[arg(long)]
+    config: Option<PathBuf>,
+}
+
+/// Format the files of `args`, and return whether all of them succeeded and none would change in
+/// check mode.
+pub fn run(args: &Fmt) -> bool {
+    let options = match &args.config {
+        Some(path) => match read_options(path) {
+            Ok(options) => options,
+            Err(error) => {
+                eprintln!("{error}");
+                return false;
+            }
+        },
+        None => FormatOptions::default(),
+    };
+
+    let mut ok = true;
+    for path in &args.paths {
+        if path.as_os_str() == "-" {
+            ok &= format_stdin(args, &options);
+            continue;
+        }
+        let mut files = vec![];
+        if let Err(error) = collect_files(path, &mut files) {
+            eprintln!("Error reading {}: {error}", path.display());
+            ok = false;
+        }
+        for file in files {
+            ok &= format_file(args, &options, &file);
+        }
+    }
+    ok
+}
+
+/// `path` if it is a file, or the `.swon` files in it, in order. Symbolic links to directories
+/// inside `path` are skipped, so that a link to a parent directory doesn't loop.
+pub(crate) fn collect_files(path: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    if !path.is_dir() {
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+    let mut entries = fs::read_dir(path)?
+        .map(|entry| entry.and_then(|entry| Ok((entry.path(), entry.file_type()?))))
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    for (entry, file_type) in entries {
+        if file_type.is_dir() {
+            collect_files(&entry, files)?;
+        } else if file_type.is_symlink() && entry.is_dir() {
+            continue;
+        } else if entry
+            .extension()
+            .is_some_and(|extension| extension == "swon")
+        {
+            files.push(entry);
+        }
+    }
+    Ok(())
+}
+
+fn format_file(args: &Fmt, options: &FormatOptions, path: &Path) -> bool {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(error) => {
+            eprintln!("Error reading {}: {error}", path.display());
+            return false;
+        }
+    };
+    let name = path.display().to_string();
+    let Some(formatted) = format(&contents, &name, path.parent(), options) else {
+        return false;
+    };
+    if formatted == contents {
+        return true;
+    }
+    if args.diff {
+        print_diff(&contents, &formatted, &name);
+    }
+    if args.check {
+        println!("Would reformat {name}");
+        return false;
+    }
+    if !args.diff
+        && let Err(error) = fs::write(path, &formatted)
+    {
+        eprintln!("Error writing {name}: {error}");
+        return false;
+    }
+    true
+}
+
+fn format_stdin(args: &Fmt, options: &FormatOptions) -> bool {
+    let mut contents = String::new();
+    if let Err(error) = std::io::stdin().read_to_string(&mut contents) {
+        eprintln!("Error reading stdin: {error}");
+        return false;
+    }
+    let name = "<stdin>";
+    let Some(formatted) = format(&contents, name, None, options) else {
+        return false;
+    };
+    if args.diff && formatted != contents {
+        print_diff(&contents, &formatted, name);
+    }
+    if args.check {
+        if formatted != contents {
+            println!("Would reformat {name}");
+            return false;
+        }
+        return true;
+    }
+    if !args.diff {
+        print!("{formatted}");
+        std::io::stdout().flush().ok();
+    }
+    true
+}
+
+/// Format `contents`, with the options of the config file it refers to if any. A relative config
+/// path is resolved from `dir`, or the current directory.
+fn format(
+    contents: &str,
+    name: &str,
+    dir: Option<&Path>,
+    options: &FormatOptions,
+) -> Option<String> {
+    let mut cst = match swon_parol::parse(contents, name) {
+        Ok(cst) => cst,
+        Err(error) => {
+            report_parse_error(&error.error, contents, name);
+            return None;
+        }
+    };
+    let values = match cst.values(contents) {
+        Ok(values) => values,
+        Err(error) => {
+            report_values_error(&error, &cst, contents, name);
+            return None;
+        }
+    };
+    let options = match document_config(&values) {
+        Some(DocumentConfig::Reference(reference)) => {
+            if reference.contains("://") {
+                eprintln!("Error in {name}: config URLs are not supported: {reference}");
+                return None;
+            }
+            let path = dir.unwrap_or(Path::new("")).join(reference);
+            match read_options(&path) {
+                Ok(options) => options,
+                Err(error) => {
+                    eprintln!("Error in {name}: {error}");
+                    return None;
+                }
+            }
+        }
+        _ => options.clone(),
+    };
+    if let Err(error) = fmt_with_options(contents, &mut cst, &options) {
+        eprintln!("Error formatting {name}: {error}");
+        return None;
+    }
+    let mut formatted = String::new();
+    if let Err(error) = cst.write(contents, &mut formatted) {
+        eprintln!("Error writing {name}: {error}");
+        return None;
+    }
+    Some(formatted)
+}
+
+fn read_options(path: &Path) -> Result<FormatOptions, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|error| format!("Error reading config {}: {error}", path.display()))?;
+    FormatOptions::from_swon(&contents)
+        .map_err(|error| format!("Invalid config {}: {error}", path.display()))
+}
+
+fn print_diff(contents: &str, formatted: &str, name: &str) {
+    let diff = TextDiff::from_lines(contents, formatted);
+    print!("{}", diff.unified_diff().header(name, name));
+}
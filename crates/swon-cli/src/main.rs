@@ -1,10 +1,12 @@
+mod convert;
+mod fmt;
+
 use clap::{Args, Parser, Subcommand};
 use std::fs;
+use std::io::IsTerminal as _;
 use swon_fmt::unformat::{unformat, unformat_with_seed};
-use swon_parol::TreeConstruct;
-use swon_parol::grammar::Grammar;
-use swon_parol::parser::parse_into;
-use swon_parol::tree::CstBuilder;
+use swon_parol::{diagnostics::line_column, parol_runtime::ParolError};
+use swon_tree::{Cst, value_visitor::ValueVisitorError};
 
 #[derive(Parser)]
 #[command(name = "swon", about = "SWON file utilities")]
@@ -19,6 +21,10 @@ enum Commands {
     Inspect(Inspect),
     /// Unformat SWON file
     Unformat(Unformat),
+    /// Format SWON files in place
+    Fmt(fmt::Fmt),
+    /// Convert between SWON and JSON, JSON Lines, TOML or YAML
+    Convert(convert::Convert),
 }
 
 #[derive(Args)]
@@ -49,7 +55,13 @@ fn main() {
                 }
             };
 
-            let mut tree = swon_parol::parse(&contents).unwrap();
+            let tree = match swon_parol::parse(&contents, &file) {
+                Ok(tree) => tree,
+                Err(e) => {
+                    report_parse_error(&e.error, &contents, &file);
+                    return;
+                }
+            };
             let mut out = String::new();
             tree.inspect(&contents, &mut out).unwrap();
             println!("{}", out);
@@ -62,7 +74,13 @@ fn main() {
                     return;
                 }
             };
-            let mut tree = swon_parol::parse(&contents).unwrap();
+            let mut tree = match swon_parol::parse(&contents, &file) {
+                Ok(tree) => tree,
+                Err(e) => {
+                    report_parse_error(&e.error, &contents, &file);
+                    return;
+                }
+            };
             if let Some(seed) = seed {
                 unformat_with_seed(&mut tree, seed);
             } else {
@@ -72,5 +90,33 @@ fn main() {
             tree.write(&contents, &mut out).unwrap();
             println!("{}", out);
         }
+        Commands::Fmt(args) => {
+            if !fmt::run(&args) {
+                std::process::exit(1);
+            }
+        }
+        Commands::Convert(args) => {
+            if !convert::run(&args) {
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+fn report_parse_error(error: &ParolError, contents: &str, file: &str) {
+    let color = std::io::stderr().is_terminal();
+    for diagnostic in swon_parol::diagnostics::diagnostics(error, contents) {
+        eprint!("{}", diagnostic.render(contents, file, color));
+    }
+}
+
+/// Report an error of the values of `cst` at the key or the value it is about.
+fn report_values_error(error: &ValueVisitorError, cst: &Cst, contents: &str, file: &str) {
+    match error.token_start(cst) {
+        Some(start) => {
+            let (line, column) = line_column(contents, start);
+            eprintln!("Error in {file}:{line}:{column}: {error}");
+        }
+        None => eprintln!("Error in {file}: {error}"),
     }
 }
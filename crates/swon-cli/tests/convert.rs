@@ -0,0 +1,142 @@
+mod common;
+
+use std::fs;
+
+use common::{stderr, stdout, swon, temp_dir};
+
+#[test]
+fn test_jsonl_to_swon() {
+    let dir = temp_dir("jsonl-to-swon");
+    fs::write(
+        dir.join("data.jsonl"),
+        "{\"a\": 1}\n\n{\"b\": \"x\"}\n{\"c\": }\n{\"d\": [true, null]}\n",
+    )
+    .unwrap();
+
+    // A bad record is reported by line, and the other records are still written
+    let output = swon(&dir, &["convert", "data.jsonl", "-o", "out"], "");
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).starts_with("Error in data.jsonl:4: "),
+        "{}",
+        stderr(&output)
+    );
+    let mut files = fs::read_dir(dir.join("out"))
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect::<Vec<_>>();
+    files.sort();
+    assert_eq!(files, ["1.swon", "2.swon", "4.swon"]);
+    let read = |name: &str| fs::read_to_string(dir.join("out").join(name)).unwrap();
+    assert_eq!(read("1.swon"), "a = 1\n");
+    assert_eq!(read("2.swon"), "b: x\n");
+    assert_eq!(read("4.swon"), "d = [true, null]\n");
+
+    // Records are read from stdin too
+    let output = swon(
+        &dir,
+        &["convert", "--from", "jsonl", "-o", "stdin"],
+        "{\"a\": 1}\n",
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    let file = fs::read_to_string(dir.join("stdin/1.swon")).unwrap();
+    assert_eq!(file, "a = 1\n");
+}
+
+#[test]
+fn test_swon_to_jsonl() {
+    let dir = temp_dir("swon-to-jsonl");
+    fs::write(dir.join("a.swon"), "a = 1\nb {\n  c = \"x\"\n}\n").unwrap();
+    fs::write(dir.join("b.swon"), "d = [true, null]\n").unwrap();
+
+    let output = swon(&dir, &["convert", "--to", "jsonl", "a.swon", "b.swon"], "");
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        stdout(&output),
+        "{\"a\":1,\"b\":{\"c\":\"x\"}}\n{\"d\":[true,null]}\n"
+    );
+}
+
+#[test]
+fn test_detect_formats() {
+    let dir = temp_dir("detect-formats");
+    fs::write(dir.join("a.swon"), "a = 1\n").unwrap();
+    fs::write(dir.join("a.json"), "{\"a\": 1}\n").unwrap();
+
+    // The input format from its extension, and SWON on the other side
+    let output = swon(&dir, &["convert", "a.json"], "");
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "a = 1\n");
+
+    // The output format from its extension
+    let output = swon(&dir, &["convert", "a.swon", "-o", "out.yaml"], "");
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(fs::read_to_string(dir.join("out.yaml")).unwrap(), "a: 1\n");
+
+    let output = swon(&dir, &["convert", "a.swon"], "");
+    assert!(!output.status.success());
+    assert_eq!(
+        stderr(&output),
+        "Can't detect the output format, so it needs --to <FORMAT>\n"
+    );
+    let output = swon(&dir, &["convert", "-o", "out.swon"], "a = 1\n");
+    assert!(!output.status.success());
+    assert_eq!(
+        stderr(&output),
+        "Can't detect the input format, so it needs --from <FORMAT>\n"
+    );
+    let output = swon(&dir, &["convert"], "a = 1\n");
+    assert!(!output.status.success());
+    assert_eq!(
+        stderr(&output),
+        "Can't detect the formats, so it needs --from <FORMAT> or --to <FORMAT>\n"
+    );
+}
+
+#[test]
+fn test_lossy() {
+    let dir = temp_dir("lossy");
+    fs::write(dir.join("a.swon"), "a = 1\nb = (1, 2)\n").unwrap();
+
+    let output = swon(&dir, &["convert", "--to", "json", "a.swon"], "");
+    assert!(!output.status.success());
+    assert_eq!(stdout(&output), "");
+    assert_eq!(
+        stderr(&output),
+        "Error in a.swon:2:1: Tuple is converted to an array\n\
+         a.swon can't be converted to JSON without losing information, pass --allow-lossy to \
+         convert it anyway\n"
+    );
+
+    // A failed conversion leaves no output file behind
+    let output = swon(&dir, &["convert", "a.swon", "-o", "out.json"], "");
+    assert!(!output.status.success());
+    assert!(!dir.join("out.json").exists());
+
+    let output = swon(
+        &dir,
+        &["convert", "a.swon", "-o", "out.json", "--allow-lossy"],
+        "",
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        stderr(&output),
+        "Warning in a.swon:2:1: Tuple is converted to an array\n"
+    );
+    let json = fs::read_to_string(dir.join("out.json")).unwrap();
+    assert_eq!(json, "{\n  \"a\": 1,\n  \"b\": [\n    1,\n    2\n  ]\n}\n");
+}
+
+#[test]
+fn test_swon_errors() {
+    let dir = temp_dir("swon-errors");
+    fs::write(dir.join("a.swon"), "a = 1\na = 2\n").unwrap();
+
+    let output = swon(&dir, &["convert", "a.swon", "-o", "out.json"], "");
+    assert!(!output.status.success());
+    assert_eq!(
+        stderr(&output),
+        "Error in a.swon:2:1: Key is already assigned\n"
+    );
+    assert!(!dir.join("out.json").exists());
+}
@@ -0,0 +1,138 @@
+mod common;
+
+use std::fs;
+
+use common::{stderr, stdout, swon, temp_dir};
+
+const UNFORMATTED: &str = "a  =  1\nb = 2\n";
+const FORMATTED: &str = "a = 1\nb = 2\n";
+
+#[test]
+fn test_fmt_files() {
+    let dir = temp_dir("fmt-files");
+    fs::write(dir.join("a.swon"), UNFORMATTED).unwrap();
+    fs::write(dir.join("b.swon"), FORMATTED).unwrap();
+
+    let output = swon(&dir, &["fmt", "a.swon", "b.swon"], "");
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(fs::read_to_string(dir.join("a.swon")).unwrap(), FORMATTED);
+    assert_eq!(fs::read_to_string(dir.join("b.swon")).unwrap(), FORMATTED);
+}
+
+#[test]
+fn test_fmt_check() {
+    let dir = temp_dir("fmt-check");
+    fs::write(dir.join("a.swon"), UNFORMATTED).unwrap();
+    fs::write(dir.join("b.swon"), FORMATTED).unwrap();
+
+    let output = swon(&dir, &["fmt", "--check", "a.swon", "b.swon"], "");
+    assert!(!output.status.success());
+    assert_eq!(stdout(&output), "Would reformat a.swon\n");
+    assert_eq!(fs::read_to_string(dir.join("a.swon")).unwrap(), UNFORMATTED);
+
+    let output = swon(&dir, &["fmt", "--check", "b.swon"], "");
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "");
+}
+
+#[test]
+fn test_fmt_diff() {
+    let dir = temp_dir("fmt-diff");
+    fs::write(dir.join("a.swon"), UNFORMATTED).unwrap();
+
+    let output = swon(&dir, &["fmt", "--diff", "a.swon"], "");
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "--- a.swon\n+++ a.swon\n@@ -1,2 +1,2 @@\n-a  =  1\n+a = 1\n b = 2\n"
+    );
+    assert_eq!(fs::read_to_string(dir.join("a.swon")).unwrap(), UNFORMATTED);
+}
+
+#[test]
+fn test_fmt_stdin() {
+    let dir = temp_dir("fmt-stdin");
+    let output = swon(&dir, &["fmt", "-"], UNFORMATTED);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), FORMATTED);
+
+    let output = swon(&dir, &["fmt", "--check", "-"], UNFORMATTED);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_fmt_errors() {
+    let dir = temp_dir("fmt-errors");
+    fs::write(dir.join("a.swon"), "a = 1\na  =  2\n").unwrap();
+    fs::write(dir.join("b.swon"), UNFORMATTED).unwrap();
+
+    // Errors are reported by file, and the other files are still formatted
+    let output = swon(&dir, &["fmt", "a.swon", "b.swon"], "");
+    assert!(!output.status.success());
+    assert_eq!(
+        stderr(&output),
+        "Error in a.swon:2:1: Key is already assigned\n"
+    );
+    assert_eq!(fs::read_to_string(dir.join("b.swon")).unwrap(), FORMATTED);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_fmt_directories() {
+    let dir = temp_dir("fmt-directories");
+    fs::create_dir_all(dir.join("root/sub")).unwrap();
+    fs::write(dir.join("root/a.swon"), UNFORMATTED).unwrap();
+    fs::write(dir.join("root/sub/b.swon"), UNFORMATTED).unwrap();
+    fs::write(dir.join("root/notes.txt"), UNFORMATTED).unwrap();
+    // A link to the parent would loop if it was followed
+    std::os::unix::fs::symlink("..", dir.join("root/sub/parent")).unwrap();
+
+    let output = swon(&dir, &["fmt", "--check", "root"], "");
+    assert_eq!(
+        stdout(&output),
+        "Would reformat root/a.swon\nWould reformat root/sub/b.swon\n"
+    );
+
+    let output = swon(&dir, &["fmt", "root"], "");
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        fs::read_to_string(dir.join("root/a.swon")).unwrap(),
+        FORMATTED
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("root/sub/b.swon")).unwrap(),
+        FORMATTED
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("root/notes.txt")).unwrap(),
+        UNFORMATTED
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_fmt_write_error() {
+    use std::os::unix::fs::PermissionsExt as _;
+
+    let dir = temp_dir("fmt-write-error");
+    fs::write(dir.join("a.swon"), UNFORMATTED).unwrap();
+    fs::write(dir.join("b.swon"), UNFORMATTED).unwrap();
+    fs::set_permissions(dir.join("a.swon"), fs::Permissions::from_mode(0o444)).unwrap();
+    if fs::OpenOptions::new()
+        .write(true)
+        .open(dir.join("a.swon"))
+        .is_ok()
+    {
+        // Running as root, which can write read-only files
+        return;
+    }
+
+    let output = swon(&dir, &["fmt", "a.swon", "b.swon"], "");
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).starts_with("Error writing a.swon: "),
+        "{}",
+        stderr(&output)
+    );
+    assert_eq!(fs::read_to_string(dir.join("b.swon")).unwrap(), FORMATTED);
+}
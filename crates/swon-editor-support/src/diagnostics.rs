@@ -1,68 +1,31 @@
 use lsp_types::{Diagnostic, DiagnosticSeverity, Position, Range};
-use swon_parol::parol_runtime::{LexerError, ParolError, ParserError, SyntaxError};
+use swon_parol::diagnostics::{ParseDiagnostic, diagnostics};
+use swon_parol::parol_runtime::ParolError;
 
-/// Convert a ParolError to an LSP Diagnostic
-pub fn error_to_diagnostic(error: &ParolError) -> Vec<Diagnostic> {
-    // The default position is the beginning of the file
-    let position = Position::new(0, 0);
-    let range = Range::new(position, position);
-    let mut message = error.to_string();
-
-    match error {
-        ParolError::ParserError(parser_error) => match parser_error {
-            ParserError::TreeError { .. } => {}
-            ParserError::DataError(_) => {}
-            ParserError::PredictionError { .. } => {}
-            ParserError::SyntaxErrors { entries } => {
-                return entries.iter().map(parse_error_to_diagnostic).collect();
-            }
-            ParserError::UnprocessedInput { .. } => {}
-            ParserError::PopOnEmptyScannerStateStack { .. } => {}
-            ParserError::TooManyErrors { count } => {
-                message = format!("Too many errors: {}", count);
-            }
-            ParserError::RecoveryFailed => {}
-            ParserError::InternalError(_) => {}
-        },
-        ParolError::LexerError(lexer_error) => match lexer_error {
-            LexerError::TokenBufferEmptyError => {}
-            LexerError::InternalError(_) => {}
-            LexerError::LookaheadExceedsMaximum => {}
-            LexerError::LookaheadExceedsTokenBufferLength => {}
-            LexerError::ScannerStackEmptyError => {}
-            LexerError::RecoveryError(_) => {}
-            LexerError::RegexError(_) => {}
-        },
-        ParolError::UserError(_) => {}
-    }
-
-    // Create a diagnostic with the error message
-    vec![Diagnostic {
-        range,
-        severity: Some(DiagnosticSeverity::ERROR),
-        code: None,
-        code_description: None,
-        source: Some("swon".to_string()),
-        message,
-        related_information: None,
-        tags: None,
-        data: None,
-    }]
+/// Convert a ParolError of `text` to LSP Diagnostics
+pub fn error_to_diagnostic(error: &ParolError, text: &str) -> Vec<Diagnostic> {
+    diagnostics(error, text)
+        .iter()
+        .map(|diagnostic| parse_diagnostic_to_lsp(diagnostic, text))
+        .collect()
 }
 
-/// Convert a SyntaxError to an LSP Diagnostic.
-/// Parol line and column are 1-based, but LSP is 0-based.
-pub fn parse_error_to_diagnostic(error: &SyntaxError) -> Diagnostic {
-    let start = Position {
-        line: error.error_location.start_line - 1,
-        character: error.error_location.start_column - 1,
-    };
-    let end = Position {
-        line: error.error_location.end_line - 1,
-        character: error.error_location.end_column - 1,
+/// Convert a ParseDiagnostic to an LSP Diagnostic.
+/// Errors without a location are put at the beginning of the file.
+pub fn parse_diagnostic_to_lsp(diagnostic: &ParseDiagnostic, text: &str) -> Diagnostic {
+    let range = match diagnostic.span {
+        Some(span) => Range::new(
+            position(text, span.start as usize),
+            position(text, span.end as usize),
+        ),
+        None => Range::default(),
     };
-    let range = Range::new(start, end);
-    let message = error.cause.clone();
+    let mut message = diagnostic.message.clone();
+    match diagnostic.expected.as_slice() {
+        [] => {}
+        [expected] => message.push_str(&format!("\nexpected {expected}")),
+        expected => message.push_str(&format!("\nexpected one of {}", expected.join(", "))),
+    }
     Diagnostic {
         range,
         severity: Some(DiagnosticSeverity::ERROR),
@@ -75,3 +38,13 @@ pub fn parse_error_to_diagnostic(error: &SyntaxError) -> Diagnostic {
         data: None,
     }
 }
+
+/// LSP position of a byte offset. LSP lines are 0-based and characters are UTF-16 code units.
+fn position(text: &str, offset: usize) -> Position {
+    let before = &text[..offset.min(text.len())];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Position {
+        line: before.matches('\n').count() as u32,
+        character: before[line_start..].encode_utf16().count() as u32,
+    }
+}
@@ -1,7 +1,8 @@
+use lsp_types::{Position, Range};
+use swon_parol::ParseError;
+use swon_parol::incremental::{TextEdit, reparse};
 use swon_parol::parol_runtime::ParolError;
-use swon_parol::tree::CstBuilder;
-use swon_parol::{TreeConstruct, parser};
-use swon_tree::Cst;
+use swon_tree::{Cst, tree::InputSpan};
 
 pub enum ParseResult {
     Ok(Cst),
@@ -10,16 +11,98 @@ pub enum ParseResult {
 
 /// Parse a document and return a CST
 pub fn parse_document(text: &str) -> ParseResult {
-    let mut actions = swon_parol::grammar::Grammar::new();
-    let mut tree_builder = CstBuilder::new();
+    swon_parol::parse(text, "document.swon").into()
+}
+
+/// Reparse a document after replacing `range` of `previous_text` with `change`, reusing the
+/// unchanged sections of `previous`. Returns the new text and the result.
+pub fn reparse_document(
+    previous: Cst,
+    previous_text: &str,
+    range: Range,
+    change: &str,
+) -> (String, ParseResult) {
+    let edit = TextEdit {
+        range: InputSpan {
+            start: byte_offset(previous_text, range.start) as u32,
+            end: byte_offset(previous_text, range.end) as u32,
+        },
+        text: change.to_string(),
+    };
+    let text = edit.apply(previous_text);
+    let result = reparse(previous, &text, &edit, "document.swon").into();
+    (text, result)
+}
 
-    // Parse the document and capture any error
-    let parse_result = parser::parse_into(text, &mut tree_builder, "document.swon", &mut actions);
+impl From<Result<Cst, Box<ParseError>>> for ParseResult {
+    fn from(result: Result<Cst, Box<ParseError>>) -> Self {
+        match result {
+            Ok(cst) => ParseResult::Ok(cst),
+            Err(error) => {
+                let ParseError { cst, error } = *error;
+                ParseResult::ErrWithCst { cst, error }
+            }
+        }
+    }
+}
+
+/// Byte offset of an LSP position, whose character is in UTF-16 code units.
+fn byte_offset(text: &str, position: Position) -> usize {
+    let line_start = text
+        .split_inclusive('\n')
+        .take(position.line as usize)
+        .map(str::len)
+        .sum::<usize>();
+    let mut units = 0;
+    for (offset, c) in text[line_start..].char_indices() {
+        if units >= position.character as usize || c == '\n' {
+            return line_start + offset;
+        }
+        units += c.len_utf16();
+    }
+    text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(line: u32, character: u32) -> Position {
+        Position { line, character }
+    }
+
+    #[test]
+    fn test_byte_offset() {
+        // `é` is 2 bytes and 1 UTF-16 unit, `😀` 4 bytes and 2 units
+        let text = "a = \"é\"\nb = \"😀x\"\n";
+        assert_eq!(byte_offset(text, position(0, 0)), 0);
+        assert_eq!(byte_offset(text, position(0, 5)), 5);
+        assert_eq!(byte_offset(text, position(0, 6)), 7);
+        assert_eq!(byte_offset(text, position(1, 5)), 14);
+        assert_eq!(byte_offset(text, position(1, 7)), 18);
+        assert_eq!(byte_offset(text, position(1, 8)), 19);
+        // Past the end of a line is its end, and past the last line the end of text
+        assert_eq!(byte_offset(text, position(0, 100)), 8);
+        assert_eq!(byte_offset(text, position(5, 0)), text.len());
+    }
 
-    let cst = tree_builder.build().expect("TreeConstruction never fails");
-    // Handle the result
-    match parse_result {
-        Ok(()) => ParseResult::Ok(cst),
-        Err(err) => ParseResult::ErrWithCst { cst, error: err },
+    #[test]
+    fn test_reparse_document() {
+        let text = "@ a\nb = \"😀x\"\n@ c\nd = \"é\"\n";
+        let ParseResult::Ok(cst) = parse_document(text) else {
+            panic!("the document must parse");
+        };
+        let range = Range {
+            start: position(1, 7),
+            end: position(1, 8),
+        };
+        let (text, result) = reparse_document(cst, text, range, "y");
+        assert_eq!(text, "@ a\nb = \"😀y\"\n@ c\nd = \"é\"\n");
+        let ParseResult::Ok(cst) = result else {
+            panic!("the edited document must parse");
+        };
+        let mut out = String::new();
+        cst.write(&text, &mut out).unwrap();
+        assert_eq!(out, text);
     }
 }
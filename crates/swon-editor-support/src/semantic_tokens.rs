@@ -125,7 +125,7 @@ fn process_terminal(
     // Map terminal kind to semantic token type
     let token_type = match kind {
         TerminalKind::True | TerminalKind::False | TerminalKind::Null => SemanticTokenType::KEYWORD,
-        TerminalKind::Integer => SemanticTokenType::NUMBER,
+        TerminalKind::Integer | TerminalKind::Float => SemanticTokenType::NUMBER,
         TerminalKind::Quote
         | TerminalKind::TypedQuote
         | TerminalKind::InStr
@@ -176,7 +176,9 @@ fn process_terminal(
             return;
         }
         TerminalKind::Ident => SemanticTokenType::PROPERTY,
-        TerminalKind::Dollar => SemanticTokenType::OPERATOR,
+        TerminalKind::Dollar | TerminalKind::LParen | TerminalKind::RParen => {
+            SemanticTokenType::OPERATOR
+        }
         TerminalKind::NewLine
         | TerminalKind::Whitespace
         | TerminalKind::Newline
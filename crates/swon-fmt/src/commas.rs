@@ -0,0 +1,233 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+
+use swon_tree::{action::NodeTarget, prelude::*};
+
+use crate::{Container, ContainerKind, Formatter, Token, TrailingCommas, disable::Frozen};
+
+/// The commas to add and remove.
+#[derive(Default)]
+pub(crate) struct Commas {
+    inserts: Vec<Insert>,
+    /// Comma tokens, and the nodes to delete to remove them
+    removes: Vec<(usize, CstNodeId)>,
+}
+
+struct Insert {
+    /// The last token of the value before the comma
+    after: usize,
+    /// The optional node that holds the comma after the value
+    opt: CstNodeId,
+    /// `None` for objects
+    kinds: Option<&'static ElementKinds>,
+}
+
+impl Commas {
+    /// The commas inserted after each token, for measuring lines before the commands exist.
+    pub(crate) fn planned(&self, tokens: &[Token]) -> (HashMap<usize, NodeTarget>, HashSet<usize>) {
+        let inserted = self
+            .inserts
+            .iter()
+            .map(|insert| (insert.after, tokens[insert.after].target))
+            .collect();
+        (inserted, self.removed())
+    }
+
+    /// Add the commands, and return the inserted commas by the token they follow, and the removed
+    /// comma tokens.
+    pub(crate) fn apply(
+        &self,
+        commands: &mut CstCommands,
+    ) -> (HashMap<usize, NodeTarget>, HashSet<usize>) {
+        for &(_, node) in &self.removes {
+            commands.delete_node(node);
+        }
+        let mut inserted = HashMap::new();
+        for insert in &self.inserts {
+            let terminal = commands.insert_dynamic_terminal(TerminalKind::Comma, ",");
+            let parent = match insert.kinds {
+                None => insert.opt.into(),
+                Some(kinds) => {
+                    let tail = commands.insert_node(insert.opt, dynamic(kinds.tail));
+                    // Inserted nodes become the first child
+                    commands.insert_node(tail, dynamic(kinds.tail_opt));
+                    NodeTarget::from(tail)
+                }
+            };
+            let comma = commands.insert_node(parent, dynamic(NonTerminalKind::Comma));
+            commands.change_parent(terminal, comma);
+            inserted.insert(insert.after, terminal.into());
+        }
+        (inserted, self.removed())
+    }
+
+    /// Keep the commas in the selected gaps, by the index of the token after each gap.
+    pub(crate) fn select(mut self, selection: &RangeInclusive<usize>) -> Self {
+        self.inserts
+            .retain(|insert| selection.contains(&(insert.after + 1)));
+        self.removes.retain(|(token, _)| selection.contains(token));
+        self
+    }
+
+    fn removed(&self) -> HashSet<usize> {
+        self.removes.iter().map(|&(token, _)| token).collect()
+    }
+}
+
+impl Formatter<'_> {
+    /// Plan the commas that [TrailingCommas] asks for. Single-line containers always have commas
+    /// between their entries.
+    pub(crate) fn commas(&self, cst: &Cst, containers: &[Container], frozen: &Frozen) -> Commas {
+        let mut commas = Commas::default();
+        if self.options.trailing_commas == TrailingCommas::Preserve {
+            return commas;
+        }
+        let ranges = self
+            .nodes
+            .iter()
+            .map(|node| (node.id, (node.first, node.last)))
+            .collect::<HashMap<_, _>>();
+        for container in containers {
+            if container.has_error
+                || container.empty
+                || frozen.contains(container.open, container.close)
+            {
+                continue;
+            }
+            let (entries, kinds) = match container.kind {
+                ContainerKind::Block => continue,
+                ContainerKind::Object => (object_entries(cst, container.id), None),
+                ContainerKind::Array => (elements(cst, container.id, &ARRAY), Some(&ARRAY)),
+                ContainerKind::Tuple => (elements(cst, container.id, &TUPLE), Some(&TUPLE)),
+            };
+            let count = entries.len();
+            for (index, entry) in entries.into_iter().enumerate() {
+                let wanted = index + 1 < count
+                    || (container.multi_line
+                        && self.options.trailing_commas == TrailingCommas::MultiLine);
+                match entry.comma {
+                    Some(comma) if !wanted => {
+                        if let Some(&(first, _)) = ranges.get(&comma) {
+                            commas.removes.push((first, entry.removed));
+                        }
+                    }
+                    None if wanted => {
+                        if let Some(&(_, last)) = ranges.get(&entry.value) {
+                            commas.inserts.push(Insert {
+                                after: last,
+                                opt: entry.opt,
+                                kinds,
+                            });
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        commas
+    }
+}
+
+pub(crate) fn dynamic(kind: NonTerminalKind) -> CstNode {
+    CstNode::new_non_terminal(kind, NonTerminalData::Dynamic)
+}
+
+/// An element of an array or tuple, or an entry of an object.
+struct Entry {
+    value: CstNodeId,
+    /// The optional node that holds the comma after the value
+    opt: CstNodeId,
+    /// The comma non-terminal
+    comma: Option<CstNodeId>,
+    /// The node to delete to remove the comma
+    removed: CstNodeId,
+}
+
+struct ElementKinds {
+    opt: NonTerminalKind,
+    elements: NonTerminalKind,
+    elements_opt: NonTerminalKind,
+    tail: NonTerminalKind,
+    tail_opt: NonTerminalKind,
+}
+
+const ARRAY: ElementKinds = ElementKinds {
+    opt: NonTerminalKind::ArrayOpt,
+    elements: NonTerminalKind::ArrayElements,
+    elements_opt: NonTerminalKind::ArrayElementsOpt,
+    tail: NonTerminalKind::ArrayElementsTail,
+    tail_opt: NonTerminalKind::ArrayElementsTailOpt,
+};
+
+const TUPLE: ElementKinds = ElementKinds {
+    opt: NonTerminalKind::TupleOpt,
+    elements: NonTerminalKind::TupleElements,
+    elements_opt: NonTerminalKind::TupleElementsOpt,
+    tail: NonTerminalKind::TupleElementsTail,
+    tail_opt: NonTerminalKind::TupleElementsTailOpt,
+};
+
+fn elements(cst: &Cst, container: CstNodeId, kinds: &ElementKinds) -> Vec<Entry> {
+    let mut entries = vec![];
+    let mut elements =
+        child(cst, container, kinds.opt).and_then(|opt| child(cst, opt, kinds.elements));
+    while let Some(node) = elements {
+        let (Some(value), Some(opt)) = (
+            child(cst, node, NonTerminalKind::Value),
+            child(cst, node, kinds.elements_opt),
+        ) else {
+            break;
+        };
+        let tail = child(cst, opt, kinds.tail);
+        let comma = tail.and_then(|tail| child(cst, tail, NonTerminalKind::Comma));
+        elements = tail
+            .and_then(|tail| child(cst, tail, kinds.tail_opt))
+            .and_then(|tail_opt| child(cst, tail_opt, kinds.elements));
+        entries.push(Entry {
+            value,
+            opt,
+            comma,
+            removed: tail.unwrap_or(opt),
+        });
+    }
+    entries
+}
+
+fn object_entries(cst: &Cst, object: CstNodeId) -> Vec<Entry> {
+    let mut entries = vec![];
+    let mut list = child(cst, object, NonTerminalKind::ObjectList);
+    while let Some(node) = list {
+        let (Some(value), Some(opt)) = (
+            child(cst, node, NonTerminalKind::Value),
+            child(cst, node, NonTerminalKind::ObjectOpt),
+        ) else {
+            break;
+        };
+        let comma = child(cst, opt, NonTerminalKind::Comma);
+        entries.push(Entry {
+            value,
+            opt,
+            comma,
+            removed: comma.unwrap_or(opt),
+        });
+        list = child(cst, node, NonTerminalKind::ObjectList);
+    }
+    entries
+}
+
+fn child(cst: &Cst, node: CstNodeId, kind: NonTerminalKind) -> Option<CstNodeId> {
+    cst.children(node).find(|&child| {
+        matches!(cst.node_data(child), Some(CstNode::NonTerminal { kind: k, .. }) if k == kind)
+    })
+}
+
+pub(crate) fn has_error(cst: &Cst, node: CstNodeId) -> bool {
+    cst.children(node).any(|child| match cst.node_data(child) {
+        Some(CstNode::Terminal {
+            data: TerminalData::Error(_),
+            ..
+        }) => true,
+        Some(CstNode::NonTerminal { .. }) => has_error(cst, child),
+        _ => false,
+    })
+}
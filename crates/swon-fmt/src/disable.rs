@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use swon_tree::{
+    prelude::*,
+    spanned::{SpannedContent, SpannedValue},
+    value_visitor::ValueVisitorError,
+};
+
+use crate::{Formatter, options::is_disabled};
+
+/// Token ranges of the values with `$swon-fmt.disable = true`.
+#[derive(Default)]
+pub(crate) struct Frozen(Vec<(usize, usize)>);
+
+impl Frozen {
+    /// Whether the trivia before the token at `index` is inside a disabled value.
+    pub(crate) fn gap(&self, index: usize) -> bool {
+        self.0
+            .iter()
+            .any(|&(first, last)| first < index && index <= last)
+    }
+
+    pub(crate) fn contains(&self, first: usize, last: usize) -> bool {
+        self.0
+            .iter()
+            .any(|&(start, end)| start <= first && last <= end)
+    }
+}
+
+impl Formatter<'_> {
+    /// Find the disabled values, or `None` if the whole document is disabled.
+    ///
+    /// A document with syntax errors whose values can't be read has no disabled values. Other
+    /// documents whose values can't be read are an error, so that no disabled value is formatted.
+    pub(crate) fn frozen(&self, cst: &Cst) -> Result<Option<Frozen>, ValueVisitorError> {
+        let document = match cst.spanned_values(self.input) {
+            Ok(document) => document,
+            Err(_) if cst.has_errors() => return Ok(Some(Frozen::default())),
+            Err(error) => return Err(error),
+        };
+        if is_disabled(&document.root) {
+            return Ok(None);
+        }
+        let ranges = self
+            .nodes
+            .iter()
+            .map(|node| (node.id, (node.first, node.last)))
+            .collect::<HashMap<_, _>>();
+        let mut frozen = Frozen::default();
+        collect(&document.root, &ranges, false, &mut frozen.0);
+        Ok(Some(frozen))
+    }
+}
+
+/// Collect the ranges from the key to the value of every value in a disabled subtree.
+fn collect(
+    value: &SpannedValue,
+    ranges: &HashMap<CstNodeId, (usize, usize)>,
+    disabled: bool,
+    frozen: &mut Vec<(usize, usize)>,
+) {
+    let disabled = disabled || is_disabled(value);
+    if disabled {
+        let range = [value.key, value.value]
+            .into_iter()
+            .flatten()
+            .filter_map(|location| ranges.get(&location.node))
+            .copied()
+            .reduce(|(first, last), (start, end)| (first.min(start), last.max(end)));
+        frozen.extend(range);
+    }
+    let children = match &value.content {
+        SpannedContent::Map(entries) => entries.iter().map(|(_, value)| value).collect(),
+        SpannedContent::Array(elements) | SpannedContent::Tuple(elements) => {
+            elements.iter().collect()
+        }
+        SpannedContent::Variant(variant) => vec![variant.content.as_ref()],
+        SpannedContent::Value(_) => vec![],
+    };
+    for child in children
+        .into_iter()
+        .chain(value.extensions.iter().map(|(_, value)| value))
+    {
+        collect(child, ranges, disabled, frozen);
+    }
+}
@@ -0,0 +1,232 @@
+use swon_tree::{
+    action::{CommandNodeId, NodeTarget},
+    prelude::*,
+};
+
+use crate::{Bracket, ContainerKind, FormatOptions, Gap, Line, Separator, Token};
+
+/// The trivia that the options and the input ask for.
+pub(crate) struct Style {
+    pub(crate) newline: &'static str,
+    pub(crate) indent: String,
+    /// New lines before a section
+    pub(crate) section_lines: usize,
+}
+
+impl Style {
+    pub(crate) fn new(input: &str, options: &FormatOptions) -> Self {
+        Self {
+            newline: if input.contains("\r\n") { "\r\n" } else { "\n" },
+            indent: options.indent(),
+            section_lines: options.blank_lines_between_sections + 1,
+        }
+    }
+}
+
+fn is_open(token: &Token) -> bool {
+    token.bracket.is_some_and(|bracket| bracket.open)
+}
+
+/// The separator between two tokens when there are no comments between them.
+fn separator(prev: &Token, next: &Token, newline: bool, style: &Style) -> Separator {
+    let after_open = is_open(prev);
+    if let Some(line) = next.line {
+        let (min, max) = match line {
+            _ if after_open => (1, 1),
+            Line::Section => (style.section_lines, style.section_lines),
+            Line::Item => (1, 2),
+            Line::Close => (1, 1),
+        };
+        return Separator::Line {
+            min,
+            max,
+            level: next.level,
+        };
+    }
+    let inside = |bracket: Bracket| {
+        if bracket.kind == ContainerKind::Object && !bracket.empty {
+            Separator::Space
+        } else {
+            Separator::None
+        }
+    };
+    if let Some(bracket) = next.bracket.filter(|bracket| !bracket.open) {
+        return inside(bracket);
+    }
+    if let Some(bracket) = prev.bracket.filter(|bracket| bracket.open) {
+        return inside(bracket);
+    }
+    match (prev.kind, next.kind) {
+        (_, TerminalKind::Comma) => Separator::None,
+        (TerminalKind::Comma, _)
+        | (TerminalKind::Bind, _)
+        | (_, TerminalKind::Bind)
+        | (TerminalKind::At, _)
+        | (_, TerminalKind::LBrace)
+        | (_, TerminalKind::Esc) => Separator::Space,
+        // Keep a string continued on the next line there
+        (TerminalKind::Esc, _) if newline => Separator::Line {
+            min: 1,
+            max: 1,
+            level: next.level + 1,
+        },
+        (TerminalKind::Esc, _) => Separator::Space,
+        _ => Separator::None,
+    }
+}
+
+/// The trivia to put before each comment of the gap, and before `next`.
+pub(crate) fn layout_gap(
+    prev: Option<&Token>,
+    next: Option<&Token>,
+    gap: &Gap,
+    style: &Style,
+) -> Vec<String> {
+    // New lines in the trivia before each segment, plus the one that ends the element before it.
+    // The new line token of a text binding and line comments include their new line.
+    let ends_line = |index: usize| match index {
+        0 => usize::from(prev.is_some_and(|prev| prev.kind == TerminalKind::Newline)),
+        _ => usize::from(gap.ends_line(index - 1)),
+    };
+    let newlines = |index: usize| gap.newlines(index) + ends_line(index);
+    let line = |index: usize, newlines: usize, level: usize| {
+        let mut text = style.newline.repeat(newlines - ends_line(index));
+        text.push_str(&style.indent.repeat(level));
+        text
+    };
+    let last = gap.comments.len();
+    let separator = match (prev, next) {
+        (Some(prev), Some(next)) => separator(prev, next, newlines(last) > 0, style),
+        _ => Separator::None,
+    };
+    let (first_min, first_max) = match separator {
+        Separator::Line { min, max, .. } => (min, max),
+        _ => (1, 1),
+    };
+    // Comments before a closing bracket may have a blank line before them
+    let (comment_min, comment_max) = match next.and_then(|next| next.line) {
+        Some(Line::Close) if !prev.is_some_and(is_open) => (1, 2),
+        _ => (first_min, first_max),
+    };
+    let comment_level = match next {
+        Some(next) if next.line == Some(Line::Close) => next.level + 1,
+        Some(next) => next.level,
+        None => 0,
+    };
+
+    let mut segments = vec![];
+    // Whether a comment has been put on its own line
+    let mut own_line = false;
+    for index in 0..gap.comments.len() {
+        let segment = if prev.is_none() && index == 0 {
+            own_line = true;
+            String::new()
+        } else if index == 0 && newlines(index) == 0 {
+            // A comment at the end of the line of `prev`
+            " ".to_string()
+        } else {
+            let (min, max) = if own_line {
+                (1, 2)
+            } else {
+                (comment_min, comment_max)
+            };
+            own_line = true;
+            line(index, newlines(index).clamp(min, max), comment_level)
+        };
+        segments.push(segment);
+    }
+
+    let segment = match (prev, next) {
+        (None, _) if gap.comments.is_empty() => String::new(),
+        (_, None) => line(last, 1, 0),
+        (_, Some(next)) if !gap.comments.is_empty() => {
+            let (min, max) = match (own_line, next.line) {
+                (true, Some(Line::Close)) => (1, 1),
+                (true, _) => (1, 2),
+                (false, _) => (first_min, first_max),
+            };
+            let level = match separator {
+                Separator::Line { level, .. } => level,
+                _ => next.level,
+            };
+            // A line comment always ends the line
+            line(last, newlines(last).clamp(min.max(1), max.max(1)), level)
+        }
+        (_, Some(_)) => match separator {
+            Separator::None => String::new(),
+            Separator::Space => " ".to_string(),
+            Separator::Line { min, max, level } => {
+                line(last, newlines(last).clamp(min, max), level)
+            }
+        },
+    };
+    segments.push(segment);
+    segments
+}
+
+/// Replace the whitespace and new lines of `gap` with `segments`.
+pub(crate) fn apply_gap(
+    cst: &Cst,
+    commands: &mut CstCommands,
+    next: Option<&Token>,
+    gap: &Gap,
+    segments: &[String],
+) {
+    for (index, (spaces, segment)) in gap.spaces.iter().zip(segments).enumerate() {
+        let existing = spaces
+            .iter()
+            .map(|(_, text)| text.as_str())
+            .collect::<String>();
+        if !gap.forced && existing == *segment {
+            continue;
+        }
+        for &(id, _) in spaces {
+            commands.delete_node(id);
+        }
+        if segment.is_empty() {
+            continue;
+        }
+        let nodes = trivia(commands, segment);
+        let before = match gap.comments.get(index) {
+            Some(&(comment, _)) => Some(NodeTarget::from(comment)),
+            None => next.map(|next| next.target),
+        };
+        match before {
+            Some(NodeTarget::CstNodeId(before)) => {
+                if let Some(parent) = cst.parent(before) {
+                    commands.add_nodes_before(parent, before, nodes);
+                }
+            }
+            Some(NodeTarget::CommandNodeId(_)) => {}
+            None => {
+                // The end of input, after the document and the trailing comments
+                let root = cst.root();
+                let after = cst
+                    .children(root)
+                    .filter(|&child| !spaces.iter().any(|&(id, _)| id == child));
+                if let Some(after) = after.last() {
+                    commands.add_nodes_after(root, after, nodes);
+                }
+            }
+        }
+    }
+}
+
+/// Insert terminals for whitespace and new lines.
+fn trivia(commands: &mut CstCommands, text: &str) -> Vec<CommandNodeId> {
+    let mut nodes = vec![];
+    let mut rest = text;
+    while !rest.is_empty() {
+        let (kind, len) = if rest.starts_with("\r\n") {
+            (TerminalKind::NewLine, 2)
+        } else if rest.starts_with('\n') {
+            (TerminalKind::NewLine, 1)
+        } else {
+            let len = rest.find(['\r', '\n']).unwrap_or(rest.len());
+            (TerminalKind::Whitespace, len)
+        };
+        nodes.push(commands.insert_dynamic_terminal(kind, &rest[..len]));
+        rest = &rest[len..];
+    }
+    nodes
+}
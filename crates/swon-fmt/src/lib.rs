@@ -1,43 +1,783 @@
+mod commas;
+mod disable;
+mod gap;
+mod options;
+mod prefer;
+mod quote;
+mod range;
+#[cfg(test)]
+mod roundtrip;
 #[cfg(any(feature = "unformat", test))]
 pub mod unformat;
+mod width;
 
+use std::collections::{HashMap, HashSet};
 use std::convert::Infallible;
+use std::ops::RangeInclusive;
 
-use swon_tree::prelude::*;
+use swon_tree::{
+    action::{CommandApplyError, NodeTarget},
+    prelude::*,
+    value_visitor::ValueVisitorError,
+};
+use thiserror::Error;
 
-pub fn fmt(input: &str, cst: &mut Cst) {
-    let mut formatter = Formatter::new();
+pub use crate::options::{
+    DocumentConfig, EXTENSION, FormatOptions, IndentStyle, KeyQuoting, MapStyle, OptionsError,
+    TrailingCommas, document_config,
+};
+pub use crate::range::{format_on_type, format_range};
+use crate::{
+    commas::has_error,
+    gap::{Style, apply_gap, layout_gap},
+};
+
+/// Format `cst` of `input` in place with the default options.
+///
+/// Only trivia, commas, the quotes of keys and the form of maps are changed, so comments and
+/// input skipped by error recovery are kept as they are.
+pub fn fmt(input: &str, cst: &mut Cst) -> Result<(), FmtError> {
+    fmt_with_options(input, cst, &FormatOptions::default())
+}
+
+/// Format `cst` of `input` in place.
+///
+/// An inline `$swon-fmt.config` of the document overrides `options`. Values with
+/// `$swon-fmt.disable = true` are left as they are.
+pub fn fmt_with_options(
+    input: &str,
+    cst: &mut Cst,
+    options: &FormatOptions,
+) -> Result<(), FmtError> {
+    let options = document_options(input, cst, options)?;
+    prefer::convert_maps(input, cst, options.map_style)?;
+    if options.key_quoting != KeyQuoting::Preserve {
+        let mut formatter = Formatter::new(input, options.clone());
+        let Ok(_) = formatter.visit_root_handle(cst.root_handle(), cst);
+        formatter.quote_keys(cst)?.apply_to(cst)?;
+    }
+    let mut formatter = Formatter::new(input, options);
     let Ok(_) = formatter.visit_root_handle(cst.root_handle(), cst);
+    formatter.commands(cst)?.apply_to(cst)?;
+    Ok(())
+}
+
+/// `options` with the inline `$swon-fmt.config` of the document merged into them.
+///
+/// The config of a document with syntax errors whose values can't be read is ignored.
+fn document_options(
+    input: &str,
+    cst: &Cst,
+    options: &FormatOptions,
+) -> Result<FormatOptions, FmtError> {
+    let mut options = options.clone();
+    let values = match cst.values(input) {
+        Ok(values) => Some(values),
+        Err(_) if cst.has_errors() => None,
+        Err(error) => return Err(error.into()),
+    };
+    if let Some(DocumentConfig::Inline(config)) = values.as_ref().and_then(document_config) {
+        options.merge(&config)?;
+    }
+    Ok(options)
 }
 
+#[derive(Debug, Error)]
 pub enum FmtError {
-    InvalidWhitespace { id: CstNodeId },
+    #[error(transparent)]
+    CommandApplyError(#[from] CommandApplyError),
+    #[error(transparent)]
+    OptionsError(#[from] OptionsError),
+    /// The values of a document without syntax errors can't be read, like one with duplicate
+    /// keys, so its config and disabled values are unknown.
+    #[error(transparent)]
+    Values(#[from] ValueVisitorError),
+}
+
+/// Collects the tokens and trivia of a tree, and computes the commands to format it.
+pub struct Formatter<'a> {
+    input: &'a str,
+    options: FormatOptions,
+    items: Vec<Item>,
+    /// Indices of the tokens in `items`
+    tokens: Vec<usize>,
+    /// Visited non-terminals with the range of their tokens
+    nodes: Vec<NodeRange>,
+    open: Vec<(CstNodeId, usize)>,
+    /// The gaps to format, by the index of the token after them. All gaps if `None`.
+    selection: Option<RangeInclusive<usize>>,
+}
+
+enum Item {
+    Token {
+        id: CstNodeId,
+        kind: TerminalKind,
+        text: String,
+    },
+    /// Whitespace and new lines
+    Space {
+        id: CstNodeId,
+        text: String,
+    },
+    Comment {
+        id: CstNodeId,
+        text: String,
+    },
+    /// Input skipped by error recovery
+    Error,
+}
+
+struct NodeRange {
+    id: CstNodeId,
+    kind: NonTerminalKind,
+    first: usize,
+    last: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ContainerKind {
+    /// The `{}` of a section binding
+    Block,
+    Object,
+    Array,
+    Tuple,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Container {
+    id: CstNodeId,
+    kind: ContainerKind,
+    open: usize,
+    close: usize,
+    multi_line: bool,
+    empty: bool,
+    has_error: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bracket {
+    kind: ContainerKind,
+    open: bool,
+    empty: bool,
+}
+
+/// Tokens that must start a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Line {
+    /// `@`, which has blank lines before it
+    Section,
+    /// A binding, or an element of a multi-line array, tuple or object
+    Item,
+    /// The closing bracket of a multi-line container
+    Close,
 }
 
-pub struct Formatter {
-    desired_indent: usize,
-    new_line_count: usize,
-    current_indent: usize,
-    errors: Vec<(FmtError, CstCommands)>,
+#[derive(Debug, Clone, Copy)]
+struct Token {
+    target: NodeTarget,
+    kind: TerminalKind,
+    level: usize,
+    line: Option<Line>,
+    bracket: Option<Bracket>,
 }
 
-impl Formatter {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Separator {
+    None,
+    Space,
+    Line {
+        min: usize,
+        max: usize,
+        level: usize,
+    },
+}
+
+/// The trivia between two tokens.
+#[derive(Default)]
+struct Gap {
+    /// Whitespace and new lines before each comment, and after the last one
+    spaces: Vec<Vec<(CstNodeId, String)>>,
+    comments: Vec<(CstNodeId, String)>,
+    has_error: bool,
+    /// A removed comma was in the gap, so the remaining trivia must be rewritten
+    forced: bool,
+}
+
+impl Gap {
     fn new() -> Self {
         Self {
-            desired_indent: 0,
-            new_line_count: 0,
-            current_indent: 0,
-            errors: vec![],
+            spaces: vec![vec![]],
+            ..Default::default()
         }
     }
+
+    fn newlines(&self, index: usize) -> usize {
+        self.spaces[index]
+            .iter()
+            .map(|(_, text)| text.matches('\n').count())
+            .sum()
+    }
+
+    /// Whether the comment at `index` includes the new line after it, like line comments do.
+    fn ends_line(&self, index: usize) -> bool {
+        self.comments[index].1.ends_with(['\r', '\n'])
+    }
 }
 
-impl Default for Formatter {
-    fn default() -> Self {
-        Self::new()
+impl<'a> Formatter<'a> {
+    pub fn new(input: &'a str, options: FormatOptions) -> Self {
+        Self {
+            input,
+            options,
+            items: vec![],
+            tokens: vec![],
+            nodes: vec![],
+            open: vec![],
+            selection: None,
+        }
+    }
+
+    /// Compute the commands that format the visited tree.
+    pub fn commands(&self, cst: &Cst) -> Result<CstCommands, FmtError> {
+        let mut commands = CstCommands::default();
+        let Some(frozen) = self.frozen(cst)? else {
+            return Ok(commands);
+        };
+        let mut containers = self.containers(cst);
+        let (tokens, commas) = loop {
+            let tokens = self.layout(&containers);
+            let commas = self.commas(cst, &containers, &frozen);
+            if !self.break_long_lines(&tokens, &commas, &frozen, &mut containers) {
+                break (tokens, commas);
+            }
+        };
+        let commas = match &self.selection {
+            Some(selection) => commas.select(selection),
+            None => commas,
+        };
+        let (inserted, removed) = commas.apply(&mut commands);
+
+        let style = Style::new(self.input, &self.options);
+        self.walk(&tokens, &inserted, &removed, |prev, next, gap| {
+            let index = next.map_or(tokens.len(), |(index, _)| index);
+            let selected = self
+                .selection
+                .as_ref()
+                .is_none_or(|selection| selection.contains(&index));
+            if !gap.has_error && !frozen.gap(index) && selected {
+                let next = next.map(|(_, next)| next);
+                let segments = layout_gap(prev, next, gap, &style);
+                apply_gap(cst, &mut commands, next, gap, &segments);
+            }
+        });
+        Ok(commands)
+    }
+
+    /// Call `emit` with the trivia before each token that is kept, and before the end of input.
+    /// A comma inserted after a token is the previous token of the next gap.
+    fn walk(
+        &self,
+        tokens: &[Token],
+        inserted: &HashMap<usize, NodeTarget>,
+        removed: &HashSet<usize>,
+        mut emit: impl FnMut(Option<&Token>, Option<(usize, &Token)>, &Gap),
+    ) {
+        let mut prev = None;
+        let mut gap = Gap::new();
+        let mut index = 0;
+        for item in &self.items {
+            match item {
+                Item::Token { .. } => {
+                    let token = tokens[index];
+                    if removed.contains(&index) {
+                        gap.forced = true;
+                    } else {
+                        emit(prev.as_ref(), Some((index, &token)), &gap);
+                        gap = Gap::new();
+                        prev = Some(token);
+                        if let Some(&comma) = inserted.get(&index) {
+                            prev = Some(Token {
+                                target: comma,
+                                kind: TerminalKind::Comma,
+                                line: None,
+                                bracket: None,
+                                ..token
+                            });
+                        }
+                    }
+                    index += 1;
+                }
+                Item::Space { id, text } => {
+                    gap.spaces.last_mut().unwrap().push((*id, text.clone()))
+                }
+                Item::Comment { id, text } => {
+                    gap.comments.push((*id, text.clone()));
+                    gap.spaces.push(vec![]);
+                }
+                Item::Error => gap.has_error = true,
+            }
+        }
+        emit(prev.as_ref(), None, &gap);
+    }
+
+    fn containers(&self, cst: &Cst) -> Vec<Container> {
+        let mut containers = vec![];
+        for node in &self.nodes {
+            let kind = match node.kind {
+                NonTerminalKind::SectionBinding => ContainerKind::Block,
+                NonTerminalKind::Object => ContainerKind::Object,
+                NonTerminalKind::Array => ContainerKind::Array,
+                NonTerminalKind::Tuple => ContainerKind::Tuple,
+                _ => continue,
+            };
+            let (open, close) = (self.tokens[node.first], self.tokens[node.last]);
+            let inner = &self.items[open + 1..close];
+            let has_newline = inner.iter().any(|item| match item {
+                Item::Space { text, .. } => text.contains('\n'),
+                Item::Comment { .. } | Item::Error => true,
+                Item::Token { .. } => false,
+            });
+            let empty = inner.iter().all(|item| matches!(item, Item::Space { .. }));
+            containers.push(Container {
+                id: node.id,
+                kind,
+                open: node.first,
+                close: node.last,
+                multi_line: if kind == ContainerKind::Block {
+                    !empty
+                } else {
+                    has_newline
+                },
+                empty,
+                has_error: inner.iter().any(|item| matches!(item, Item::Error))
+                    || has_error(cst, node.id),
+            });
+        }
+        containers
+    }
+
+    /// Decide the indentation and line breaks of each token.
+    fn layout(&self, containers: &[Container]) -> Vec<Token> {
+        let mut tokens = self
+            .tokens
+            .iter()
+            .map(|&index| {
+                let Item::Token { id, kind, .. } = self.items[index] else {
+                    unreachable!("tokens must point to token items")
+                };
+                Token {
+                    target: id.into(),
+                    kind,
+                    level: 0,
+                    line: None,
+                    bracket: None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut open = HashMap::new();
+        let mut close = HashMap::new();
+        for (index, container) in containers.iter().enumerate() {
+            open.insert(container.open, index);
+            close.insert(container.close, index);
+            let bracket = |open| Bracket {
+                kind: container.kind,
+                open,
+                empty: container.empty,
+            };
+            tokens[container.open].bracket = Some(bracket(true));
+            tokens[container.close].bracket = Some(bracket(false));
+            if container.multi_line {
+                tokens[container.close].line = Some(Line::Close);
+            }
+        }
+
+        // The innermost container of each token, not counting its own brackets
+        let mut enclosing = vec![None; tokens.len()];
+        let mut stack = Vec::<usize>::new();
+        for (index, token) in tokens.iter_mut().enumerate() {
+            if close.contains_key(&index) {
+                stack.pop();
+            }
+            enclosing[index] = stack.last().copied();
+            token.level = stack
+                .iter()
+                .filter(|&&container| containers[container].multi_line)
+                .count();
+            if let Some(&container) = open.get(&index) {
+                stack.push(container);
+            }
+        }
+
+        for node in &self.nodes {
+            let line = match node.kind {
+                NonTerminalKind::Binding => Line::Item,
+                NonTerminalKind::Section => Line::Section,
+                NonTerminalKind::ObjectList
+                | NonTerminalKind::ArrayElements
+                | NonTerminalKind::TupleElements => {
+                    let multi_line =
+                        enclosing[node.first].is_some_and(|c| containers[c].multi_line);
+                    if !multi_line {
+                        continue;
+                    }
+                    Line::Item
+                }
+                _ => continue,
+            };
+            tokens[node.first].line.get_or_insert(line);
+        }
+        tokens
     }
 }
 
-impl<F: CstFacade> CstVisitor<F> for Formatter {
+impl<F: CstFacade> CstVisitor<F> for Formatter<'_> {
     type Error = Infallible;
+
+    fn visit_non_terminal(
+        &mut self,
+        id: CstNodeId,
+        _kind: NonTerminalKind,
+        _data: NonTerminalData,
+        _tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.open.push((id, self.tokens.len()));
+        Ok(())
+    }
+
+    fn visit_non_terminal_close(
+        &mut self,
+        id: CstNodeId,
+        kind: NonTerminalKind,
+        _data: NonTerminalData,
+        _tree: &F,
+    ) -> Result<(), Self::Error> {
+        if let Some((open, first)) = self.open.pop()
+            && open == id
+            && first < self.tokens.len()
+        {
+            self.nodes.push(NodeRange {
+                id,
+                kind,
+                first,
+                last: self.tokens.len() - 1,
+            });
+        }
+        Ok(())
+    }
+
+    fn visit_terminal(
+        &mut self,
+        id: CstNodeId,
+        kind: TerminalKind,
+        data: TerminalData,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        let text = tree
+            .get_str(data, self.input)
+            .unwrap_or_default()
+            .to_string();
+        let item = match (kind, data) {
+            (_, TerminalData::Error(_)) => Item::Error,
+            (TerminalKind::Whitespace | TerminalKind::NewLine, _) => Item::Space { id, text },
+            (TerminalKind::LineComment | TerminalKind::BlockComment, _) => {
+                Item::Comment { id, text }
+            }
+            _ => {
+                self.tokens.push(self.items.len());
+                Item::Token { id, kind, text }
+            }
+        };
+        self.items.push(item);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(input: &str) -> String {
+        format_with(input, &FormatOptions::default())
+    }
+
+    fn format_with(input: &str, options: &FormatOptions) -> String {
+        let mut cst = swon_parol::parse(input, "test.swon").unwrap();
+        fmt_with_options(input, &mut cst, options).unwrap();
+        let mut output = String::new();
+        cst.write(input, &mut output).unwrap();
+        output
+    }
+
+    #[test]
+    fn test_fmt() {
+        let input = r#"   # leading
+
+a=1
+b   =  [1,2,3,]
+c = {x=1 y=2,}
+d = [
+1,2
+  # note
+]
+
+
+
+@ sec
+x=1 # trailing
+# own
+y.z  =  "s"  \\
+"t"  \\  "u"
+@ other   {
+q=( 1,2 )
+t: text here
+e = {  }
+}
+# end"#;
+        let expected = r#"# leading
+
+a = 1
+b = [1, 2, 3]
+c = { x = 1, y = 2 }
+d = [
+  1,
+  2,
+  # note
+]
+
+@ sec
+x = 1 # trailing
+# own
+y.z = "s" \\
+  "t" \\ "u"
+
+@ other {
+  q = (1, 2)
+  t: text here
+  e = {}
+}
+# end
+"#;
+        assert_eq!(format(input), expected);
+        assert_eq!(format(expected), expected);
+    }
+
+    #[test]
+    fn test_fmt_comments() {
+        let input = "a = 1 # c1\n# c2\n\n\n\n# c3\n@ s # hdr\nb = [ # open\n1, # one\n2 # two\n\n\n# last\n]\nc {\n\n# first\nd=1\n}\n";
+        let expected = "a = 1 # c1\n\n# c2\n\n# c3\n@ s # hdr\nb = [ # open\n  1, # one\n  2, # two\n\n  # last\n]\nc {\n  # first\n  d = 1\n}\n";
+        assert_eq!(format(input), expected);
+        assert_eq!(format(expected), expected);
+    }
+
+    #[test]
+    fn test_fmt_keeps_values() {
+        let input = include_str!("../../../example.swon");
+        let mut cst = swon_parol::parse(input, "example.swon").unwrap();
+        let values = cst.values(input).unwrap();
+        fmt(input, &mut cst).unwrap();
+        assert_eq!(cst.values(input).unwrap(), values);
+
+        let mut output = String::new();
+        cst.write(input, &mut output).unwrap();
+        let formatted = swon_parol::parse(&output, "example.swon").unwrap();
+        assert_eq!(formatted.values(&output).unwrap(), values);
+        assert_eq!(format(&output), output);
+    }
+
+    #[test]
+    fn test_fmt_keeps_errors() {
+        let input = "a=1\nb = = 2\nc  =  3\n";
+        let mut cst = swon_parol::parse(input, "test.swon").unwrap_err().cst;
+        fmt(input, &mut cst).unwrap();
+        let mut output = String::new();
+        cst.write(input, &mut output).unwrap();
+        assert_eq!(output, "a = 1\nb = = 2\nc = 3\n");
+    }
+
+    #[test]
+    fn test_fmt_options() {
+        let input = r#"a = [1, 2,]
+long = [[1, 2, 3], { x = "aaaaaaa", y = [4, 5] }, (1, 2)]
+"key" = 1
+"true" = 2
+$ext = 3
+
+
+@ s
+b={
+x=1,
+y=2,
+}
+"#;
+        let options = FormatOptions {
+            indent_style: IndentStyle::Tab,
+            max_width: Some(30),
+            trailing_commas: TrailingCommas::Never,
+            blank_lines_between_sections: 0,
+            key_quoting: KeyQuoting::AsNeeded,
+            ..Default::default()
+        };
+        let expected = "a = [1, 2]\nlong = [\n\t[1, 2, 3],\n\t{\n\t\tx = \"aaaaaaa\",\n\t\ty = [4, 5]\n\t},\n\t(1, 2)\n]\nkey = 1\n\"true\" = 2\n$ext = 3\n@ s\nb = {\n\tx = 1,\n\ty = 2\n}\n";
+        assert_eq!(format_with(input, &options), expected);
+        assert_eq!(format_with(expected, &options), expected);
+
+        let options = FormatOptions {
+            indent_width: 4,
+            key_quoting: KeyQuoting::Always,
+            ..Default::default()
+        };
+        let expected = r#""a" = [1, 2]
+"long" = [[1, 2, 3], { "x" = "aaaaaaa", "y" = [4, 5] }, (1, 2)]
+"key" = 1
+"true" = 2
+$ext = 3
+
+@ "s"
+"b" = {
+    "x" = 1,
+    "y" = 2,
+}
+"#;
+        assert_eq!(format_with(input, &options), expected);
+        assert_eq!(format_with(expected, &options), expected);
+    }
+
+    #[test]
+    fn test_fmt_disable() {
+        let input = "a  =  1\nb {\n$swon-fmt.disable = true\nx=[1,2,]\n}\nc  =  [ 3 ]\nd.e  =  1\nd.$swon-fmt.disable = true\nd.f  =  {x=1}\n";
+        let expected = "a = 1\nb {\n$swon-fmt.disable = true\nx=[1,2,]\n}\nc = [3]\nd.e  =  1\nd.$swon-fmt.disable = true\nd.f  =  {x=1}\n";
+        assert_eq!(format(input), expected);
+
+        let input = "$swon-fmt.disable = true\nb  =  1\n";
+        assert_eq!(format(input), input);
+
+        // Values that can't be read may hide a disabled value, so nothing is formatted
+        let input = "a  =  1\na  =  2\nb {\n$swon-fmt.disable = true\nx=[1,2,]\n}\n";
+        let mut cst = swon_parol::parse(input, "test.swon").unwrap();
+        assert!(matches!(
+            fmt(input, &mut cst),
+            Err(FmtError::Values(ValueVisitorError::DuplicateKey { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_fmt_config() {
+        let input = "$swon-fmt.config = { indent-width = 4, trailing-commas = \"never\" }\nb {\nx=[\n1,2,]\n}\n";
+        let expected = "$swon-fmt.config = { indent-width = 4, trailing-commas = \"never\" }\nb {\n    x = [\n        1,\n        2\n    ]\n}\n";
+        assert_eq!(format(input), expected);
+
+        let input = "$swon-fmt.config = { indent-width = 4 }\nb = !\n";
+        let mut cst = swon_parol::parse(input, "test.swon").unwrap();
+        assert!(matches!(
+            fmt(input, &mut cst),
+            Err(FmtError::Values(ValueVisitorError::Hole { .. }))
+        ));
+
+        let options = FormatOptions::from_swon("indent-style = \"tab\"\nmax-width = 80\n").unwrap();
+        assert_eq!(options.indent_style, IndentStyle::Tab);
+        assert_eq!(options.max_width, Some(80));
+        assert!(matches!(
+            FormatOptions::from_swon("indent-width = \"4\"\n"),
+            Err(OptionsError::InvalidValue {
+                option: "indent-width",
+                ..
+            })
+        ));
+        assert!(matches!(
+            FormatOptions::from_swon("indent = 4\n"),
+            Err(OptionsError::UnknownOption(option)) if option == "indent"
+        ));
+    }
+
+    #[test]
+    fn test_fmt_prefer() {
+        let input = "a = { x = 1 } # on a\na.$prefer.section = true\nb { # in b\ny = 2\n}\nb.$prefer.inline = true\n\n@ c\nz = 3 # on z\n$prefer.inline = false\n";
+        let expected = "a.$prefer.section = true\nb = { # in b\n  y = 2,\n}\nb.$prefer.inline = true\n\nc {\n  z = 3 # on z\n  $prefer.inline = false\n}\n\n@ a\nx = 1 # on a\n";
+        assert_eq!(format(input), expected);
+        assert_eq!(format(expected), expected);
+        assert_values_eq(input, expected);
+    }
+
+    #[test]
+    fn test_fmt_map_style() {
+        let input = "a = 1 # on a\nb { x = 1 }\nc = { y = { z = 2 } }\n\n@ s # on s\nd = { w = 3 }\ne = 4\n";
+        let cases = [
+            (
+                MapStyle::Section,
+                "a = 1 # on a\n\n@ b\nx = 1\n\n@ c\n\n@ c.y\nz = 2\n\n@ s # on s\ne = 4\n\n@ s.d\nw = 3\n",
+            ),
+            (
+                MapStyle::Inline,
+                "a = 1 # on a\nb = { x = 1 }\nc = { y = { z = 2 } }\n\ns = { # on s\n  d = { w = 3 },\n  e = 4,\n}\n",
+            ),
+            (
+                MapStyle::Block,
+                "a = 1 # on a\nb {\n  x = 1\n}\nc {\n  y {\n    z = 2\n  }\n}\n\ns { # on s\n  d {\n    w = 3\n  }\n  e = 4\n}\n",
+            ),
+        ];
+        for (map_style, expected) in cases {
+            let options = FormatOptions {
+                map_style,
+                ..Default::default()
+            };
+            assert_eq!(format_with(input, &options), expected, "{map_style:?}");
+            assert_eq!(format_with(expected, &options), expected, "{map_style:?}");
+            assert_values_eq(input, expected);
+        }
+    }
+
+    #[test]
+    fn test_format_range() {
+        let input = "a  =  1\nb = {x=1 y=2,}\nc  =  3\n";
+        let cst = swon_parol::parse(input, "test.swon").unwrap();
+        let x = input.find('x').unwrap();
+        let edits = format_range(input, &cst, x..x + 1, &FormatOptions::default()).unwrap();
+        assert_eq!(
+            apply_edits(input, &edits),
+            "a  =  1\nb = { x = 1, y = 2 }\nc  =  3\n"
+        );
+        assert!(edits.iter().all(|edit| edit.text.len() <= 2));
+
+        let edits = format_range(input, &cst, 0..input.len(), &FormatOptions::default()).unwrap();
+        assert_eq!(apply_edits(input, &edits), format(input));
+    }
+
+    #[test]
+    fn test_format_on_type() {
+        let input = "a  =  1\nb {\nx=[1,2,]\n}\n";
+        let cst = swon_parol::parse(input, "test.swon").unwrap();
+        let offset = input.rfind('}').unwrap() + 1;
+        let edits = format_on_type(input, &cst, offset, '}', &FormatOptions::default()).unwrap();
+        assert_eq!(
+            apply_edits(input, &edits),
+            "a  =  1\nb {\n  x = [1, 2]\n}\n"
+        );
+
+        let input = "b {\n  x  =  1\ny = 2\n}\n";
+        let cst = swon_parol::parse(input, "test.swon").unwrap();
+        let offset = input.find("y").unwrap();
+        let edits = format_on_type(input, &cst, offset, '\n', &FormatOptions::default()).unwrap();
+        assert_eq!(apply_edits(input, &edits), "b {\n  x  =  1\n  y = 2\n}\n");
+
+        let edits = format_on_type(input, &cst, offset, 'y', &FormatOptions::default()).unwrap();
+        assert!(edits.is_empty());
+    }
+
+    fn apply_edits(input: &str, edits: &[swon_parol::incremental::TextEdit]) -> String {
+        edits
+            .iter()
+            .rev()
+            .fold(input.to_string(), |output, edit| edit.apply(&output))
+    }
+
+    fn assert_values_eq(left: &str, right: &str) {
+        let values = |input| {
+            swon_parol::parse(input, "test.swon")
+                .unwrap()
+                .values(input)
+                .unwrap()
+        };
+        assert_eq!(values(left), values(right));
+    }
 }
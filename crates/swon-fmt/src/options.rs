@@ -0,0 +1,261 @@
+use swon_tree::{
+    spanned::{SpannedContent, SpannedValue},
+    value_visitor::{ValueVisitorError, Values},
+};
+use swon_value::value::{KeyCmpValue, Value};
+use thiserror::Error;
+
+/// The name of the extension that configures the formatter in a document.
+pub const EXTENSION: &str = "swon-fmt";
+
+/// Options of the formatter.
+///
+/// A config file is a SWON document with the options at its root, and a document can embed them
+/// in `$swon-fmt.config`:
+///
+/// ```swon
+/// indent-style = "space"
+/// indent-width = 2
+/// max-width = 100
+/// trailing-commas = "multi-line"
+/// blank-lines-between-sections = 1
+/// key-quoting = "preserve"
+/// map-style = "preserve"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatOptions {
+    pub indent_style: IndentStyle,
+    /// Spaces per level, and the width of a tab when measuring lines
+    pub indent_width: usize,
+    /// Arrays, tuples and objects on a longer line are broken into one element per line
+    pub max_width: Option<usize>,
+    pub trailing_commas: TrailingCommas,
+    pub blank_lines_between_sections: usize,
+    pub key_quoting: KeyQuoting,
+    /// How maps are written, unless they have `$prefer.section` or `$prefer.inline`
+    pub map_style: MapStyle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndentStyle {
+    Space,
+    Tab,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrailingCommas {
+    /// After the last element of multi-line containers only
+    MultiLine,
+    Never,
+    /// Leave every comma as written
+    Preserve,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyQuoting {
+    Preserve,
+    /// Unquote string keys that can be written as identifiers
+    AsNeeded,
+    /// Quote identifier keys. Extension names are never quoted.
+    Always,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapStyle {
+    Preserve,
+    /// `key = { ... }`
+    Inline,
+    /// `key { ... }`
+    Block,
+    /// `@ key`, for maps bound at the top level or in a section
+    Section,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            indent_style: IndentStyle::Space,
+            indent_width: 2,
+            max_width: None,
+            trailing_commas: TrailingCommas::MultiLine,
+            blank_lines_between_sections: 1,
+            key_quoting: KeyQuoting::Preserve,
+            map_style: MapStyle::Preserve,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum OptionsError {
+    #[error(transparent)]
+    Parse(#[from] Box<swon_parol::ParseError>),
+    #[error(transparent)]
+    Value(#[from] ValueVisitorError),
+    #[error("Format options must be a map")]
+    NotAMap,
+    #[error("Unknown format option: {0}")]
+    UnknownOption(String),
+    #[error("Invalid value for format option {option}: expected {expected}")]
+    InvalidValue {
+        option: &'static str,
+        expected: &'static str,
+    },
+}
+
+/// The `$swon-fmt.config` of a document.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DocumentConfig {
+    /// Options to merge into the ones given to the formatter
+    Inline(Value),
+    /// A path or URL of a config file, which is left to the caller to resolve
+    Reference(String),
+}
+
+impl FormatOptions {
+    /// Read options from a SWON config file.
+    pub fn from_swon(input: &str) -> Result<Self, OptionsError> {
+        let cst = swon_parol::parse(input, "swon-fmt.swon")?;
+        Self::from_value(&cst.values(input)?.root)
+    }
+
+    /// Read options from a map. Missing options have their default value.
+    pub fn from_value(value: &Value) -> Result<Self, OptionsError> {
+        let mut options = Self::default();
+        options.merge(value)?;
+        Ok(options)
+    }
+
+    /// Override options with the ones in a map.
+    pub fn merge(&mut self, value: &Value) -> Result<(), OptionsError> {
+        let Value::Map(map) = value else {
+            return Err(OptionsError::NotAMap);
+        };
+        for (key, value) in map.0.iter() {
+            let KeyCmpValue::String(key) = key else {
+                return Err(OptionsError::UnknownOption(format!("{key:?}")));
+            };
+            match key.as_str() {
+                "indent-style" => {
+                    self.indent_style = match value {
+                        Value::String(style) if style == "space" => IndentStyle::Space,
+                        Value::String(style) if style == "tab" => IndentStyle::Tab,
+                        _ => return Err(invalid("indent-style", r#""space" or "tab""#)),
+                    }
+                }
+                "indent-width" => self.indent_width = count(value, "indent-width")?,
+                "max-width" => {
+                    self.max_width = match value {
+                        Value::Null => None,
+                        value => Some(count(value, "max-width")?),
+                    }
+                }
+                "trailing-commas" => {
+                    self.trailing_commas = match value {
+                        Value::String(commas) if commas == "multi-line" => {
+                            TrailingCommas::MultiLine
+                        }
+                        Value::String(commas) if commas == "never" => TrailingCommas::Never,
+                        Value::String(commas) if commas == "preserve" => TrailingCommas::Preserve,
+                        _ => {
+                            return Err(invalid(
+                                "trailing-commas",
+                                r#""multi-line", "never" or "preserve""#,
+                            ));
+                        }
+                    }
+                }
+                "blank-lines-between-sections" => {
+                    self.blank_lines_between_sections =
+                        count(value, "blank-lines-between-sections")?
+                }
+                "key-quoting" => {
+                    self.key_quoting = match value {
+                        Value::String(quoting) if quoting == "preserve" => KeyQuoting::Preserve,
+                        Value::String(quoting) if quoting == "as-needed" => KeyQuoting::AsNeeded,
+                        Value::String(quoting) if quoting == "always" => KeyQuoting::Always,
+                        _ => {
+                            return Err(invalid(
+                                "key-quoting",
+                                r#""preserve", "as-needed" or "always""#,
+                            ));
+                        }
+                    }
+                }
+                "map-style" => {
+                    self.map_style = match value {
+                        Value::String(style) if style == "preserve" => MapStyle::Preserve,
+                        Value::String(style) if style == "inline" => MapStyle::Inline,
+                        Value::String(style) if style == "block" => MapStyle::Block,
+                        Value::String(style) if style == "section" => MapStyle::Section,
+                        _ => {
+                            return Err(invalid(
+                                "map-style",
+                                r#""preserve", "inline", "block" or "section""#,
+                            ));
+                        }
+                    }
+                }
+                _ => return Err(OptionsError::UnknownOption(key.clone())),
+            }
+        }
+        Ok(())
+    }
+
+    /// The text of one indentation level.
+    pub(crate) fn indent(&self) -> String {
+        match self.indent_style {
+            IndentStyle::Space => " ".repeat(self.indent_width),
+            IndentStyle::Tab => "\t".to_string(),
+        }
+    }
+}
+
+fn invalid(option: &'static str, expected: &'static str) -> OptionsError {
+    OptionsError::InvalidValue { option, expected }
+}
+
+fn count(value: &Value, option: &'static str) -> Result<usize, OptionsError> {
+    let count = match value {
+        Value::I64(count) => usize::try_from(*count).ok(),
+        Value::U64(count) => usize::try_from(*count).ok(),
+        _ => None,
+    };
+    count.ok_or_else(|| invalid(option, "a non-negative integer"))
+}
+
+/// Read `$swon-fmt.config` of the document root.
+pub fn document_config(values: &Values) -> Option<DocumentConfig> {
+    let Some(Value::Map(extension)) = values.extensions.get(&[], EXTENSION) else {
+        return None;
+    };
+    let config = extension
+        .0
+        .get(&KeyCmpValue::String("config".to_string()))?;
+    Some(match config {
+        Value::String(reference) => DocumentConfig::Reference(reference.clone()),
+        Value::TypedString(reference) => DocumentConfig::Reference(reference.value.clone()),
+        config => DocumentConfig::Inline(config.clone()),
+    })
+}
+
+/// Whether `$swon-fmt.disable = true` is attached to the value.
+pub(crate) fn is_disabled(value: &SpannedValue) -> bool {
+    extension(value, EXTENSION)
+        .and_then(|extension| entry(extension, "disable"))
+        .is_some_and(|disable| disable.content == SpannedContent::Value(Value::Bool(true)))
+}
+
+pub(crate) fn extension<'a>(value: &'a SpannedValue, name: &str) -> Option<&'a SpannedValue> {
+    value
+        .extensions
+        .iter()
+        .find(|(extension, _)| extension.as_ref() == name)
+        .map(|(_, extension)| extension)
+}
+
+pub(crate) fn entry<'a>(value: &'a SpannedValue, key: &str) -> Option<&'a SpannedValue> {
+    let SpannedContent::Map(entries) = &value.content else {
+        return None;
+    };
+    entries.get(&KeyCmpValue::String(key.to_string()))
+}
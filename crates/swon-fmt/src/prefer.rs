@@ -0,0 +1,928 @@
+use std::collections::{HashMap, HashSet};
+
+use swon_tree::{
+    action::NodeTarget,
+    prelude::*,
+    spanned::{SpannedContent, SpannedValue},
+};
+use swon_value::value::Value;
+
+use crate::{
+    FmtError, MapStyle,
+    commas::dynamic,
+    options::{entry, extension, is_disabled},
+};
+
+/// The name of the extension that chooses how a map is written.
+const PREFER: &str = "prefer";
+
+/// How a map is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Form {
+    /// `key = { ... }`
+    Inline,
+    /// `key { ... }`
+    Block,
+    /// `@ key`
+    Section,
+}
+
+/// Where a binding or section is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Place {
+    /// A binding of the document root
+    Root,
+    /// A binding in the body of a section
+    Body(CstNodeId),
+    /// A section of the document root
+    Section,
+    /// A binding in a section binding
+    Nested,
+}
+
+/// A binding or section that gives a map.
+struct Construct {
+    node: CstNodeId,
+    /// The node of the map, which is the location of its value
+    value: CstNodeId,
+    form: Form,
+    place: Place,
+    /// The list node that holds the construct
+    list: CstNodeId,
+}
+
+/// Rewrite maps between inline objects, section bindings and sections as `$prefer.section`,
+/// `$prefer.inline` and [MapStyle] ask.
+///
+/// Each rewrite is checked to keep the values of the document, and skipped otherwise.
+pub(crate) fn convert_maps(input: &str, cst: &mut Cst, style: MapStyle) -> Result<(), FmtError> {
+    let Ok(values) = cst.values(input) else {
+        return Ok(());
+    };
+    let style = match style {
+        MapStyle::Preserve => None,
+        MapStyle::Inline => Some(Form::Inline),
+        MapStyle::Block => Some(Form::Block),
+        MapStyle::Section => Some(Form::Section),
+    };
+    let mut skipped = HashSet::new();
+    'convert: loop {
+        let Ok(document) = cst.spanned_values(input) else {
+            return Ok(());
+        };
+        let mut preferences = Preferences::default();
+        preferences.collect(&document.root, false);
+        let (sections, others): (Vec<_>, Vec<_>) = constructs(cst)
+            .into_iter()
+            .filter(|construct| {
+                !skipped.contains(&construct.node)
+                    && !preferences.disabled.contains(&construct.value)
+            })
+            .filter_map(|construct| {
+                let target = preferences.forms.get(&construct.value).copied().or(style)?;
+                (target != construct.form).then_some((construct, target))
+            })
+            .partition(|(_, target)| *target == Form::Section);
+        // New sections are inserted before the following ones, so the last binding goes first to
+        // keep them in order
+        for (construct, target) in sections.into_iter().rev().chain(others) {
+            let mut builder = Builder::new(cst, input);
+            if builder.convert(&construct, target).is_some() {
+                let mut converted = cst.clone();
+                builder.commands.apply_to(&mut converted)?;
+                if converted
+                    .values(input)
+                    .is_ok_and(|converted| converted == values)
+                {
+                    *cst = converted;
+                    // Converting a map can make its parent convertible
+                    skipped.clear();
+                    continue 'convert;
+                }
+            }
+            skipped.insert(construct.node);
+        }
+        return Ok(());
+    }
+}
+
+/// Preferred forms and disabled maps by the node of their value.
+#[derive(Default)]
+struct Preferences {
+    forms: HashMap<CstNodeId, Form>,
+    disabled: HashSet<CstNodeId>,
+}
+
+impl Preferences {
+    fn collect(&mut self, value: &SpannedValue, disabled: bool) {
+        let disabled = disabled || is_disabled(value);
+        if let Some(location) = value.value {
+            if disabled {
+                self.disabled.insert(location.node);
+            }
+            if let Some(form) = preferred_form(value) {
+                self.forms.insert(location.node, form);
+            }
+        }
+        let children = match &value.content {
+            SpannedContent::Map(entries) => entries.iter().map(|(_, value)| value).collect(),
+            SpannedContent::Array(elements) | SpannedContent::Tuple(elements) => {
+                elements.iter().collect()
+            }
+            SpannedContent::Variant(variant) => vec![variant.content.as_ref()],
+            SpannedContent::Value(_) => vec![],
+        };
+        for child in children
+            .into_iter()
+            .chain(value.extensions.iter().map(|(_, value)| value))
+        {
+            self.collect(child, disabled);
+        }
+    }
+}
+
+fn preferred_form(value: &SpannedValue) -> Option<Form> {
+    let prefer = extension(value, PREFER)?;
+    let flag = |name| {
+        entry(prefer, name).and_then(|flag| match flag.content {
+            SpannedContent::Value(Value::Bool(flag)) => Some(flag),
+            _ => None,
+        })
+    };
+    match (flag("section"), flag("inline")) {
+        (Some(true), _) => Some(Form::Section),
+        (_, Some(true)) => Some(Form::Inline),
+        (Some(false), _) | (_, Some(false)) => Some(Form::Block),
+        (None, None) => None,
+    }
+}
+
+/// The bindings and sections that give maps, in document order.
+fn constructs(cst: &Cst) -> Vec<Construct> {
+    let mut constructs = vec![];
+    if let Some(swon) = child(cst, cst.root(), NonTerminalKind::Swon) {
+        collect_swon(cst, swon, true, &mut constructs);
+    }
+    constructs
+}
+
+fn collect_swon(cst: &Cst, swon: CstNodeId, root: bool, constructs: &mut Vec<Construct>) {
+    let place = if root { Place::Root } else { Place::Nested };
+    for (list, binding) in items(cst, child(cst, swon, NonTerminalKind::SwonList)) {
+        collect_binding(cst, binding, list, place, constructs);
+    }
+    for (list, section) in items(cst, child(cst, swon, NonTerminalKind::SwonList0)) {
+        if root {
+            constructs.push(Construct {
+                node: section,
+                value: section,
+                form: Form::Section,
+                place: Place::Section,
+                list,
+            });
+        }
+        let Some(body) = child(cst, section, NonTerminalKind::SectionBody) else {
+            continue;
+        };
+        if let Some(block) = child(cst, body, NonTerminalKind::SectionBinding) {
+            if let Some(swon) = child(cst, block, NonTerminalKind::Swon) {
+                collect_swon(cst, swon, false, constructs);
+            }
+            continue;
+        }
+        let place = if root {
+            Place::Body(section)
+        } else {
+            Place::Nested
+        };
+        for (list, binding) in items(cst, child(cst, body, NonTerminalKind::SectionBodyList)) {
+            collect_binding(cst, binding, list, place, constructs);
+        }
+    }
+}
+
+fn collect_binding(
+    cst: &Cst,
+    binding: CstNodeId,
+    list: CstNodeId,
+    place: Place,
+    constructs: &mut Vec<Construct>,
+) {
+    let Some(rhs) = child(cst, binding, NonTerminalKind::BindingRhs) else {
+        return;
+    };
+    let (value, form) = if let Some(block) = child(cst, rhs, NonTerminalKind::SectionBinding) {
+        if let Some(swon) = child(cst, block, NonTerminalKind::Swon) {
+            collect_swon(cst, swon, false, constructs);
+        }
+        (block, Form::Block)
+    } else if let Some(value) = child(cst, rhs, NonTerminalKind::ValueBinding)
+        .and_then(|value_binding| child(cst, value_binding, NonTerminalKind::Value))
+        .filter(|&value| child(cst, value, NonTerminalKind::Object).is_some())
+    {
+        (value, Form::Inline)
+    } else {
+        return;
+    };
+    constructs.push(Construct {
+        node: binding,
+        value,
+        form,
+        place,
+        list,
+    });
+}
+
+/// The list nodes of a list like `SwonList`, and the item each of them holds.
+fn items(cst: &Cst, list: Option<CstNodeId>) -> Vec<(CstNodeId, CstNodeId)> {
+    let mut items = vec![];
+    let mut list = list;
+    while let Some(node) = list {
+        let Some(kind) = non_terminal_kind(cst, node) else {
+            break;
+        };
+        let Some(item) = cst
+            .children(node)
+            .find(|&child| non_terminal_kind(cst, child).is_some_and(|item| item != kind))
+        else {
+            break;
+        };
+        items.push((node, item));
+        list = child(cst, node, kind);
+    }
+    items
+}
+
+fn construct_keys(cst: &Cst, construct: CstNodeId) -> Option<CstNodeId> {
+    child(cst, construct, NonTerminalKind::Keys)
+}
+
+fn child(cst: &Cst, node: CstNodeId, kind: NonTerminalKind) -> Option<CstNodeId> {
+    cst.children(node)
+        .find(|&child| non_terminal_kind(cst, child) == Some(kind))
+}
+
+fn non_terminal_kind(cst: &Cst, node: CstNodeId) -> Option<NonTerminalKind> {
+    match cst.node_data(node)? {
+        CstNode::NonTerminal { kind, .. } => Some(kind),
+        CstNode::Terminal { .. } => None,
+    }
+}
+
+fn terminal_kind(cst: &Cst, node: CstNodeId) -> Option<TerminalKind> {
+    match cst.node_data(node)? {
+        CstNode::Terminal { kind, .. } => Some(kind),
+        CstNode::NonTerminal { .. } => None,
+    }
+}
+
+/// An entry of an inline object.
+struct Entry {
+    key: CstNodeId,
+    bind: CstNodeId,
+    value: CstNodeId,
+    /// Comments of the nodes around the entry that are dropped
+    comments: Vec<NodeTarget>,
+}
+
+/// The parts of an inline object or a section binding.
+struct Braces {
+    begin: CstNodeId,
+    end: CstNodeId,
+    /// `ObjectList` or `Swon`
+    content: CstNodeId,
+}
+
+/// Builds the commands of a rewrite, tracking the children it gives to existing nodes.
+struct Builder<'c> {
+    cst: &'c Cst,
+    input: &'c str,
+    commands: CstCommands,
+    children: HashMap<CstNodeId, Vec<NodeTarget>>,
+}
+
+impl<'c> Builder<'c> {
+    fn new(cst: &'c Cst, input: &'c str) -> Self {
+        Self {
+            cst,
+            input,
+            commands: CstCommands::default(),
+            children: HashMap::new(),
+        }
+    }
+
+    /// Compute the commands that rewrite `construct` into `target`, or `None` if it can't be.
+    fn convert(&mut self, construct: &Construct, target: Form) -> Option<()> {
+        match target {
+            Form::Inline => self.make_inline(construct),
+            Form::Block => self.make_block(construct),
+            Form::Section => self.make_section(construct),
+        }
+    }
+
+    fn make_inline(&mut self, construct: &Construct) -> Option<()> {
+        let node = construct.node;
+        let (braces, closing) = match construct.form {
+            Form::Inline => return None,
+            Form::Block => {
+                let rhs = child(self.cst, node, NonTerminalKind::BindingRhs)?;
+                let block = child(self.cst, rhs, NonTerminalKind::SectionBinding)?;
+                (self.braces(block)?, self.comments(block))
+            }
+            Form::Section => {
+                let body = child(self.cst, node, NonTerminalKind::SectionBody)?;
+                let mut closing = self.comments(node);
+                closing.extend(self.comments(body));
+                match child(self.cst, body, NonTerminalKind::SectionBinding) {
+                    Some(block) => {
+                        closing.extend(self.comments(block));
+                        (self.braces(block)?, closing)
+                    }
+                    None => {
+                        let list = child(self.cst, body, NonTerminalKind::SectionBodyList)?;
+                        let bindings = items(self.cst, Some(list));
+                        let entries = self.binding_entries(&bindings)?;
+                        let begin = self.token(NonTerminalKind::Begin, TerminalKind::LBrace, "{");
+                        closing.push(self.terminal(TerminalKind::RBrace, "}"));
+                        let end = self.node(NonTerminalKind::End, closing);
+                        let object = self.object(begin, entries, end, vec![]);
+                        return self.section_to_binding(construct, object);
+                    }
+                }
+            }
+        };
+        let swon = braces.content;
+        let sections = child(self.cst, swon, NonTerminalKind::SwonList0)?;
+        if !items(self.cst, Some(sections)).is_empty() {
+            return None;
+        }
+        let bindings = items(self.cst, child(self.cst, swon, NonTerminalKind::SwonList));
+        let entries = self.binding_entries(&bindings)?;
+        let mut closing = closing;
+        closing.extend(self.comments(swon));
+        let object = self.object(braces.begin.into(), entries, braces.end.into(), closing);
+        match construct.form {
+            Form::Section => self.section_to_binding(construct, object),
+            _ => {
+                let rhs = child(self.cst, node, NonTerminalKind::BindingRhs)?;
+                let mut children = vec![object];
+                children.extend(self.comments(rhs));
+                self.set_children(rhs, children);
+                Some(())
+            }
+        }
+    }
+
+    fn make_block(&mut self, construct: &Construct) -> Option<()> {
+        let node = construct.node;
+        match construct.form {
+            Form::Block => None,
+            Form::Inline => {
+                let rhs = child(self.cst, node, NonTerminalKind::BindingRhs)?;
+                let value_binding = child(self.cst, rhs, NonTerminalKind::ValueBinding)?;
+                let bind = child(self.cst, value_binding, NonTerminalKind::Bind)?;
+                let value = child(self.cst, value_binding, NonTerminalKind::Value)?;
+                let object = child(self.cst, value, NonTerminalKind::Object)?;
+                let braces = self.braces(object)?;
+                let bindings = self.entry_bindings(braces.content);
+                // Comments before `=` go before `{`
+                let mut begin = self.comments(bind);
+                begin.extend(self.children(braces.begin));
+                self.set_children(braces.begin, begin);
+                let mut end = self.comments(value_binding);
+                end.extend(self.comments(value));
+                end.extend(self.comments(object));
+                end.extend(self.children(braces.end));
+                self.set_children(braces.end, end);
+                let block = self.block(braces.begin.into(), bindings, braces.end.into());
+                let mut children = vec![block];
+                children.extend(self.comments(rhs));
+                self.set_children(rhs, children);
+                Some(())
+            }
+            Form::Section => {
+                let body = child(self.cst, node, NonTerminalKind::SectionBody)?;
+                let block = match child(self.cst, body, NonTerminalKind::SectionBinding) {
+                    Some(block) => block.into(),
+                    None => {
+                        let list = child(self.cst, body, NonTerminalKind::SectionBodyList)?;
+                        let bindings = items(self.cst, Some(list))
+                            .into_iter()
+                            .map(|(_, binding)| binding.into())
+                            .collect();
+                        let begin = self.token(NonTerminalKind::Begin, TerminalKind::LBrace, "{");
+                        let mut end = self.comments(node);
+                        end.extend(self.comments(body));
+                        end.push(self.terminal(TerminalKind::RBrace, "}"));
+                        let end = self.node(NonTerminalKind::End, end);
+                        self.block(begin, bindings, end)
+                    }
+                };
+                self.section_to_binding(construct, block)
+            }
+        }
+    }
+
+    fn make_section(&mut self, construct: &Construct) -> Option<()> {
+        let node = construct.node;
+        let rhs = child(self.cst, node, NonTerminalKind::BindingRhs)?;
+        let mut trailing = self.comments(node);
+        trailing.extend(self.comments(rhs));
+        let body = match construct.form {
+            Form::Section => return None,
+            Form::Inline => {
+                let value_binding = child(self.cst, rhs, NonTerminalKind::ValueBinding)?;
+                let bind = child(self.cst, value_binding, NonTerminalKind::Bind)?;
+                let value = child(self.cst, value_binding, NonTerminalKind::Value)?;
+                let object = child(self.cst, value, NonTerminalKind::Object)?;
+                let braces = self.braces(object)?;
+                for dropped in [bind, value_binding, value, object, braces.begin, braces.end] {
+                    trailing.extend(self.comments(dropped));
+                }
+                let bindings = self.entry_bindings(braces.content);
+                let list = self.list(NonTerminalKind::SectionBodyList, bindings);
+                self.node(NonTerminalKind::SectionBody, [list])
+            }
+            Form::Block => {
+                let block = child(self.cst, rhs, NonTerminalKind::SectionBinding)?;
+                let braces = self.braces(block)?;
+                let sections = child(self.cst, braces.content, NonTerminalKind::SwonList0)?;
+                if items(self.cst, Some(sections)).is_empty() {
+                    for dropped in [block, braces.content, braces.begin, braces.end] {
+                        trailing.extend(self.comments(dropped));
+                    }
+                    let bindings = items(
+                        self.cst,
+                        child(self.cst, braces.content, NonTerminalKind::SwonList),
+                    )
+                    .into_iter()
+                    .map(|(_, binding)| binding.into())
+                    .collect();
+                    let list = self.list(NonTerminalKind::SectionBodyList, bindings);
+                    self.node(NonTerminalKind::SectionBody, [list])
+                } else {
+                    // Sections in the block need the braces
+                    self.node(NonTerminalKind::SectionBody, [block.into()])
+                }
+            }
+        };
+
+        let keys = construct_keys(self.cst, node)?;
+        let trivia = self.leading_trivia(keys)?;
+        // A comment at the end of the line before the binding stays there
+        let previous = match (self.previous_item(construct.list), construct.place) {
+            (Some(previous), _) => Some(previous),
+            (None, Place::Body(section)) => construct_keys(self.cst, section),
+            (None, _) => None,
+        };
+        let leading = match previous {
+            Some(previous) => {
+                let (stay, leading) = self.split_trivia(trivia);
+                self.append_children(previous, stay);
+                leading
+            }
+            None => trivia,
+        };
+        trailing.extend(self.remove_item(construct.list, &leading)?);
+        let keys = match construct.place {
+            Place::Root => keys.into(),
+            Place::Body(section) => {
+                let key = child(self.cst, keys, NonTerminalKind::Key)?;
+                let rest = child(self.cst, keys, NonTerminalKind::KeysList)?;
+                self.join_keys(construct_keys(self.cst, section)?, key, rest)?
+            }
+            Place::Section | Place::Nested => return None,
+        };
+        let mut at = leading;
+        at.push(self.terminal(TerminalKind::At, "@"));
+        let at = self.node(NonTerminalKind::At, at);
+        let mut section = vec![at, keys, body];
+        section.extend(trailing);
+        let section = self.node(NonTerminalKind::Section, section);
+
+        let swon = child(self.cst, self.cst.root(), NonTerminalKind::Swon)?;
+        let next = match construct.place {
+            Place::Body(section) => {
+                let (list, _) = items(self.cst, child(self.cst, swon, NonTerminalKind::SwonList0))
+                    .into_iter()
+                    .find(|&(_, item)| item == section)?;
+                child(self.cst, list, NonTerminalKind::SwonList0)?
+            }
+            _ => child(self.cst, swon, NonTerminalKind::SwonList0)?,
+        };
+        self.insert_item(next, section)
+    }
+
+    /// Replace a section with a binding of `value` at the end of the bindings of the root.
+    fn section_to_binding(&mut self, construct: &Construct, value: NodeTarget) -> Option<()> {
+        let section = construct.node;
+        let keys = construct_keys(self.cst, section)?;
+        let at = child(self.cst, section, NonTerminalKind::At)?;
+        let trivia = self.trivia_before_token(at);
+        let leading = match self.previous_item(construct.list) {
+            Some(previous) => {
+                let (stay, leading) = self.split_trivia(trivia);
+                self.append_children(previous, stay);
+                leading
+            }
+            None => trivia,
+        };
+        let first = self.first_wrapper(keys)?;
+        let trailing = self.remove_item(construct.list, &leading)?;
+        self.prepend_children(first, leading);
+
+        let rhs = self.node(NonTerminalKind::BindingRhs, [value]);
+        let mut binding = vec![keys.into(), rhs];
+        binding.extend(trailing);
+        let binding = self.node(NonTerminalKind::Binding, binding);
+        let swon = child(self.cst, self.cst.root(), NonTerminalKind::Swon)?;
+        let (last, _) = items(self.cst, child(self.cst, swon, NonTerminalKind::SwonList))
+            .last()
+            .copied()
+            .unzip();
+        let end = match last {
+            Some(last) => child(self.cst, last, NonTerminalKind::SwonList)?,
+            None => child(self.cst, swon, NonTerminalKind::SwonList)?,
+        };
+        let tail = self.node(NonTerminalKind::SwonList, []);
+        self.set_children(end, vec![binding, tail]);
+        Some(())
+    }
+
+    /// The `Begin`, `End` and the content of an inline object or a section binding.
+    fn braces(&self, node: CstNodeId) -> Option<Braces> {
+        let begin = child(self.cst, node, NonTerminalKind::Begin)?;
+        let end = child(self.cst, node, NonTerminalKind::End)?;
+        let content = child(self.cst, node, NonTerminalKind::ObjectList)
+            .or_else(|| child(self.cst, node, NonTerminalKind::Swon))?;
+        Some(Braces {
+            begin,
+            end,
+            content,
+        })
+    }
+
+    /// Entries for bindings like `key = value`, or `None` if any binding has another form.
+    fn binding_entries(&self, bindings: &[(CstNodeId, CstNodeId)]) -> Option<Vec<Entry>> {
+        bindings
+            .iter()
+            .map(|&(list, binding)| {
+                let keys = construct_keys(self.cst, binding)?;
+                let key = child(self.cst, keys, NonTerminalKind::Key)?;
+                let keys_list = child(self.cst, keys, NonTerminalKind::KeysList)?;
+                if child(self.cst, keys_list, NonTerminalKind::Key).is_some() {
+                    return None;
+                }
+                let rhs = child(self.cst, binding, NonTerminalKind::BindingRhs)?;
+                let value_binding = child(self.cst, rhs, NonTerminalKind::ValueBinding)?;
+                let mut comments = vec![];
+                for dropped in [list, binding, keys, keys_list, rhs, value_binding] {
+                    comments.extend(self.comments(dropped));
+                }
+                Some(Entry {
+                    key,
+                    bind: child(self.cst, value_binding, NonTerminalKind::Bind)?,
+                    value: child(self.cst, value_binding, NonTerminalKind::Value)?,
+                    comments,
+                })
+            })
+            .collect()
+    }
+
+    /// Bindings for the entries of an `ObjectList`.
+    fn entry_bindings(&mut self, list: CstNodeId) -> Vec<NodeTarget> {
+        let mut bindings = vec![];
+        let mut list = Some(list);
+        while let Some(node) = list {
+            let (Some(key), Some(bind), Some(value), Some(opt)) = (
+                child(self.cst, node, NonTerminalKind::Key),
+                child(self.cst, node, NonTerminalKind::Bind),
+                child(self.cst, node, NonTerminalKind::Value),
+                child(self.cst, node, NonTerminalKind::ObjectOpt),
+            ) else {
+                break;
+            };
+            let mut comments = self.comments(node);
+            comments.extend(self.comments(opt));
+            if let Some(comma) = child(self.cst, opt, NonTerminalKind::Comma) {
+                comments.extend(self.comments(comma));
+            }
+            let keys_list = self.node(NonTerminalKind::KeysList, []);
+            let keys = self.node(NonTerminalKind::Keys, [key.into(), keys_list]);
+            let value_binding =
+                self.node(NonTerminalKind::ValueBinding, [bind.into(), value.into()]);
+            let rhs = self.node(NonTerminalKind::BindingRhs, [value_binding]);
+            let mut binding = vec![keys, rhs];
+            binding.extend(comments);
+            bindings.push(self.node(NonTerminalKind::Binding, binding));
+            list = child(self.cst, node, NonTerminalKind::ObjectList);
+        }
+        bindings
+    }
+
+    /// A `Value` of an inline object. Entries are separated by commas.
+    fn object(
+        &mut self,
+        begin: NodeTarget,
+        entries: Vec<Entry>,
+        end: NodeTarget,
+        closing: Vec<NodeTarget>,
+    ) -> NodeTarget {
+        let mut list = self.node(NonTerminalKind::ObjectList, []);
+        let count = entries.len();
+        for (index, entry) in entries.into_iter().enumerate().rev() {
+            let mut opt = vec![];
+            if index + 1 < count {
+                let comma = self.token(NonTerminalKind::Comma, TerminalKind::Comma, ",");
+                opt.push(comma);
+            }
+            opt.extend(entry.comments);
+            let opt = self.node(NonTerminalKind::ObjectOpt, opt);
+            list = self.node(
+                NonTerminalKind::ObjectList,
+                [
+                    entry.key.into(),
+                    entry.bind.into(),
+                    entry.value.into(),
+                    opt,
+                    list,
+                ],
+            );
+        }
+        let mut object = vec![begin, list, end];
+        object.extend(closing);
+        let object = self.node(NonTerminalKind::Object, object);
+        let value = self.node(NonTerminalKind::Value, [object]);
+        let bind = self.token(NonTerminalKind::Bind, TerminalKind::Bind, "=");
+        self.node(NonTerminalKind::ValueBinding, [bind, value])
+    }
+
+    /// A `SectionBinding` of bindings.
+    fn block(
+        &mut self,
+        begin: NodeTarget,
+        bindings: Vec<NodeTarget>,
+        end: NodeTarget,
+    ) -> NodeTarget {
+        let list = self.list(NonTerminalKind::SwonList, bindings);
+        let sections = self.node(NonTerminalKind::SwonList0, []);
+        let swon = self.node(NonTerminalKind::Swon, [list, sections]);
+        self.node(NonTerminalKind::SectionBinding, [begin, swon, end])
+    }
+
+    /// A list like `SwonList` of `items`.
+    fn list(&mut self, kind: NonTerminalKind, items: Vec<NodeTarget>) -> NodeTarget {
+        let mut list = self.node(kind, []);
+        for item in items.into_iter().rev() {
+            list = self.node(kind, [item, list]);
+        }
+        list
+    }
+
+    /// The keys of `section` followed by `key` and `rest`, copied as new nodes.
+    fn join_keys(
+        &mut self,
+        section: CstNodeId,
+        key: CstNodeId,
+        rest: CstNodeId,
+    ) -> Option<NodeTarget> {
+        let data = self.cst.node_data(section)?;
+        match data {
+            CstNode::Terminal { kind, data } => {
+                if kind.is_builtin_terminal() {
+                    return None;
+                }
+                let text = self.cst.get_str(data, self.input)?.to_string();
+                Some(self.terminal(kind, &text))
+            }
+            CstNode::NonTerminal { kind, .. } => {
+                let children = self.cst.children(section).collect::<Vec<_>>();
+                if kind == NonTerminalKind::KeysList
+                    && child(self.cst, section, NonTerminalKind::Key).is_none()
+                {
+                    let dot = self.token(NonTerminalKind::Dot, TerminalKind::Dot, ".");
+                    return Some(self.node(kind, [dot, key.into(), rest.into()]));
+                }
+                let children = children
+                    .into_iter()
+                    .filter_map(|child| self.join_keys(child, key, rest))
+                    .collect::<Vec<_>>();
+                Some(self.node(kind, children))
+            }
+        }
+    }
+
+    /// Detach the trivia before the first token of `keys`.
+    fn leading_trivia(&mut self, keys: CstNodeId) -> Option<Vec<NodeTarget>> {
+        let wrapper = self.first_wrapper(keys)?;
+        let trivia = self.trivia_before_token(wrapper);
+        let rest = self
+            .children(wrapper)
+            .into_iter()
+            .skip(trivia.len())
+            .collect();
+        self.set_children(wrapper, rest);
+        Some(trivia)
+    }
+
+    fn trivia_before_token(&self, wrapper: CstNodeId) -> Vec<NodeTarget> {
+        self.children(wrapper)
+            .into_iter()
+            .take_while(|&child| self.is_trivia(child))
+            .collect()
+    }
+
+    /// Split a comment that ends the line before, with the whitespace before it.
+    fn split_trivia(&self, trivia: Vec<NodeTarget>) -> (Vec<NodeTarget>, Vec<NodeTarget>) {
+        let mut split = 0;
+        for (index, &node) in trivia.iter().enumerate() {
+            let NodeTarget::CstNodeId(node) = node else {
+                break;
+            };
+            match terminal_kind(self.cst, node) {
+                Some(TerminalKind::Whitespace) => continue,
+                Some(TerminalKind::LineComment | TerminalKind::BlockComment) => {
+                    split = index + 1;
+                }
+                _ => {}
+            }
+            break;
+        }
+        let rest = trivia[split..].to_vec();
+        let mut stay = trivia;
+        stay.truncate(split);
+        (stay, rest)
+    }
+
+    /// The node that holds the first token under `node`.
+    fn first_wrapper(&self, node: CstNodeId) -> Option<CstNodeId> {
+        let mut node = node;
+        loop {
+            let children = self.children(node);
+            let has_token = children.iter().any(|&child| match child {
+                NodeTarget::CstNodeId(child) => {
+                    terminal_kind(self.cst, child).is_some_and(|kind| !kind.is_builtin_terminal())
+                }
+                NodeTarget::CommandNodeId(_) => false,
+            });
+            if has_token {
+                return Some(node);
+            }
+            let NodeTarget::CstNodeId(first) = *children.first()? else {
+                return None;
+            };
+            node = first;
+        }
+    }
+
+    /// The item of the list node before `list`, if it is in the same list.
+    fn previous_item(&self, list: CstNodeId) -> Option<CstNodeId> {
+        let parent = self.cst.parent(list)?;
+        let kind = non_terminal_kind(self.cst, list)?;
+        if non_terminal_kind(self.cst, parent) != Some(kind) {
+            return None;
+        }
+        items(self.cst, Some(parent)).first().map(|&(_, item)| item)
+    }
+
+    /// Remove the item of a list node, keeping the rest of the list, and return the comment at the
+    /// end of its line.
+    ///
+    /// The new lines before the next item are replaced with the ones that were before the removed
+    /// item, given as its `leading` trivia.
+    fn remove_item(&mut self, list: CstNodeId, leading: &[NodeTarget]) -> Option<Vec<NodeTarget>> {
+        let parent = self.cst.parent(list)?;
+        let kind = non_terminal_kind(self.cst, list)?;
+        let tail = child(self.cst, list, kind)?;
+        self.replace_child(parent, list, tail.into());
+
+        let Some(&(_, next)) = items(self.cst, Some(tail)).first() else {
+            return Some(vec![]);
+        };
+        let wrapper = self.first_wrapper(next)?;
+        let trivia = self.trivia_before_token(wrapper);
+        let (stay, _) = self.split_trivia(trivia);
+        let mut children = leading
+            .iter()
+            .map_while(|&node| self.copy_space(node))
+            .collect::<Vec<_>>();
+        children.extend(
+            self.children(wrapper)
+                .into_iter()
+                .skip(stay.len())
+                .skip_while(|&node| self.space(node).is_some()),
+        );
+        self.set_children(wrapper, children);
+        Some(stay)
+    }
+
+    /// The kind and text of a whitespace or new line terminal.
+    fn space(&self, node: NodeTarget) -> Option<(TerminalKind, &'c str)> {
+        let NodeTarget::CstNodeId(node) = node else {
+            return None;
+        };
+        match self.cst.node_data(node)? {
+            CstNode::Terminal {
+                kind: kind @ (TerminalKind::Whitespace | TerminalKind::NewLine),
+                data: data @ (TerminalData::Input(_) | TerminalData::Dynamic(_)),
+            } => Some((kind, self.cst.get_str(data, self.input)?)),
+            _ => None,
+        }
+    }
+
+    fn copy_space(&mut self, node: NodeTarget) -> Option<NodeTarget> {
+        let (kind, text) = self.space(node)?;
+        Some(self.terminal(kind, text))
+    }
+
+    /// Insert an item before the list node `list`.
+    fn insert_item(&mut self, list: CstNodeId, item: NodeTarget) -> Option<()> {
+        let parent = self.cst.parent(list)?;
+        let kind = non_terminal_kind(self.cst, list)?;
+        let node = self.node(kind, [item, list.into()]);
+        self.replace_child(parent, list, node);
+        Some(())
+    }
+
+    /// The comments directly under `node`, which are lost if it is dropped.
+    fn comments(&self, node: CstNodeId) -> Vec<NodeTarget> {
+        self.children(node)
+            .into_iter()
+            .filter(|&child| match child {
+                NodeTarget::CstNodeId(child) => matches!(
+                    terminal_kind(self.cst, child),
+                    Some(TerminalKind::LineComment | TerminalKind::BlockComment)
+                ),
+                NodeTarget::CommandNodeId(_) => false,
+            })
+            .collect()
+    }
+
+    fn is_trivia(&self, node: NodeTarget) -> bool {
+        match node {
+            NodeTarget::CstNodeId(node) => {
+                terminal_kind(self.cst, node).is_some_and(|kind| kind.is_builtin_terminal())
+            }
+            NodeTarget::CommandNodeId(_) => false,
+        }
+    }
+
+    fn children(&self, node: CstNodeId) -> Vec<NodeTarget> {
+        match self.children.get(&node) {
+            Some(children) => children.clone(),
+            None => self.cst.children(node).map(NodeTarget::from).collect(),
+        }
+    }
+
+    fn set_children(&mut self, node: CstNodeId, children: Vec<NodeTarget>) {
+        self.commands.update_children(node, children.clone());
+        self.children.insert(node, children);
+    }
+
+    fn replace_child(&mut self, node: CstNodeId, old: CstNodeId, new: NodeTarget) {
+        let children = self
+            .children(node)
+            .into_iter()
+            .map(|child| if child == old.into() { new } else { child })
+            .collect();
+        self.set_children(node, children);
+    }
+
+    fn append_children(&mut self, node: CstNodeId, nodes: Vec<NodeTarget>) {
+        if !nodes.is_empty() {
+            let mut children = self.children(node);
+            children.extend(nodes);
+            self.set_children(node, children);
+        }
+    }
+
+    fn prepend_children(&mut self, node: CstNodeId, nodes: Vec<NodeTarget>) {
+        if !nodes.is_empty() {
+            let mut children = nodes;
+            children.extend(self.children(node));
+            self.set_children(node, children);
+        }
+    }
+
+    /// A new non-terminal with `children`.
+    fn node(
+        &mut self,
+        kind: NonTerminalKind,
+        children: impl IntoIterator<Item = NodeTarget>,
+    ) -> NodeTarget {
+        // Inserted under the root until it is given its place
+        let node = self.commands.insert_node(self.cst.root(), dynamic(kind));
+        self.commands.update_children(node, children);
+        node.into()
+    }
+
+    fn terminal(&mut self, kind: TerminalKind, text: &str) -> NodeTarget {
+        self.commands.insert_dynamic_terminal(kind, text).into()
+    }
+
+    /// A new token with its non-terminal.
+    fn token(&mut self, wrapper: NonTerminalKind, kind: TerminalKind, text: &str) -> NodeTarget {
+        let terminal = self.terminal(kind, text);
+        self.node(wrapper, [terminal])
+    }
+}
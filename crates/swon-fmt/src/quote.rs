@@ -0,0 +1,143 @@
+use swon_tree::{
+    action::{CommandNodeId, NodeTarget},
+    notation::is_bare_key,
+    prelude::*,
+};
+
+use crate::{FmtError, Formatter, KeyQuoting, commas::dynamic};
+
+impl Formatter<'_> {
+    /// Compute the commands that quote or unquote keys as [KeyQuoting] asks.
+    ///
+    /// The trivia before a key are moved into the new key.
+    pub fn quote_keys(&self, cst: &Cst) -> Result<CstCommands, FmtError> {
+        let mut commands = CstCommands::default();
+        let Some(frozen) = self.frozen(cst)? else {
+            return Ok(commands);
+        };
+        for node in &self.nodes {
+            if node.kind != NonTerminalKind::KeyBase || frozen.contains(node.first, node.last) {
+                continue;
+            }
+            let Some(key) = cst.children(node.id).next() else {
+                continue;
+            };
+            match (self.options.key_quoting, cst.node_data(key)) {
+                (
+                    KeyQuoting::AsNeeded,
+                    Some(CstNode::NonTerminal {
+                        kind: NonTerminalKind::Str,
+                        ..
+                    }),
+                ) => {
+                    if let Some(text) = self.str_text(cst, key)
+                        && is_bare_key(text)
+                    {
+                        unquote(cst, &mut commands, key, text);
+                    }
+                }
+                (
+                    KeyQuoting::Always,
+                    Some(CstNode::NonTerminal {
+                        kind: NonTerminalKind::Ident,
+                        ..
+                    }),
+                ) => quote(cst, &mut commands, key, self.input),
+                _ => {}
+            }
+        }
+        Ok(commands)
+    }
+
+    /// The raw content of a string.
+    fn str_text<'c>(&'c self, cst: &'c Cst, str: CstNodeId) -> Option<&'c str> {
+        let str_opt = cst.children(str).nth(1)?;
+        let in_str = cst.children(str_opt).next()?;
+        let (_, data) = terminal(cst, in_str, TerminalKind::InStr)?;
+        cst.get_str(data, self.input)
+    }
+}
+
+/// Replace a `Str` key with an `Ident` one.
+fn unquote(cst: &Cst, commands: &mut CstCommands, str: CstNodeId, text: &str) {
+    let children = cst.children(str).collect::<Vec<_>>();
+    let [open, str_opt, close] = children[..] else {
+        return;
+    };
+    let Some((quote, _)) = terminal(cst, open, TerminalKind::Quote) else {
+        return;
+    };
+    let mut nodes = trivia(cst, commands, open, quote);
+    nodes.push(
+        commands
+            .insert_dynamic_terminal(TerminalKind::Ident, text)
+            .into(),
+    );
+    commands.add_nodes_after(str, open, nodes);
+    for node in [open, str_opt, close] {
+        commands.delete_node(node);
+    }
+    commands.update_node(str, dynamic(NonTerminalKind::Ident));
+}
+
+/// Replace an `Ident` key with a `Str` one.
+fn quote(cst: &Cst, commands: &mut CstCommands, ident: CstNodeId, input: &str) {
+    let Some((terminal, data)) = terminal(cst, ident, TerminalKind::Ident) else {
+        return;
+    };
+    let Some(text) = cst.get_str(data, input) else {
+        return;
+    };
+    let trivia = trivia(cst, commands, ident, terminal);
+    // Inserted nodes become the first child
+    let quote = (NonTerminalKind::Quote, TerminalKind::Quote, "\"");
+    token(commands, ident.into(), quote);
+    let str_opt = commands.insert_node(ident, dynamic(NonTerminalKind::StrOpt));
+    let in_str = (NonTerminalKind::InStr, TerminalKind::InStr, text);
+    token(commands, str_opt.into(), in_str);
+    let (wrapper, quote) = token(commands, ident.into(), quote);
+    commands.add_nodes_before(wrapper, quote, trivia);
+    commands.delete_node(terminal);
+    commands.update_node(ident, dynamic(NonTerminalKind::Str));
+}
+
+/// Insert a token with its wrapper as the first child of `parent`.
+fn token(
+    commands: &mut CstCommands,
+    parent: NodeTarget,
+    (wrapper, kind, text): (NonTerminalKind, TerminalKind, &str),
+) -> (CommandNodeId, CommandNodeId) {
+    let wrapper = commands.insert_node(parent, dynamic(wrapper));
+    let terminal = commands.insert_dynamic_terminal(kind, text);
+    commands.change_parent(terminal, wrapper);
+    (wrapper, terminal)
+}
+
+/// Detach the trivia before `token` in `wrapper`, to move them into a new node.
+fn trivia(
+    cst: &Cst,
+    commands: &mut CstCommands,
+    wrapper: CstNodeId,
+    token: CstNodeId,
+) -> Vec<NodeTarget> {
+    let trivia = cst
+        .children(wrapper)
+        .take_while(|&child| child != token)
+        .collect::<Vec<_>>();
+    for &node in &trivia {
+        commands.delete_node(node);
+    }
+    trivia.into_iter().map(NodeTarget::from).collect()
+}
+
+fn terminal(
+    cst: &Cst,
+    wrapper: CstNodeId,
+    kind: TerminalKind,
+) -> Option<(CstNodeId, TerminalData)> {
+    cst.children(wrapper)
+        .find_map(|child| match cst.node_data(child)? {
+            CstNode::Terminal { kind: k, data } if k == kind => Some((child, data)),
+            _ => None,
+        })
+}
@@ -0,0 +1,220 @@
+use std::convert::Infallible;
+use std::ops::{Range, RangeInclusive};
+
+use swon_parol::incremental::TextEdit;
+use swon_tree::{prelude::*, tree::InputSpan};
+
+use crate::{FmtError, FormatOptions, Formatter, Item, document_options};
+
+/// Format the bindings and sections that overlap `range`, a byte range of `input`, and return
+/// the edits that do it.
+///
+/// Only trivia and commas are changed, so keys keep their quotes and maps keep their form. The
+/// edits are sorted and don't overlap, and each one replaces only the text that changes.
+pub fn format_range(
+    input: &str,
+    cst: &Cst,
+    range: Range<usize>,
+    options: &FormatOptions,
+) -> Result<Vec<TextEdit>, FmtError> {
+    format_gaps(input, cst, options, |formatter, spans| {
+        let gaps = formatter.gaps_touching(input, spans, &range);
+        formatter.expand_to_items(gaps)
+    })
+}
+
+/// Format after `typed` was inserted just before `offset`, and return the edits that do it.
+///
+/// A closing `}`, `]` or `)` formats the binding or section it ends, and a new line fixes the
+/// indentation of the line it starts. Other characters change nothing.
+pub fn format_on_type(
+    input: &str,
+    cst: &Cst,
+    offset: usize,
+    typed: char,
+    options: &FormatOptions,
+) -> Result<Vec<TextEdit>, FmtError> {
+    match typed {
+        '}' | ']' | ')' => {
+            let range = offset.saturating_sub(typed.len_utf8())..offset;
+            format_range(input, cst, range, options)
+        }
+        '\n' => format_gaps(input, cst, options, |formatter, spans| {
+            formatter.gaps_touching(input, spans, &(offset..offset))
+        }),
+        _ => Ok(vec![]),
+    }
+}
+
+fn format_gaps(
+    input: &str,
+    cst: &Cst,
+    options: &FormatOptions,
+    select: impl FnOnce(&Formatter, &[Range<usize>]) -> RangeInclusive<usize>,
+) -> Result<Vec<TextEdit>, FmtError> {
+    let options = document_options(input, cst, options)?;
+    let mut formatter = Formatter::new(input, options);
+    let Ok(_) = formatter.visit_root_handle(cst.root_handle(), cst);
+    let spans = formatter.token_spans(cst);
+    formatter.selection = Some(select(&formatter, &spans));
+
+    let mut formatted = cst.clone();
+    formatter.commands(cst)?.apply_to(&mut formatted)?;
+    Ok(edits(input, &formatted))
+}
+
+impl Formatter<'_> {
+    /// The byte range of each token. A token that is not from the input gets an empty range after
+    /// the previous one.
+    fn token_spans(&self, cst: &Cst) -> Vec<Range<usize>> {
+        let mut end = 0;
+        self.tokens
+            .iter()
+            .map(|&index| {
+                let Item::Token { id, .. } = self.items[index] else {
+                    unreachable!("tokens must point to token items")
+                };
+                let span = match cst.node_data(id) {
+                    Some(CstNode::Terminal {
+                        data: TerminalData::Input(span),
+                        ..
+                    }) => span.start as usize..span.end as usize,
+                    _ => end..end,
+                };
+                end = span.end;
+                span
+            })
+            .collect()
+    }
+
+    /// The gaps whose trivia touch `range`, by the index of the token after each gap. A range in
+    /// a token selects the gaps around it.
+    fn gaps_touching(
+        &self,
+        input: &str,
+        spans: &[Range<usize>],
+        range: &Range<usize>,
+    ) -> RangeInclusive<usize> {
+        let start = |gap: usize| if gap == 0 { 0 } else { spans[gap - 1].end };
+        let end = |gap: usize| spans.get(gap).map_or(input.len(), |span| span.start);
+        let gaps = 0..=spans.len();
+        let first = gaps
+            .clone()
+            .find(|&gap| end(gap) >= range.start)
+            .unwrap_or(spans.len());
+        let last = gaps.rev().find(|&gap| start(gap) <= range.end).unwrap_or(0);
+        first.min(last)..=first.max(last)
+    }
+
+    /// Extend `gaps` to every gap of the innermost bindings and sections around the tokens at
+    /// both of its ends.
+    fn expand_to_items(&self, gaps: RangeInclusive<usize>) -> RangeInclusive<usize> {
+        let (mut first, mut last) = gaps.into_inner();
+        let innermost = |token: usize| {
+            self.nodes
+                .iter()
+                .filter(|node| {
+                    matches!(
+                        node.kind,
+                        NonTerminalKind::Binding | NonTerminalKind::Section
+                    ) && node.first <= token
+                        && token <= node.last
+                })
+                .min_by_key(|node| node.last - node.first)
+        };
+        if let Some(node) = innermost(first) {
+            first = first.min(node.first);
+        }
+        if let Some(node) = last.checked_sub(1).and_then(innermost) {
+            last = last.max(node.last);
+        }
+        first..=last
+    }
+}
+
+/// The edits that turn `input` into the text of `formatted`, which must keep the terminals from
+/// the input in order.
+fn edits(input: &str, formatted: &Cst) -> Vec<TextEdit> {
+    let mut terminals = Terminals {
+        input,
+        terminals: vec![],
+    };
+    let Ok(_) = terminals.visit_root_handle(formatted.root_handle(), formatted);
+
+    let mut edits = vec![];
+    // The end of the last terminal from the input, and the text written since then
+    let mut kept = 0;
+    let mut written = String::new();
+    for (data, text) in terminals.terminals {
+        match data {
+            TerminalData::Input(span) | TerminalData::Error(span)
+                if span.start as usize >= kept =>
+            {
+                edits.extend(edit(input, kept..span.start as usize, &written));
+                kept = span.end as usize;
+                written.clear();
+            }
+            _ => written.push_str(&text),
+        }
+    }
+    edits.extend(edit(input, kept..input.len(), &written));
+    edits
+}
+
+/// Replace `range` of `input` with `text`, without the parts they have in common.
+fn edit(input: &str, range: Range<usize>, text: &str) -> Option<TextEdit> {
+    let old = &input[range.clone()];
+    if old == text {
+        return None;
+    }
+    let prefix = common_len(old.char_indices(), text.char_indices(), |(index, c)| {
+        index + c.len_utf8()
+    });
+    let (old_rest, text_rest) = (&old[prefix..], &text[prefix..]);
+    let suffix = common_len(
+        old_rest.char_indices().rev(),
+        text_rest.char_indices().rev(),
+        |(index, _)| old_rest.len() - index,
+    );
+    Some(TextEdit {
+        range: InputSpan {
+            start: (range.start + prefix) as u32,
+            end: (range.end - suffix) as u32,
+        },
+        text: text_rest[..text_rest.len() - suffix].to_string(),
+    })
+}
+
+/// The byte length of the common part of two char sequences, measured by `len` on `left`.
+fn common_len(
+    left: impl Iterator<Item = (usize, char)>,
+    right: impl Iterator<Item = (usize, char)>,
+    len: impl Fn((usize, char)) -> usize,
+) -> usize {
+    left.zip(right)
+        .take_while(|((_, left), (_, right))| left == right)
+        .last()
+        .map_or(0, |(left, _)| len(left))
+}
+
+/// Collects the terminals of a tree in order.
+struct Terminals<'a> {
+    input: &'a str,
+    terminals: Vec<(TerminalData, String)>,
+}
+
+impl<F: CstFacade> CstVisitor<F> for Terminals<'_> {
+    type Error = Infallible;
+
+    fn visit_terminal(
+        &mut self,
+        _id: CstNodeId,
+        _kind: TerminalKind,
+        data: TerminalData,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        let text = tree.get_str(data, self.input).unwrap_or_default();
+        self.terminals.push((data, text.to_string()));
+        Ok(())
+    }
+}
@@ -0,0 +1,409 @@
+//! Round-trip property tests: random documents are unformatted and formatted back, which must
+//! keep their values, and formatting the result again must not change it.
+//!
+//! Set `SWON_FMT_SEED` to run one seed and print its documents, or `SWON_FMT_CASES` to change the
+//! number of seeds.
+
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use rand::{Rng as _, SeedableRng as _, rngs::SmallRng, seq::IndexedRandom as _};
+
+use crate::{
+    FormatOptions, IndentStyle, KeyQuoting, MapStyle, TrailingCommas, fmt_with_options,
+    format_range, unformat::unformat_with_seed,
+};
+
+const DEFAULT_CASES: u64 = 200;
+
+/// Generates a random valid document. Every key is new, so bindings never conflict.
+struct Generator {
+    rng: SmallRng,
+    out: String,
+    names: usize,
+}
+
+impl Generator {
+    fn new(seed: u64) -> Self {
+        Self {
+            rng: SmallRng::seed_from_u64(seed),
+            out: String::new(),
+            names: 0,
+        }
+    }
+
+    fn document(mut self) -> String {
+        for _ in 0..self.rng.random_range(0..5) {
+            self.binding(0, 3);
+        }
+        for _ in 0..self.rng.random_range(0..4) {
+            self.comment_line(0);
+            self.out.push_str("\n@ ");
+            self.keys();
+            if self.rng.random_bool(0.2) {
+                self.out.push_str(" {\n");
+                self.swon(1, 2);
+                self.out.push_str("}\n");
+            } else {
+                self.trailing_comment();
+                self.out.push('\n');
+                for _ in 0..self.rng.random_range(0..4) {
+                    self.binding(0, 3);
+                }
+            }
+        }
+        self.out
+    }
+
+    fn swon(&mut self, level: usize, depth: usize) {
+        for _ in 0..self.rng.random_range(0..4) {
+            self.binding(level, depth);
+        }
+        if depth > 0 && self.rng.random_bool(0.2) {
+            self.indent(level);
+            self.out.push_str("@ ");
+            self.keys();
+            self.out.push('\n');
+            for _ in 0..self.rng.random_range(1..3) {
+                self.binding(level, depth - 1);
+            }
+        }
+    }
+
+    fn binding(&mut self, level: usize, depth: usize) {
+        self.comment_line(level);
+        self.indent(level);
+        self.keys();
+        match self.rng.random_range(0..10) {
+            0 => {
+                self.out.push_str(": ");
+                self.text();
+                self.out.push('\n');
+                return;
+            }
+            1 if depth > 0 => {
+                self.out.push_str(" {");
+                self.trailing_comment();
+                self.out.push('\n');
+                self.swon(level + 1, depth - 1);
+                self.indent(level);
+                self.out.push('}');
+            }
+            _ => {
+                self.out.push_str(" = ");
+                self.value(level, depth);
+            }
+        }
+        self.trailing_comment();
+        self.out.push('\n');
+    }
+
+    fn keys(&mut self) {
+        for index in 0..self.rng.random_range(1..=3) {
+            if index > 0 {
+                self.out.push('.');
+            }
+            let name = self.name();
+            match self.rng.random_range(0..8) {
+                0 => self.out.push_str(&format!("\"{name} key\"")),
+                1 => self.out.push_str(&format!("${name}")),
+                2 => self.out.push_str(&format!("{name}[]")),
+                // The first element of a new tuple
+                3 => self.out.push_str(&format!("{name}.0")),
+                _ => self.out.push_str(&name),
+            }
+        }
+    }
+
+    fn name(&mut self) -> String {
+        self.names += 1;
+        let prefix = ["a", "key", "b-c", "日本", "x_y"]
+            .choose(&mut self.rng)
+            .unwrap();
+        format!("{prefix}{}", self.names)
+    }
+
+    fn value(&mut self, level: usize, depth: usize) {
+        let kinds = if depth > 0 { 14 } else { 11 };
+        match self.rng.random_range(0..kinds) {
+            0 => {
+                let integer = ["0", "-42", "+7", "1_000", "0x1F", "0o17", "0b101"];
+                self.push_choice(&integer);
+            }
+            1 => self.push_choice(&["1.5", "-2.5e3", "1e10", "0.0", "inf", "-inf"]),
+            2 => self.push_choice(&["true", "false", "null"]),
+            3..=5 => self.string(),
+            6 => self.push_choice(&["url\"https://swon.dev\"", "path\"a/b\""]),
+            7 => self.push_choice(&["`code`", "rust`let a = 1;`"]),
+            8 => self
+                .out
+                .push_str("```rust\nfn main() {\n    let a = 1;\n}\n```"),
+            9 => {
+                self.out.push('.');
+                let name = self.name();
+                self.out.push_str(&name);
+            }
+            10 => {
+                self.out.push_str("()");
+            }
+            11 => self.elements(level, depth, '[', ']'),
+            12 => self.elements(level, depth, '(', ')'),
+            _ => self.object(level, depth),
+        }
+    }
+
+    fn string(&mut self) {
+        let text = [
+            "",
+            "text",
+            "with space",
+            "\\\"quoted\\\"",
+            "tab\\t",
+            "日本語",
+            "a # b",
+        ];
+        self.out.push('"');
+        self.push_choice(&text);
+        self.out.push('"');
+    }
+
+    fn text(&mut self) {
+        self.push_choice(&["plain text", "text with \"quotes\" and # hash", "日本語"]);
+    }
+
+    fn elements(&mut self, level: usize, depth: usize, open: char, close: char) {
+        let count = self.rng.random_range(if open == '(' { 1 } else { 0 }..4);
+        let multi_line = self.rng.random_bool(0.4);
+        self.out.push(open);
+        for index in 0..count {
+            if multi_line {
+                self.out.push('\n');
+                self.comment_line(level + 1);
+                self.indent(level + 1);
+            } else if index > 0 {
+                self.out.push(' ');
+            }
+            self.value(level + 1, depth - 1);
+            if index + 1 < count || self.rng.random_bool(0.5) {
+                self.out.push(',');
+            }
+            if multi_line {
+                self.trailing_comment();
+            }
+        }
+        if multi_line {
+            self.out.push('\n');
+            self.indent(level);
+        }
+        self.out.push(close);
+    }
+
+    fn object(&mut self, level: usize, depth: usize) {
+        let count = self.rng.random_range(0..4);
+        let multi_line = self.rng.random_bool(0.4);
+        self.out.push('{');
+        for index in 0..count {
+            if multi_line {
+                self.out.push('\n');
+                self.comment_line(level + 1);
+                self.indent(level + 1);
+            } else {
+                self.out.push(' ');
+            }
+            let name = self.name();
+            self.out.push_str(&name);
+            self.out.push_str(" = ");
+            self.value(level + 1, depth - 1);
+            if index + 1 < count || self.rng.random_bool(0.5) {
+                self.out.push(',');
+            }
+            if multi_line {
+                self.trailing_comment();
+            }
+        }
+        if multi_line {
+            self.out.push('\n');
+            self.indent(level);
+        } else if count > 0 {
+            self.out.push(' ');
+        }
+        self.out.push('}');
+    }
+
+    fn comment_line(&mut self, level: usize) {
+        if self.rng.random_bool(0.15) {
+            self.indent(level);
+            self.out.push_str("# comment\n");
+        }
+    }
+
+    fn trailing_comment(&mut self) {
+        if self.rng.random_bool(0.15) {
+            self.out.push_str(" # trailing");
+        }
+    }
+
+    fn indent(&mut self, level: usize) {
+        self.out.push_str(&"  ".repeat(level));
+    }
+
+    fn push_choice(&mut self, choices: &[&str]) {
+        let choice = choices.choose(&mut self.rng).unwrap();
+        self.out.push_str(choice);
+    }
+}
+
+fn options(seed: u64) -> FormatOptions {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    FormatOptions {
+        indent_style: *[IndentStyle::Space, IndentStyle::Tab]
+            .choose(&mut rng)
+            .unwrap(),
+        indent_width: rng.random_range(1..=4),
+        max_width: [None, Some(20), Some(80)]
+            .choose(&mut rng)
+            .copied()
+            .flatten(),
+        trailing_commas: *[
+            TrailingCommas::MultiLine,
+            TrailingCommas::Never,
+            TrailingCommas::Preserve,
+        ]
+        .choose(&mut rng)
+        .unwrap(),
+        blank_lines_between_sections: rng.random_range(0..=2),
+        key_quoting: *[
+            KeyQuoting::Preserve,
+            KeyQuoting::AsNeeded,
+            KeyQuoting::Always,
+        ]
+        .choose(&mut rng)
+        .unwrap(),
+        map_style: *[
+            MapStyle::Preserve,
+            MapStyle::Inline,
+            MapStyle::Block,
+            MapStyle::Section,
+        ]
+        .choose(&mut rng)
+        .unwrap(),
+    }
+}
+
+fn write(cst: &swon_tree::prelude::Cst, input: &str) -> String {
+    let mut output = String::new();
+    cst.write(input, &mut output).unwrap();
+    output
+}
+
+fn format(input: &str, options: &FormatOptions) -> Result<String, String> {
+    let mut cst = swon_parol::parse(input, "roundtrip.swon").map_err(|error| error.to_string())?;
+    fmt_with_options(input, &mut cst, options).map_err(|error| error.to_string())?;
+    Ok(write(&cst, input))
+}
+
+/// Unformat and format the document of `seed`, and describe what went wrong.
+fn check(seed: u64) -> Result<(), String> {
+    let document = Generator::new(seed).document();
+    let options = options(seed);
+    let report = |stage: &str, detail: String| {
+        format!("{stage}\n{options:?}\n--- document\n{document}--- detail\n{detail}")
+    };
+
+    let values = |input: &str| {
+        swon_parol::parse(input, "roundtrip.swon")
+            .map_err(|error| error.to_string())
+            .and_then(|cst| cst.values(input).map_err(|error| error.to_string()))
+    };
+    let expected = values(&document).map_err(|error| report("invalid document", error))?;
+
+    let mut cst = swon_parol::parse(&document, "roundtrip.swon").unwrap();
+    unformat_with_seed(&mut cst, seed);
+    let unformatted = write(&cst, &document);
+    match values(&unformatted) {
+        Ok(values) if values == expected => {}
+        Ok(_) => return Err(report("unformat changed values", unformatted)),
+        Err(error) => {
+            return Err(report(
+                "invalid unformatted",
+                format!("{unformatted}{error}"),
+            ));
+        }
+    }
+
+    let formatted = format(&unformatted, &options)
+        .map_err(|error| report("format failed", format!("{unformatted}{error}")))?;
+    match values(&formatted) {
+        Ok(values) if values == expected => {}
+        Ok(_) => return Err(report("format changed values", formatted)),
+        Err(error) => return Err(report("invalid formatted", format!("{formatted}{error}"))),
+    }
+    // Formatting the whole document as a range changes only trivia and commas
+    let trivia_options = FormatOptions {
+        key_quoting: KeyQuoting::Preserve,
+        map_style: MapStyle::Preserve,
+        ..options.clone()
+    };
+    let cst = swon_parol::parse(&unformatted, "roundtrip.swon").unwrap();
+    let edits = format_range(&unformatted, &cst, 0..unformatted.len(), &trivia_options)
+        .map_err(|error| report("format range failed", format!("{unformatted}{error}")))?;
+    let ranged = edits
+        .iter()
+        .rev()
+        .fold(unformatted.clone(), |output, edit| edit.apply(&output));
+    let expected_ranged = format(&unformatted, &trivia_options)
+        .map_err(|error| report("format failed", format!("{unformatted}{error}")))?;
+    if ranged != expected_ranged {
+        return Err(report(
+            "format range differs",
+            format!("{expected_ranged}--- format range\n{ranged}"),
+        ));
+    }
+
+    let again = format(&formatted, &options)
+        .map_err(|error| report("format failed", format!("{formatted}{error}")))?;
+    if again != formatted {
+        return Err(report(
+            "not idempotent",
+            format!("{formatted}--- formatted again\n{again}"),
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_roundtrip() {
+    let seeds = match std::env::var("SWON_FMT_SEED") {
+        Ok(seed) => {
+            let seed = seed.parse().expect("SWON_FMT_SEED must be a number");
+            println!("{}", Generator::new(seed).document());
+            seed..seed + 1
+        }
+        Err(_) => {
+            let cases = std::env::var("SWON_FMT_CASES")
+                .map(|cases| cases.parse().expect("SWON_FMT_CASES must be a number"))
+                .unwrap_or(DEFAULT_CASES);
+            0..cases
+        }
+    };
+    let mut failures = vec![];
+    for seed in seeds {
+        let result = catch_unwind(AssertUnwindSafe(|| check(seed))).unwrap_or_else(|_| {
+            let document = Generator::new(seed).document();
+            Err(format!(
+                "panicked\n{:?}\n--- document\n{document}",
+                options(seed)
+            ))
+        });
+        if let Err(error) = result {
+            failures.push((seed, error));
+        }
+    }
+    if let Some((seed, error)) = failures.first() {
+        let seeds = failures.iter().map(|(seed, _)| seed).collect::<Vec<_>>();
+        panic!(
+            "round trip failed for seeds {seeds:?}\n\
+             rerun one with SWON_FMT_SEED={seed} cargo test -p swon-fmt test_roundtrip\n\
+             seed {seed}: {error}"
+        );
+    }
+}
@@ -6,7 +6,7 @@ use swon_tree::prelude::*;
 pub fn unformat(tree: &mut Cst) {
     let mut unformatter = Unformatter::new();
     let Ok(_) = tree.visit_from_root(&mut unformatter);
-    unformatter.commands.apply_to(tree).unwrap();
+    unformatter.finish().apply_to(tree).unwrap();
 }
 
 pub fn unformat_with_seed(tree: &mut Cst, seed: u64) {
@@ -15,7 +15,7 @@ pub fn unformat_with_seed(tree: &mut Cst, seed: u64) {
         ..Default::default()
     };
     let Ok(_) = tree.visit_from_root(&mut unformatter);
-    unformatter.commands.apply_to(tree).unwrap();
+    unformatter.finish().apply_to(tree).unwrap();
 }
 
 pub struct Unformatter {
@@ -33,6 +33,12 @@ pub struct Unformatter {
     whitespace_removal_probability: f32,
     /// Current parent non-terminal
     parent: Option<CstNodeId>,
+    /// The previous token
+    previous: Option<TerminalKind>,
+    /// Trivia to remove since the previous token
+    removed: Vec<CstNodeId>,
+    /// Whether something still separates the previous token from the next one
+    separated: bool,
 }
 
 impl Unformatter {
@@ -46,9 +52,20 @@ impl Unformatter {
             line_removal_probability: 0.2,
             whitespace_removal_probability: 0.2,
             parent: None,
+            previous: None,
+            removed: vec![],
+            separated: false,
         }
     }
 
+    /// The commands to unformat the visited tree.
+    pub fn finish(mut self) -> CstCommands {
+        for id in self.removed.drain(..) {
+            self.commands.delete_node(id);
+        }
+        self.commands
+    }
+
     fn random_whitespace(&mut self) -> String {
         let ws_kind = [' ', '\t', '\u{3000}'];
         let ws_kind = ws_kind[self.rng.random_range(0..ws_kind.len())];
@@ -84,6 +101,18 @@ impl<F: CstFacade> CstVisitor<F> for Unformatter {
         Ok(())
     }
 
+    fn visit_text_binding(
+        &mut self,
+        handle: TextBindingHandle,
+        view: TextBindingView,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.no_white_space_here = true;
+        self.visit_text_binding_super(handle, view, tree)?;
+        self.no_white_space_here = false;
+        Ok(())
+    }
+
     fn visit_typed_str(
         &mut self,
         handle: TypedStrHandle,
@@ -129,24 +158,42 @@ impl<F: CstFacade> CstVisitor<F> for Unformatter {
                 })
                 .collect::<Vec<_>>();
             self.commands.add_nodes_before(parent, id, spaces);
+            self.separated = true;
         }
-        if self.rng.random::<f32>() < self.empty_line_probability {
+        if !self.no_white_space_here && self.rng.random::<f32>() < self.empty_line_probability {
             let inserted = self
                 .commands
                 .insert_dynamic_terminal(TerminalKind::NewLine, "\n");
             self.commands.add_nodes_before(parent, id, vec![inserted]);
+            self.separated = true;
+        }
+        // Keep one space if removing the trivia joins two tokens into one, like `true` and `b`
+        let joined = self
+            .previous
+            .is_some_and(|previous| is_word(previous) && joins_word(kind));
+        if !self.separated && joined {
+            self.removed.pop();
         }
+        for id in self.removed.drain(..) {
+            self.commands.delete_node(id);
+        }
+        self.previous = Some(kind);
+        self.separated = false;
         Ok(())
     }
 
     fn visit_whitespace_terminal(
         &mut self,
         terminal: Whitespace,
-        _data: TerminalData,
+        data: TerminalData,
         _tree: &F,
     ) -> Result<(), Self::Error> {
-        if self.rng.random::<f32>() < self.whitespace_removal_probability {
-            self.commands.delete_node(terminal.node_id());
+        if matches!(data, TerminalData::Error(_))
+            || self.rng.random::<f32>() >= self.whitespace_removal_probability
+        {
+            self.separated = true;
+        } else {
+            self.removed.push(terminal.node_id());
         }
         Ok(())
     }
@@ -158,8 +205,55 @@ impl<F: CstFacade> CstVisitor<F> for Unformatter {
         _tree: &F,
     ) -> Result<(), Self::Error> {
         if self.rng.random::<f32>() < self.line_removal_probability {
-            self.commands.delete_node(terminal.node_id());
+            self.removed.push(terminal.node_id());
+        } else {
+            self.separated = true;
         }
         Ok(())
     }
+
+    fn visit_line_comment_terminal(
+        &mut self,
+        _terminal: LineComment,
+        _data: TerminalData,
+        _tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.separated = true;
+        Ok(())
+    }
+
+    fn visit_block_comment_terminal(
+        &mut self,
+        _terminal: BlockComment,
+        _data: TerminalData,
+        _tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.separated = true;
+        Ok(())
+    }
+}
+
+/// Tokens that continue into a following word, like `true` into `trueb` or `1` into `1"a"`.
+fn is_word(kind: TerminalKind) -> bool {
+    matches!(
+        kind,
+        TerminalKind::Integer
+            | TerminalKind::Float
+            | TerminalKind::True
+            | TerminalKind::False
+            | TerminalKind::Null
+            | TerminalKind::Ident
+    )
+}
+
+fn joins_word(kind: TerminalKind) -> bool {
+    is_word(kind)
+        || matches!(
+            kind,
+            TerminalKind::Quote
+                | TerminalKind::TypedQuote
+                | TerminalKind::NamedCode
+                | TerminalKind::Code
+                | TerminalKind::CodeBlock
+        )
 }
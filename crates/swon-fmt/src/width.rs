@@ -0,0 +1,101 @@
+use crate::{
+    Container, ContainerKind, Formatter, Item, Token,
+    commas::Commas,
+    disable::Frozen,
+    gap::{Style, layout_gap},
+};
+
+impl Formatter<'_> {
+    /// Break the outermost single-line container of each line longer than the max width into
+    /// lines. Returns whether any container was broken.
+    pub(crate) fn break_long_lines(
+        &self,
+        tokens: &[Token],
+        commas: &Commas,
+        frozen: &Frozen,
+        containers: &mut [Container],
+    ) -> bool {
+        let Some(max_width) = self.options.max_width else {
+            return false;
+        };
+        let (lines, widths) = self.measure(tokens, commas, frozen);
+        let mut broken = false;
+        for (line, &width) in widths.iter().enumerate() {
+            if width <= max_width {
+                continue;
+            }
+            let outermost = containers
+                .iter_mut()
+                .filter(|container| {
+                    container.kind != ContainerKind::Block
+                        && !container.multi_line
+                        && !container.empty
+                        && !container.has_error
+                        && !frozen.contains(container.open, container.close)
+                        && (lines[container.open]..=lines[container.close]).contains(&line)
+                })
+                .min_by_key(|container| container.open);
+            if let Some(container) = outermost {
+                container.multi_line = true;
+                broken = true;
+            }
+        }
+        broken
+    }
+
+    /// The line of each token and the width of each line, as they would be formatted.
+    fn measure(
+        &self,
+        tokens: &[Token],
+        commas: &Commas,
+        frozen: &Frozen,
+    ) -> (Vec<usize>, Vec<usize>) {
+        let style = Style::new(self.input, &self.options);
+        let (inserted, removed) = commas.planned(tokens);
+        let tab = self.options.indent_width;
+        let mut lines = vec![0; tokens.len()];
+        let mut widths = vec![0];
+        let write = |widths: &mut Vec<usize>, text: &str| {
+            for (index, part) in text.split('\n').enumerate() {
+                if index > 0 {
+                    widths.push(0);
+                }
+                *widths.last_mut().unwrap() += part
+                    .chars()
+                    .map(|c| match c {
+                        '\t' => tab,
+                        '\r' => 0,
+                        _ => 1,
+                    })
+                    .sum::<usize>();
+            }
+        };
+        self.walk(tokens, &inserted, &removed, |prev, next, gap| {
+            let kept = gap.has_error || next.is_some_and(|(index, _)| frozen.gap(index));
+            let segments = if kept {
+                gap.spaces
+                    .iter()
+                    .map(|spaces| spaces.iter().map(|(_, text)| text.as_str()).collect())
+                    .collect()
+            } else {
+                layout_gap(prev, next.map(|(_, next)| next), gap, &style)
+            };
+            for (index, segment) in segments.iter().enumerate() {
+                write(&mut widths, segment);
+                if let Some((_, comment)) = gap.comments.get(index) {
+                    write(&mut widths, comment);
+                }
+            }
+            if let Some((index, _)) = next {
+                lines[index] = widths.len() - 1;
+                if let Item::Token { text, .. } = &self.items[self.tokens[index]] {
+                    write(&mut widths, text);
+                }
+                if inserted.contains_key(&index) {
+                    write(&mut widths, ",");
+                }
+            }
+        });
+        (lines, widths)
+    }
+}
@@ -0,0 +1,586 @@
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use swon_fmt::FmtError;
+use swon_tree::{
+    action::{CommandApplyError, NodeTarget},
+    notation::{escape, is_bare_key, is_text},
+    prelude::*,
+    tree::NonTerminalData,
+};
+use thiserror::Error;
+
+#[derive(Debug, Clone)]
+pub struct FromJsonOptions {
+    /// How deep objects and arrays of objects become sections. `@ a` is at depth 1 and `@ a.b`
+    /// at depth 2. Deeper objects become blocks `key { ... }`, and deeper arrays stay inline.
+    pub max_section_depth: usize,
+    /// Write strings on one line without surrounding whitespace as text bindings `key: text`.
+    pub text_bindings: bool,
+}
+
+impl Default for FromJsonOptions {
+    fn default() -> Self {
+        Self {
+            max_section_depth: 2,
+            text_bindings: true,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum FromJsonError {
+    #[error("The root of a SWON document must be an object")]
+    RootNotObject,
+    #[error(transparent)]
+    CommandApplyError(#[from] CommandApplyError),
+    #[error(transparent)]
+    FmtError(#[from] FmtError),
+}
+
+/// Convert a JSON object to a formatted SWON document.
+///
+/// Objects become sections like `@ a.b` and arrays of objects become one `@ key[]` section per
+/// element, up to [FromJsonOptions::max_section_depth]. Sections are not nested in the sections of
+/// array elements. Keys that are not identifiers are quoted.
+pub fn from_json(json: &JsonValue, options: &FromJsonOptions) -> Result<String, FromJsonError> {
+    let cst = from_json_cst(json, options)?;
+    let mut output = String::new();
+    cst.write("", &mut output)
+        .expect("writing to a string never fails");
+    Ok(output)
+}
+
+/// Build the tree of [from_json]. All of its tokens are dynamic, so its input is `""`.
+pub fn from_json_cst(json: &JsonValue, options: &FromJsonOptions) -> Result<Cst, FromJsonError> {
+    let JsonValue::Object(object) = json else {
+        return Err(FromJsonError::RootNotObject);
+    };
+    let mut cst = swon_parol::parse("", "json.swon").expect("an empty document is valid");
+    let mut builder = Builder {
+        commands: CstCommands::default(),
+        root: cst.root(),
+        options,
+        started: false,
+        line_start: true,
+    };
+    let (bindings, sections) = plan(object, &[], 0, options);
+    let swon = builder.swon(bindings, sections);
+    builder.commands.update_children(cst.root(), [swon]);
+    builder.commands.apply_to(&mut cst)?;
+    swon_fmt::fmt("", &mut cst)?;
+    Ok(cst)
+}
+
+/// A key of a section, with `[]` if it adds an element to an array.
+type SectionKey<'a> = (&'a str, bool);
+
+struct Section<'a> {
+    keys: Vec<SectionKey<'a>>,
+    bindings: Vec<(&'a str, &'a JsonValue)>,
+}
+
+/// Split `object` at `path` into its bindings and the sections after them.
+fn plan<'a>(
+    object: &'a JsonMap<String, JsonValue>,
+    path: &[SectionKey<'a>],
+    depth: usize,
+    options: &FromJsonOptions,
+) -> (Vec<(&'a str, &'a JsonValue)>, Vec<Section<'a>>) {
+    let mut bindings = vec![];
+    let mut sections = vec![];
+    for (key, value) in object {
+        if depth >= options.max_section_depth {
+            bindings.push((key.as_str(), value));
+            continue;
+        }
+        match value {
+            JsonValue::Object(object) if !object.is_empty() => {
+                let mut keys = path.to_vec();
+                keys.push((key, false));
+                let (body, nested) = plan(object, &keys, depth + 1, options);
+                // The nested sections create the map if it has no bindings
+                if !body.is_empty() {
+                    sections.push(Section {
+                        keys,
+                        bindings: body,
+                    });
+                }
+                sections.extend(nested);
+            }
+            JsonValue::Array(elements) if is_array_of_objects(elements) => {
+                let mut keys = path.to_vec();
+                keys.push((key, true));
+                for element in elements {
+                    let JsonValue::Object(element) = element else {
+                        unreachable!("elements must be objects");
+                    };
+                    sections.push(Section {
+                        keys: keys.clone(),
+                        bindings: element
+                            .iter()
+                            .map(|(key, value)| (key.as_str(), value))
+                            .collect(),
+                    });
+                }
+            }
+            value => bindings.push((key, value)),
+        }
+    }
+    (bindings, sections)
+}
+
+fn is_array_of_objects(elements: &[JsonValue]) -> bool {
+    !elements.is_empty()
+        && elements
+            .iter()
+            .all(|element| matches!(element, JsonValue::Object(object) if !object.is_empty()))
+}
+
+/// The trivia before a token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Trivia {
+    None,
+    Space,
+    /// Lines to break, where 2 leaves a blank line
+    Lines(usize),
+}
+
+/// Builds the nodes of a document with [CstCommands].
+struct Builder<'a> {
+    commands: CstCommands,
+    root: CstNodeId,
+    options: &'a FromJsonOptions,
+    /// Whether anything is written yet
+    started: bool,
+    /// Whether the last token ends a line
+    line_start: bool,
+}
+
+impl Builder<'_> {
+    fn swon(&mut self, bindings: Vec<(&str, &JsonValue)>, sections: Vec<Section>) -> NodeTarget {
+        let bindings = bindings
+            .into_iter()
+            .map(|(key, value)| self.binding(key, value))
+            .collect();
+        let bindings = self.list(NonTerminalKind::SwonList, bindings);
+        let sections = sections
+            .into_iter()
+            .map(|section| self.section(section))
+            .collect();
+        let sections = self.list(NonTerminalKind::SwonList0, sections);
+        self.node(NonTerminalKind::Swon, [bindings, sections])
+    }
+
+    fn section(&mut self, section: Section) -> NodeTarget {
+        let before = self.line_break(2);
+        let at = self.token(NonTerminalKind::At, TerminalKind::At, "@", before);
+        let keys = self.keys(&section.keys, Trivia::Space);
+        let bindings = section
+            .bindings
+            .into_iter()
+            .map(|(key, value)| self.binding(key, value))
+            .collect();
+        let bindings = self.list(NonTerminalKind::SectionBodyList, bindings);
+        let body = self.node(NonTerminalKind::SectionBody, [bindings]);
+        self.node(NonTerminalKind::Section, [at, keys, body])
+    }
+
+    fn binding(&mut self, key: &str, value: &JsonValue) -> NodeTarget {
+        let before = self.line_break(1);
+        let keys = self.keys(&[(key, false)], before);
+        let rhs = match value {
+            JsonValue::String(text) if self.options.text_bindings && is_text(text) => {
+                let start = self.token(
+                    NonTerminalKind::TextStart,
+                    TerminalKind::TextStart,
+                    ":",
+                    Trivia::None,
+                );
+                let opt = self.node(NonTerminalKind::TextBindingOpt, []);
+                let text = format!(" {text}");
+                let text = self.token(
+                    NonTerminalKind::Text,
+                    TerminalKind::Text,
+                    &text,
+                    Trivia::None,
+                );
+                let newline = self.token(
+                    NonTerminalKind::Newline,
+                    TerminalKind::Newline,
+                    "\n",
+                    Trivia::None,
+                );
+                self.line_start = true;
+                self.node(NonTerminalKind::TextBinding, [start, opt, text, newline])
+            }
+            JsonValue::Object(object) if !object.is_empty() => {
+                let begin = self.token(
+                    NonTerminalKind::Begin,
+                    TerminalKind::LBrace,
+                    "{",
+                    Trivia::Space,
+                );
+                let bindings = object
+                    .iter()
+                    .map(|(key, value)| (key.as_str(), value))
+                    .collect();
+                let swon = self.swon(bindings, vec![]);
+                let before = self.line_break(1);
+                let end = self.token(NonTerminalKind::End, TerminalKind::RBrace, "}", before);
+                self.node(NonTerminalKind::SectionBinding, [begin, swon, end])
+            }
+            value => {
+                let bind = self.token(
+                    NonTerminalKind::Bind,
+                    TerminalKind::Bind,
+                    "=",
+                    Trivia::Space,
+                );
+                let value = self.value(value, Trivia::Space);
+                self.node(NonTerminalKind::ValueBinding, [bind, value])
+            }
+        };
+        let rhs = self.node(NonTerminalKind::BindingRhs, [rhs]);
+        self.node(NonTerminalKind::Binding, [keys, rhs])
+    }
+
+    fn keys(&mut self, keys: &[SectionKey], before: Trivia) -> NodeTarget {
+        let mut keys = keys
+            .iter()
+            .enumerate()
+            .map(|(index, &(key, array))| {
+                self.key(key, array, if index == 0 { before } else { Trivia::None })
+            })
+            .collect::<Vec<_>>();
+        let first = keys.remove(0);
+        let mut list = self.node(NonTerminalKind::KeysList, []);
+        for key in keys.into_iter().rev() {
+            let dot = self.token(NonTerminalKind::Dot, TerminalKind::Dot, ".", Trivia::None);
+            list = self.node(NonTerminalKind::KeysList, [dot, key, list]);
+        }
+        self.node(NonTerminalKind::Keys, [first, list])
+    }
+
+    fn key(&mut self, key: &str, array: bool, before: Trivia) -> NodeTarget {
+        let base = if is_bare_key(key) {
+            self.token(NonTerminalKind::Ident, TerminalKind::Ident, key, before)
+        } else {
+            self.str(key, before)
+        };
+        let base = self.node(NonTerminalKind::KeyBase, [base]);
+        let marker = if array {
+            let begin = self.token(
+                NonTerminalKind::ArrayBegin,
+                TerminalKind::LBracket,
+                "[",
+                Trivia::None,
+            );
+            let opt = self.node(NonTerminalKind::ArrayMarkerOpt, []);
+            let end = self.token(
+                NonTerminalKind::ArrayEnd,
+                TerminalKind::RBracket,
+                "]",
+                Trivia::None,
+            );
+            vec![self.node(NonTerminalKind::ArrayMarker, [begin, opt, end])]
+        } else {
+            vec![]
+        };
+        let opt = self.node(NonTerminalKind::KeyOpt, marker);
+        self.node(NonTerminalKind::Key, [base, opt])
+    }
+
+    fn value(&mut self, value: &JsonValue, before: Trivia) -> NodeTarget {
+        let value = match value {
+            JsonValue::Null => {
+                self.token(NonTerminalKind::Null, TerminalKind::Null, "null", before)
+            }
+            JsonValue::Bool(true) => {
+                let token = self.token(NonTerminalKind::True, TerminalKind::True, "true", before);
+                self.node(NonTerminalKind::Boolean, [token])
+            }
+            JsonValue::Bool(false) => {
+                let token =
+                    self.token(NonTerminalKind::False, TerminalKind::False, "false", before);
+                self.node(NonTerminalKind::Boolean, [token])
+            }
+            JsonValue::Number(number) => match number.as_f64() {
+                Some(float) if !number.is_i64() && !number.is_u64() => {
+                    // `{:?}` keeps a fractional part or an exponent, so `1.0` stays a float
+                    let text = format!("{float:?}");
+                    self.token(NonTerminalKind::Float, TerminalKind::Float, &text, before)
+                }
+                _ => {
+                    let text = number.to_string();
+                    self.token(
+                        NonTerminalKind::Integer,
+                        TerminalKind::Integer,
+                        &text,
+                        before,
+                    )
+                }
+            },
+            JsonValue::String(text) => {
+                let str = self.str(text, before);
+                let list = self.node(NonTerminalKind::StrContinuesList, []);
+                self.node(NonTerminalKind::StrContinues, [str, list])
+            }
+            JsonValue::Array(elements) => self.array(elements, before),
+            JsonValue::Object(object) => self.object(object, before),
+        };
+        self.node(NonTerminalKind::Value, [value])
+    }
+
+    fn array(&mut self, elements: &[JsonValue], before: Trivia) -> NodeTarget {
+        let begin = self.token(
+            NonTerminalKind::ArrayBegin,
+            TerminalKind::LBracket,
+            "[",
+            before,
+        );
+        let mut rest = None;
+        for (index, element) in elements.iter().enumerate().rev() {
+            let before = if index == 0 {
+                Trivia::None
+            } else {
+                Trivia::Space
+            };
+            let value = self.value(element, before);
+            let tail = match rest {
+                Some(rest) => {
+                    let comma = self.token(
+                        NonTerminalKind::Comma,
+                        TerminalKind::Comma,
+                        ",",
+                        Trivia::None,
+                    );
+                    let opt = self.node(NonTerminalKind::ArrayElementsTailOpt, [rest]);
+                    vec![self.node(NonTerminalKind::ArrayElementsTail, [comma, opt])]
+                }
+                None => vec![],
+            };
+            let opt = self.node(NonTerminalKind::ArrayElementsOpt, tail);
+            rest = Some(self.node(NonTerminalKind::ArrayElements, [value, opt]));
+        }
+        let opt = self.node(NonTerminalKind::ArrayOpt, rest);
+        let end = self.token(
+            NonTerminalKind::ArrayEnd,
+            TerminalKind::RBracket,
+            "]",
+            Trivia::None,
+        );
+        self.node(NonTerminalKind::Array, [begin, opt, end])
+    }
+
+    fn object(&mut self, object: &JsonMap<String, JsonValue>, before: Trivia) -> NodeTarget {
+        let begin = self.token(NonTerminalKind::Begin, TerminalKind::LBrace, "{", before);
+        let mut list = self.node(NonTerminalKind::ObjectList, []);
+        let entries = object.iter().collect::<Vec<_>>();
+        for (index, (key, value)) in entries.iter().enumerate().rev() {
+            let key = self.key(key, false, Trivia::Space);
+            let bind = self.token(
+                NonTerminalKind::Bind,
+                TerminalKind::Bind,
+                "=",
+                Trivia::Space,
+            );
+            let value = self.value(value, Trivia::Space);
+            let comma = if index + 1 < entries.len() {
+                vec![self.token(
+                    NonTerminalKind::Comma,
+                    TerminalKind::Comma,
+                    ",",
+                    Trivia::None,
+                )]
+            } else {
+                vec![]
+            };
+            let opt = self.node(NonTerminalKind::ObjectOpt, comma);
+            list = self.node(NonTerminalKind::ObjectList, [key, bind, value, opt, list]);
+        }
+        let before = if entries.is_empty() {
+            Trivia::None
+        } else {
+            Trivia::Space
+        };
+        let end = self.token(NonTerminalKind::End, TerminalKind::RBrace, "}", before);
+        self.node(NonTerminalKind::Object, [begin, list, end])
+    }
+
+    fn str(&mut self, text: &str, before: Trivia) -> NodeTarget {
+        let open = self.token(NonTerminalKind::Quote, TerminalKind::Quote, "\"", before);
+        let in_str = if text.is_empty() {
+            vec![]
+        } else {
+            vec![self.token(
+                NonTerminalKind::InStr,
+                TerminalKind::InStr,
+                &escape(text),
+                Trivia::None,
+            )]
+        };
+        let opt = self.node(NonTerminalKind::StrOpt, in_str);
+        let close = self.token(
+            NonTerminalKind::Quote,
+            TerminalKind::Quote,
+            "\"",
+            Trivia::None,
+        );
+        self.node(NonTerminalKind::Str, [open, opt, close])
+    }
+
+    /// The trivia before an item that starts a line, after `lines` line breaks if it follows
+    /// something.
+    fn line_break(&mut self, lines: usize) -> Trivia {
+        let trivia = match (self.started, self.line_start) {
+            (false, _) => Trivia::None,
+            (true, true) if lines == 1 => Trivia::None,
+            (true, true) => Trivia::Lines(lines - 1),
+            (true, false) => Trivia::Lines(lines),
+        };
+        self.started = true;
+        self.line_start = false;
+        trivia
+    }
+
+    /// A list like `SwonList` of `items`.
+    fn list(&mut self, kind: NonTerminalKind, items: Vec<NodeTarget>) -> NodeTarget {
+        let mut list = self.node(kind, []);
+        for item in items.into_iter().rev() {
+            list = self.node(kind, [item, list]);
+        }
+        list
+    }
+
+    fn node(
+        &mut self,
+        kind: NonTerminalKind,
+        children: impl IntoIterator<Item = NodeTarget>,
+    ) -> NodeTarget {
+        // Inserted under the root until it is given its place
+        let node = self.commands.insert_node(
+            self.root,
+            CstNode::new_non_terminal(kind, NonTerminalData::Dynamic),
+        );
+        self.commands.update_children(node, children);
+        node.into()
+    }
+
+    /// A new token with its non-terminal and the trivia before it.
+    fn token(
+        &mut self,
+        wrapper: NonTerminalKind,
+        kind: TerminalKind,
+        text: &str,
+        before: Trivia,
+    ) -> NodeTarget {
+        let mut children = vec![];
+        match before {
+            Trivia::None => {}
+            Trivia::Space => children.push(self.terminal(TerminalKind::Whitespace, " ")),
+            Trivia::Lines(lines) => {
+                for _ in 0..lines {
+                    children.push(self.terminal(TerminalKind::NewLine, "\n"));
+                }
+            }
+        }
+        children.push(self.terminal(kind, text));
+        self.node(wrapper, children)
+    }
+
+    fn terminal(&mut self, kind: TerminalKind, text: &str) -> NodeTarget {
+        self.commands.insert_dynamic_terminal(kind, text).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{ToJsonOptions, to_json};
+
+    fn round_trip(json: &JsonValue, options: &FromJsonOptions) -> String {
+        let output = from_json(json, options).unwrap();
+        let cst = swon_parol::parse(&output, "test.swon")
+            .unwrap_or_else(|error| panic!("{error:?}\n{output}"));
+        let back = to_json(&cst.values(&output).unwrap(), &ToJsonOptions::default()).unwrap();
+        assert_eq!(&back.value, json, "{output}");
+        output
+    }
+
+    #[test]
+    fn test_from_json() {
+        let json = json!({
+            "name": "swon",
+            "version": 1,
+            "ratio": 1.0,
+            "tags": ["a", "b c"],
+            "empty": "",
+            "padded": " x ",
+            "lines": "a\nb\t\"c\"",
+            "true": true,
+            "my key": null,
+            "package": {
+                "edition": "2024",
+                "metadata": { "docs": { "all": true }, "x": [] },
+            },
+            "actions": [
+                { "run": "build", "args": { "release": true } },
+                { "run": "test" },
+            ],
+        });
+        let output = round_trip(&json, &FromJsonOptions::default());
+        assert_eq!(
+            output,
+            r#"empty = ""
+lines = "a\nb\t\"c\""
+"my key" = null
+name: swon
+padded = " x "
+ratio = 1.0
+tags = ["a", "b c"]
+"true" = true
+version = 1
+
+@ actions[]
+args {
+  release = true
+}
+run: build
+
+@ actions[]
+run: test
+
+@ package
+edition: 2024
+
+@ package.metadata
+docs {
+  all = true
+}
+x = []
+"#
+        );
+    }
+
+    #[test]
+    fn test_from_json_options() {
+        let json = json!({ "a": { "b": { "c": "d" } }, "e": {} });
+        let options = FromJsonOptions {
+            max_section_depth: 0,
+            text_bindings: false,
+        };
+        assert_eq!(
+            round_trip(&json, &options),
+            "a {\n  b {\n    c = \"d\"\n  }\n}\ne = {}\n"
+        );
+        assert_eq!(
+            round_trip(&json, &FromJsonOptions::default()),
+            "e = {}\n\n@ a.b\nc: d\n"
+        );
+        assert!(matches!(
+            from_json(&json!([1]), &options),
+            Err(FromJsonError::RootNotObject)
+        ));
+    }
+}
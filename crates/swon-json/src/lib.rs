@@ -1,14 +1,10 @@
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
-}
+//! Conversion between SWON and JSON.
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+mod from_json;
+mod to_json;
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
+pub use from_json::{FromJsonError, FromJsonOptions, from_json, from_json_cst};
+pub use to_json::{
+    EXTENSION, ExtensionHandling, Json, Loss, LossKind, ToJsonError, ToJsonErrorKind,
+    ToJsonOptions, VariantRepresentation, to_json, value_to_json,
+};
@@ -0,0 +1,602 @@
+use std::fmt;
+
+use serde_json::{Map as JsonMap, Number, Value as JsonValue};
+use swon_tree::value_visitor::Values;
+use swon_value::{
+    identifier::Identifier,
+    value::{KeyCmpValue, Path, PathSegment, Value, pop_element, push_element},
+};
+use thiserror::Error;
+
+/// The extension namespace read by the converter. It is never written to JSON.
+pub const EXTENSION: &str = "json";
+
+/// How extensions other than `$json` are converted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExtensionHandling {
+    /// Drop them, and report each one as a [Loss].
+    #[default]
+    Drop,
+    /// Write them as `"$name"` keys of the object they are attached to. Extensions on other values
+    /// are dropped and reported.
+    Preserve,
+}
+
+/// How a variant is written, from `$json.variant-representation`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum VariantRepresentation {
+    /// The content alone. The tag is dropped and reported.
+    #[default]
+    Untagged,
+    /// `{ "<variant>": <content> }`
+    ExternallyTagged,
+    /// The content object with `"<tag>": "<variant>"` added to it.
+    InternallyTagged { tag: String },
+    /// `{ "<tag>": "<variant>", "<content>": <content> }`
+    AdjacentlyTagged { tag: String, content: String },
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ToJsonOptions {
+    pub extensions: ExtensionHandling,
+    /// The representation of variants without `$json.variant-representation`
+    pub variant_representation: VariantRepresentation,
+}
+
+/// A converted document with the information that JSON could not hold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Json {
+    pub value: JsonValue,
+    /// In the order the values are converted, with map entries sorted by key
+    pub losses: Vec<Loss>,
+}
+
+/// Information dropped while converting the value at `path`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Loss {
+    pub path: Path,
+    pub kind: LossKind,
+}
+
+impl fmt::Display for Loss {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match path_notation(&self.path).as_str() {
+            "" => write!(f, "{}", self.kind),
+            path => write!(f, "{} at {path}", self.kind),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LossKind {
+    #[error("Type name `{0}` of a typed string is dropped")]
+    TypeName(String),
+    #[error("Language `{0}` of code is dropped")]
+    Language(String),
+    #[error("Tuple is converted to an array")]
+    Tuple,
+    #[error("Unit is converted to null")]
+    Unit,
+    #[error("Path is converted to a string")]
+    Path,
+    #[error("Tag `{0}` of an untagged variant is dropped")]
+    VariantTag(String),
+    #[error("Key {0} is converted to a string")]
+    NonStringKey(String),
+    #[error("Extension `${0}` is dropped")]
+    Extension(Identifier),
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+#[error("{kind}")]
+pub struct ToJsonError {
+    /// The value that can't be converted
+    pub path: Path,
+    pub kind: ToJsonErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ToJsonErrorKind {
+    #[error("JSON has no representation for the float {0}")]
+    NonFiniteFloat(f64),
+    #[error("Key {0:?} appears twice after converting keys to strings")]
+    DuplicateKey(String),
+    #[error("Content of an internally tagged variant must be a map")]
+    InternallyTaggedContent,
+    #[error("Invalid $json.variant-representation: {0}")]
+    InvalidRepresentation(String),
+}
+
+/// Convert a document to JSON.
+///
+/// | SWON | JSON |
+/// |------|------|
+/// | null, boolean, integer, float, string, array, map | the same |
+/// | typed string `url"..."` | its string, dropping the type name |
+/// | code | its string, dropping the language |
+/// | tuple | an array |
+/// | unit `()` | null |
+/// | path `.a.b` | its notation as a string |
+/// | variant | by `$json.variant-representation` of the variant or its nearest ancestor, else [ToJsonOptions::variant_representation] |
+/// | non-string key | its notation as a string, like `"1"` or `"true"` |
+/// | extension | by [ToJsonOptions::extensions] |
+///
+/// Every conversion that drops information is reported in [Json::losses].
+pub fn to_json(values: &Values, options: &ToJsonOptions) -> Result<Json, ToJsonError> {
+    let mut converter = Converter {
+        values: Some(values),
+        options,
+        path: vec![],
+        losses: vec![],
+    };
+    let value = converter.value(&values.root)?;
+    Ok(Json {
+        value,
+        losses: converter.losses,
+    })
+}
+
+/// Convert a value without extensions to JSON. See [to_json].
+pub fn value_to_json(value: &Value, options: &ToJsonOptions) -> Result<Json, ToJsonError> {
+    let mut converter = Converter {
+        values: None,
+        options,
+        path: vec![],
+        losses: vec![],
+    };
+    let value = converter.value(value)?;
+    Ok(Json {
+        value,
+        losses: converter.losses,
+    })
+}
+
+struct Converter<'a> {
+    values: Option<&'a Values>,
+    options: &'a ToJsonOptions,
+    /// The path of the current value, as in [swon_tree::value_visitor::ExtensionMap]
+    path: Vec<PathSegment>,
+    losses: Vec<Loss>,
+}
+
+impl Converter<'_> {
+    fn value(&mut self, value: &Value) -> Result<JsonValue, ToJsonError> {
+        let mut json = self.content(value)?;
+        self.extensions(&mut json)?;
+        Ok(json)
+    }
+
+    fn content(&mut self, value: &Value) -> Result<JsonValue, ToJsonError> {
+        Ok(match value {
+            Value::Null => JsonValue::Null,
+            Value::Bool(value) => JsonValue::Bool(*value),
+            Value::I64(value) => JsonValue::from(*value),
+            Value::U64(value) => JsonValue::from(*value),
+            // Through the shortest decimal, so that `1.1` stays `1.1`
+            Value::F32(value) => self.float(*value as f64, value.to_string().parse().ok())?,
+            Value::F64(value) => self.float(*value, Some(*value))?,
+            Value::String(value) => JsonValue::String(value.clone()),
+            Value::TypedString(typed) => {
+                self.lose(LossKind::TypeName(typed.type_name.clone()));
+                JsonValue::String(typed.value.clone())
+            }
+            Value::Code(code) => {
+                if !code.language.is_empty() {
+                    self.lose(LossKind::Language(code.language.clone()));
+                }
+                JsonValue::String(code.content.clone())
+            }
+            Value::Array(array) => {
+                let mut elements = vec![];
+                for (index, element) in array.0.iter().enumerate() {
+                    push_element(&mut self.path, index);
+                    let element = self.value(element);
+                    pop_element(&mut self.path);
+                    elements.push(element?);
+                }
+                JsonValue::Array(elements)
+            }
+            Value::Tuple(tuple) => {
+                self.lose(LossKind::Tuple);
+                let mut elements = vec![];
+                for (index, element) in tuple.0.iter().enumerate() {
+                    self.path.push(PathSegment::TupleIndex(index as u8));
+                    let element = self.value(element);
+                    self.path.pop();
+                    elements.push(element?);
+                }
+                JsonValue::Array(elements)
+            }
+            Value::Map(map) => {
+                let mut entries = map.0.iter().collect::<Vec<_>>();
+                entries.sort_by_key(|(key, _)| *key);
+                let mut object = JsonMap::new();
+                for (key, value) in entries {
+                    let name = match key {
+                        KeyCmpValue::String(name) => name.clone(),
+                        key => {
+                            let name = key_notation(key);
+                            self.lose(LossKind::NonStringKey(name.clone()));
+                            name
+                        }
+                    };
+                    if object.contains_key(&name) {
+                        return Err(self.error(ToJsonErrorKind::DuplicateKey(name)));
+                    }
+                    self.path.push(PathSegment::Value(key.clone().into()));
+                    let value = self.value(value);
+                    self.path.pop();
+                    object.insert(name, value?);
+                }
+                JsonValue::Object(object)
+            }
+            Value::Variant(variant) => {
+                // The content has the path of the variant, so its extensions are added only once
+                let content = self.content(&variant.content)?;
+                let tag = JsonValue::String(variant.tag.clone());
+                match self.representation()? {
+                    VariantRepresentation::Untagged => {
+                        self.lose(LossKind::VariantTag(variant.tag.clone()));
+                        content
+                    }
+                    VariantRepresentation::ExternallyTagged => {
+                        JsonValue::Object(JsonMap::from_iter([(variant.tag.clone(), content)]))
+                    }
+                    VariantRepresentation::InternallyTagged { tag: key } => {
+                        let JsonValue::Object(mut object) = content else {
+                            return Err(self.error(ToJsonErrorKind::InternallyTaggedContent));
+                        };
+                        if object.contains_key(&key) {
+                            return Err(self.error(ToJsonErrorKind::DuplicateKey(key)));
+                        }
+                        object.insert(key, tag);
+                        JsonValue::Object(object)
+                    }
+                    VariantRepresentation::AdjacentlyTagged {
+                        tag: tag_key,
+                        content: content_key,
+                    } => {
+                        if tag_key == content_key {
+                            return Err(self.error(ToJsonErrorKind::DuplicateKey(tag_key)));
+                        }
+                        JsonValue::Object(JsonMap::from_iter([
+                            (tag_key, tag),
+                            (content_key, content),
+                        ]))
+                    }
+                }
+            }
+            Value::Unit => {
+                self.lose(LossKind::Unit);
+                JsonValue::Null
+            }
+            Value::Path(path) => {
+                self.lose(LossKind::Path);
+                JsonValue::String(path_notation(path))
+            }
+        })
+    }
+
+    fn float(&self, value: f64, decimal: Option<f64>) -> Result<JsonValue, ToJsonError> {
+        decimal
+            .and_then(Number::from_f64)
+            .map(JsonValue::Number)
+            .ok_or_else(|| self.error(ToJsonErrorKind::NonFiniteFloat(value)))
+    }
+
+    /// Add the extensions of the current value to `json`.
+    fn extensions(&mut self, json: &mut JsonValue) -> Result<(), ToJsonError> {
+        let Some(values) = self.values else {
+            return Ok(());
+        };
+        let extensions = values.extensions.at(&self.path).collect::<Vec<_>>();
+        for extension in extensions {
+            if extension.name.as_ref() == EXTENSION {
+                continue;
+            }
+            let object = match (self.options.extensions, &mut *json) {
+                (ExtensionHandling::Preserve, JsonValue::Object(object)) => object,
+                _ => {
+                    self.lose(LossKind::Extension(extension.name.clone()));
+                    continue;
+                }
+            };
+            let key = format!("${}", extension.name);
+            if object.contains_key(&key) {
+                return Err(self.error(ToJsonErrorKind::DuplicateKey(key)));
+            }
+            self.path
+                .push(PathSegment::Extension(extension.name.clone()));
+            let value = self.value(&extension.value);
+            self.path.pop();
+            object.insert(key, value?);
+        }
+        Ok(())
+    }
+
+    /// The `$json.variant-representation` of the current value or its nearest ancestor.
+    fn representation(&self) -> Result<VariantRepresentation, ToJsonError> {
+        let configured = self.values.and_then(|values| {
+            self.ancestors().into_iter().find_map(|path| {
+                match values.extensions.get(&path, EXTENSION)? {
+                    Value::Map(json) => json.0.get(&string_key("variant-representation")),
+                    _ => None,
+                }
+            })
+        });
+        let Some(configured) = configured else {
+            return Ok(self.options.variant_representation.clone());
+        };
+        let invalid =
+            |message: &str| self.error(ToJsonErrorKind::InvalidRepresentation(message.into()));
+        let Value::Map(configured) = configured else {
+            return Err(invalid("expected a map"));
+        };
+        let field = |name: &str, default: &str| match configured.0.get(&string_key(name)) {
+            None => Ok(default.to_string()),
+            Some(Value::String(value)) => Ok(value.clone()),
+            Some(_) => Err(invalid(&format!("`{name}` must be a string"))),
+        };
+        Ok(match field("method", "untagged")?.as_str() {
+            "untagged" => VariantRepresentation::Untagged,
+            "externally tagged" => VariantRepresentation::ExternallyTagged,
+            "internally tagged" => VariantRepresentation::InternallyTagged {
+                tag: field("tag", "tag")?,
+            },
+            "adjacently tagged" => VariantRepresentation::AdjacentlyTagged {
+                tag: field("tag", "tag")?,
+                content: field("content", "content")?,
+            },
+            method => return Err(invalid(&format!("unknown method {method:?}"))),
+        })
+    }
+
+    /// The paths of the current value and its ancestors, from the innermost.
+    fn ancestors(&self) -> Vec<Vec<PathSegment>> {
+        let mut ancestors = vec![];
+        for len in (0..=self.path.len()).rev() {
+            ancestors.push(self.path[..len].to_vec());
+            // `key[index]` is an element of the array at `key`
+            if let Some(PathSegment::Array {
+                key,
+                index: Some(_),
+            }) = self.path[..len].last()
+            {
+                let mut array = self.path[..len - 1].to_vec();
+                array.push(PathSegment::Value(key.clone()));
+                ancestors.push(array);
+            }
+        }
+        ancestors
+    }
+
+    fn lose(&mut self, kind: LossKind) {
+        self.losses.push(Loss {
+            path: Path(self.path.clone()),
+            kind,
+        });
+    }
+
+    fn error(&self, kind: ToJsonErrorKind) -> ToJsonError {
+        ToJsonError {
+            path: Path(self.path.clone()),
+            kind,
+        }
+    }
+}
+
+fn string_key(key: &str) -> KeyCmpValue {
+    KeyCmpValue::String(key.to_string())
+}
+
+/// A key as it is written in SWON, like `1`, `true` or `(1, "a")`.
+fn key_notation(key: &KeyCmpValue) -> String {
+    match key {
+        KeyCmpValue::Null => "null".to_string(),
+        KeyCmpValue::Bool(value) => value.to_string(),
+        KeyCmpValue::I64(value) => value.to_string(),
+        KeyCmpValue::U64(value) => value.to_string(),
+        KeyCmpValue::String(value) => JsonValue::String(value.clone()).to_string(),
+        KeyCmpValue::Tuple(tuple) => {
+            let elements = tuple.0.iter().map(key_notation).collect::<Vec<_>>();
+            format!("({})", elements.join(", "))
+        }
+        KeyCmpValue::Unit => "()".to_string(),
+    }
+}
+
+/// A path as it is written in SWON, like `.a.b[0]`.
+fn path_notation(path: &Path) -> String {
+    let mut notation = String::new();
+    let mut index_allowed = true;
+    for segment in &path.0 {
+        match segment {
+            PathSegment::Value(Value::U64(index)) if index_allowed => {
+                if notation.is_empty() {
+                    notation.push('.');
+                }
+                notation.push_str(&format!("[{index}]"));
+            }
+            PathSegment::Value(key) => {
+                notation.push('.');
+                notation.push_str(&path_key(key));
+            }
+            PathSegment::Extension(name) => notation.push_str(&format!(".${name}")),
+            PathSegment::TupleIndex(index) => notation.push_str(&format!(".{index}")),
+            PathSegment::Array { key, index } => {
+                notation.push('.');
+                notation.push_str(&path_key(key));
+                match index {
+                    Some(index) => notation.push_str(&format!("[{}]", path_key(index))),
+                    None => notation.push_str("[]"),
+                }
+            }
+        }
+        // An integer is an index only at the head and after an extension or a tuple index
+        index_allowed = matches!(
+            segment,
+            PathSegment::Extension(_) | PathSegment::TupleIndex(_)
+        );
+    }
+    notation
+}
+
+fn path_key(key: &Value) -> String {
+    match KeyCmpValue::try_from(key.clone()) {
+        Ok(KeyCmpValue::String(name)) if name.parse::<Identifier>().is_ok() => name,
+        Ok(key) => key_notation(&key),
+        Err(_) => "?".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(input: &str, options: &ToJsonOptions) -> Result<Json, ToJsonError> {
+        let cst = swon_parol::parse(input, "test.swon").unwrap();
+        to_json(&cst.values(input).unwrap(), options)
+    }
+
+    fn losses(json: &Json) -> Vec<(String, LossKind)> {
+        json.losses
+            .iter()
+            .map(|loss| (path_notation(&loss.path), loss.kind.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_to_json() {
+        let input = r#"
+a = 1
+b = [1.5, true, null, "s"]
+c = { d = -2 }
+url = url"https://swon.dev"
+code = rust`let a = 1;`
+tuple = (1, "x")
+unit = ()
+path = .c.d
+text: hello
+"#;
+        let json = convert(input, &ToJsonOptions::default()).unwrap();
+        assert_eq!(
+            json.value,
+            serde_json::json!({
+                "a": 1,
+                "b": [1.5, true, null, "s"],
+                "c": { "d": -2 },
+                "url": "https://swon.dev",
+                "code": "let a = 1;",
+                "tuple": [1, "x"],
+                "unit": null,
+                "path": ".c.d",
+                "text": "hello",
+            })
+        );
+        let mut losses = losses(&json);
+        losses.sort_by_key(|(path, _)| path.clone());
+        assert_eq!(
+            losses,
+            [
+                (".code".into(), LossKind::Language("rust".into())),
+                (".path".into(), LossKind::Path),
+                (".tuple".into(), LossKind::Tuple),
+                (".unit".into(), LossKind::Unit),
+                (".url".into(), LossKind::TypeName("url".into())),
+            ]
+        );
+        let unit = json.losses.iter().find(|loss| loss.kind == LossKind::Unit);
+        assert_eq!(
+            unit.unwrap().to_string(),
+            "Unit is converted to null at .unit"
+        );
+    }
+
+    #[test]
+    fn test_to_json_variants() {
+        let input = r#"
+untagged = { $variant = "a", x = 1 }
+external = { $variant = "b", x = 1 }
+external.$json.variant-representation.method = "externally tagged"
+items = [{ $variant = "c", x = 1 }]
+items.$json.variant-representation = { method = "internally tagged", tag = "type" }
+
+@ adjacent
+$json.variant-representation.method = "adjacently tagged"
+first = { $variant = "d", x = 1 }
+"#;
+        let json = convert(input, &ToJsonOptions::default()).unwrap();
+        assert_eq!(
+            json.value,
+            serde_json::json!({
+                "untagged": { "x": 1 },
+                "external": { "b": { "x": 1 } },
+                "items": [{ "type": "c", "x": 1 }],
+                "adjacent": { "first": { "tag": "d", "content": { "x": 1 } } },
+            })
+        );
+        assert_eq!(
+            losses(&json),
+            [(".untagged".into(), LossKind::VariantTag("a".into()))]
+        );
+
+        let input =
+            "a = { $variant = \"b\", x = 1 }\n$json.variant-representation.method = \"tagged\"\n";
+        let error = convert(input, &ToJsonOptions::default()).unwrap_err();
+        assert_eq!(
+            error.path,
+            Path(vec![PathSegment::Value(Value::String("a".into()))])
+        );
+        assert!(matches!(
+            error.kind,
+            ToJsonErrorKind::InvalidRepresentation(_)
+        ));
+    }
+
+    #[test]
+    fn test_to_json_extensions() {
+        let input = "$schema = \"s.swon\"\na.b = 1\na.$note = \"n\"\na.b.$note = \"m\"\n";
+        let json = convert(input, &ToJsonOptions::default()).unwrap();
+        assert_eq!(json.value, serde_json::json!({ "a": { "b": 1 } }));
+        assert_eq!(json.losses.len(), 3);
+
+        let options = ToJsonOptions {
+            extensions: ExtensionHandling::Preserve,
+            ..Default::default()
+        };
+        let json = convert(input, &options).unwrap();
+        assert_eq!(
+            json.value,
+            serde_json::json!({ "$schema": "s.swon", "a": { "b": 1, "$note": "n" } })
+        );
+        assert_eq!(
+            losses(&json),
+            [(".a.b".into(), LossKind::Extension("note".parse().unwrap()))]
+        );
+    }
+
+    #[test]
+    fn test_to_json_errors() {
+        let error = convert("a = [inf]\n", &ToJsonOptions::default()).unwrap_err();
+        assert_eq!(path_notation(&error.path), ".a[0]");
+        assert_eq!(error.kind, ToJsonErrorKind::NonFiniteFloat(f64::INFINITY));
+
+        // Integer keys are tuple indices in SWON, so they only come from values built in code
+        let map = |entries: Vec<(KeyCmpValue, Value)>| {
+            Value::Map(swon_value::value::Map(entries.into_iter().collect()))
+        };
+        let values = Values {
+            root: map(vec![(
+                KeyCmpValue::String("a".into()),
+                map(vec![
+                    (KeyCmpValue::I64(1), Value::I64(1)),
+                    (KeyCmpValue::String("1".into()), Value::I64(2)),
+                ]),
+            )]),
+            extensions: Default::default(),
+        };
+        let error = to_json(&values, &ToJsonOptions::default()).unwrap_err();
+        assert_eq!(path_notation(&error.path), ".a");
+        assert_eq!(error.kind, ToJsonErrorKind::DuplicateKey("1".into()));
+    }
+}
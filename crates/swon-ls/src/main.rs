@@ -34,7 +34,7 @@ fn main() -> anyhow::Result<()> {
         ),
         // Add textDocumentSync capability if not already present, needed for tracking documents
         text_document_sync: Some(lsp_types::TextDocumentSyncCapability::Kind(
-            lsp_types::TextDocumentSyncKind::FULL, // Or INCREMENTAL if handled
+            lsp_types::TextDocumentSyncKind::INCREMENTAL,
         )),
         // Include diagnostic capability
         diagnostic_provider: Some(lsp_types::DiagnosticServerCapabilities::Options(
@@ -139,19 +139,27 @@ impl ServerContext {
 
                             self.process_document(uri, text, Some(version))?;
                         }
-                    } else if not.method == "textDocument/didChange" {
-                        if let Ok(params) = serde_json::from_value::<
+                    } else if not.method == "textDocument/didChange"
+                        && let Ok(params) = serde_json::from_value::<
                             lsp_types::DidChangeTextDocumentParams,
                         >(not.params)
-                        {
-                            let uri = params.text_document.uri.clone();
-                            let version = params.text_document.version;
+                    {
+                        let uri = params.text_document.uri.clone();
+                        let version = params.text_document.version;
 
-                            // For FULL sync, we just get the full content from the last change
-                            if let Some(last_change) = params.content_changes.last() {
-                                let text = last_change.text.clone();
-                                self.process_document(uri, text, Some(version))?;
-                            }
+                        // Changes are applied in order, each to the result of the previous one
+                        for change in params.content_changes {
+                            let previous = self.documents.remove(&uri.to_string());
+                            let (text, parse_result) = match (change.range, previous) {
+                                (Some(range), Some((Some(cst), text))) => {
+                                    parser::reparse_document(cst, &text, range, &change.text)
+                                }
+                                _ => {
+                                    let parse_result = parser::parse_document(&change.text);
+                                    (change.text, parse_result)
+                                }
+                            };
+                            self.store_document(uri.clone(), text, parse_result, Some(version))?;
                         }
                     }
                 }
@@ -167,8 +175,19 @@ impl ServerContext {
         version: Option<i32>,
     ) -> anyhow::Result<()> {
         // Try to parse the document using swon-editor-support
-        let uri_string = uri.to_string();
         let parse_result = parser::parse_document(&text);
+        self.store_document(uri, text, parse_result, version)
+    }
+
+    // Store a parsed document and publish its diagnostics
+    fn store_document(
+        &mut self,
+        uri: Uri,
+        text: String,
+        parse_result: parser::ParseResult,
+        version: Option<i32>,
+    ) -> anyhow::Result<()> {
+        let uri_string = uri.to_string();
 
         // Prepare diagnostics and store CST based on parse result
         let (cst, diagnostics) = match parse_result {
@@ -178,7 +197,7 @@ impl ServerContext {
             }
             parser::ParseResult::ErrWithCst { cst, error } => {
                 // We have both a partial CST and an error
-                (Some(cst), diagnostics::error_to_diagnostic(&error))
+                (Some(cst), diagnostics::error_to_diagnostic(&error, &text))
             }
         };
 
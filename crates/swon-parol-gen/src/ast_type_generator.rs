@@ -489,7 +489,7 @@ impl AstTypeGenerator {
                 (
                     quote!(#handle_name),
                     quote!(NodeKind::NonTerminal(NonTerminalKind::#kind_variant)),
-                    quote!(#handle_name::new_with_visit(child, tree, visit_ignored)?),
+                    quote!(#handle_name(child)),
                 )
             }
         };
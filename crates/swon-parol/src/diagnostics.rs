@@ -0,0 +1,157 @@
+//! Structured diagnostics for parse errors, and a renderer for terminals.
+
+use std::fmt::{Display, Write as _};
+
+use parol_runtime::{ParolError, ParserError, SyntaxError};
+use swon_tree::tree::InputSpan;
+
+/// A parse error located in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDiagnostic {
+    pub message: String,
+    /// Byte range in the input. `None` if the error has no location, like most lexer errors.
+    pub span: Option<InputSpan>,
+    /// Names of the terminals that could appear at `span`.
+    pub expected: Vec<String>,
+    /// The terminal name and the text of the token found at `span`.
+    pub found: Option<(String, String)>,
+}
+
+/// Turn a parse error of `input` into diagnostics, one for each syntax error.
+pub fn diagnostics(error: &ParolError, input: &str) -> Vec<ParseDiagnostic> {
+    match error {
+        ParolError::ParserError(ParserError::SyntaxErrors { entries }) => entries
+            .iter()
+            .map(|entry| syntax_error(entry, input))
+            .collect(),
+        ParolError::ParserError(ParserError::UnprocessedInput { last_token, .. }) => {
+            // Point at the first non-whitespace character after the last consumed token
+            let start = (last_token.end as usize).min(input.len());
+            let rest = &input[start..];
+            let start = start + (rest.len() - rest.trim_start().len());
+            vec![ParseDiagnostic {
+                message: "Unexpected input after the end of the document".to_string(),
+                span: Some(InputSpan {
+                    start: start as u32,
+                    end: input.trim_end().len().max(start) as u32,
+                }),
+                expected: Vec::new(),
+                found: None,
+            }]
+        }
+        error => vec![ParseDiagnostic {
+            message: error.to_string(),
+            span: None,
+            expected: Vec::new(),
+            found: None,
+        }],
+    }
+}
+
+fn syntax_error(error: &SyntaxError, input: &str) -> ParseDiagnostic {
+    let mut expected = Vec::<String>::new();
+    for token in error.expected_tokens.iter() {
+        if !expected.contains(token) {
+            expected.push(token.clone());
+        }
+    }
+    let found = error
+        .unexpected_tokens
+        .first()
+        .map(|token| (token.token_type.clone(), token.name.clone()));
+    let message = match &found {
+        Some((kind, _)) if kind == "EndOfInput" => "Unexpected end of input".to_string(),
+        Some((_, text)) => format!("Unexpected token `{}`", text.escape_debug()),
+        None => error.cause.clone(),
+    };
+    let location = error
+        .unexpected_tokens
+        .first()
+        .map(|token| &token.token)
+        .unwrap_or(&error.error_location);
+    let end = (location.end as usize).min(input.len()) as u32;
+    ParseDiagnostic {
+        message,
+        span: Some(InputSpan {
+            start: location.start.min(end),
+            end,
+        }),
+        expected,
+        found,
+    }
+}
+
+/// 1-based line and column, in characters, of a byte offset.
+pub fn line_column(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset.min(input.len())];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let line = before.matches('\n').count() + 1;
+    (line, before[line_start..].chars().count() + 1)
+}
+
+const RED: &str = "\x1b[1;31m";
+const BLUE: &str = "\x1b[1;34m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+impl ParseDiagnostic {
+    /// Render this diagnostic with a snippet of the input, like compiler errors.
+    /// `color` enables ANSI escape codes.
+    pub fn render(&self, input: &str, file_name: impl Display, color: bool) -> String {
+        let paint = |style: &'static str| if color { style } else { "" };
+        let (red, blue, bold, reset) = (paint(RED), paint(BLUE), paint(BOLD), paint(RESET));
+
+        let mut out = String::new();
+        writeln!(out, "{red}error{reset}{bold}: {}{reset}", self.message).unwrap();
+        let Some(span) = self.span else {
+            writeln!(out, "{blue} -->{reset} {file_name}").unwrap();
+            self.write_notes(&mut out, "", blue, reset);
+            return out;
+        };
+
+        let start = (span.start as usize).min(input.len());
+        let (line, column) = line_column(input, start);
+        let gutter = " ".repeat(line.to_string().len());
+        writeln!(out, "{gutter}{blue}-->{reset} {file_name}:{line}:{column}").unwrap();
+        writeln!(out, "{gutter} {blue}|{reset}").unwrap();
+
+        let line_start = start - (column_bytes(input, start));
+        let text = input[line_start..]
+            .split(['\r', '\n'])
+            .next()
+            .unwrap_or_default();
+        writeln!(out, "{blue}{line} |{reset} {text}").unwrap();
+
+        // Keep tabs so the carets line up with the snippet
+        let indent: String = text[..start - line_start]
+            .chars()
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let end = (span.end as usize).clamp(start, line_start + text.len());
+        let carets = "^".repeat(input[start..end].chars().count().max(1));
+        writeln!(out, "{gutter} {blue}|{reset} {indent}{red}{carets}{reset}").unwrap();
+        self.write_notes(&mut out, &gutter, blue, reset);
+        out
+    }
+
+    fn write_notes(&self, out: &mut String, gutter: &str, blue: &str, reset: &str) {
+        match self.expected.as_slice() {
+            [] => {}
+            [expected] => writeln!(out, "{gutter} {blue}={reset} expected {expected}").unwrap(),
+            expected => writeln!(
+                out,
+                "{gutter} {blue}={reset} expected one of {}",
+                expected.join(", ")
+            )
+            .unwrap(),
+        }
+        if let Some((kind, _)) = &self.found {
+            writeln!(out, "{gutter} {blue}={reset} found {kind}").unwrap();
+        }
+    }
+}
+
+/// Byte offset of `offset` from the start of its line.
+fn column_bytes(input: &str, offset: usize) -> usize {
+    offset - input[..offset].rfind('\n').map_or(0, |i| i + 1)
+}
@@ -99,11 +99,46 @@ pub trait GrammarTrait<'t> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ArrayElements'
+    fn array_elements(&mut self, _arg: &ArrayElements<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'ArrayElementsTail'
+    fn array_elements_tail(&mut self, _arg: &ArrayElementsTail<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Tuple'
+    fn tuple(&mut self, _arg: &Tuple<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'TupleElements'
+    fn tuple_elements(&mut self, _arg: &TupleElements<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'TupleElementsTail'
+    fn tuple_elements_tail(&mut self, _arg: &TupleElementsTail<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Integer'
     fn integer(&mut self, _arg: &Integer<'t>) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Float'
+    fn float(&mut self, _arg: &Float<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'FloatName'
+    fn float_name(&mut self, _arg: &FloatName<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Boolean'
     fn boolean(&mut self, _arg: &Boolean<'t>) -> Result<()> {
         Ok(())
@@ -129,6 +164,16 @@ pub trait GrammarTrait<'t> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Path'
+    fn path(&mut self, _arg: &Path<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'PathHead'
+    fn path_head(&mut self, _arg: &PathHead<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'StrContinues'
     fn str_continues(&mut self, _arg: &StrContinues<'t>) -> Result<()> {
         Ok(())
@@ -224,6 +269,16 @@ pub trait GrammarTrait<'t> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'TupleBegin'
+    fn tuple_begin(&mut self, _arg: &TupleBegin<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'TupleEnd'
+    fn tuple_end(&mut self, _arg: &TupleEnd<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Bind'
     fn bind(&mut self, _arg: &Bind<'t>) -> Result<()> {
         Ok(())
@@ -419,7 +474,24 @@ impl ToSpan for KeyBaseInteger<'_> {
 }
 
 ///
-/// Type derived for production 33
+/// Type derived for production 32
+///
+/// `KeyBase: Float;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct KeyBaseFloat<'t> {
+    pub float: Float<'t>,
+}
+
+impl ToSpan for KeyBaseFloat<'_> {
+    fn span(&self) -> Span {
+        self.float.span()
+    }
+}
+
+///
+/// Type derived for production 34
 ///
 /// `Value: Object;`
 ///
@@ -436,7 +508,7 @@ impl ToSpan for ValueObject<'_> {
 }
 
 ///
-/// Type derived for production 34
+/// Type derived for production 35
 ///
 /// `Value: Array;`
 ///
@@ -453,7 +525,24 @@ impl ToSpan for ValueArray<'_> {
 }
 
 ///
-/// Type derived for production 35
+/// Type derived for production 36
+///
+/// `Value: Tuple;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ValueTuple<'t> {
+    pub tuple: Tuple<'t>,
+}
+
+impl ToSpan for ValueTuple<'_> {
+    fn span(&self) -> Span {
+        self.tuple.span()
+    }
+}
+
+///
+/// Type derived for production 37
 ///
 /// `Value: Integer;`
 ///
@@ -470,7 +559,41 @@ impl ToSpan for ValueInteger<'_> {
 }
 
 ///
-/// Type derived for production 36
+/// Type derived for production 38
+///
+/// `Value: Float;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ValueFloat<'t> {
+    pub float: Float<'t>,
+}
+
+impl ToSpan for ValueFloat<'_> {
+    fn span(&self) -> Span {
+        self.float.span()
+    }
+}
+
+///
+/// Type derived for production 39
+///
+/// `Value: FloatName;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ValueFloatName<'t> {
+    pub float_name: FloatName<'t>,
+}
+
+impl ToSpan for ValueFloatName<'_> {
+    fn span(&self) -> Span {
+        self.float_name.span()
+    }
+}
+
+///
+/// Type derived for production 40
 ///
 /// `Value: Boolean;`
 ///
@@ -487,7 +610,7 @@ impl ToSpan for ValueBoolean<'_> {
 }
 
 ///
-/// Type derived for production 37
+/// Type derived for production 41
 ///
 /// `Value: Null;`
 ///
@@ -504,7 +627,7 @@ impl ToSpan for ValueNull<'_> {
 }
 
 ///
-/// Type derived for production 38
+/// Type derived for production 42
 ///
 /// `Value: StrContinues;`
 ///
@@ -521,7 +644,7 @@ impl ToSpan for ValueStrContinues<'_> {
 }
 
 ///
-/// Type derived for production 39
+/// Type derived for production 43
 ///
 /// `Value: TypedStr;`
 ///
@@ -538,7 +661,7 @@ impl ToSpan for ValueTypedStr<'_> {
 }
 
 ///
-/// Type derived for production 40
+/// Type derived for production 44
 ///
 /// `Value: Hole;`
 ///
@@ -555,7 +678,7 @@ impl ToSpan for ValueHole<'_> {
 }
 
 ///
-/// Type derived for production 41
+/// Type derived for production 45
 ///
 /// `Value: CodeBlock;`
 ///
@@ -572,7 +695,7 @@ impl ToSpan for ValueCodeBlock<'_> {
 }
 
 ///
-/// Type derived for production 42
+/// Type derived for production 46
 ///
 /// `Value: NamedCode;`
 ///
@@ -589,7 +712,7 @@ impl ToSpan for ValueNamedCode<'_> {
 }
 
 ///
-/// Type derived for production 43
+/// Type derived for production 47
 ///
 /// `Value: Code;`
 ///
@@ -606,7 +729,24 @@ impl ToSpan for ValueCode<'_> {
 }
 
 ///
-/// Type derived for production 55
+/// Type derived for production 48
+///
+/// `Value: Path;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ValuePath<'t> {
+    pub path: Path<'t>,
+}
+
+impl ToSpan for ValuePath<'_> {
+    fn span(&self) -> Span {
+        self.path.span()
+    }
+}
+
+///
+/// Type derived for production 75
 ///
 /// `Boolean: True;`
 ///
@@ -623,7 +763,7 @@ impl ToSpan for BooleanTrue<'_> {
 }
 
 ///
-/// Type derived for production 56
+/// Type derived for production 76
 ///
 /// `Boolean: False;`
 ///
@@ -639,6 +779,40 @@ impl ToSpan for BooleanFalse<'_> {
     }
 }
 
+///
+/// Type derived for production 84
+///
+/// `PathHead: Key;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct PathHeadKey<'t> {
+    pub key: Key<'t>,
+}
+
+impl ToSpan for PathHeadKey<'_> {
+    fn span(&self) -> Span {
+        self.key.span()
+    }
+}
+
+///
+/// Type derived for production 85
+///
+/// `PathHead: ArrayMarker;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct PathHeadArrayMarker<'t> {
+    pub array_marker: ArrayMarker<'t>,
+}
+
+impl ToSpan for PathHeadArrayMarker<'_> {
+    fn span(&self) -> Span {
+        self.array_marker.span()
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 //
 // Types of non-terminals deduced from the structure of the transformed grammar
@@ -651,7 +825,7 @@ impl ToSpan for BooleanFalse<'_> {
 #[derive(Debug, Clone)]
 pub struct Array<'t> {
     pub array_begin: ArrayBegin<'t>,
-    pub array_list: Vec<ArrayList<'t>>,
+    pub array_opt: Option<Box<ArrayOpt<'t>>>,
     pub array_end: ArrayEnd<'t>,
 }
 
@@ -659,10 +833,9 @@ impl ToSpan for Array<'_> {
     fn span(&self) -> Span {
         self.array_begin.span()
             + self
-                .array_list
-                .first()
-                .map_or(Span::default(), |f| f.span())
-            + self.array_list.last().map_or(Span::default(), |l| l.span())
+                .array_opt
+                .as_ref()
+                .map_or(Span::default(), |o| o.span())
             + self.array_end.span()
     }
 }
@@ -683,40 +856,90 @@ impl ToSpan for ArrayBegin<'_> {
 }
 
 ///
-/// Type derived for non-terminal ArrayEnd
+/// Type derived for non-terminal ArrayElements
 ///
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
-pub struct ArrayEnd<'t> {
-    pub array_end: Token<'t>, /* ] */
+pub struct ArrayElements<'t> {
+    pub value: Value<'t>,
+    pub array_elements_opt: Option<Box<ArrayElementsOpt<'t>>>,
 }
 
-impl ToSpan for ArrayEnd<'_> {
+impl ToSpan for ArrayElements<'_> {
     fn span(&self) -> Span {
-        self.array_end.span()
+        self.value.span()
+            + self
+                .array_elements_opt
+                .as_ref()
+                .map_or(Span::default(), |o| o.span())
     }
 }
 
 ///
-/// Type derived for non-terminal ArrayList
+/// Type derived for non-terminal ArrayElementsOpt
 ///
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
-pub struct ArrayList<'t> {
-    pub value: Value<'t>,
-    pub array_opt: Option<ArrayOpt<'t>>,
+pub struct ArrayElementsOpt<'t> {
+    pub array_elements_tail: ArrayElementsTail<'t>,
 }
 
-impl ToSpan for ArrayList<'_> {
+impl ToSpan for ArrayElementsOpt<'_> {
     fn span(&self) -> Span {
-        self.value.span()
+        self.array_elements_tail.span()
+    }
+}
+
+///
+/// Type derived for non-terminal ArrayElementsTail
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ArrayElementsTail<'t> {
+    pub comma: Comma<'t>,
+    pub array_elements_tail_opt: Option<ArrayElementsTailOpt<'t>>,
+}
+
+impl ToSpan for ArrayElementsTail<'_> {
+    fn span(&self) -> Span {
+        self.comma.span()
             + self
-                .array_opt
+                .array_elements_tail_opt
                 .as_ref()
                 .map_or(Span::default(), |o| o.span())
     }
 }
 
+///
+/// Type derived for non-terminal ArrayElementsTailOpt
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ArrayElementsTailOpt<'t> {
+    pub array_elements: ArrayElements<'t>,
+}
+
+impl ToSpan for ArrayElementsTailOpt<'_> {
+    fn span(&self) -> Span {
+        self.array_elements.span()
+    }
+}
+
+///
+/// Type derived for non-terminal ArrayEnd
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ArrayEnd<'t> {
+    pub array_end: Token<'t>, /* ] */
+}
+
+impl ToSpan for ArrayEnd<'_> {
+    fn span(&self) -> Span {
+        self.array_end.span()
+    }
+}
+
 ///
 /// Type derived for non-terminal ArrayMarker
 ///
@@ -760,12 +983,12 @@ impl ToSpan for ArrayMarkerOpt<'_> {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct ArrayOpt<'t> {
-    pub comma: Comma<'t>,
+    pub array_elements: ArrayElements<'t>,
 }
 
 impl ToSpan for ArrayOpt<'_> {
     fn span(&self) -> Span {
-        self.comma.span()
+        self.array_elements.span()
     }
 }
 
@@ -891,7 +1114,7 @@ impl ToSpan for Code<'_> {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct CodeBlock<'t> {
-    pub code_block: Token<'t>, /* ```[a-zA-Z0-9-_]*(\r\n|\r|\n)([^`]|[`]{1,2})*``` */
+    pub code_block: Token<'t>, /* ```[a-zA-Z0-9-_]*(\r\n|\r|\n)([^`]|`[^`]|``[^`])*``` */
 }
 
 impl ToSpan for CodeBlock<'_> {
@@ -1006,6 +1229,36 @@ impl ToSpan for False<'_> {
     }
 }
 
+///
+/// Type derived for non-terminal Float
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Float<'t> {
+    pub float: Token<'t>, /* [+-]?(\d[\d_]*\.\d[\d_]*([eE][+-]?\d[\d_]*)?|\d[\d_]*[eE][+-]?\d[\d_]*)|[+-](inf|nan) */
+}
+
+impl ToSpan for Float<'_> {
+    fn span(&self) -> Span {
+        self.float.span()
+    }
+}
+
+///
+/// Type derived for non-terminal FloatName
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct FloatName<'t> {
+    pub ident: Ident<'t>,
+}
+
+impl ToSpan for FloatName<'_> {
+    fn span(&self) -> Span {
+        self.ident.span()
+    }
+}
+
 ///
 /// Type derived for non-terminal Hole
 ///
@@ -1042,7 +1295,7 @@ impl ToSpan for Ident<'_> {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct InStr<'t> {
-    pub in_str: Token<'t>, /* (\\[nrt\\"0]|[^\\"\r\n])* */
+    pub in_str: Token<'t>, /* (\\[nrt\\"0]|[^\\"\r\n])+ */
 }
 
 impl ToSpan for InStr<'_> {
@@ -1057,7 +1310,7 @@ impl ToSpan for InStr<'_> {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct Integer<'t> {
-    pub integer: Token<'t>, /* \d[\d_]* */
+    pub integer: Token<'t>, /* [+-]?(0x[0-9a-fA-F][0-9a-fA-F_]*|0o[0-7][0-7_]*|0b[01][01_]*|\d[\d_]*) */
 }
 
 impl ToSpan for Integer<'_> {
@@ -1092,6 +1345,7 @@ pub enum KeyBase<'t> {
     ExtensionNameSpace(KeyBaseExtensionNameSpace<'t>),
     Str(KeyBaseStr<'t>),
     Integer(KeyBaseInteger<'t>),
+    Float(KeyBaseFloat<'t>),
 }
 
 impl ToSpan for KeyBase<'_> {
@@ -1101,6 +1355,7 @@ impl ToSpan for KeyBase<'_> {
             KeyBase::ExtensionNameSpace(v) => v.span(),
             KeyBase::Str(v) => v.span(),
             KeyBase::Integer(v) => v.span(),
+            KeyBase::Float(v) => v.span(),
         }
     }
 }
@@ -1264,6 +1519,61 @@ impl ToSpan for ObjectOpt<'_> {
     }
 }
 
+///
+/// Type derived for non-terminal Path
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Path<'t> {
+    pub dot: Dot<'t>,
+    pub path_head: PathHead<'t>,
+    pub path_list: Vec<PathList<'t>>,
+}
+
+impl ToSpan for Path<'_> {
+    fn span(&self) -> Span {
+        self.dot.span()
+            + self.path_head.span()
+            + self.path_list.first().map_or(Span::default(), |f| f.span())
+            + self.path_list.last().map_or(Span::default(), |l| l.span())
+    }
+}
+
+///
+/// Type derived for non-terminal PathHead
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum PathHead<'t> {
+    Key(PathHeadKey<'t>),
+    ArrayMarker(PathHeadArrayMarker<'t>),
+}
+
+impl ToSpan for PathHead<'_> {
+    fn span(&self) -> Span {
+        match self {
+            PathHead::Key(v) => v.span(),
+            PathHead::ArrayMarker(v) => v.span(),
+        }
+    }
+}
+
+///
+/// Type derived for non-terminal PathList
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct PathList<'t> {
+    pub dot: Dot<'t>,
+    pub key: Key<'t>,
+}
+
+impl ToSpan for PathList<'_> {
+    fn span(&self) -> Span {
+        self.dot.span() + self.key.span()
+    }
+}
+
 ///
 /// Type derived for non-terminal Quote
 ///
@@ -1354,13 +1664,15 @@ impl ToSpan for SectionBodyList<'_> {
 #[derive(Debug, Clone)]
 pub struct Str<'t> {
     pub quote: Quote<'t>,
-    pub in_str: InStr<'t>,
+    pub str_opt: Option<StrOpt<'t>>,
     pub quote0: Quote<'t>,
 }
 
 impl ToSpan for Str<'_> {
     fn span(&self) -> Span {
-        self.quote.span() + self.in_str.span() + self.quote0.span()
+        self.quote.span()
+            + self.str_opt.as_ref().map_or(Span::default(), |o| o.span())
+            + self.quote0.span()
     }
 }
 
@@ -1405,13 +1717,28 @@ impl ToSpan for StrContinuesList<'_> {
 }
 
 ///
-/// Type derived for non-terminal Swon
+/// Type derived for non-terminal StrOpt
 ///
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
-pub struct Swon<'t> {
-    pub swon_list: Vec<SwonList<'t>>,
-    pub swon_list0: Vec<SwonList0<'t>>,
+pub struct StrOpt<'t> {
+    pub in_str: InStr<'t>,
+}
+
+impl ToSpan for StrOpt<'_> {
+    fn span(&self) -> Span {
+        self.in_str.span()
+    }
+}
+
+///
+/// Type derived for non-terminal Swon
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Swon<'t> {
+    pub swon_list: Vec<SwonList<'t>>,
+    pub swon_list0: Vec<SwonList0<'t>>,
 }
 
 impl ToSpan for Swon<'_> {
@@ -1538,6 +1865,143 @@ impl ToSpan for True<'_> {
     }
 }
 
+///
+/// Type derived for non-terminal Tuple
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Tuple<'t> {
+    pub tuple_begin: TupleBegin<'t>,
+    pub tuple_opt: Option<Box<TupleOpt<'t>>>,
+    pub tuple_end: TupleEnd<'t>,
+}
+
+impl ToSpan for Tuple<'_> {
+    fn span(&self) -> Span {
+        self.tuple_begin.span()
+            + self
+                .tuple_opt
+                .as_ref()
+                .map_or(Span::default(), |o| o.span())
+            + self.tuple_end.span()
+    }
+}
+
+///
+/// Type derived for non-terminal TupleBegin
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct TupleBegin<'t> {
+    pub tuple_begin: Token<'t>, /* ( */
+}
+
+impl ToSpan for TupleBegin<'_> {
+    fn span(&self) -> Span {
+        self.tuple_begin.span()
+    }
+}
+
+///
+/// Type derived for non-terminal TupleElements
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct TupleElements<'t> {
+    pub value: Value<'t>,
+    pub tuple_elements_opt: Option<Box<TupleElementsOpt<'t>>>,
+}
+
+impl ToSpan for TupleElements<'_> {
+    fn span(&self) -> Span {
+        self.value.span()
+            + self
+                .tuple_elements_opt
+                .as_ref()
+                .map_or(Span::default(), |o| o.span())
+    }
+}
+
+///
+/// Type derived for non-terminal TupleElementsOpt
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct TupleElementsOpt<'t> {
+    pub tuple_elements_tail: TupleElementsTail<'t>,
+}
+
+impl ToSpan for TupleElementsOpt<'_> {
+    fn span(&self) -> Span {
+        self.tuple_elements_tail.span()
+    }
+}
+
+///
+/// Type derived for non-terminal TupleElementsTail
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct TupleElementsTail<'t> {
+    pub comma: Comma<'t>,
+    pub tuple_elements_tail_opt: Option<TupleElementsTailOpt<'t>>,
+}
+
+impl ToSpan for TupleElementsTail<'_> {
+    fn span(&self) -> Span {
+        self.comma.span()
+            + self
+                .tuple_elements_tail_opt
+                .as_ref()
+                .map_or(Span::default(), |o| o.span())
+    }
+}
+
+///
+/// Type derived for non-terminal TupleElementsTailOpt
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct TupleElementsTailOpt<'t> {
+    pub tuple_elements: TupleElements<'t>,
+}
+
+impl ToSpan for TupleElementsTailOpt<'_> {
+    fn span(&self) -> Span {
+        self.tuple_elements.span()
+    }
+}
+
+///
+/// Type derived for non-terminal TupleEnd
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct TupleEnd<'t> {
+    pub tuple_end: Token<'t>, /* ) */
+}
+
+impl ToSpan for TupleEnd<'_> {
+    fn span(&self) -> Span {
+        self.tuple_end.span()
+    }
+}
+
+///
+/// Type derived for non-terminal TupleOpt
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct TupleOpt<'t> {
+    pub tuple_elements: TupleElements<'t>,
+}
+
+impl ToSpan for TupleOpt<'_> {
+    fn span(&self) -> Span {
+        self.tuple_elements.span()
+    }
+}
+
 ///
 /// Type derived for non-terminal TypedQuote
 ///
@@ -1560,13 +2024,33 @@ impl ToSpan for TypedQuote<'_> {
 #[derive(Debug, Clone)]
 pub struct TypedStr<'t> {
     pub typed_quote: TypedQuote<'t>,
-    pub in_str: InStr<'t>,
+    pub typed_str_opt: Option<TypedStrOpt<'t>>,
     pub quote: Quote<'t>,
 }
 
 impl ToSpan for TypedStr<'_> {
     fn span(&self) -> Span {
-        self.typed_quote.span() + self.in_str.span() + self.quote.span()
+        self.typed_quote.span()
+            + self
+                .typed_str_opt
+                .as_ref()
+                .map_or(Span::default(), |o| o.span())
+            + self.quote.span()
+    }
+}
+
+///
+/// Type derived for non-terminal TypedStrOpt
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct TypedStrOpt<'t> {
+    pub in_str: InStr<'t>,
+}
+
+impl ToSpan for TypedStrOpt<'_> {
+    fn span(&self) -> Span {
+        self.in_str.span()
     }
 }
 
@@ -1578,7 +2062,10 @@ impl ToSpan for TypedStr<'_> {
 pub enum Value<'t> {
     Object(ValueObject<'t>),
     Array(ValueArray<'t>),
+    Tuple(ValueTuple<'t>),
     Integer(ValueInteger<'t>),
+    Float(ValueFloat<'t>),
+    FloatName(ValueFloatName<'t>),
     Boolean(ValueBoolean<'t>),
     Null(ValueNull<'t>),
     StrContinues(ValueStrContinues<'t>),
@@ -1587,6 +2074,7 @@ pub enum Value<'t> {
     CodeBlock(ValueCodeBlock<'t>),
     NamedCode(ValueNamedCode<'t>),
     Code(ValueCode<'t>),
+    Path(ValuePath<'t>),
 }
 
 impl ToSpan for Value<'_> {
@@ -1594,7 +2082,10 @@ impl ToSpan for Value<'_> {
         match self {
             Value::Object(v) => v.span(),
             Value::Array(v) => v.span(),
+            Value::Tuple(v) => v.span(),
             Value::Integer(v) => v.span(),
+            Value::Float(v) => v.span(),
+            Value::FloatName(v) => v.span(),
             Value::Boolean(v) => v.span(),
             Value::Null(v) => v.span(),
             Value::StrContinues(v) => v.span(),
@@ -1603,6 +2094,7 @@ impl ToSpan for Value<'_> {
             Value::CodeBlock(v) => v.span(),
             Value::NamedCode(v) => v.span(),
             Value::Code(v) => v.span(),
+            Value::Path(v) => v.span(),
         }
     }
 }
@@ -1648,8 +2140,11 @@ impl ToSpan for Ws<'_> {
 pub enum ASTType<'t> {
     Array(Array<'t>),
     ArrayBegin(ArrayBegin<'t>),
+    ArrayElements(ArrayElements<'t>),
+    ArrayElementsOpt(Option<ArrayElementsOpt<'t>>),
+    ArrayElementsTail(ArrayElementsTail<'t>),
+    ArrayElementsTailOpt(Option<ArrayElementsTailOpt<'t>>),
     ArrayEnd(ArrayEnd<'t>),
-    ArrayList(Vec<ArrayList<'t>>),
     ArrayMarker(ArrayMarker<'t>),
     ArrayMarkerOpt(Option<ArrayMarkerOpt<'t>>),
     ArrayOpt(Option<ArrayOpt<'t>>),
@@ -1668,6 +2163,8 @@ pub enum ASTType<'t> {
     Ext(Ext<'t>),
     ExtensionNameSpace(ExtensionNameSpace<'t>),
     False(False<'t>),
+    Float(Float<'t>),
+    FloatName(FloatName<'t>),
     Hole(Hole<'t>),
     Ident(Ident<'t>),
     InStr(InStr<'t>),
@@ -1683,6 +2180,9 @@ pub enum ASTType<'t> {
     Object(Object<'t>),
     ObjectList(Vec<ObjectList<'t>>),
     ObjectOpt(Option<ObjectOpt<'t>>),
+    Path(Path<'t>),
+    PathHead(PathHead<'t>),
+    PathList(Vec<PathList<'t>>),
     Quote(Quote<'t>),
     Section(Section<'t>),
     SectionBinding(SectionBinding<'t>),
@@ -1691,6 +2191,7 @@ pub enum ASTType<'t> {
     Str(Str<'t>),
     StrContinues(StrContinues<'t>),
     StrContinuesList(Vec<StrContinuesList<'t>>),
+    StrOpt(Option<StrOpt<'t>>),
     Swon(Swon<'t>),
     SwonList(Vec<SwonList<'t>>),
     SwonList0(Vec<SwonList0<'t>>),
@@ -1699,8 +2200,17 @@ pub enum ASTType<'t> {
     TextBindingOpt(Option<TextBindingOpt>),
     TextStart(TextStart<'t>),
     True(True<'t>),
+    Tuple(Tuple<'t>),
+    TupleBegin(TupleBegin<'t>),
+    TupleElements(TupleElements<'t>),
+    TupleElementsOpt(Option<TupleElementsOpt<'t>>),
+    TupleElementsTail(TupleElementsTail<'t>),
+    TupleElementsTailOpt(Option<TupleElementsTailOpt<'t>>),
+    TupleEnd(TupleEnd<'t>),
+    TupleOpt(Option<TupleOpt<'t>>),
     TypedQuote(TypedQuote<'t>),
     TypedStr(TypedStr<'t>),
+    TypedStrOpt(Option<TypedStrOpt<'t>>),
     Value(Value<'t>),
     ValueBinding(ValueBinding<'t>),
     Ws(Ws<'t>),
@@ -1710,11 +2220,11 @@ impl ToSpan for ASTType<'_> {
         match self {
             ASTType::Array(v) => v.span(),
             ASTType::ArrayBegin(v) => v.span(),
+            ASTType::ArrayElements(v) => v.span(),
+            ASTType::ArrayElementsOpt(o) => o.as_ref().map_or(Span::default(), |o| o.span()),
+            ASTType::ArrayElementsTail(v) => v.span(),
+            ASTType::ArrayElementsTailOpt(o) => o.as_ref().map_or(Span::default(), |o| o.span()),
             ASTType::ArrayEnd(v) => v.span(),
-            ASTType::ArrayList(v) => {
-                v.first().map_or(Span::default(), |f| f.span())
-                    + v.last().map_or(Span::default(), |l| l.span())
-            }
             ASTType::ArrayMarker(v) => v.span(),
             ASTType::ArrayMarkerOpt(o) => o.as_ref().map_or(Span::default(), |o| o.span()),
             ASTType::ArrayOpt(o) => o.as_ref().map_or(Span::default(), |o| o.span()),
@@ -1733,6 +2243,8 @@ impl ToSpan for ASTType<'_> {
             ASTType::Ext(v) => v.span(),
             ASTType::ExtensionNameSpace(v) => v.span(),
             ASTType::False(v) => v.span(),
+            ASTType::Float(v) => v.span(),
+            ASTType::FloatName(v) => v.span(),
             ASTType::Hole(v) => v.span(),
             ASTType::Ident(v) => v.span(),
             ASTType::InStr(v) => v.span(),
@@ -1754,6 +2266,12 @@ impl ToSpan for ASTType<'_> {
                     + v.last().map_or(Span::default(), |l| l.span())
             }
             ASTType::ObjectOpt(o) => o.as_ref().map_or(Span::default(), |o| o.span()),
+            ASTType::Path(v) => v.span(),
+            ASTType::PathHead(v) => v.span(),
+            ASTType::PathList(v) => {
+                v.first().map_or(Span::default(), |f| f.span())
+                    + v.last().map_or(Span::default(), |l| l.span())
+            }
             ASTType::Quote(v) => v.span(),
             ASTType::Section(v) => v.span(),
             ASTType::SectionBinding(v) => v.span(),
@@ -1768,6 +2286,7 @@ impl ToSpan for ASTType<'_> {
                 v.first().map_or(Span::default(), |f| f.span())
                     + v.last().map_or(Span::default(), |l| l.span())
             }
+            ASTType::StrOpt(o) => o.as_ref().map_or(Span::default(), |o| o.span()),
             ASTType::Swon(v) => v.span(),
             ASTType::SwonList(v) => {
                 v.first().map_or(Span::default(), |f| f.span())
@@ -1782,8 +2301,17 @@ impl ToSpan for ASTType<'_> {
             ASTType::TextBindingOpt(o) => o.as_ref().map_or(Span::default(), |o| o.span()),
             ASTType::TextStart(v) => v.span(),
             ASTType::True(v) => v.span(),
+            ASTType::Tuple(v) => v.span(),
+            ASTType::TupleBegin(v) => v.span(),
+            ASTType::TupleElements(v) => v.span(),
+            ASTType::TupleElementsOpt(o) => o.as_ref().map_or(Span::default(), |o| o.span()),
+            ASTType::TupleElementsTail(v) => v.span(),
+            ASTType::TupleElementsTailOpt(o) => o.as_ref().map_or(Span::default(), |o| o.span()),
+            ASTType::TupleEnd(v) => v.span(),
+            ASTType::TupleOpt(o) => o.as_ref().map_or(Span::default(), |o| o.span()),
             ASTType::TypedQuote(v) => v.span(),
             ASTType::TypedStr(v) => v.span(),
+            ASTType::TypedStrOpt(o) => o.as_ref().map_or(Span::default(), |o| o.span()),
             ASTType::Value(v) => v.span(),
             ASTType::ValueBinding(v) => v.span(),
             ASTType::Ws(v) => v.span(),
@@ -2440,6 +2968,23 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
 
     /// Semantic action for production 32:
     ///
+    /// `KeyBase: Float;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn key_base_4(&mut self, _float: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let float = pop_item!(self, float, Float, context);
+        let key_base_4_built = KeyBaseFloat { float };
+        let key_base_4_built = KeyBase::Float(key_base_4_built);
+        // Calling user action here
+        self.user_grammar.key_base(&key_base_4_built)?;
+        self.push(ASTType::KeyBase(key_base_4_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 33:
+    ///
     /// `ExtensionNameSpace: Ext Ident;`
     ///
     #[parol_runtime::function_name::named]
@@ -2463,7 +3008,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 33:
+    /// Semantic action for production 34:
     ///
     /// `Value: Object;`
     ///
@@ -2480,7 +3025,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 34:
+    /// Semantic action for production 35:
     ///
     /// `Value: Array;`
     ///
@@ -2497,160 +3042,228 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 35:
+    /// Semantic action for production 36:
     ///
-    /// `Value: Integer;`
+    /// `Value: Tuple;`
     ///
     #[parol_runtime::function_name::named]
-    fn value_2(&mut self, _integer: &ParseTreeType<'t>) -> Result<()> {
+    fn value_2(&mut self, _tuple: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let integer = pop_item!(self, integer, Integer, context);
-        let value_2_built = ValueInteger { integer };
-        let value_2_built = Value::Integer(value_2_built);
+        let tuple = pop_item!(self, tuple, Tuple, context);
+        let value_2_built = ValueTuple { tuple };
+        let value_2_built = Value::Tuple(value_2_built);
         // Calling user action here
         self.user_grammar.value(&value_2_built)?;
         self.push(ASTType::Value(value_2_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 36:
+    /// Semantic action for production 37:
     ///
-    /// `Value: Boolean;`
+    /// `Value: Integer;`
     ///
     #[parol_runtime::function_name::named]
-    fn value_3(&mut self, _boolean: &ParseTreeType<'t>) -> Result<()> {
+    fn value_3(&mut self, _integer: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let boolean = pop_item!(self, boolean, Boolean, context);
-        let value_3_built = ValueBoolean { boolean };
-        let value_3_built = Value::Boolean(value_3_built);
+        let integer = pop_item!(self, integer, Integer, context);
+        let value_3_built = ValueInteger { integer };
+        let value_3_built = Value::Integer(value_3_built);
         // Calling user action here
         self.user_grammar.value(&value_3_built)?;
         self.push(ASTType::Value(value_3_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 37:
+    /// Semantic action for production 38:
     ///
-    /// `Value: Null;`
+    /// `Value: Float;`
     ///
     #[parol_runtime::function_name::named]
-    fn value_4(&mut self, _null: &ParseTreeType<'t>) -> Result<()> {
+    fn value_4(&mut self, _float: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let null = pop_item!(self, null, Null, context);
-        let value_4_built = ValueNull { null };
-        let value_4_built = Value::Null(value_4_built);
+        let float = pop_item!(self, float, Float, context);
+        let value_4_built = ValueFloat { float };
+        let value_4_built = Value::Float(value_4_built);
         // Calling user action here
         self.user_grammar.value(&value_4_built)?;
         self.push(ASTType::Value(value_4_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 38:
+    /// Semantic action for production 39:
     ///
-    /// `Value: StrContinues;`
+    /// `Value: FloatName;`
     ///
     #[parol_runtime::function_name::named]
-    fn value_5(&mut self, _str_continues: &ParseTreeType<'t>) -> Result<()> {
+    fn value_5(&mut self, _float_name: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let str_continues = pop_item!(self, str_continues, StrContinues, context);
-        let value_5_built = ValueStrContinues { str_continues };
-        let value_5_built = Value::StrContinues(value_5_built);
+        let float_name = pop_item!(self, float_name, FloatName, context);
+        let value_5_built = ValueFloatName { float_name };
+        let value_5_built = Value::FloatName(value_5_built);
         // Calling user action here
         self.user_grammar.value(&value_5_built)?;
         self.push(ASTType::Value(value_5_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 39:
+    /// Semantic action for production 40:
     ///
-    /// `Value: TypedStr;`
+    /// `Value: Boolean;`
     ///
     #[parol_runtime::function_name::named]
-    fn value_6(&mut self, _typed_str: &ParseTreeType<'t>) -> Result<()> {
+    fn value_6(&mut self, _boolean: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let typed_str = pop_item!(self, typed_str, TypedStr, context);
-        let value_6_built = ValueTypedStr { typed_str };
-        let value_6_built = Value::TypedStr(value_6_built);
+        let boolean = pop_item!(self, boolean, Boolean, context);
+        let value_6_built = ValueBoolean { boolean };
+        let value_6_built = Value::Boolean(value_6_built);
         // Calling user action here
         self.user_grammar.value(&value_6_built)?;
         self.push(ASTType::Value(value_6_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 40:
+    /// Semantic action for production 41:
     ///
-    /// `Value: Hole;`
+    /// `Value: Null;`
     ///
     #[parol_runtime::function_name::named]
-    fn value_7(&mut self, _hole: &ParseTreeType<'t>) -> Result<()> {
+    fn value_7(&mut self, _null: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let hole = pop_item!(self, hole, Hole, context);
-        let value_7_built = ValueHole { hole };
-        let value_7_built = Value::Hole(value_7_built);
+        let null = pop_item!(self, null, Null, context);
+        let value_7_built = ValueNull { null };
+        let value_7_built = Value::Null(value_7_built);
         // Calling user action here
         self.user_grammar.value(&value_7_built)?;
         self.push(ASTType::Value(value_7_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 41:
+    /// Semantic action for production 42:
     ///
-    /// `Value: CodeBlock;`
+    /// `Value: StrContinues;`
     ///
     #[parol_runtime::function_name::named]
-    fn value_8(&mut self, _code_block: &ParseTreeType<'t>) -> Result<()> {
+    fn value_8(&mut self, _str_continues: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let code_block = pop_item!(self, code_block, CodeBlock, context);
-        let value_8_built = ValueCodeBlock { code_block };
-        let value_8_built = Value::CodeBlock(value_8_built);
+        let str_continues = pop_item!(self, str_continues, StrContinues, context);
+        let value_8_built = ValueStrContinues { str_continues };
+        let value_8_built = Value::StrContinues(value_8_built);
         // Calling user action here
         self.user_grammar.value(&value_8_built)?;
         self.push(ASTType::Value(value_8_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 42:
+    /// Semantic action for production 43:
     ///
-    /// `Value: NamedCode;`
+    /// `Value: TypedStr;`
     ///
     #[parol_runtime::function_name::named]
-    fn value_9(&mut self, _named_code: &ParseTreeType<'t>) -> Result<()> {
+    fn value_9(&mut self, _typed_str: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let named_code = pop_item!(self, named_code, NamedCode, context);
-        let value_9_built = ValueNamedCode { named_code };
-        let value_9_built = Value::NamedCode(value_9_built);
+        let typed_str = pop_item!(self, typed_str, TypedStr, context);
+        let value_9_built = ValueTypedStr { typed_str };
+        let value_9_built = Value::TypedStr(value_9_built);
         // Calling user action here
         self.user_grammar.value(&value_9_built)?;
         self.push(ASTType::Value(value_9_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 43:
+    /// Semantic action for production 44:
     ///
-    /// `Value: Code;`
+    /// `Value: Hole;`
     ///
     #[parol_runtime::function_name::named]
-    fn value_10(&mut self, _code: &ParseTreeType<'t>) -> Result<()> {
+    fn value_10(&mut self, _hole: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let code = pop_item!(self, code, Code, context);
-        let value_10_built = ValueCode { code };
-        let value_10_built = Value::Code(value_10_built);
+        let hole = pop_item!(self, hole, Hole, context);
+        let value_10_built = ValueHole { hole };
+        let value_10_built = Value::Hole(value_10_built);
         // Calling user action here
         self.user_grammar.value(&value_10_built)?;
         self.push(ASTType::Value(value_10_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 44:
+    /// Semantic action for production 45:
+    ///
+    /// `Value: CodeBlock;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn value_11(&mut self, _code_block: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let code_block = pop_item!(self, code_block, CodeBlock, context);
+        let value_11_built = ValueCodeBlock { code_block };
+        let value_11_built = Value::CodeBlock(value_11_built);
+        // Calling user action here
+        self.user_grammar.value(&value_11_built)?;
+        self.push(ASTType::Value(value_11_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 46:
+    ///
+    /// `Value: NamedCode;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn value_12(&mut self, _named_code: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let named_code = pop_item!(self, named_code, NamedCode, context);
+        let value_12_built = ValueNamedCode { named_code };
+        let value_12_built = Value::NamedCode(value_12_built);
+        // Calling user action here
+        self.user_grammar.value(&value_12_built)?;
+        self.push(ASTType::Value(value_12_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 47:
+    ///
+    /// `Value: Code;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn value_13(&mut self, _code: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let code = pop_item!(self, code, Code, context);
+        let value_13_built = ValueCode { code };
+        let value_13_built = Value::Code(value_13_built);
+        // Calling user action here
+        self.user_grammar.value(&value_13_built)?;
+        self.push(ASTType::Value(value_13_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 48:
+    ///
+    /// `Value: Path;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn value_14(&mut self, _path: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let path = pop_item!(self, path, Path, context);
+        let value_14_built = ValuePath { path };
+        let value_14_built = Value::Path(value_14_built);
+        // Calling user action here
+        self.user_grammar.value(&value_14_built)?;
+        self.push(ASTType::Value(value_14_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 49:
     ///
     /// `Object: Begin ObjectList /* Vec */ End;`
     ///
@@ -2677,7 +3290,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 45:
+    /// Semantic action for production 50:
     ///
     /// `ObjectList /* Vec<T>::Push */: Key Bind Value ObjectOpt /* Option */ ObjectList;`
     ///
@@ -2709,7 +3322,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 46:
+    /// Semantic action for production 51:
     ///
     /// `ObjectList /* Vec<T>::New */: ;`
     ///
@@ -2722,7 +3335,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 47:
+    /// Semantic action for production 52:
     ///
     /// `ObjectOpt /* Option<T>::Some */: Comma;`
     ///
@@ -2736,7 +3349,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 48:
+    /// Semantic action for production 53:
     ///
     /// `ObjectOpt /* Option<T>::None */: ;`
     ///
@@ -2748,25 +3361,25 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 49:
+    /// Semantic action for production 54:
     ///
-    /// `Array: ArrayBegin ArrayList /* Vec */ ArrayEnd;`
+    /// `Array: ArrayBegin ArrayOpt /* Option */ ArrayEnd;`
     ///
     #[parol_runtime::function_name::named]
     fn array(
         &mut self,
         _array_begin: &ParseTreeType<'t>,
-        _array_list: &ParseTreeType<'t>,
+        _array_opt: &ParseTreeType<'t>,
         _array_end: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let array_end = pop_item!(self, array_end, ArrayEnd, context);
-        let array_list = pop_and_reverse_item!(self, array_list, ArrayList, context);
+        let array_opt = pop_item!(self, array_opt, ArrayOpt, context);
         let array_begin = pop_item!(self, array_begin, ArrayBegin, context);
         let array_built = Array {
             array_begin,
-            array_list,
+            array_opt: array_opt.map(Box::new),
             array_end,
         };
         // Calling user action here
@@ -2775,71 +3388,314 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 50:
+    /// Semantic action for production 55:
     ///
-    /// `ArrayList /* Vec<T>::Push */: Value ArrayOpt /* Option */ ArrayList;`
+    /// `ArrayOpt /* Option<T>::Some */: ArrayElements;`
     ///
     #[parol_runtime::function_name::named]
-    fn array_list_0(
+    fn array_opt_0(&mut self, _array_elements: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let array_elements = pop_item!(self, array_elements, ArrayElements, context);
+        let array_opt_0_built = ArrayOpt { array_elements };
+        self.push(ASTType::ArrayOpt(Some(array_opt_0_built)), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 56:
+    ///
+    /// `ArrayOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn array_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::ArrayOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 57:
+    ///
+    /// `ArrayElements: Value ArrayElementsOpt /* Option */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn array_elements(
         &mut self,
         _value: &ParseTreeType<'t>,
-        _array_opt: &ParseTreeType<'t>,
-        _array_list: &ParseTreeType<'t>,
+        _array_elements_opt: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let mut array_list = pop_item!(self, array_list, ArrayList, context);
-        let array_opt = pop_item!(self, array_opt, ArrayOpt, context);
+        let array_elements_opt = pop_item!(self, array_elements_opt, ArrayElementsOpt, context);
         let value = pop_item!(self, value, Value, context);
-        let array_list_0_built = ArrayList { array_opt, value };
-        // Add an element to the vector
-        array_list.push(array_list_0_built);
-        self.push(ASTType::ArrayList(array_list), context);
+        let array_elements_built = ArrayElements {
+            value,
+            array_elements_opt: array_elements_opt.map(Box::new),
+        };
+        // Calling user action here
+        self.user_grammar.array_elements(&array_elements_built)?;
+        self.push(ASTType::ArrayElements(array_elements_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 51:
+    /// Semantic action for production 58:
+    ///
+    /// `ArrayElementsOpt /* Option<T>::Some */: ArrayElementsTail;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn array_elements_opt_0(&mut self, _array_elements_tail: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let array_elements_tail = pop_item!(self, array_elements_tail, ArrayElementsTail, context);
+        let array_elements_opt_0_built = ArrayElementsOpt {
+            array_elements_tail,
+        };
+        self.push(
+            ASTType::ArrayElementsOpt(Some(array_elements_opt_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 59:
     ///
-    /// `ArrayList /* Vec<T>::New */: ;`
+    /// `ArrayElementsOpt /* Option<T>::None */: ;`
     ///
     #[parol_runtime::function_name::named]
-    fn array_list_1(&mut self) -> Result<()> {
+    fn array_elements_opt_1(&mut self) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let array_list_1_built = Vec::new();
-        self.push(ASTType::ArrayList(array_list_1_built), context);
+        self.push(ASTType::ArrayElementsOpt(None), context);
         Ok(())
     }
 
-    /// Semantic action for production 52:
+    /// Semantic action for production 60:
     ///
-    /// `ArrayOpt /* Option<T>::Some */: Comma;`
+    /// `ArrayElementsTail: Comma ArrayElementsTailOpt /* Option */;`
     ///
     #[parol_runtime::function_name::named]
-    fn array_opt_0(&mut self, _comma: &ParseTreeType<'t>) -> Result<()> {
+    fn array_elements_tail(
+        &mut self,
+        _comma: &ParseTreeType<'t>,
+        _array_elements_tail_opt: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
+        let array_elements_tail_opt =
+            pop_item!(self, array_elements_tail_opt, ArrayElementsTailOpt, context);
         let comma = pop_item!(self, comma, Comma, context);
-        let array_opt_0_built = ArrayOpt { comma };
-        self.push(ASTType::ArrayOpt(Some(array_opt_0_built)), context);
+        let array_elements_tail_built = ArrayElementsTail {
+            comma,
+            array_elements_tail_opt,
+        };
+        // Calling user action here
+        self.user_grammar
+            .array_elements_tail(&array_elements_tail_built)?;
+        self.push(
+            ASTType::ArrayElementsTail(array_elements_tail_built),
+            context,
+        );
         Ok(())
     }
 
-    /// Semantic action for production 53:
+    /// Semantic action for production 61:
     ///
-    /// `ArrayOpt /* Option<T>::None */: ;`
+    /// `ArrayElementsTailOpt /* Option<T>::Some */: ArrayElements;`
     ///
     #[parol_runtime::function_name::named]
-    fn array_opt_1(&mut self) -> Result<()> {
+    fn array_elements_tail_opt_0(&mut self, _array_elements: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        self.push(ASTType::ArrayOpt(None), context);
+        let array_elements = pop_item!(self, array_elements, ArrayElements, context);
+        let array_elements_tail_opt_0_built = ArrayElementsTailOpt { array_elements };
+        self.push(
+            ASTType::ArrayElementsTailOpt(Some(array_elements_tail_opt_0_built)),
+            context,
+        );
         Ok(())
     }
 
-    /// Semantic action for production 54:
+    /// Semantic action for production 62:
     ///
-    /// `Integer: /\d[\d_]*/;`
+    /// `ArrayElementsTailOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn array_elements_tail_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::ArrayElementsTailOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 63:
+    ///
+    /// `Tuple: TupleBegin TupleOpt /* Option */ TupleEnd;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn tuple(
+        &mut self,
+        _tuple_begin: &ParseTreeType<'t>,
+        _tuple_opt: &ParseTreeType<'t>,
+        _tuple_end: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let tuple_end = pop_item!(self, tuple_end, TupleEnd, context);
+        let tuple_opt = pop_item!(self, tuple_opt, TupleOpt, context);
+        let tuple_begin = pop_item!(self, tuple_begin, TupleBegin, context);
+        let tuple_built = Tuple {
+            tuple_begin,
+            tuple_opt: tuple_opt.map(Box::new),
+            tuple_end,
+        };
+        // Calling user action here
+        self.user_grammar.tuple(&tuple_built)?;
+        self.push(ASTType::Tuple(tuple_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 64:
+    ///
+    /// `TupleOpt /* Option<T>::Some */: TupleElements;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn tuple_opt_0(&mut self, _tuple_elements: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let tuple_elements = pop_item!(self, tuple_elements, TupleElements, context);
+        let tuple_opt_0_built = TupleOpt { tuple_elements };
+        self.push(ASTType::TupleOpt(Some(tuple_opt_0_built)), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 65:
+    ///
+    /// `TupleOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn tuple_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::TupleOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 66:
+    ///
+    /// `TupleElements: Value TupleElementsOpt /* Option */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn tuple_elements(
+        &mut self,
+        _value: &ParseTreeType<'t>,
+        _tuple_elements_opt: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let tuple_elements_opt = pop_item!(self, tuple_elements_opt, TupleElementsOpt, context);
+        let value = pop_item!(self, value, Value, context);
+        let tuple_elements_built = TupleElements {
+            value,
+            tuple_elements_opt: tuple_elements_opt.map(Box::new),
+        };
+        // Calling user action here
+        self.user_grammar.tuple_elements(&tuple_elements_built)?;
+        self.push(ASTType::TupleElements(tuple_elements_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 67:
+    ///
+    /// `TupleElementsOpt /* Option<T>::Some */: TupleElementsTail;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn tuple_elements_opt_0(&mut self, _tuple_elements_tail: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let tuple_elements_tail = pop_item!(self, tuple_elements_tail, TupleElementsTail, context);
+        let tuple_elements_opt_0_built = TupleElementsOpt {
+            tuple_elements_tail,
+        };
+        self.push(
+            ASTType::TupleElementsOpt(Some(tuple_elements_opt_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 68:
+    ///
+    /// `TupleElementsOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn tuple_elements_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::TupleElementsOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 69:
+    ///
+    /// `TupleElementsTail: Comma TupleElementsTailOpt /* Option */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn tuple_elements_tail(
+        &mut self,
+        _comma: &ParseTreeType<'t>,
+        _tuple_elements_tail_opt: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let tuple_elements_tail_opt =
+            pop_item!(self, tuple_elements_tail_opt, TupleElementsTailOpt, context);
+        let comma = pop_item!(self, comma, Comma, context);
+        let tuple_elements_tail_built = TupleElementsTail {
+            comma,
+            tuple_elements_tail_opt,
+        };
+        // Calling user action here
+        self.user_grammar
+            .tuple_elements_tail(&tuple_elements_tail_built)?;
+        self.push(
+            ASTType::TupleElementsTail(tuple_elements_tail_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 70:
+    ///
+    /// `TupleElementsTailOpt /* Option<T>::Some */: TupleElements;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn tuple_elements_tail_opt_0(&mut self, _tuple_elements: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let tuple_elements = pop_item!(self, tuple_elements, TupleElements, context);
+        let tuple_elements_tail_opt_0_built = TupleElementsTailOpt { tuple_elements };
+        self.push(
+            ASTType::TupleElementsTailOpt(Some(tuple_elements_tail_opt_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 71:
+    ///
+    /// `TupleElementsTailOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn tuple_elements_tail_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::TupleElementsTailOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 72:
+    ///
+    /// `Integer: /[+-]?(0x[0-9a-fA-F][0-9a-fA-F_]*|0o[0-7][0-7_]*|0b[01][01_]*|\d[\d_]*)/;`
     ///
     #[parol_runtime::function_name::named]
     fn integer(&mut self, integer: &ParseTreeType<'t>) -> Result<()> {
@@ -2853,7 +3709,39 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 55:
+    /// Semantic action for production 73:
+    ///
+    /// `Float: /[+-]?(\d[\d_]*\.\d[\d_]*([eE][+-]?\d[\d_]*)?|\d[\d_]*[eE][+-]?\d[\d_]*)|[+-](inf|nan)/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn float(&mut self, float: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let float = float.token()?.clone();
+        let float_built = Float { float };
+        // Calling user action here
+        self.user_grammar.float(&float_built)?;
+        self.push(ASTType::Float(float_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 74:
+    ///
+    /// `FloatName: Ident;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn float_name(&mut self, _ident: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let ident = pop_item!(self, ident, Ident, context);
+        let float_name_built = FloatName { ident };
+        // Calling user action here
+        self.user_grammar.float_name(&float_name_built)?;
+        self.push(ASTType::FloatName(float_name_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 75:
     ///
     /// `Boolean: True;`
     ///
@@ -2870,7 +3758,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 56:
+    /// Semantic action for production 76:
     ///
     /// `Boolean: False;`
     ///
@@ -2887,7 +3775,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 57:
+    /// Semantic action for production 77:
     ///
     /// `True: 'true';`
     ///
@@ -2903,7 +3791,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 58:
+    /// Semantic action for production 78:
     ///
     /// `False: 'false';`
     ///
@@ -2919,7 +3807,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 59:
+    /// Semantic action for production 79:
     ///
     /// `Null: 'null';`
     ///
@@ -2935,7 +3823,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 60:
+    /// Semantic action for production 80:
     ///
     /// `Hole: '!';`
     ///
@@ -2951,7 +3839,104 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 61:
+    /// Semantic action for production 81:
+    ///
+    /// `Path: Dot PathHead PathList /* Vec */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn path(
+        &mut self,
+        _dot: &ParseTreeType<'t>,
+        _path_head: &ParseTreeType<'t>,
+        _path_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let path_list = pop_and_reverse_item!(self, path_list, PathList, context);
+        let path_head = pop_item!(self, path_head, PathHead, context);
+        let dot = pop_item!(self, dot, Dot, context);
+        let path_built = Path {
+            dot,
+            path_head,
+            path_list,
+        };
+        // Calling user action here
+        self.user_grammar.path(&path_built)?;
+        self.push(ASTType::Path(path_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 82:
+    ///
+    /// `PathList /* Vec<T>::Push */: Dot Key PathList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn path_list_0(
+        &mut self,
+        _dot: &ParseTreeType<'t>,
+        _key: &ParseTreeType<'t>,
+        _path_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut path_list = pop_item!(self, path_list, PathList, context);
+        let key = pop_item!(self, key, Key, context);
+        let dot = pop_item!(self, dot, Dot, context);
+        let path_list_0_built = PathList { key, dot };
+        // Add an element to the vector
+        path_list.push(path_list_0_built);
+        self.push(ASTType::PathList(path_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 83:
+    ///
+    /// `PathList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn path_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let path_list_1_built = Vec::new();
+        self.push(ASTType::PathList(path_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 84:
+    ///
+    /// `PathHead: Key;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn path_head_0(&mut self, _key: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let key = pop_item!(self, key, Key, context);
+        let path_head_0_built = PathHeadKey { key };
+        let path_head_0_built = PathHead::Key(path_head_0_built);
+        // Calling user action here
+        self.user_grammar.path_head(&path_head_0_built)?;
+        self.push(ASTType::PathHead(path_head_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 85:
+    ///
+    /// `PathHead: ArrayMarker;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn path_head_1(&mut self, _array_marker: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let array_marker = pop_item!(self, array_marker, ArrayMarker, context);
+        let path_head_1_built = PathHeadArrayMarker { array_marker };
+        let path_head_1_built = PathHead::ArrayMarker(path_head_1_built);
+        // Calling user action here
+        self.user_grammar.path_head(&path_head_1_built)?;
+        self.push(ASTType::PathHead(path_head_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 86:
     ///
     /// `StrContinues: Str StrContinuesList /* Vec */;`
     ///
@@ -2976,7 +3961,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 62:
+    /// Semantic action for production 87:
     ///
     /// `StrContinuesList /* Vec<T>::Push */: Continue Str StrContinuesList;`
     ///
@@ -2999,7 +3984,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 63:
+    /// Semantic action for production 88:
     ///
     /// `StrContinuesList /* Vec<T>::New */: ;`
     ///
@@ -3015,25 +4000,25 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 64:
+    /// Semantic action for production 89:
     ///
-    /// `Str: Quote InStr Quote;`
+    /// `Str: Quote StrOpt /* Option */ Quote;`
     ///
     #[parol_runtime::function_name::named]
     fn str(
         &mut self,
         _quote: &ParseTreeType<'t>,
-        _in_str: &ParseTreeType<'t>,
+        _str_opt: &ParseTreeType<'t>,
         _quote0: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let quote0 = pop_item!(self, quote0, Quote, context);
-        let in_str = pop_item!(self, in_str, InStr, context);
+        let str_opt = pop_item!(self, str_opt, StrOpt, context);
         let quote = pop_item!(self, quote, Quote, context);
         let str_built = Str {
             quote,
-            in_str,
+            str_opt,
             quote0,
         };
         // Calling user action here
@@ -3042,25 +4027,51 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 65:
+    /// Semantic action for production 90:
     ///
-    /// `TypedStr: TypedQuote InStr Quote;`
+    /// `StrOpt /* Option<T>::Some */: InStr;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn str_opt_0(&mut self, _in_str: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let in_str = pop_item!(self, in_str, InStr, context);
+        let str_opt_0_built = StrOpt { in_str };
+        self.push(ASTType::StrOpt(Some(str_opt_0_built)), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 91:
+    ///
+    /// `StrOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn str_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::StrOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 92:
+    ///
+    /// `TypedStr: TypedQuote TypedStrOpt /* Option */ Quote;`
     ///
     #[parol_runtime::function_name::named]
     fn typed_str(
         &mut self,
         _typed_quote: &ParseTreeType<'t>,
-        _in_str: &ParseTreeType<'t>,
+        _typed_str_opt: &ParseTreeType<'t>,
         _quote: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let quote = pop_item!(self, quote, Quote, context);
-        let in_str = pop_item!(self, in_str, InStr, context);
+        let typed_str_opt = pop_item!(self, typed_str_opt, TypedStrOpt, context);
         let typed_quote = pop_item!(self, typed_quote, TypedQuote, context);
         let typed_str_built = TypedStr {
             typed_quote,
-            in_str,
+            typed_str_opt,
             quote,
         };
         // Calling user action here
@@ -3069,7 +4080,33 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 66:
+    /// Semantic action for production 93:
+    ///
+    /// `TypedStrOpt /* Option<T>::Some */: InStr;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn typed_str_opt_0(&mut self, _in_str: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let in_str = pop_item!(self, in_str, InStr, context);
+        let typed_str_opt_0_built = TypedStrOpt { in_str };
+        self.push(ASTType::TypedStrOpt(Some(typed_str_opt_0_built)), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 94:
+    ///
+    /// `TypedStrOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn typed_str_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::TypedStrOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 95:
     ///
     /// `Quote: <INITIAL, Str>'"';`
     ///
@@ -3085,7 +4122,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 67:
+    /// Semantic action for production 96:
     ///
     /// `TypedQuote: /[a-zA-Z0-9-_]+"/;`
     ///
@@ -3101,9 +4138,9 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 68:
+    /// Semantic action for production 97:
     ///
-    /// `InStr: <Str>/(\\[nrt\\"0]|[^\\"\r\n])*/;`
+    /// `InStr: <Str>/(\\[nrt\\"0]|[^\\"\r\n])+/;`
     ///
     #[parol_runtime::function_name::named]
     fn in_str(&mut self, in_str: &ParseTreeType<'t>) -> Result<()> {
@@ -3117,7 +4154,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 69:
+    /// Semantic action for production 98:
     ///
     /// `Text: <Text>/[^\r\n]*/;`
     ///
@@ -3133,9 +4170,9 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 70:
+    /// Semantic action for production 99:
     ///
-    /// `CodeBlock: /```[a-zA-Z0-9-_]*(\r\n|\r|\n)([^`]|[`]{1,2})*```/;`
+    /// `CodeBlock: /```[a-zA-Z0-9-_]*(\r\n|\r|\n)([^`]|`[^`]|``[^`])*```/;`
     ///
     #[parol_runtime::function_name::named]
     fn code_block(&mut self, code_block: &ParseTreeType<'t>) -> Result<()> {
@@ -3149,7 +4186,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 71:
+    /// Semantic action for production 100:
     ///
     /// `NamedCode: /[a-zA-Z0-9-_]+`([^`\r\n]|\\`)*`/;`
     ///
@@ -3165,7 +4202,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 72:
+    /// Semantic action for production 101:
     ///
     /// `Code: /`([^`\r\n]|\\`)*`/;`
     ///
@@ -3181,7 +4218,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 73:
+    /// Semantic action for production 102:
     ///
     /// `Newline: <Text>/\r\n|\r|\n/;`
     ///
@@ -3197,7 +4234,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 74:
+    /// Semantic action for production 103:
     ///
     /// `Ws: <Str, Text>/[\s--\r\n]+/;`
     ///
@@ -3213,7 +4250,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 75:
+    /// Semantic action for production 104:
     ///
     /// `At: '@';`
     ///
@@ -3229,7 +4266,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 76:
+    /// Semantic action for production 105:
     ///
     /// `Ext: '$';`
     ///
@@ -3245,7 +4282,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 77:
+    /// Semantic action for production 106:
     ///
     /// `Dot: '.';`
     ///
@@ -3261,7 +4298,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 78:
+    /// Semantic action for production 107:
     ///
     /// `Begin: '{';`
     ///
@@ -3277,7 +4314,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 79:
+    /// Semantic action for production 108:
     ///
     /// `End: '}';`
     ///
@@ -3293,7 +4330,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 80:
+    /// Semantic action for production 109:
     ///
     /// `ArrayBegin: '[';`
     ///
@@ -3309,7 +4346,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 81:
+    /// Semantic action for production 110:
     ///
     /// `ArrayEnd: ']';`
     ///
@@ -3325,7 +4362,39 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 82:
+    /// Semantic action for production 111:
+    ///
+    /// `TupleBegin: '(';`
+    ///
+    #[parol_runtime::function_name::named]
+    fn tuple_begin(&mut self, tuple_begin: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let tuple_begin = tuple_begin.token()?.clone();
+        let tuple_begin_built = TupleBegin { tuple_begin };
+        // Calling user action here
+        self.user_grammar.tuple_begin(&tuple_begin_built)?;
+        self.push(ASTType::TupleBegin(tuple_begin_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 112:
+    ///
+    /// `TupleEnd: ')';`
+    ///
+    #[parol_runtime::function_name::named]
+    fn tuple_end(&mut self, tuple_end: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let tuple_end = tuple_end.token()?.clone();
+        let tuple_end_built = TupleEnd { tuple_end };
+        // Calling user action here
+        self.user_grammar.tuple_end(&tuple_end_built)?;
+        self.push(ASTType::TupleEnd(tuple_end_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 113:
     ///
     /// `Bind: '=';`
     ///
@@ -3341,7 +4410,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 83:
+    /// Semantic action for production 114:
     ///
     /// `Comma: ',';`
     ///
@@ -3357,7 +4426,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 84:
+    /// Semantic action for production 115:
     ///
     /// `Continue: '\\';`
     ///
@@ -3373,7 +4442,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 85:
+    /// Semantic action for production 116:
     ///
     /// `TextStart: ":";`
     ///
@@ -3389,7 +4458,7 @@ impl<'t, 'u> GrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 86:
+    /// Semantic action for production 117:
     ///
     /// `Ident: /\p{XID_Start}[\p{XID_Continue}-]*/;`
     ///
@@ -3448,67 +4517,98 @@ impl<'t> UserActionsTrait<'t> for GrammarAuto<'t, '_> {
             29 => self.key_base_1(&children[0]),
             30 => self.key_base_2(&children[0]),
             31 => self.key_base_3(&children[0]),
-            32 => self.extension_name_space(&children[0], &children[1]),
-            33 => self.value_0(&children[0]),
-            34 => self.value_1(&children[0]),
-            35 => self.value_2(&children[0]),
-            36 => self.value_3(&children[0]),
-            37 => self.value_4(&children[0]),
-            38 => self.value_5(&children[0]),
-            39 => self.value_6(&children[0]),
-            40 => self.value_7(&children[0]),
-            41 => self.value_8(&children[0]),
-            42 => self.value_9(&children[0]),
-            43 => self.value_10(&children[0]),
-            44 => self.object(&children[0], &children[1], &children[2]),
-            45 => self.object_list_0(
+            32 => self.key_base_4(&children[0]),
+            33 => self.extension_name_space(&children[0], &children[1]),
+            34 => self.value_0(&children[0]),
+            35 => self.value_1(&children[0]),
+            36 => self.value_2(&children[0]),
+            37 => self.value_3(&children[0]),
+            38 => self.value_4(&children[0]),
+            39 => self.value_5(&children[0]),
+            40 => self.value_6(&children[0]),
+            41 => self.value_7(&children[0]),
+            42 => self.value_8(&children[0]),
+            43 => self.value_9(&children[0]),
+            44 => self.value_10(&children[0]),
+            45 => self.value_11(&children[0]),
+            46 => self.value_12(&children[0]),
+            47 => self.value_13(&children[0]),
+            48 => self.value_14(&children[0]),
+            49 => self.object(&children[0], &children[1], &children[2]),
+            50 => self.object_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            46 => self.object_list_1(),
-            47 => self.object_opt_0(&children[0]),
-            48 => self.object_opt_1(),
-            49 => self.array(&children[0], &children[1], &children[2]),
-            50 => self.array_list_0(&children[0], &children[1], &children[2]),
-            51 => self.array_list_1(),
-            52 => self.array_opt_0(&children[0]),
-            53 => self.array_opt_1(),
-            54 => self.integer(&children[0]),
-            55 => self.boolean_0(&children[0]),
-            56 => self.boolean_1(&children[0]),
-            57 => self.r#true(&children[0]),
-            58 => self.r#false(&children[0]),
-            59 => self.null(&children[0]),
-            60 => self.hole(&children[0]),
-            61 => self.str_continues(&children[0], &children[1]),
-            62 => self.str_continues_list_0(&children[0], &children[1], &children[2]),
-            63 => self.str_continues_list_1(),
-            64 => self.str(&children[0], &children[1], &children[2]),
-            65 => self.typed_str(&children[0], &children[1], &children[2]),
-            66 => self.quote(&children[0]),
-            67 => self.typed_quote(&children[0]),
-            68 => self.in_str(&children[0]),
-            69 => self.text(&children[0]),
-            70 => self.code_block(&children[0]),
-            71 => self.named_code(&children[0]),
-            72 => self.code(&children[0]),
-            73 => self.newline(&children[0]),
-            74 => self.ws(&children[0]),
-            75 => self.at(&children[0]),
-            76 => self.ext(&children[0]),
-            77 => self.dot(&children[0]),
-            78 => self.begin(&children[0]),
-            79 => self.end(&children[0]),
-            80 => self.array_begin(&children[0]),
-            81 => self.array_end(&children[0]),
-            82 => self.bind(&children[0]),
-            83 => self.comma(&children[0]),
-            84 => self.r#continue(&children[0]),
-            85 => self.text_start(&children[0]),
-            86 => self.ident(&children[0]),
+            51 => self.object_list_1(),
+            52 => self.object_opt_0(&children[0]),
+            53 => self.object_opt_1(),
+            54 => self.array(&children[0], &children[1], &children[2]),
+            55 => self.array_opt_0(&children[0]),
+            56 => self.array_opt_1(),
+            57 => self.array_elements(&children[0], &children[1]),
+            58 => self.array_elements_opt_0(&children[0]),
+            59 => self.array_elements_opt_1(),
+            60 => self.array_elements_tail(&children[0], &children[1]),
+            61 => self.array_elements_tail_opt_0(&children[0]),
+            62 => self.array_elements_tail_opt_1(),
+            63 => self.tuple(&children[0], &children[1], &children[2]),
+            64 => self.tuple_opt_0(&children[0]),
+            65 => self.tuple_opt_1(),
+            66 => self.tuple_elements(&children[0], &children[1]),
+            67 => self.tuple_elements_opt_0(&children[0]),
+            68 => self.tuple_elements_opt_1(),
+            69 => self.tuple_elements_tail(&children[0], &children[1]),
+            70 => self.tuple_elements_tail_opt_0(&children[0]),
+            71 => self.tuple_elements_tail_opt_1(),
+            72 => self.integer(&children[0]),
+            73 => self.float(&children[0]),
+            74 => self.float_name(&children[0]),
+            75 => self.boolean_0(&children[0]),
+            76 => self.boolean_1(&children[0]),
+            77 => self.r#true(&children[0]),
+            78 => self.r#false(&children[0]),
+            79 => self.null(&children[0]),
+            80 => self.hole(&children[0]),
+            81 => self.path(&children[0], &children[1], &children[2]),
+            82 => self.path_list_0(&children[0], &children[1], &children[2]),
+            83 => self.path_list_1(),
+            84 => self.path_head_0(&children[0]),
+            85 => self.path_head_1(&children[0]),
+            86 => self.str_continues(&children[0], &children[1]),
+            87 => self.str_continues_list_0(&children[0], &children[1], &children[2]),
+            88 => self.str_continues_list_1(),
+            89 => self.str(&children[0], &children[1], &children[2]),
+            90 => self.str_opt_0(&children[0]),
+            91 => self.str_opt_1(),
+            92 => self.typed_str(&children[0], &children[1], &children[2]),
+            93 => self.typed_str_opt_0(&children[0]),
+            94 => self.typed_str_opt_1(),
+            95 => self.quote(&children[0]),
+            96 => self.typed_quote(&children[0]),
+            97 => self.in_str(&children[0]),
+            98 => self.text(&children[0]),
+            99 => self.code_block(&children[0]),
+            100 => self.named_code(&children[0]),
+            101 => self.code(&children[0]),
+            102 => self.newline(&children[0]),
+            103 => self.ws(&children[0]),
+            104 => self.at(&children[0]),
+            105 => self.ext(&children[0]),
+            106 => self.dot(&children[0]),
+            107 => self.begin(&children[0]),
+            108 => self.end(&children[0]),
+            109 => self.array_begin(&children[0]),
+            110 => self.array_end(&children[0]),
+            111 => self.tuple_begin(&children[0]),
+            112 => self.tuple_end(&children[0]),
+            113 => self.bind(&children[0]),
+            114 => self.comma(&children[0]),
+            115 => self.r#continue(&children[0]),
+            116 => self.text_start(&children[0]),
+            117 => self.ident(&children[0]),
             _ => Err(ParserError::InternalError(format!(
                 "Unhandled production number: {}",
                 prod_num
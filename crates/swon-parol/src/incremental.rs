@@ -0,0 +1,295 @@
+//! Incremental reparsing of edited sections.
+//!
+//! Top-level sections are restart points: a section always starts at `@` and ends before the
+//! next one, so the sections touched by an edit can be parsed on their own and spliced into the
+//! previous tree. The nodes of the replaced sections are freed for reuse, and everything else
+//! keeps its [CstNodeId] and only has its span shifted. Each later section is shifted as a whole
+//! by [Cst::shift_subtree], so an edit costs the size of the edited sections and the number of
+//! sections, not the size of the document. Edits that touch the top-level bindings, or that do
+//! not parse as whole sections, fall back to a full parse.
+
+use std::path::Path;
+
+use swon_tree::{
+    Cst,
+    node_kind::NonTerminalKind,
+    tree::{CstNodeData, CstNodeId, InputSpan, NonTerminalData, TerminalData},
+};
+
+use crate::{ParseError, grammar, parser, tree::CstBuilder};
+
+/// A replacement of a byte range of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    /// The replaced range in the input before the edit.
+    pub range: InputSpan,
+    pub text: String,
+}
+
+impl TextEdit {
+    pub fn apply(&self, input: &str) -> String {
+        let mut output = input.to_string();
+        output.replace_range(
+            self.range.start as usize..self.range.end as usize,
+            &self.text,
+        );
+        output
+    }
+}
+
+/// Reparse `input`, the result of applying `edit` to the input of `previous`.
+pub fn reparse(
+    previous: Cst,
+    input: &str,
+    edit: &TextEdit,
+    file_name: impl AsRef<Path>,
+) -> Result<Cst, Box<ParseError>> {
+    match reparse_sections(previous, input, edit, file_name.as_ref()) {
+        Some(cst) => Ok(cst),
+        None => crate::parse(input, file_name),
+    }
+}
+
+struct TopLevelSection {
+    /// The `SwonList0` node that holds the section and the rest of the sections.
+    list: CstNodeId,
+    span: InputSpan,
+}
+
+fn reparse_sections(mut cst: Cst, input: &str, edit: &TextEdit, file_name: &Path) -> Option<Cst> {
+    if cst.has_errors() {
+        return None;
+    }
+    let root = cst.root();
+    let swon = child(&cst, root, NonTerminalKind::Swon)?;
+    let (sections, last_list) = top_level_sections(&cst, swon)?;
+
+    let delta = edit.text.len() as i64 - (edit.range.end - edit.range.start) as i64;
+    let old_len = (input.len() as i64 - delta) as u32;
+    let first = sections
+        .iter()
+        .rposition(|section| section.span.start <= edit.range.start)?;
+    let last = sections
+        .iter()
+        .rposition(|section| section.span.start < edit.range.end)
+        .map_or(first, |last| last.max(first));
+    let start = sections[first].span.start;
+    let (end, tail) = match sections.get(last + 1) {
+        Some(next) => (next.span.start, next.list),
+        None => (old_len, last_list),
+    };
+    let new_end = (end as i64 + delta) as usize;
+
+    let mut actions = grammar::Grammar::new();
+    let mut builder = CstBuilder::with_offset(start);
+    let source = input.get(start as usize..new_end)?;
+    parser::parse_into(source, &mut builder, file_name, &mut actions).ok()?;
+    let new = builder.build_tree();
+    let new_swon = child(&new, new.root(), NonTerminalKind::Swon)?;
+    let new_bindings = child(&new, new_swon, NonTerminalKind::SwonList)?;
+    if new.children(new_bindings).next().is_some() {
+        // The edit moved bindings out of the sections
+        return None;
+    }
+    let new_sections = child(&new, new_swon, NonTerminalKind::SwonList0)?;
+    let (_, new_last_list) = top_level_sections(&new, new_swon)?;
+
+    // Free the old sections for the nodes of the new ones, and move the rest of the document
+    let list = sections[first].list;
+    let mut parent = cst.parent(list)?;
+    let index = cst.children(parent).position(|node| node == list)?;
+    detach(&mut cst, tail);
+    cst.remove_subtree(list);
+    let trailing: Vec<_> = cst.children(root).filter(|&node| node != swon).collect();
+    if tail == last_list {
+        for &node in &trailing {
+            cst.remove_subtree(node);
+        }
+    }
+    shift_sections(&mut cst, tail, delta);
+    if tail != last_list {
+        for &node in &trailing {
+            cst.shift_subtree(node, delta);
+        }
+    }
+
+    let mut root_children = vec![swon];
+    if tail == last_list {
+        // The new sections end at the end of input, with their own trailing trivia
+        for node in new.children(new.root()).filter(|&node| node != new_swon) {
+            root_children.push(copy(&mut cst, &new, node, new_last_list, tail));
+        }
+    } else {
+        root_children.extend(trailing);
+        // Trivia at the end of the new sections belongs to the next section
+        let trivia = new
+            .children(new.root())
+            .filter(|&node| node != new_swon)
+            .map(|node| copy(&mut cst, &new, node, new_last_list, tail))
+            .collect::<Vec<_>>();
+        if !trivia.is_empty() {
+            prepend_trivia(&mut cst, tail, trivia);
+        }
+    }
+    let mut spliced = copy(&mut cst, &new, new_sections, new_last_list, tail);
+
+    // Put the new sections in place of the old ones and update the spans of the ancestors
+    let mut children = cst.children(parent).collect::<Vec<_>>();
+    children.insert(index, spliced);
+    cst.update_children(parent, children);
+    loop {
+        update_span(&mut cst, parent);
+        spliced = parent;
+        if parent == swon {
+            break;
+        }
+        parent = cst.parent(spliced)?;
+    }
+    cst.update_children(root, root_children);
+    update_span(&mut cst, root);
+    Some(cst)
+}
+
+fn child(cst: &Cst, node: CstNodeId, kind: NonTerminalKind) -> Option<CstNodeId> {
+    cst.children(node).find(|&child| {
+        matches!(cst.node_data(child), Some(CstNodeData::NonTerminal { kind: k, .. }) if k == kind)
+    })
+}
+
+/// The top-level sections and the empty `SwonList0` at the end.
+fn top_level_sections(cst: &Cst, swon: CstNodeId) -> Option<(Vec<TopLevelSection>, CstNodeId)> {
+    let mut sections = Vec::new();
+    let mut list = child(cst, swon, NonTerminalKind::SwonList0)?;
+    while let Some(section) = child(cst, list, NonTerminalKind::Section) {
+        sections.push(TopLevelSection {
+            list,
+            span: span(cst, section)?,
+        });
+        list = child(cst, list, NonTerminalKind::SwonList0)?;
+    }
+    Some((sections, list))
+}
+
+/// Move `list` and the sections in it by `delta`.
+fn shift_sections(cst: &mut Cst, mut list: CstNodeId, delta: i64) {
+    loop {
+        if let Some(span) = span(cst, list)
+            && span != InputSpan::EMPTY
+        {
+            let moved = |offset: u32| (offset as i64 + delta) as u32;
+            let span = InputSpan {
+                start: moved(span.start),
+                end: moved(span.end),
+            };
+            set_span(cst, list, span);
+        }
+        let Some(section) = child(cst, list, NonTerminalKind::Section) else {
+            break;
+        };
+        cst.shift_subtree(section, delta);
+        let Some(next) = child(cst, list, NonTerminalKind::SwonList0) else {
+            break;
+        };
+        list = next;
+    }
+}
+
+fn span(cst: &Cst, node: CstNodeId) -> Option<InputSpan> {
+    match cst.node_data(node)? {
+        CstNodeData::Terminal {
+            data: TerminalData::Input(span) | TerminalData::Error(span),
+            ..
+        } => Some(span),
+        CstNodeData::NonTerminal {
+            data: NonTerminalData::Input(span),
+            ..
+        } => Some(span),
+        _ => None,
+    }
+}
+
+fn set_span(cst: &mut Cst, node: CstNodeId, span: InputSpan) {
+    let data = match cst.node_data(node) {
+        Some(CstNodeData::Terminal {
+            kind,
+            data: TerminalData::Input(_),
+        }) => CstNodeData::Terminal {
+            kind,
+            data: TerminalData::Input(span),
+        },
+        Some(CstNodeData::Terminal {
+            kind,
+            data: TerminalData::Error(_),
+        }) => CstNodeData::Terminal {
+            kind,
+            data: TerminalData::Error(span),
+        },
+        Some(CstNodeData::NonTerminal {
+            kind,
+            data: NonTerminalData::Input(_),
+        }) => CstNodeData::NonTerminal {
+            kind,
+            data: NonTerminalData::Input(span),
+        },
+        _ => return,
+    };
+    cst.update_node(node, data);
+}
+
+/// Copy `node` of `from` into `cst`, using `replacement` in place of `replaced`.
+fn copy(
+    cst: &mut Cst,
+    from: &Cst,
+    node: CstNodeId,
+    replaced: CstNodeId,
+    replacement: CstNodeId,
+) -> CstNodeId {
+    if node == replaced {
+        return replacement;
+    }
+    let data = from.node_data(node).expect("the node must be in the tree");
+    let copied = cst.add_node(data);
+    let children = from
+        .children(node)
+        .map(|child| copy(cst, from, child, replaced, replacement))
+        .collect::<Vec<_>>();
+    cst.update_children(copied, children);
+    copied
+}
+
+fn detach(cst: &mut Cst, node: CstNodeId) {
+    if let Some(parent) = cst.parent(node) {
+        let children = cst
+            .children(parent)
+            .filter(|&child| child != node)
+            .collect::<Vec<_>>();
+        cst.update_children(parent, children);
+    }
+}
+
+/// Insert `trivia` before the first terminal under `node`.
+fn prepend_trivia(cst: &mut Cst, node: CstNodeId, trivia: Vec<CstNodeId>) {
+    let first = cst.children(node).next();
+    match first {
+        Some(first) if matches!(cst.node_data(first), Some(CstNodeData::NonTerminal { .. })) => {
+            prepend_trivia(cst, first, trivia)
+        }
+        _ => {
+            let children = trivia
+                .into_iter()
+                .chain(cst.children(node))
+                .collect::<Vec<_>>();
+            cst.update_children(node, children);
+        }
+    }
+    update_span(cst, node);
+}
+
+/// Recompute the span of a non-terminal from its children.
+fn update_span(cst: &mut Cst, node: CstNodeId) {
+    let span = cst
+        .children(node)
+        .filter_map(|child| span(cst, child))
+        .fold(InputSpan::EMPTY, InputSpan::merge);
+    set_span(cst, node, span);
+}
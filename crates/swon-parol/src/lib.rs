@@ -1,11 +1,15 @@
+pub mod diagnostics;
 // This module is generated by swon-parol-gen.
 pub mod grammar;
 #[allow(clippy::needless_lifetimes)]
 pub mod grammar_trait;
+pub mod incremental;
+#[allow(clippy::needless_question_mark)]
 pub mod parser;
+mod recovery;
 pub mod tree;
 
-use std::convert::Infallible;
+use std::{convert::Infallible, path::Path};
 
 pub use parol_runtime;
 use parol_runtime::ParolError;
@@ -13,16 +17,25 @@ use swon_tree::{
     Cst, CstNode,
     tree::{CstNodeData, CstNodeId, TerminalData},
 };
+use thiserror::Error;
 
 pub use parol_runtime::parser::parse_tree_type::TreeConstruct;
 use swon_tree::visitor::{NodeVisitor, NodeVisitorSuper as _};
-use tree::CstBuilder;
 
-pub fn parse(input: &str) -> Result<Cst, ParolError> {
-    let mut actions = grammar::Grammar::new();
-    let mut tree_builder = CstBuilder::new();
-    parser::parse_into(input, &mut tree_builder, "test.swon", &mut actions).unwrap();
-    Ok(tree_builder.build_tree())
+/// A syntax error with the CST built up to the point of the error.
+#[derive(Debug, Error)]
+#[error("{error}")]
+pub struct ParseError {
+    pub cst: Cst,
+    pub error: ParolError,
+}
+
+/// Parse a SWON document. `file_name` is only used in error messages.
+///
+/// Parsing recovers from syntax errors, so the error reports every syntax error and its CST
+/// covers the whole input with the skipped regions as [TerminalData::Error] terminals.
+pub fn parse(input: &str, file_name: impl AsRef<Path>) -> Result<Cst, Box<ParseError>> {
+    recovery::parse(input, file_name.as_ref())
 }
 
 #[test]
@@ -122,11 +135,834 @@ fn test_zenkaku_space() {
     @\u{3000}エー.ビー.シー
     漢字\u{3000}:\u{3000}白鳥
     ";
-    let tree = parse(input).unwrap();
+    let tree = parse(input, "test.swon").unwrap();
     let mut visitor = InspectVisitor { indent: 0, input };
     visitor.visit_node_id(tree.root(), &tree).unwrap();
 }
 
+#[test]
+fn test_values() {
+    use swon_value::value::{Code, KeyCmpValue, PathSegment, TypedString, Value};
+
+    let input = r#"
+    $swon.version: https://swon.dev/versions/v0.1.0
+    title: test
+    count = 1_000
+    flags = [true, false, null]
+    point = { x = 1, "y" = 2 }
+    message = "hello\n" \\
+      "world"
+    url = url"https://swon.dev"
+    code = rust`let a = 1;`
+
+    @ actions[]
+    $variant: use-script
+    script-id: title
+
+    @ actions[] {
+      $variant: set-text
+      @ pages[]
+      text: Hi,
+    }
+
+    @ a.b[0]
+    c = 1
+    @ a.b[0]
+    d = 2
+    @ a.b[]
+    c = 3
+    "#;
+    let tree = parse(input, "test.swon").unwrap();
+    let values = tree.values(input).unwrap();
+
+    let Value::Map(root) = &values.root else {
+        panic!("root must be a map");
+    };
+    let get = |map: &swon_value::value::Map, key: &str| {
+        map.0.get(&KeyCmpValue::String(key.to_string())).cloned()
+    };
+    let map = |value: Option<Value>| match value {
+        Some(Value::Map(map)) => map,
+        other => panic!("expected a map, got {:?}", other),
+    };
+    let array = |value: Option<Value>| match value {
+        Some(Value::Array(array)) => array.0,
+        other => panic!("expected an array, got {:?}", other),
+    };
+    let variant = |value: Value| match value {
+        Value::Variant(variant) => (variant.tag, map(Some(*variant.content))),
+        other => panic!("expected a variant, got {:?}", other),
+    };
+
+    assert_eq!(get(root, "title"), Some(Value::String("test".to_string())));
+    assert_eq!(get(root, "count"), Some(Value::I64(1000)));
+    assert_eq!(
+        array(get(root, "flags")),
+        vec![Value::Bool(true), Value::Bool(false), Value::Null]
+    );
+    let point = map(get(root, "point"));
+    assert_eq!(get(&point, "x"), Some(Value::I64(1)));
+    assert_eq!(get(&point, "y"), Some(Value::I64(2)));
+    assert_eq!(
+        get(root, "message"),
+        Some(Value::String("hello\nworld".to_string()))
+    );
+    assert_eq!(
+        get(root, "url"),
+        Some(Value::TypedString(TypedString {
+            type_name: "url".to_string(),
+            value: "https://swon.dev".to_string(),
+        }))
+    );
+    assert_eq!(
+        get(root, "code"),
+        Some(Value::Code(Code {
+            language: "rust".to_string(),
+            content: "let a = 1;".to_string(),
+        }))
+    );
+
+    let actions = array(get(root, "actions"));
+    assert_eq!(actions.len(), 2);
+    let (tag, action) = variant(actions[0].clone());
+    assert_eq!(tag, "use-script");
+    assert_eq!(
+        get(&action, "script-id"),
+        Some(Value::String("title".to_string()))
+    );
+    let (tag, action) = variant(actions[1].clone());
+    assert_eq!(tag, "set-text");
+    let pages = array(get(&action, "pages"));
+    assert_eq!(
+        get(&map(Some(pages[0].clone())), "text"),
+        Some(Value::String("Hi,".to_string()))
+    );
+
+    let b = array(get(&map(get(root, "a")), "b"));
+    assert_eq!(b.len(), 2);
+    let first = map(Some(b[0].clone()));
+    assert_eq!(get(&first, "c"), Some(Value::I64(1)));
+    assert_eq!(get(&first, "d"), Some(Value::I64(2)));
+    assert_eq!(get(&map(Some(b[1].clone())), "c"), Some(Value::I64(3)));
+
+    let key = |key: &str| Value::String(key.to_string());
+    assert_eq!(
+        values.extensions.get(&[], "swon"),
+        Some(&Value::Map(
+            [(
+                KeyCmpValue::String("version".to_string()),
+                Value::String("https://swon.dev/versions/v0.1.0".to_string())
+            )]
+            .into_iter()
+            .collect()
+        ))
+    );
+    // `$variant` is a part of the data
+    assert_eq!(
+        values.extensions.get(
+            &[PathSegment::Array {
+                key: key("actions"),
+                index: Some(Value::U64(0)),
+            }],
+            "variant"
+        ),
+        None
+    );
+}
+
+#[test]
+fn test_values_variant_errors() {
+    use swon_tree::value_visitor::ValueVisitorError;
+
+    fn error(input: &str) -> (&'static str, &str) {
+        let tree = parse(input, "test.swon").unwrap();
+        let (kind, location) = match tree.values(input) {
+            Err(ValueVisitorError::InvalidVariantTag { location }) => ("invalid tag", location),
+            Err(ValueVisitorError::VariantNotAllowed { location }) => ("not allowed", location),
+            other => panic!("unexpected result: {other:?}"),
+        };
+        (
+            kind,
+            input[location.span.start as usize..location.span.end as usize].trim(),
+        )
+    }
+
+    assert_eq!(error("$variant: a\n"), ("not allowed", "$variant"));
+    assert_eq!(error("a = 1\na.$variant: b\n"), ("not allowed", "$variant"));
+    assert_eq!(error("a.$variant = 1\n"), ("invalid tag", "$variant"));
+    assert_eq!(
+        error("a.$variant: not an ident\n"),
+        ("invalid tag", "$variant")
+    );
+}
+
+#[test]
+fn test_values_code_block() {
+    use swon_value::value::{Code, KeyCmpValue, Value};
+
+    let input = "code = ```rust\nfn main() {}\n```\nother = ```\na `` b\n```\n";
+    let tree = parse(input, "test.swon").unwrap();
+    let Value::Map(root) = tree.values(input).unwrap().root else {
+        panic!("root must be a map");
+    };
+    assert_eq!(
+        root.0.get(&KeyCmpValue::String("code".to_string())),
+        Some(&Value::Code(Code {
+            language: "rust".to_string(),
+            content: "fn main() {}\n".to_string(),
+        }))
+    );
+    // Each code block ends at its first fence
+    assert_eq!(
+        root.0.get(&KeyCmpValue::String("other".to_string())),
+        Some(&Value::Code(Code {
+            language: String::new(),
+            content: "a `` b\n".to_string(),
+        }))
+    );
+}
+
+#[test]
+fn test_values_empty_string() {
+    use swon_value::value::{KeyCmpValue, TypedString, Value};
+
+    let input = "\"\" = \"\"\nurl = url\"\"\n";
+    let tree = parse(input, "test.swon").unwrap();
+    let Value::Map(root) = tree.values(input).unwrap().root else {
+        panic!("root must be a map");
+    };
+    assert_eq!(
+        root.0.get(&KeyCmpValue::String(String::new())),
+        Some(&Value::String(String::new()))
+    );
+    assert_eq!(
+        root.0.get(&KeyCmpValue::String("url".to_string())),
+        Some(&Value::TypedString(TypedString {
+            type_name: "url".to_string(),
+            value: String::new(),
+        }))
+    );
+}
+
+#[test]
+fn test_parse_error() {
+    let input = "a = 1\nb = \n";
+    let ParseError { cst, error } = *parse(input, "broken.swon").unwrap_err();
+    assert!(matches!(error, ParolError::ParserError(_)));
+    // The partial tree still covers the whole input
+    let mut out = String::new();
+    cst.write(input, &mut out).unwrap();
+    assert_eq!(out, input);
+}
+
+#[test]
+fn test_error_recovery() {
+    let input = "a = 1\nb = = 2\nc = 3\n@ d..e\nf = 1\n@ g\nh = [1, 2\ni = true\n";
+    let ParseError { cst, error } = *parse(input, "test.swon").unwrap_err();
+    let diagnostics = diagnostics::diagnostics(&error, input);
+    let lines: Vec<_> = diagnostics
+        .iter()
+        .map(|d| diagnostics::line_column(input, d.span.unwrap().start as usize).0)
+        .collect();
+    assert_eq!(lines, vec![2, 4, 7]);
+
+    // The skipped regions are kept in the tree as error terminals
+    let mut out = String::new();
+    cst.write(input, &mut out).unwrap();
+    assert_eq!(out, input);
+    fn skipped<'a>(cst: &Cst, node: CstNodeId, input: &'a str, out: &mut Vec<&'a str>) {
+        if let Some(CstNodeData::Terminal {
+            data: TerminalData::Error(span),
+            ..
+        }) = cst.node_data(node)
+        {
+            out.push(span.as_str(input));
+        }
+        for child in cst.children(node) {
+            skipped(cst, child, input, out);
+        }
+    }
+    let mut errors = Vec::new();
+    skipped(&cst, cst.root(), input, &mut errors);
+    assert_eq!(errors, vec!["b = = 2", "@ d..e\nf = 1", "h = [1, 2"]);
+
+    // Later passes skip over them
+    let values = cst.values(input).unwrap();
+    let swon_value::value::Value::Map(root) = values.root else {
+        panic!("root must be a map");
+    };
+    let mut keys: Vec<_> = root.0.keys().cloned().collect();
+    keys.sort();
+    assert_eq!(
+        keys,
+        ["a", "c", "g"].map(|key| swon_value::value::KeyCmpValue::String(key.to_string()))
+    );
+    // The line after the unclosed array is parsed
+    let g = &root.0[&swon_value::value::KeyCmpValue::String("g".to_string())];
+    assert_eq!(
+        g,
+        &swon_value::value::Value::Map(swon_value::value::Map(
+            [(
+                swon_value::value::KeyCmpValue::String("i".to_string()),
+                swon_value::value::Value::Bool(true)
+            )]
+            .into_iter()
+            .collect()
+        ))
+    );
+
+    // Every error is reported
+    let input = "a = = 1\n".repeat(200);
+    let ParseError { error, .. } = *parse(&input, "test.swon").unwrap_err();
+    assert_eq!(diagnostics::diagnostics(&error, &input).len(), 200);
+}
+
+#[test]
+fn test_incremental_reparse() {
+    use incremental::{TextEdit, reparse};
+    use swon_tree::{
+        node_kind::NonTerminalKind,
+        tree::{InputSpan, NonTerminalData},
+    };
+
+    fn inspect(cst: &Cst, input: &str) -> String {
+        let mut out = String::new();
+        cst.inspect(input, &mut out).unwrap();
+        out
+    }
+    fn edit(input: &str, from: &str, to: &str) -> TextEdit {
+        let start = input.find(from).unwrap() as u32;
+        TextEdit {
+            range: InputSpan {
+                start,
+                end: start + from.len() as u32,
+            },
+            text: to.to_string(),
+        }
+    }
+
+    // The `Section` node whose text starts with `header`
+    fn section(cst: &Cst, node: CstNodeId, input: &str, header: &str) -> Option<CstNodeId> {
+        if let Some(CstNodeData::NonTerminal {
+            kind: NonTerminalKind::Section,
+            data: NonTerminalData::Input(span),
+        }) = cst.node_data(node)
+            && span.as_str(input).trim_start().starts_with(header)
+        {
+            return Some(node);
+        }
+        cst.children(node)
+            .find_map(|child| section(cst, child, input, header))
+    }
+
+    let input = "a = 1\n@ b\nc = 2\n@ d\ne = 3\n@ f\ng = 4\n";
+    for (from, to, reused) in [
+        // inside a section
+        ("c = 2", "c = 20\nh.i = true", Some("@ f")),
+        ("c = 2", "c = 2 # note\n", Some("@ f")),
+        // across sections
+        ("2\n@ d\ne", "5\n@ j\nk", Some("@ f")),
+        // at the end of input
+        ("g = 4\n", "g = 4\n@ l\nm = {}\n", Some("@ d")),
+        // splits a section
+        ("e = 3", "@ n\n", Some("@ f")),
+        // falls back to a full parse
+        ("a = 1", "a = 10", None),
+        ("@ d\n", "", None),
+    ] {
+        let previous = parse(input, "test.swon").unwrap();
+        let previous_section = reused.map(|h| section(&previous, previous.root(), input, h));
+        let edit = edit(input, from, to);
+        let edited = edit.apply(input);
+        let cst = reparse(previous, &edited, &edit, "test.swon").unwrap();
+        let full = parse(&edited, "test.swon").unwrap();
+        assert_eq!(inspect(&cst, &edited), inspect(&full, &edited), "{edited}");
+        assert_eq!(cst.values(&edited).unwrap(), full.values(&edited).unwrap());
+        let mut out = String::new();
+        cst.write(&edited, &mut out).unwrap();
+        assert_eq!(out, edited);
+        if let Some(header) = reused {
+            // Unchanged sections keep their node
+            assert_eq!(
+                Some(section(&cst, cst.root(), &edited, header)),
+                previous_section
+            );
+        }
+    }
+
+    // Replaced nodes are reused, so repeated edits don't grow the tree
+    let mut input = input.to_string();
+    let mut cst = parse(&input, "test.swon").unwrap();
+    let mut counts = Vec::new();
+    for i in 0..20 {
+        let (from, to) = if i % 2 == 0 {
+            ("c = 2", "c = [1, 2, 3]")
+        } else {
+            ("c = [1, 2, 3]", "c = 2")
+        };
+        let edit = edit(&input, from, to);
+        input = edit.apply(&input);
+        cst = reparse(cst, &input, &edit, "test.swon").unwrap();
+        counts.push(cst.node_count());
+    }
+    assert!(
+        counts[2..].iter().all(|&count| count <= counts[1]),
+        "{counts:?}"
+    );
+    assert_eq!(
+        inspect(&cst, &input),
+        inspect(&parse(&input, "test.swon").unwrap(), &input)
+    );
+
+    // Sections shifted by earlier edits keep their spans through later ones
+    for (from, to) in [
+        ("g = 4", "g = 40"),
+        ("c = 2", "c = 2 # note\n"),
+        ("e = 3", "e = 3\n\n@ o\np = 5"),
+        ("g = 40", "g = 4"),
+        ("c = 2 # note", "c = 200"),
+        ("p = 5", "p = 50"),
+    ] {
+        let edit = edit(&input, from, to);
+        input = edit.apply(&input);
+        cst = reparse(cst, &input, &edit, "test.swon").unwrap();
+        let full = parse(&input, "test.swon").unwrap();
+        assert_eq!(inspect(&cst, &input), inspect(&full, &input), "{input}");
+    }
+
+    // A tree with errors from recovery is parsed again as a whole
+    let input = "a = = 1\n@ b\nc = 2\n";
+    let recovered = parse(input, "test.swon").unwrap_err().cst;
+    assert!(recovered.has_errors());
+    let edit = edit(input, "a = = 1", "a = 1");
+    let edited = edit.apply(input);
+    let cst = reparse(recovered, &edited, &edit, "test.swon").unwrap();
+    assert!(!cst.has_errors());
+    assert_eq!(
+        inspect(&cst, &edited),
+        inspect(&parse(&edited, "test.swon").unwrap(), &edited)
+    );
+}
+
+#[test]
+fn test_parse_diagnostics() {
+    let input = "a = 1\nb = = 2\n";
+    let error = parse(input, "broken.swon").unwrap_err();
+    let diagnostics = diagnostics::diagnostics(&error.error, input);
+    assert_eq!(diagnostics.len(), 1);
+    let diagnostic = &diagnostics[0];
+    assert_eq!(diagnostic.message, "Unexpected token `=`");
+    let span = diagnostic.span.unwrap();
+    assert_eq!(span.start, 10);
+    assert_eq!(span.end, 11);
+    assert_eq!(
+        diagnostic.found,
+        Some(("Bind".to_string(), "=".to_string()))
+    );
+    assert!(diagnostic.expected.contains(&"Integer".to_string()));
+
+    let rendered = diagnostic.render(input, "broken.swon", false);
+    let snippet: Vec<_> = rendered.lines().take(5).collect();
+    assert_eq!(
+        snippet,
+        vec![
+            "error: Unexpected token `=`",
+            " --> broken.swon:2:5",
+            "  |",
+            "2 | b = = 2",
+            "  |     ^",
+        ]
+    );
+    assert!(rendered.ends_with("  = found Bind\n"), "{rendered}");
+    assert!(
+        diagnostic
+            .render(input, "broken.swon", true)
+            .contains("\x1b[")
+    );
+}
+
+#[test]
+fn test_values_numbers() {
+    use swon_value::value::{KeyCmpValue, Tuple, Value};
+
+    let input = r#"
+    negative = -42
+    positive = +7
+    hex = 0xff_ff
+    octal = 0o17
+    binary = -0b1010
+    min = -9_223_372_036_854_775_808
+    max = 18446744073709551615
+    float = 2.0
+    exponent = -1.5e-3
+    no-fraction = 1E3
+    infinity = -inf
+    not-a-number = nan
+    positive-infinity = inf
+    tuples.0.0 = 1
+    "#;
+    let tree = parse(input, "test.swon").unwrap();
+    let Value::Map(root) = tree.values(input).unwrap().root else {
+        panic!("root must be a map");
+    };
+    let get = |key: &str| root.0.get(&KeyCmpValue::String(key.to_string())).unwrap();
+    assert_eq!(get("negative"), &Value::I64(-42));
+    assert_eq!(get("positive"), &Value::I64(7));
+    assert_eq!(get("hex"), &Value::I64(0xffff));
+    assert_eq!(get("octal"), &Value::I64(0o17));
+    assert_eq!(get("binary"), &Value::I64(-0b1010));
+    assert_eq!(get("min"), &Value::I64(i64::MIN));
+    assert_eq!(get("max"), &Value::U64(u64::MAX));
+    assert_eq!(get("float"), &Value::F64(2.0));
+    assert_eq!(get("exponent"), &Value::F64(-1.5e-3));
+    assert_eq!(get("no-fraction"), &Value::F64(1e3));
+    assert_eq!(get("infinity"), &Value::F64(f64::NEG_INFINITY));
+    assert!(matches!(get("not-a-number"), Value::F64(value) if value.is_nan()));
+    assert_eq!(get("positive-infinity"), &Value::F64(f64::INFINITY));
+    // A float key is two tuple indices
+    assert_eq!(
+        get("tuples"),
+        &Value::Tuple(Tuple(vec![Value::Tuple(Tuple(vec![Value::I64(1)]))]))
+    );
+
+    // `inf` and `nan` are floats only as values
+    let input = "inf = nan\nnan = { inf = inf }\n";
+    let tree = parse(input, "test.swon").unwrap();
+    let Value::Map(root) = tree.values(input).unwrap().root else {
+        panic!("root must be a map");
+    };
+    let get = |key: &str| root.0.get(&KeyCmpValue::String(key.to_string())).unwrap();
+    assert!(matches!(get("inf"), Value::F64(value) if value.is_nan()));
+    let Value::Map(nan) = get("nan") else {
+        panic!("nan must be a map");
+    };
+    assert_eq!(
+        nan.0.get(&KeyCmpValue::String("inf".to_string())),
+        Some(&Value::F64(f64::INFINITY))
+    );
+    let input = "a = infinity\n";
+    let tree = parse(input, "test.swon").unwrap();
+    assert!(matches!(
+        tree.values(input),
+        Err(swon_tree::value_visitor::ValueVisitorError::UnknownFloatName { text, .. }) if text == "infinity"
+    ));
+
+    let input = "a.1e5 = 1\n";
+    let tree = parse(input, "test.swon").unwrap();
+    assert!(matches!(
+        tree.values(input),
+        Err(swon_tree::value_visitor::ValueVisitorError::FloatKey { .. })
+    ));
+    let input = "a[-1] = 1\n";
+    let tree = parse(input, "test.swon").unwrap();
+    assert!(matches!(
+        tree.values(input),
+        Err(swon_tree::value_visitor::ValueVisitorError::IntegerOutOfRange { .. })
+    ));
+}
+
+#[test]
+fn test_values_tuples() {
+    use swon_tree::value_visitor::ValueVisitorError;
+    use swon_value::value::{Array, KeyCmpValue, PathSegment, Tuple, Value};
+
+    let input = r#"
+    literal = (1, "a", [true],)
+    unit = ()
+    point.0 = 1
+    point.1 = 2
+    @ nested.0
+    $note = "first"
+    name = "a"
+    @ nested.1[]
+    name = "b"
+    "#;
+    let tree = parse(input, "test.swon").unwrap();
+    let values = tree.values(input).unwrap();
+    let Value::Map(root) = &values.root else {
+        panic!("root must be a map");
+    };
+    let get = |key: &str| root.0.get(&KeyCmpValue::String(key.to_string())).unwrap();
+    assert_eq!(
+        get("literal"),
+        &Value::Tuple(Tuple(vec![
+            Value::I64(1),
+            Value::String("a".to_string()),
+            Value::Array(Array(vec![Value::Bool(true)])),
+        ]))
+    );
+    assert_eq!(get("unit"), &Value::Unit);
+    assert_eq!(
+        get("point"),
+        &Value::Tuple(Tuple(vec![Value::I64(1), Value::I64(2)]))
+    );
+    let Value::Tuple(nested) = get("nested") else {
+        panic!("nested must be a tuple");
+    };
+    assert_eq!(nested.0.len(), 2);
+    assert!(matches!(&nested.0[1], Value::Array(array) if array.0.len() == 1));
+    let path = [
+        PathSegment::Value(Value::String("nested".to_string())),
+        PathSegment::TupleIndex(0),
+    ];
+    assert_eq!(
+        values.extensions.get(&path, "note"),
+        Some(&Value::String("first".to_string()))
+    );
+    let spanned = tree.spanned_values(input).unwrap();
+    let note = spanned
+        .get(&[
+            path[0].clone(),
+            path[1].clone(),
+            PathSegment::Extension("note".parse().unwrap()),
+        ])
+        .unwrap();
+    let span = note.value.unwrap().span;
+    assert_eq!(
+        input[span.start as usize..span.end as usize].trim(),
+        "\"first\""
+    );
+
+    let input = "a.1 = 1\n";
+    let tree = parse(input, "test.swon").unwrap();
+    assert!(matches!(
+        tree.values(input),
+        Err(ValueVisitorError::TupleIndexOutOfOrder {
+            original: None,
+            index: 1,
+            len: 0,
+            ..
+        })
+    ));
+    let input = "a.256 = 1\n";
+    let tree = parse(input, "test.swon").unwrap();
+    let Err(ValueVisitorError::TupleIndexOutOfRange { location, text }) = tree.values(input) else {
+        panic!("tuple index must be out of range");
+    };
+    assert_eq!(text, "256");
+    assert_eq!(location.token_start(&tree), Some(2));
+    let input = "a.0 = 1\na.b = 1\n";
+    let tree = parse(input, "test.swon").unwrap();
+    assert!(matches!(
+        tree.values(input),
+        Err(ValueVisitorError::ConflictingKey { .. })
+    ));
+    // The root is a map
+    let input = "0 = 1\n";
+    let tree = parse(input, "test.swon").unwrap();
+    assert!(matches!(
+        tree.values(input),
+        Err(ValueVisitorError::TupleIndexOnRoot { .. })
+    ));
+    // An inline object is a map
+    let input = "a = { 0 = 1 }\n";
+    let tree = parse(input, "test.swon").unwrap();
+    assert!(matches!(
+        tree.values(input),
+        Err(ValueVisitorError::ConflictingKey { .. })
+    ));
+}
+
+#[test]
+fn test_values_paths() {
+    use swon_tree::value_visitor::ValueVisitorError;
+    use swon_value::value::{KeyCmpValue, Path, PathError, PathSegment, Value};
+
+    let input = r#"
+    a.b = [1, 2]
+    a.$note = { text = "hi" }
+    t = ("x", ["y"])
+    to-element = .a.b[1]
+    to-extension = .a.$note.text
+    to-tuple = .t.1[0]
+    from-array = .[0].a
+    "#;
+    let tree = parse(input, "test.swon").unwrap();
+    let values = tree.values(input).unwrap();
+    let Value::Map(root) = &values.root else {
+        panic!("root must be a map");
+    };
+    let path = |key: &str| match root.0.get(&KeyCmpValue::String(key.to_string())) {
+        Some(Value::Path(path)) => path.clone(),
+        value => panic!("{key} must be a path: {value:?}"),
+    };
+    let string = |s: &str| Value::String(s.to_string());
+    assert_eq!(
+        path("to-element"),
+        Path(vec![
+            PathSegment::Value(string("a")),
+            PathSegment::Array {
+                key: string("b"),
+                index: Some(Value::U64(1)),
+            },
+        ])
+    );
+    assert_eq!(
+        path("from-array"),
+        Path(vec![
+            PathSegment::Value(Value::U64(0)),
+            PathSegment::Value(string("a")),
+        ])
+    );
+    assert_eq!(path("to-element").resolve(&values.root), Ok(&Value::I64(2)));
+    assert_eq!(values.resolve(&path("to-tuple")), Ok(&string("y")));
+    assert_eq!(
+        path("to-extension").resolve(&values.root),
+        Err(PathError::Extension { index: 1 })
+    );
+    assert_eq!(values.resolve(&path("to-extension")), Ok(&string("hi")));
+    assert_eq!(
+        values.resolve(&path("from-array")),
+        Err(PathError::NotFound { index: 0 })
+    );
+
+    let input = "a = .b[]\n";
+    let tree = parse(input, "test.swon").unwrap();
+    assert!(matches!(
+        tree.values(input),
+        Err(ValueVisitorError::ArrayPushInPath { .. })
+    ));
+
+    // Elements must be separated by commas
+    let input = "a = [1 2]\n";
+    let mut actions = grammar::Grammar::new();
+    let mut tree_builder = tree::CstBuilder::new();
+    assert!(parser::parse_into(input, &mut tree_builder, "test.swon", &mut actions).is_err());
+}
+
+#[test]
+fn test_values_key_errors() {
+    use swon_tree::value_visitor::ValueVisitorError;
+
+    fn spans(input: &str, error: ValueVisitorError) -> (&'static str, Option<&str>, &str) {
+        let text =
+            |span: swon_tree::tree::InputSpan| &input[span.start as usize..span.end as usize];
+        match error {
+            ValueVisitorError::DuplicateKey {
+                original,
+                conflicting,
+            } => (
+                "duplicate",
+                Some(text(original.span)),
+                text(conflicting.span),
+            ),
+            ValueVisitorError::ConflictingKey {
+                original,
+                conflicting,
+            } => (
+                "conflicting",
+                Some(text(original.span)),
+                text(conflicting.span),
+            ),
+            ValueVisitorError::ArrayIndexOutOfOrder {
+                original,
+                conflicting,
+                ..
+            } => (
+                "out of order",
+                original.map(|location| text(location.span)),
+                text(conflicting.span),
+            ),
+            error => panic!("unexpected error: {error}"),
+        }
+    }
+
+    let input = "a.b = 1\n@ a\nb = 2\n";
+    let cst = parse(input, "test.swon").unwrap();
+    let error = cst.values(input).unwrap_err();
+    // The error is at the second key
+    assert_eq!(error.token_start(&cst), Some(12));
+    let (kind, original, conflicting) = spans(input, error);
+    assert_eq!(kind, "duplicate");
+    assert_eq!(original.map(str::trim), Some("b"));
+    assert_eq!(conflicting.trim(), "b");
+    assert_ne!(original, Some(conflicting));
+
+    let input = "a = 1\na.b = 2\n";
+    let error = parse(input, "test.swon")
+        .unwrap()
+        .values(input)
+        .unwrap_err();
+    assert_eq!(spans(input, error), ("conflicting", Some("a"), "b"));
+
+    let input = "a[] = 1\n@ a\n";
+    let error = parse(input, "test.swon")
+        .unwrap()
+        .values(input)
+        .unwrap_err();
+    let (kind, original, _) = spans(input, error);
+    assert_eq!(kind, "conflicting");
+    assert_eq!(original, Some("a[]"));
+
+    let input = "a.b[1] = 1\n";
+    let error = parse(input, "test.swon")
+        .unwrap()
+        .values(input)
+        .unwrap_err();
+    assert_eq!(spans(input, error), ("out of order", None, "b[1]"));
+
+    let input = "a.b[0] = 1\na.b[2] = 1\n";
+    let error = parse(input, "test.swon")
+        .unwrap()
+        .values(input)
+        .unwrap_err();
+    assert_eq!(spans(input, error), ("out of order", Some("b[0]"), "b[2]"));
+}
+
+#[test]
+fn test_spanned_values() {
+    use swon_tree::spanned::SpannedContent;
+    use swon_value::value::{PathSegment, Value};
+
+    let input = "@ a\nb = [1, true]\nc: text\n@ d[]\ne.$ext = null\n";
+    let document = parse(input, "test.swon")
+        .unwrap()
+        .spanned_values(input)
+        .unwrap();
+    let text = |span: swon_tree::tree::InputSpan| &input[span.start as usize..span.end as usize];
+    let key = |s: &str| PathSegment::Value(Value::String(s.to_string()));
+
+    let b = document.get(&[key("a"), key("b")]).unwrap();
+    assert_eq!(text(b.key.unwrap().span).trim(), "b");
+    assert_eq!(text(b.value.unwrap().span).trim(), "[1, true]");
+    let element = document
+        .get(&[key("a"), key("b"), PathSegment::Value(Value::U64(1))])
+        .unwrap();
+    assert_eq!(element.key, None);
+    assert_eq!(text(element.value.unwrap().span).trim(), "true");
+
+    let c = document.get(&[key("a"), key("c")]).unwrap();
+    assert_eq!(text(c.value.unwrap().span).trim(), "text");
+    assert_eq!(
+        c.content,
+        SpannedContent::Value(Value::String("text".to_string()))
+    );
+
+    let a = document.get(&[key("a")]).unwrap();
+    assert_eq!(text(a.key.unwrap().span).trim(), "a");
+    assert!(text(a.value.unwrap().span).starts_with("@ a"));
+
+    let ext = document
+        .get(&[
+            PathSegment::Array {
+                key: Value::String("d".to_string()),
+                index: Some(Value::U64(0)),
+            },
+            key("e"),
+            PathSegment::Extension("ext".parse().unwrap()),
+        ])
+        .unwrap();
+    assert_eq!(text(ext.key.unwrap().span).trim(), "$ext");
+    assert_eq!(text(ext.value.unwrap().span).trim(), "null");
+    let e = document.get(&[key("d"), PathSegment::Value(Value::U64(0)), key("e")]);
+    assert_eq!(e.unwrap().value, None);
+
+    assert_eq!(
+        document.into_values(),
+        parse(input, "test.swon").unwrap().values(input).unwrap()
+    );
+}
+
 pub struct InspectVisitor<'a> {
     indent: usize,
     input: &'a str,
@@ -139,7 +975,7 @@ impl NodeVisitor for InspectVisitor<'_> {
         match node {
             CstNodeData::Terminal { kind, data } => {
                 let text = match data {
-                    TerminalData::Input(span) => {
+                    TerminalData::Input(span) | TerminalData::Error(span) => {
                         &self.input[span.start as usize..span.end as usize]
                     }
                     TerminalData::Dynamic(id) => tree.dynamic_token(id).unwrap(),
@@ -19,78 +19,96 @@ use parol_runtime::lexer::tokenizer::{
     ERROR_TOKEN, NEW_LINE_TOKEN, UNMATCHABLE_TOKEN, WHITESPACE_TOKEN,
 };
 
-pub const TERMINALS: &[(&str, Option<(bool, &str)>); 32] = &[
+pub const TERMINALS: &[(&str, Option<(bool, &str)>); 35] = &[
     /*  0 */ (UNMATCHABLE_TOKEN, None),
     /*  1 */ (UNMATCHABLE_TOKEN, None),
     /*  2 */ (UNMATCHABLE_TOKEN, None),
     /*  3 */ (UNMATCHABLE_TOKEN, None),
     /*  4 */ (UNMATCHABLE_TOKEN, None),
-    /*  5 */ (r"\d[\d_]*", None),
-    /*  6 */ (r"true", None),
-    /*  7 */ (r"false", None),
-    /*  8 */ (r"null", None),
-    /*  9 */ (r"!", None),
-    /* 10 */ (r#"""#, None),
-    /* 11 */ (r#"[a-zA-Z0-9-_]+""#, None),
-    /* 12 */ (r#"(\\[nrt\\"0]|[^\\"\r\n])*"#, None),
-    /* 13 */ (r"[^\r\n]*", None),
-    /* 14 */ (r"```[a-zA-Z0-9-_]*(\r\n|\r|\n)([^`]|[`]{1,2})*```", None),
-    /* 15 */ (r"[a-zA-Z0-9-_]+`([^`\r\n]|\\`)*`", None),
-    /* 16 */ (r"`([^`\r\n]|\\`)*`", None),
-    /* 17 */ (r"\r\n|\r|\n", None),
-    /* 18 */ (r"[\s--\r\n]+", None),
-    /* 19 */ (r"@", None),
-    /* 20 */ (r"\$", None),
-    /* 21 */ (r"\.", None),
-    /* 22 */ (r"\{", None),
-    /* 23 */ (r"\}", None),
-    /* 24 */ (r"\[", None),
-    /* 25 */ (r"\]", None),
-    /* 26 */ (r"=", None),
-    /* 27 */ (r",", None),
-    /* 28 */ (r"\\\\", None),
-    /* 29 */ (r":", None),
-    /* 30 */ (r"\p{XID_Start}[\p{XID_Continue}-]*", None),
-    /* 31 */ (ERROR_TOKEN, None),
+    /*  5 */
+    (
+        r"[+-]?(0x[0-9a-fA-F][0-9a-fA-F_]*|0o[0-7][0-7_]*|0b[01][01_]*|\d[\d_]*)",
+        None,
+    ),
+    /*  6 */
+    (
+        r"[+-]?(\d[\d_]*\.\d[\d_]*([eE][+-]?\d[\d_]*)?|\d[\d_]*[eE][+-]?\d[\d_]*)|[+-](inf|nan)",
+        None,
+    ),
+    /*  7 */ (r"true", None),
+    /*  8 */ (r"false", None),
+    /*  9 */ (r"null", None),
+    /* 10 */ (r"!", None),
+    /* 11 */ (r#"""#, None),
+    /* 12 */ (r#"[a-zA-Z0-9-_]+""#, None),
+    /* 13 */ (r#"(\\[nrt\\"0]|[^\\"\r\n])+"#, None),
+    /* 14 */ (r"[^\r\n]*", None),
+    /* 15 */
+    (
+        r"```[a-zA-Z0-9-_]*(\r\n|\r|\n)([^`]|`[^`]|``[^`])*```",
+        None,
+    ),
+    /* 16 */ (r"[a-zA-Z0-9-_]+`([^`\r\n]|\\`)*`", None),
+    /* 17 */ (r"`([^`\r\n]|\\`)*`", None),
+    /* 18 */ (r"\r\n|\r|\n", None),
+    /* 19 */ (r"[\s--\r\n]+", None),
+    /* 20 */ (r"@", None),
+    /* 21 */ (r"\$", None),
+    /* 22 */ (r"\.", None),
+    /* 23 */ (r"\{", None),
+    /* 24 */ (r"\}", None),
+    /* 25 */ (r"\[", None),
+    /* 26 */ (r"\]", None),
+    /* 27 */ (r"\(", None),
+    /* 28 */ (r"\)", None),
+    /* 29 */ (r"=", None),
+    /* 30 */ (r",", None),
+    /* 31 */ (r"\\\\", None),
+    /* 32 */ (r":", None),
+    /* 33 */ (r"\p{XID_Start}[\p{XID_Continue}-]*", None),
+    /* 34 */ (ERROR_TOKEN, None),
 ];
 
-pub const TERMINAL_NAMES: &[&str; 32] = &[
+pub const TERMINAL_NAMES: &[&str; 35] = &[
     /*  0 */ "EndOfInput",
     /*  1 */ "Newline",
     /*  2 */ "Whitespace",
     /*  3 */ "LineComment",
     /*  4 */ "BlockComment",
     /*  5 */ "Integer",
-    /*  6 */ "True",
-    /*  7 */ "False",
-    /*  8 */ "Null",
-    /*  9 */ "Hole",
-    /* 10 */ "Quote",
-    /* 11 */ "TypedQuote",
-    /* 12 */ "InStr",
-    /* 13 */ "Text",
-    /* 14 */ "CodeBlock",
-    /* 15 */ "NamedCode",
-    /* 16 */ "Code",
-    /* 17 */ "Newline0",
-    /* 18 */ "Ws",
-    /* 19 */ "At",
-    /* 20 */ "Ext",
-    /* 21 */ "Dot",
-    /* 22 */ "Begin",
-    /* 23 */ "End",
-    /* 24 */ "ArrayBegin",
-    /* 25 */ "ArrayEnd",
-    /* 26 */ "Bind",
-    /* 27 */ "Comma",
-    /* 28 */ "Continue",
-    /* 29 */ "TextStart",
-    /* 30 */ "Ident",
-    /* 31 */ "Error",
+    /*  6 */ "Float",
+    /*  7 */ "True",
+    /*  8 */ "False",
+    /*  9 */ "Null",
+    /* 10 */ "Hole",
+    /* 11 */ "Quote",
+    /* 12 */ "TypedQuote",
+    /* 13 */ "InStr",
+    /* 14 */ "Text",
+    /* 15 */ "CodeBlock",
+    /* 16 */ "NamedCode",
+    /* 17 */ "Code",
+    /* 18 */ "Newline0",
+    /* 19 */ "Ws",
+    /* 20 */ "At",
+    /* 21 */ "Ext",
+    /* 22 */ "Dot",
+    /* 23 */ "Begin",
+    /* 24 */ "End",
+    /* 25 */ "ArrayBegin",
+    /* 26 */ "ArrayEnd",
+    /* 27 */ "TupleBegin",
+    /* 28 */ "TupleEnd",
+    /* 29 */ "Bind",
+    /* 30 */ "Comma",
+    /* 31 */ "Continue",
+    /* 32 */ "TextStart",
+    /* 33 */ "Ident",
+    /* 34 */ "Error",
 ];
 
 /* SCANNER_0: "INITIAL" */
-const SCANNER_0: (&[&str; 5], &[TerminalIndex; 22]) = (
+const SCANNER_0: (&[&str; 5], &[TerminalIndex; 25]) = (
     &[
         /*  0 */ UNMATCHABLE_TOKEN,
         /*  1 */ NEW_LINE_TOKEN,
@@ -100,27 +118,30 @@ const SCANNER_0: (&[&str; 5], &[TerminalIndex; 22]) = (
     ],
     &[
         5,  /* Integer */
-        6,  /* True */
-        7,  /* False */
-        8,  /* Null */
-        9,  /* Hole */
-        10, /* Quote */
-        11, /* TypedQuote */
-        14, /* CodeBlock */
-        15, /* NamedCode */
-        16, /* Code */
-        19, /* At */
-        20, /* Ext */
-        21, /* Dot */
-        22, /* Begin */
-        23, /* End */
-        24, /* ArrayBegin */
-        25, /* ArrayEnd */
-        26, /* Bind */
-        27, /* Comma */
-        28, /* Continue */
-        29, /* TextStart */
-        30, /* Ident */
+        6,  /* Float */
+        7,  /* True */
+        8,  /* False */
+        9,  /* Null */
+        10, /* Hole */
+        11, /* Quote */
+        12, /* TypedQuote */
+        15, /* CodeBlock */
+        16, /* NamedCode */
+        17, /* Code */
+        20, /* At */
+        21, /* Ext */
+        22, /* Dot */
+        23, /* Begin */
+        24, /* End */
+        25, /* ArrayBegin */
+        26, /* ArrayEnd */
+        27, /* TupleBegin */
+        28, /* TupleEnd */
+        29, /* Bind */
+        30, /* Comma */
+        31, /* Continue */
+        32, /* TextStart */
+        33, /* Ident */
     ],
 );
 
@@ -133,7 +154,7 @@ const SCANNER_1: (&[&str; 5], &[TerminalIndex; 3]) = (
         /*  3 */ UNMATCHABLE_TOKEN,
         /*  4 */ UNMATCHABLE_TOKEN,
     ],
-    &[10 /* Quote */, 12 /* InStr */, 18 /* Ws */],
+    &[11 /* Quote */, 13 /* InStr */, 19 /* Ws */],
 );
 
 /* SCANNER_2: "Text" */
@@ -145,1001 +166,1352 @@ const SCANNER_2: (&[&str; 5], &[TerminalIndex; 3]) = (
         /*  3 */ UNMATCHABLE_TOKEN,
         /*  4 */ UNMATCHABLE_TOKEN,
     ],
-    &[13 /* Text */, 17 /* Newline0 */, 18 /* Ws */],
+    &[14 /* Text */, 18 /* Newline0 */, 19 /* Ws */],
 );
 
 const MAX_K: usize = 1;
 
-pub const NON_TERMINALS: &[&str; 58] = &[
+pub const NON_TERMINALS: &[&str; 76] = &[
     /*  0 */ "Array",
     /*  1 */ "ArrayBegin",
-    /*  2 */ "ArrayEnd",
-    /*  3 */ "ArrayList",
-    /*  4 */ "ArrayMarker",
-    /*  5 */ "ArrayMarkerOpt",
-    /*  6 */ "ArrayOpt",
-    /*  7 */ "At",
-    /*  8 */ "Begin",
-    /*  9 */ "Bind",
-    /* 10 */ "Binding",
-    /* 11 */ "BindingRhs",
-    /* 12 */ "Boolean",
-    /* 13 */ "Code",
-    /* 14 */ "CodeBlock",
-    /* 15 */ "Comma",
-    /* 16 */ "Continue",
-    /* 17 */ "Dot",
-    /* 18 */ "End",
-    /* 19 */ "Ext",
-    /* 20 */ "ExtensionNameSpace",
-    /* 21 */ "False",
-    /* 22 */ "Hole",
-    /* 23 */ "Ident",
-    /* 24 */ "InStr",
-    /* 25 */ "Integer",
-    /* 26 */ "Key",
-    /* 27 */ "KeyBase",
-    /* 28 */ "KeyOpt",
-    /* 29 */ "Keys",
-    /* 30 */ "KeysList",
-    /* 31 */ "NamedCode",
-    /* 32 */ "Newline",
-    /* 33 */ "Null",
-    /* 34 */ "Object",
-    /* 35 */ "ObjectList",
-    /* 36 */ "ObjectOpt",
-    /* 37 */ "Quote",
-    /* 38 */ "Section",
-    /* 39 */ "SectionBinding",
-    /* 40 */ "SectionBody",
-    /* 41 */ "SectionBodyList",
-    /* 42 */ "Str",
-    /* 43 */ "StrContinues",
-    /* 44 */ "StrContinuesList",
-    /* 45 */ "Swon",
-    /* 46 */ "SwonList",
-    /* 47 */ "SwonList0",
-    /* 48 */ "Text",
-    /* 49 */ "TextBinding",
-    /* 50 */ "TextBindingOpt",
-    /* 51 */ "TextStart",
-    /* 52 */ "True",
-    /* 53 */ "TypedQuote",
-    /* 54 */ "TypedStr",
-    /* 55 */ "Value",
-    /* 56 */ "ValueBinding",
-    /* 57 */ "Ws",
+    /*  2 */ "ArrayElements",
+    /*  3 */ "ArrayElementsOpt",
+    /*  4 */ "ArrayElementsTail",
+    /*  5 */ "ArrayElementsTailOpt",
+    /*  6 */ "ArrayEnd",
+    /*  7 */ "ArrayMarker",
+    /*  8 */ "ArrayMarkerOpt",
+    /*  9 */ "ArrayOpt",
+    /* 10 */ "At",
+    /* 11 */ "Begin",
+    /* 12 */ "Bind",
+    /* 13 */ "Binding",
+    /* 14 */ "BindingRhs",
+    /* 15 */ "Boolean",
+    /* 16 */ "Code",
+    /* 17 */ "CodeBlock",
+    /* 18 */ "Comma",
+    /* 19 */ "Continue",
+    /* 20 */ "Dot",
+    /* 21 */ "End",
+    /* 22 */ "Ext",
+    /* 23 */ "ExtensionNameSpace",
+    /* 24 */ "False",
+    /* 25 */ "Float",
+    /* 26 */ "FloatName",
+    /* 27 */ "Hole",
+    /* 28 */ "Ident",
+    /* 29 */ "InStr",
+    /* 30 */ "Integer",
+    /* 31 */ "Key",
+    /* 32 */ "KeyBase",
+    /* 33 */ "KeyOpt",
+    /* 34 */ "Keys",
+    /* 35 */ "KeysList",
+    /* 36 */ "NamedCode",
+    /* 37 */ "Newline",
+    /* 38 */ "Null",
+    /* 39 */ "Object",
+    /* 40 */ "ObjectList",
+    /* 41 */ "ObjectOpt",
+    /* 42 */ "Path",
+    /* 43 */ "PathHead",
+    /* 44 */ "PathList",
+    /* 45 */ "Quote",
+    /* 46 */ "Section",
+    /* 47 */ "SectionBinding",
+    /* 48 */ "SectionBody",
+    /* 49 */ "SectionBodyList",
+    /* 50 */ "Str",
+    /* 51 */ "StrContinues",
+    /* 52 */ "StrContinuesList",
+    /* 53 */ "StrOpt",
+    /* 54 */ "Swon",
+    /* 55 */ "SwonList",
+    /* 56 */ "SwonList0",
+    /* 57 */ "Text",
+    /* 58 */ "TextBinding",
+    /* 59 */ "TextBindingOpt",
+    /* 60 */ "TextStart",
+    /* 61 */ "True",
+    /* 62 */ "Tuple",
+    /* 63 */ "TupleBegin",
+    /* 64 */ "TupleElements",
+    /* 65 */ "TupleElementsOpt",
+    /* 66 */ "TupleElementsTail",
+    /* 67 */ "TupleElementsTailOpt",
+    /* 68 */ "TupleEnd",
+    /* 69 */ "TupleOpt",
+    /* 70 */ "TypedQuote",
+    /* 71 */ "TypedStr",
+    /* 72 */ "TypedStrOpt",
+    /* 73 */ "Value",
+    /* 74 */ "ValueBinding",
+    /* 75 */ "Ws",
 ];
 
-pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 58] = &[
+pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 76] = &[
     /* 0 - "Array" */
     LookaheadDFA {
-        prod0: 49,
+        prod0: 54,
         transitions: &[],
         k: 0,
     },
     /* 1 - "ArrayBegin" */
     LookaheadDFA {
-        prod0: 80,
+        prod0: 109,
         transitions: &[],
         k: 0,
     },
-    /* 2 - "ArrayEnd" */
+    /* 2 - "ArrayElements" */
     LookaheadDFA {
-        prod0: 81,
+        prod0: 57,
+        transitions: &[],
+        k: 0,
+    },
+    /* 3 - "ArrayElementsOpt" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 26, 2, 59), Trans(0, 30, 1, 58)],
+        k: 1,
+    },
+    /* 4 - "ArrayElementsTail" */
+    LookaheadDFA {
+        prod0: 60,
         transitions: &[],
         k: 0,
     },
-    /* 3 - "ArrayList" */
+    /* 5 - "ArrayElementsTailOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 5, 1, 50),
-            Trans(0, 6, 1, 50),
-            Trans(0, 7, 1, 50),
-            Trans(0, 8, 1, 50),
-            Trans(0, 9, 1, 50),
-            Trans(0, 10, 1, 50),
-            Trans(0, 11, 1, 50),
-            Trans(0, 14, 1, 50),
-            Trans(0, 15, 1, 50),
-            Trans(0, 16, 1, 50),
-            Trans(0, 22, 1, 50),
-            Trans(0, 24, 1, 50),
-            Trans(0, 25, 2, 51),
+            Trans(0, 5, 1, 61),
+            Trans(0, 6, 1, 61),
+            Trans(0, 7, 1, 61),
+            Trans(0, 8, 1, 61),
+            Trans(0, 9, 1, 61),
+            Trans(0, 10, 1, 61),
+            Trans(0, 11, 1, 61),
+            Trans(0, 12, 1, 61),
+            Trans(0, 15, 1, 61),
+            Trans(0, 16, 1, 61),
+            Trans(0, 17, 1, 61),
+            Trans(0, 22, 1, 61),
+            Trans(0, 23, 1, 61),
+            Trans(0, 25, 1, 61),
+            Trans(0, 26, 2, 62),
+            Trans(0, 27, 1, 61),
+            Trans(0, 33, 1, 61),
         ],
         k: 1,
     },
-    /* 4 - "ArrayMarker" */
+    /* 6 - "ArrayEnd" */
+    LookaheadDFA {
+        prod0: 110,
+        transitions: &[],
+        k: 0,
+    },
+    /* 7 - "ArrayMarker" */
     LookaheadDFA {
         prod0: 25,
         transitions: &[],
         k: 0,
     },
-    /* 5 - "ArrayMarkerOpt" */
+    /* 8 - "ArrayMarkerOpt" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 5, 1, 26), Trans(0, 25, 2, 27)],
+        transitions: &[Trans(0, 5, 1, 26), Trans(0, 26, 2, 27)],
         k: 1,
     },
-    /* 6 - "ArrayOpt" */
+    /* 9 - "ArrayOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 5, 2, 53),
-            Trans(0, 6, 2, 53),
-            Trans(0, 7, 2, 53),
-            Trans(0, 8, 2, 53),
-            Trans(0, 9, 2, 53),
-            Trans(0, 10, 2, 53),
-            Trans(0, 11, 2, 53),
-            Trans(0, 14, 2, 53),
-            Trans(0, 15, 2, 53),
-            Trans(0, 16, 2, 53),
-            Trans(0, 22, 2, 53),
-            Trans(0, 24, 2, 53),
-            Trans(0, 25, 2, 53),
-            Trans(0, 27, 1, 52),
+            Trans(0, 5, 1, 55),
+            Trans(0, 6, 1, 55),
+            Trans(0, 7, 1, 55),
+            Trans(0, 8, 1, 55),
+            Trans(0, 9, 1, 55),
+            Trans(0, 10, 1, 55),
+            Trans(0, 11, 1, 55),
+            Trans(0, 12, 1, 55),
+            Trans(0, 15, 1, 55),
+            Trans(0, 16, 1, 55),
+            Trans(0, 17, 1, 55),
+            Trans(0, 22, 1, 55),
+            Trans(0, 23, 1, 55),
+            Trans(0, 25, 1, 55),
+            Trans(0, 26, 2, 56),
+            Trans(0, 27, 1, 55),
+            Trans(0, 33, 1, 55),
         ],
         k: 1,
     },
-    /* 7 - "At" */
+    /* 10 - "At" */
     LookaheadDFA {
-        prod0: 75,
+        prod0: 104,
         transitions: &[],
         k: 0,
     },
-    /* 8 - "Begin" */
+    /* 11 - "Begin" */
     LookaheadDFA {
-        prod0: 78,
+        prod0: 107,
         transitions: &[],
         k: 0,
     },
-    /* 9 - "Bind" */
+    /* 12 - "Bind" */
     LookaheadDFA {
-        prod0: 82,
+        prod0: 113,
         transitions: &[],
         k: 0,
     },
-    /* 10 - "Binding" */
+    /* 13 - "Binding" */
     LookaheadDFA {
         prod0: 5,
         transitions: &[],
         k: 0,
     },
-    /* 11 - "BindingRhs" */
+    /* 14 - "BindingRhs" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 22, 2, 7), Trans(0, 26, 1, 6), Trans(0, 29, 3, 8)],
+        transitions: &[Trans(0, 23, 2, 7), Trans(0, 29, 1, 6), Trans(0, 32, 3, 8)],
         k: 1,
     },
-    /* 12 - "Boolean" */
+    /* 15 - "Boolean" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 6, 1, 55), Trans(0, 7, 2, 56)],
+        transitions: &[Trans(0, 7, 1, 75), Trans(0, 8, 2, 76)],
         k: 1,
     },
-    /* 13 - "Code" */
+    /* 16 - "Code" */
     LookaheadDFA {
-        prod0: 72,
+        prod0: 101,
         transitions: &[],
         k: 0,
     },
-    /* 14 - "CodeBlock" */
+    /* 17 - "CodeBlock" */
     LookaheadDFA {
-        prod0: 70,
+        prod0: 99,
         transitions: &[],
         k: 0,
     },
-    /* 15 - "Comma" */
+    /* 18 - "Comma" */
     LookaheadDFA {
-        prod0: 83,
+        prod0: 114,
         transitions: &[],
         k: 0,
     },
-    /* 16 - "Continue" */
+    /* 19 - "Continue" */
     LookaheadDFA {
-        prod0: 84,
+        prod0: 115,
         transitions: &[],
         k: 0,
     },
-    /* 17 - "Dot" */
+    /* 20 - "Dot" */
     LookaheadDFA {
-        prod0: 77,
+        prod0: 106,
         transitions: &[],
         k: 0,
     },
-    /* 18 - "End" */
+    /* 21 - "End" */
     LookaheadDFA {
-        prod0: 79,
+        prod0: 108,
         transitions: &[],
         k: 0,
     },
-    /* 19 - "Ext" */
+    /* 22 - "Ext" */
     LookaheadDFA {
-        prod0: 76,
+        prod0: 105,
         transitions: &[],
         k: 0,
     },
-    /* 20 - "ExtensionNameSpace" */
+    /* 23 - "ExtensionNameSpace" */
     LookaheadDFA {
-        prod0: 32,
+        prod0: 33,
         transitions: &[],
         k: 0,
     },
-    /* 21 - "False" */
+    /* 24 - "False" */
     LookaheadDFA {
-        prod0: 58,
+        prod0: 78,
         transitions: &[],
         k: 0,
     },
-    /* 22 - "Hole" */
+    /* 25 - "Float" */
     LookaheadDFA {
-        prod0: 60,
+        prod0: 73,
         transitions: &[],
         k: 0,
     },
-    /* 23 - "Ident" */
+    /* 26 - "FloatName" */
     LookaheadDFA {
-        prod0: 86,
+        prod0: 74,
         transitions: &[],
         k: 0,
     },
-    /* 24 - "InStr" */
+    /* 27 - "Hole" */
     LookaheadDFA {
-        prod0: 68,
+        prod0: 80,
         transitions: &[],
         k: 0,
     },
-    /* 25 - "Integer" */
+    /* 28 - "Ident" */
     LookaheadDFA {
-        prod0: 54,
+        prod0: 117,
+        transitions: &[],
+        k: 0,
+    },
+    /* 29 - "InStr" */
+    LookaheadDFA {
+        prod0: 97,
+        transitions: &[],
+        k: 0,
+    },
+    /* 30 - "Integer" */
+    LookaheadDFA {
+        prod0: 72,
         transitions: &[],
         k: 0,
     },
-    /* 26 - "Key" */
+    /* 31 - "Key" */
     LookaheadDFA {
         prod0: 22,
         transitions: &[],
         k: 0,
     },
-    /* 27 - "KeyBase" */
+    /* 32 - "KeyBase" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
             Trans(0, 5, 4, 31),
-            Trans(0, 10, 3, 30),
-            Trans(0, 20, 2, 29),
-            Trans(0, 30, 1, 28),
+            Trans(0, 6, 5, 32),
+            Trans(0, 11, 3, 30),
+            Trans(0, 21, 2, 29),
+            Trans(0, 33, 1, 28),
         ],
         k: 1,
     },
-    /* 28 - "KeyOpt" */
+    /* 33 - "KeyOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
             Trans(0, 0, 2, 24),
             Trans(0, 5, 2, 24),
-            Trans(0, 10, 2, 24),
-            Trans(0, 19, 2, 24),
+            Trans(0, 6, 2, 24),
+            Trans(0, 11, 2, 24),
             Trans(0, 20, 2, 24),
             Trans(0, 21, 2, 24),
             Trans(0, 22, 2, 24),
             Trans(0, 23, 2, 24),
-            Trans(0, 24, 1, 23),
+            Trans(0, 24, 2, 24),
+            Trans(0, 25, 1, 23),
             Trans(0, 26, 2, 24),
+            Trans(0, 28, 2, 24),
             Trans(0, 29, 2, 24),
             Trans(0, 30, 2, 24),
+            Trans(0, 32, 2, 24),
+            Trans(0, 33, 2, 24),
         ],
         k: 1,
     },
-    /* 29 - "Keys" */
+    /* 34 - "Keys" */
     LookaheadDFA {
         prod0: 19,
         transitions: &[],
         k: 0,
     },
-    /* 30 - "KeysList" */
+    /* 35 - "KeysList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
             Trans(0, 0, 2, 21),
             Trans(0, 5, 2, 21),
-            Trans(0, 10, 2, 21),
-            Trans(0, 19, 2, 21),
+            Trans(0, 6, 2, 21),
+            Trans(0, 11, 2, 21),
             Trans(0, 20, 2, 21),
-            Trans(0, 21, 1, 20),
-            Trans(0, 22, 2, 21),
+            Trans(0, 21, 2, 21),
+            Trans(0, 22, 1, 20),
             Trans(0, 23, 2, 21),
-            Trans(0, 26, 2, 21),
+            Trans(0, 24, 2, 21),
             Trans(0, 29, 2, 21),
-            Trans(0, 30, 2, 21),
+            Trans(0, 32, 2, 21),
+            Trans(0, 33, 2, 21),
         ],
         k: 1,
     },
-    /* 31 - "NamedCode" */
+    /* 36 - "NamedCode" */
     LookaheadDFA {
-        prod0: 71,
+        prod0: 100,
         transitions: &[],
         k: 0,
     },
-    /* 32 - "Newline" */
+    /* 37 - "Newline" */
     LookaheadDFA {
-        prod0: 73,
+        prod0: 102,
         transitions: &[],
         k: 0,
     },
-    /* 33 - "Null" */
+    /* 38 - "Null" */
     LookaheadDFA {
-        prod0: 59,
+        prod0: 79,
         transitions: &[],
         k: 0,
     },
-    /* 34 - "Object" */
+    /* 39 - "Object" */
     LookaheadDFA {
-        prod0: 44,
+        prod0: 49,
         transitions: &[],
         k: 0,
     },
-    /* 35 - "ObjectList" */
+    /* 40 - "ObjectList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 5, 1, 45),
-            Trans(0, 10, 1, 45),
-            Trans(0, 20, 1, 45),
-            Trans(0, 23, 2, 46),
-            Trans(0, 30, 1, 45),
+            Trans(0, 5, 1, 50),
+            Trans(0, 6, 1, 50),
+            Trans(0, 11, 1, 50),
+            Trans(0, 21, 1, 50),
+            Trans(0, 24, 2, 51),
+            Trans(0, 33, 1, 50),
         ],
         k: 1,
     },
-    /* 36 - "ObjectOpt" */
+    /* 41 - "ObjectOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 5, 2, 48),
-            Trans(0, 10, 2, 48),
-            Trans(0, 20, 2, 48),
-            Trans(0, 23, 2, 48),
-            Trans(0, 27, 1, 47),
-            Trans(0, 30, 2, 48),
+            Trans(0, 5, 2, 53),
+            Trans(0, 6, 2, 53),
+            Trans(0, 11, 2, 53),
+            Trans(0, 21, 2, 53),
+            Trans(0, 24, 2, 53),
+            Trans(0, 30, 1, 52),
+            Trans(0, 33, 2, 53),
         ],
         k: 1,
     },
-    /* 37 - "Quote" */
+    /* 42 - "Path" */
     LookaheadDFA {
-        prod0: 66,
+        prod0: 81,
+        transitions: &[],
+        k: 0,
+    },
+    /* 43 - "PathHead" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 5, 1, 84),
+            Trans(0, 6, 1, 84),
+            Trans(0, 11, 1, 84),
+            Trans(0, 21, 1, 84),
+            Trans(0, 25, 2, 85),
+            Trans(0, 33, 1, 84),
+        ],
+        k: 1,
+    },
+    /* 44 - "PathList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 0, 2, 83),
+            Trans(0, 5, 2, 83),
+            Trans(0, 6, 2, 83),
+            Trans(0, 11, 2, 83),
+            Trans(0, 20, 2, 83),
+            Trans(0, 21, 2, 83),
+            Trans(0, 22, 1, 82),
+            Trans(0, 24, 2, 83),
+            Trans(0, 26, 2, 83),
+            Trans(0, 28, 2, 83),
+            Trans(0, 30, 2, 83),
+            Trans(0, 33, 2, 83),
+        ],
+        k: 1,
+    },
+    /* 45 - "Quote" */
+    LookaheadDFA {
+        prod0: 95,
         transitions: &[],
         k: 0,
     },
-    /* 38 - "Section" */
+    /* 46 - "Section" */
     LookaheadDFA {
         prod0: 14,
         transitions: &[],
         k: 0,
     },
-    /* 39 - "SectionBinding" */
+    /* 47 - "SectionBinding" */
     LookaheadDFA {
         prod0: 10,
         transitions: &[],
         k: 0,
     },
-    /* 40 - "SectionBody" */
+    /* 48 - "SectionBody" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
             Trans(0, 0, 1, 15),
             Trans(0, 5, 1, 15),
-            Trans(0, 10, 1, 15),
-            Trans(0, 19, 1, 15),
+            Trans(0, 6, 1, 15),
+            Trans(0, 11, 1, 15),
             Trans(0, 20, 1, 15),
-            Trans(0, 22, 2, 18),
-            Trans(0, 23, 1, 15),
-            Trans(0, 30, 1, 15),
+            Trans(0, 21, 1, 15),
+            Trans(0, 23, 2, 18),
+            Trans(0, 24, 1, 15),
+            Trans(0, 33, 1, 15),
         ],
         k: 1,
     },
-    /* 41 - "SectionBodyList" */
+    /* 49 - "SectionBodyList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
             Trans(0, 0, 2, 17),
             Trans(0, 5, 1, 16),
-            Trans(0, 10, 1, 16),
-            Trans(0, 19, 2, 17),
-            Trans(0, 20, 1, 16),
-            Trans(0, 23, 2, 17),
-            Trans(0, 30, 1, 16),
+            Trans(0, 6, 1, 16),
+            Trans(0, 11, 1, 16),
+            Trans(0, 20, 2, 17),
+            Trans(0, 21, 1, 16),
+            Trans(0, 24, 2, 17),
+            Trans(0, 33, 1, 16),
         ],
         k: 1,
     },
-    /* 42 - "Str" */
+    /* 50 - "Str" */
     LookaheadDFA {
-        prod0: 64,
+        prod0: 89,
         transitions: &[],
         k: 0,
     },
-    /* 43 - "StrContinues" */
+    /* 51 - "StrContinues" */
     LookaheadDFA {
-        prod0: 61,
+        prod0: 86,
         transitions: &[],
         k: 0,
     },
-    /* 44 - "StrContinuesList" */
+    /* 52 - "StrContinuesList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 0, 2, 63),
-            Trans(0, 5, 2, 63),
-            Trans(0, 6, 2, 63),
-            Trans(0, 7, 2, 63),
-            Trans(0, 8, 2, 63),
-            Trans(0, 9, 2, 63),
-            Trans(0, 10, 2, 63),
-            Trans(0, 11, 2, 63),
-            Trans(0, 14, 2, 63),
-            Trans(0, 15, 2, 63),
-            Trans(0, 16, 2, 63),
-            Trans(0, 19, 2, 63),
-            Trans(0, 20, 2, 63),
-            Trans(0, 22, 2, 63),
-            Trans(0, 23, 2, 63),
-            Trans(0, 24, 2, 63),
-            Trans(0, 25, 2, 63),
-            Trans(0, 27, 2, 63),
-            Trans(0, 28, 1, 62),
-            Trans(0, 30, 2, 63),
+            Trans(0, 0, 2, 88),
+            Trans(0, 5, 2, 88),
+            Trans(0, 6, 2, 88),
+            Trans(0, 11, 2, 88),
+            Trans(0, 20, 2, 88),
+            Trans(0, 21, 2, 88),
+            Trans(0, 24, 2, 88),
+            Trans(0, 26, 2, 88),
+            Trans(0, 28, 2, 88),
+            Trans(0, 30, 2, 88),
+            Trans(0, 31, 1, 87),
+            Trans(0, 33, 2, 88),
         ],
         k: 1,
     },
-    /* 45 - "Swon" */
+    /* 53 - "StrOpt" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 11, 2, 91), Trans(0, 13, 1, 90)],
+        k: 1,
+    },
+    /* 54 - "Swon" */
     LookaheadDFA {
         prod0: 0,
         transitions: &[],
         k: 0,
     },
-    /* 46 - "SwonList" */
+    /* 55 - "SwonList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
             Trans(0, 0, 2, 4),
             Trans(0, 5, 1, 3),
-            Trans(0, 10, 1, 3),
-            Trans(0, 19, 2, 4),
-            Trans(0, 20, 1, 3),
-            Trans(0, 23, 2, 4),
-            Trans(0, 30, 1, 3),
+            Trans(0, 6, 1, 3),
+            Trans(0, 11, 1, 3),
+            Trans(0, 20, 2, 4),
+            Trans(0, 21, 1, 3),
+            Trans(0, 24, 2, 4),
+            Trans(0, 33, 1, 3),
         ],
         k: 1,
     },
-    /* 47 - "SwonList0" */
+    /* 56 - "SwonList0" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 0, 2, 2), Trans(0, 19, 1, 1), Trans(0, 23, 2, 2)],
+        transitions: &[Trans(0, 0, 2, 2), Trans(0, 20, 1, 1), Trans(0, 24, 2, 2)],
         k: 1,
     },
-    /* 48 - "Text" */
+    /* 57 - "Text" */
     LookaheadDFA {
-        prod0: 69,
+        prod0: 98,
         transitions: &[],
         k: 0,
     },
-    /* 49 - "TextBinding" */
+    /* 58 - "TextBinding" */
     LookaheadDFA {
         prod0: 11,
         transitions: &[],
         k: 0,
     },
-    /* 50 - "TextBindingOpt" */
+    /* 59 - "TextBindingOpt" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 13, 2, 13), Trans(0, 18, 1, 12)],
+        transitions: &[Trans(0, 14, 2, 13), Trans(0, 19, 1, 12)],
         k: 1,
     },
-    /* 51 - "TextStart" */
+    /* 60 - "TextStart" */
     LookaheadDFA {
-        prod0: 85,
+        prod0: 116,
         transitions: &[],
         k: 0,
     },
-    /* 52 - "True" */
+    /* 61 - "True" */
     LookaheadDFA {
-        prod0: 57,
+        prod0: 77,
+        transitions: &[],
+        k: 0,
+    },
+    /* 62 - "Tuple" */
+    LookaheadDFA {
+        prod0: 63,
+        transitions: &[],
+        k: 0,
+    },
+    /* 63 - "TupleBegin" */
+    LookaheadDFA {
+        prod0: 111,
+        transitions: &[],
+        k: 0,
+    },
+    /* 64 - "TupleElements" */
+    LookaheadDFA {
+        prod0: 66,
         transitions: &[],
         k: 0,
     },
-    /* 53 - "TypedQuote" */
+    /* 65 - "TupleElementsOpt" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 28, 2, 68), Trans(0, 30, 1, 67)],
+        k: 1,
+    },
+    /* 66 - "TupleElementsTail" */
     LookaheadDFA {
-        prod0: 67,
+        prod0: 69,
         transitions: &[],
         k: 0,
     },
-    /* 54 - "TypedStr" */
+    /* 67 - "TupleElementsTailOpt" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 5, 1, 70),
+            Trans(0, 6, 1, 70),
+            Trans(0, 7, 1, 70),
+            Trans(0, 8, 1, 70),
+            Trans(0, 9, 1, 70),
+            Trans(0, 10, 1, 70),
+            Trans(0, 11, 1, 70),
+            Trans(0, 12, 1, 70),
+            Trans(0, 15, 1, 70),
+            Trans(0, 16, 1, 70),
+            Trans(0, 17, 1, 70),
+            Trans(0, 22, 1, 70),
+            Trans(0, 23, 1, 70),
+            Trans(0, 25, 1, 70),
+            Trans(0, 27, 1, 70),
+            Trans(0, 28, 2, 71),
+            Trans(0, 33, 1, 70),
+        ],
+        k: 1,
+    },
+    /* 68 - "TupleEnd" */
     LookaheadDFA {
-        prod0: 65,
+        prod0: 112,
         transitions: &[],
         k: 0,
     },
-    /* 55 - "Value" */
+    /* 69 - "TupleOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 5, 3, 35),
-            Trans(0, 6, 4, 36),
-            Trans(0, 7, 4, 36),
-            Trans(0, 8, 5, 37),
-            Trans(0, 9, 8, 40),
-            Trans(0, 10, 6, 38),
-            Trans(0, 11, 7, 39),
-            Trans(0, 14, 9, 41),
-            Trans(0, 15, 10, 42),
-            Trans(0, 16, 11, 43),
-            Trans(0, 22, 1, 33),
-            Trans(0, 24, 2, 34),
+            Trans(0, 5, 1, 64),
+            Trans(0, 6, 1, 64),
+            Trans(0, 7, 1, 64),
+            Trans(0, 8, 1, 64),
+            Trans(0, 9, 1, 64),
+            Trans(0, 10, 1, 64),
+            Trans(0, 11, 1, 64),
+            Trans(0, 12, 1, 64),
+            Trans(0, 15, 1, 64),
+            Trans(0, 16, 1, 64),
+            Trans(0, 17, 1, 64),
+            Trans(0, 22, 1, 64),
+            Trans(0, 23, 1, 64),
+            Trans(0, 25, 1, 64),
+            Trans(0, 27, 1, 64),
+            Trans(0, 28, 2, 65),
+            Trans(0, 33, 1, 64),
         ],
         k: 1,
     },
-    /* 56 - "ValueBinding" */
+    /* 70 - "TypedQuote" */
+    LookaheadDFA {
+        prod0: 96,
+        transitions: &[],
+        k: 0,
+    },
+    /* 71 - "TypedStr" */
+    LookaheadDFA {
+        prod0: 92,
+        transitions: &[],
+        k: 0,
+    },
+    /* 72 - "TypedStrOpt" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 11, 2, 94), Trans(0, 13, 1, 93)],
+        k: 1,
+    },
+    /* 73 - "Value" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 5, 4, 37),
+            Trans(0, 6, 5, 38),
+            Trans(0, 7, 7, 40),
+            Trans(0, 8, 7, 40),
+            Trans(0, 9, 8, 41),
+            Trans(0, 10, 11, 44),
+            Trans(0, 11, 9, 42),
+            Trans(0, 12, 10, 43),
+            Trans(0, 15, 12, 45),
+            Trans(0, 16, 13, 46),
+            Trans(0, 17, 14, 47),
+            Trans(0, 22, 15, 48),
+            Trans(0, 23, 1, 34),
+            Trans(0, 25, 2, 35),
+            Trans(0, 27, 3, 36),
+            Trans(0, 33, 6, 39),
+        ],
+        k: 1,
+    },
+    /* 74 - "ValueBinding" */
     LookaheadDFA {
         prod0: 9,
         transitions: &[],
         k: 0,
     },
-    /* 57 - "Ws" */
+    /* 75 - "Ws" */
     LookaheadDFA {
-        prod0: 74,
+        prod0: 103,
         transitions: &[],
         k: 0,
     },
 ];
 
-pub const PRODUCTIONS: &[Production; 87] = &[
+pub const PRODUCTIONS: &[Production; 118] = &[
     // 0 - Swon: SwonList /* Vec */ SwonList0 /* Vec */;
     Production {
-        lhs: 45,
-        production: &[ParseType::N(47), ParseType::N(46)],
+        lhs: 54,
+        production: &[ParseType::N(56), ParseType::N(55)],
     },
     // 1 - SwonList0: Section SwonList0;
     Production {
-        lhs: 47,
-        production: &[ParseType::N(47), ParseType::N(38)],
+        lhs: 56,
+        production: &[ParseType::N(56), ParseType::N(46)],
     },
     // 2 - SwonList0: ;
     Production {
-        lhs: 47,
+        lhs: 56,
         production: &[],
     },
     // 3 - SwonList: Binding SwonList;
     Production {
-        lhs: 46,
-        production: &[ParseType::N(46), ParseType::N(10)],
+        lhs: 55,
+        production: &[ParseType::N(55), ParseType::N(13)],
     },
     // 4 - SwonList: ;
     Production {
-        lhs: 46,
+        lhs: 55,
         production: &[],
     },
     // 5 - Binding: Keys BindingRhs;
     Production {
-        lhs: 10,
-        production: &[ParseType::N(11), ParseType::N(29)],
+        lhs: 13,
+        production: &[ParseType::N(14), ParseType::N(34)],
     },
     // 6 - BindingRhs: ValueBinding;
     Production {
-        lhs: 11,
-        production: &[ParseType::N(56)],
+        lhs: 14,
+        production: &[ParseType::N(74)],
     },
     // 7 - BindingRhs: SectionBinding;
     Production {
-        lhs: 11,
-        production: &[ParseType::N(39)],
+        lhs: 14,
+        production: &[ParseType::N(47)],
     },
     // 8 - BindingRhs: TextBinding;
     Production {
-        lhs: 11,
-        production: &[ParseType::N(49)],
+        lhs: 14,
+        production: &[ParseType::N(58)],
     },
     // 9 - ValueBinding: Bind Value;
     Production {
-        lhs: 56,
-        production: &[ParseType::N(55), ParseType::N(9)],
+        lhs: 74,
+        production: &[ParseType::N(73), ParseType::N(12)],
     },
     // 10 - SectionBinding: Begin Swon End;
     Production {
-        lhs: 39,
-        production: &[ParseType::N(18), ParseType::N(45), ParseType::N(8)],
+        lhs: 47,
+        production: &[ParseType::N(21), ParseType::N(54), ParseType::N(11)],
     },
     // 11 - TextBinding: TextStart TextBindingOpt /* Option */ Text Newline;
     Production {
-        lhs: 49,
+        lhs: 58,
         production: &[
-            ParseType::N(32),
-            ParseType::N(48),
-            ParseType::N(50),
-            ParseType::N(51),
+            ParseType::N(37),
+            ParseType::N(57),
+            ParseType::N(59),
+            ParseType::N(60),
         ],
     },
     // 12 - TextBindingOpt: Ws^ /* Clipped */;
     Production {
-        lhs: 50,
-        production: &[ParseType::N(57)],
+        lhs: 59,
+        production: &[ParseType::N(75)],
     },
     // 13 - TextBindingOpt: ;
     Production {
-        lhs: 50,
+        lhs: 59,
         production: &[],
     },
     // 14 - Section: At Keys SectionBody;
     Production {
-        lhs: 38,
-        production: &[ParseType::N(40), ParseType::N(29), ParseType::N(7)],
+        lhs: 46,
+        production: &[ParseType::N(48), ParseType::N(34), ParseType::N(10)],
     },
     // 15 - SectionBody: SectionBodyList /* Vec */;
     Production {
-        lhs: 40,
-        production: &[ParseType::N(41)],
+        lhs: 48,
+        production: &[ParseType::N(49)],
     },
     // 16 - SectionBodyList: Binding SectionBodyList;
     Production {
-        lhs: 41,
-        production: &[ParseType::N(41), ParseType::N(10)],
+        lhs: 49,
+        production: &[ParseType::N(49), ParseType::N(13)],
     },
     // 17 - SectionBodyList: ;
     Production {
-        lhs: 41,
+        lhs: 49,
         production: &[],
     },
     // 18 - SectionBody: SectionBinding;
     Production {
-        lhs: 40,
-        production: &[ParseType::N(39)],
+        lhs: 48,
+        production: &[ParseType::N(47)],
     },
     // 19 - Keys: Key KeysList /* Vec */;
     Production {
-        lhs: 29,
-        production: &[ParseType::N(30), ParseType::N(26)],
+        lhs: 34,
+        production: &[ParseType::N(35), ParseType::N(31)],
     },
     // 20 - KeysList: Dot Key KeysList;
     Production {
-        lhs: 30,
-        production: &[ParseType::N(30), ParseType::N(26), ParseType::N(17)],
+        lhs: 35,
+        production: &[ParseType::N(35), ParseType::N(31), ParseType::N(20)],
     },
     // 21 - KeysList: ;
     Production {
-        lhs: 30,
+        lhs: 35,
         production: &[],
     },
     // 22 - Key: KeyBase KeyOpt /* Option */;
     Production {
-        lhs: 26,
-        production: &[ParseType::N(28), ParseType::N(27)],
+        lhs: 31,
+        production: &[ParseType::N(33), ParseType::N(32)],
     },
     // 23 - KeyOpt: ArrayMarker;
     Production {
-        lhs: 28,
-        production: &[ParseType::N(4)],
+        lhs: 33,
+        production: &[ParseType::N(7)],
     },
     // 24 - KeyOpt: ;
     Production {
-        lhs: 28,
+        lhs: 33,
         production: &[],
     },
     // 25 - ArrayMarker: ArrayBegin ArrayMarkerOpt /* Option */ ArrayEnd;
     Production {
-        lhs: 4,
-        production: &[ParseType::N(2), ParseType::N(5), ParseType::N(1)],
+        lhs: 7,
+        production: &[ParseType::N(6), ParseType::N(8), ParseType::N(1)],
     },
     // 26 - ArrayMarkerOpt: Integer;
     Production {
-        lhs: 5,
-        production: &[ParseType::N(25)],
+        lhs: 8,
+        production: &[ParseType::N(30)],
     },
     // 27 - ArrayMarkerOpt: ;
     Production {
-        lhs: 5,
+        lhs: 8,
         production: &[],
     },
     // 28 - KeyBase: Ident;
     Production {
-        lhs: 27,
-        production: &[ParseType::N(23)],
+        lhs: 32,
+        production: &[ParseType::N(28)],
     },
     // 29 - KeyBase: ExtensionNameSpace;
     Production {
-        lhs: 27,
-        production: &[ParseType::N(20)],
+        lhs: 32,
+        production: &[ParseType::N(23)],
     },
     // 30 - KeyBase: Str;
     Production {
-        lhs: 27,
-        production: &[ParseType::N(42)],
+        lhs: 32,
+        production: &[ParseType::N(50)],
     },
     // 31 - KeyBase: Integer;
     Production {
-        lhs: 27,
+        lhs: 32,
+        production: &[ParseType::N(30)],
+    },
+    // 32 - KeyBase: Float;
+    Production {
+        lhs: 32,
         production: &[ParseType::N(25)],
     },
-    // 32 - ExtensionNameSpace: Ext Ident;
+    // 33 - ExtensionNameSpace: Ext Ident;
     Production {
-        lhs: 20,
-        production: &[ParseType::N(23), ParseType::N(19)],
+        lhs: 23,
+        production: &[ParseType::N(28), ParseType::N(22)],
     },
-    // 33 - Value: Object;
+    // 34 - Value: Object;
     Production {
-        lhs: 55,
-        production: &[ParseType::N(34)],
+        lhs: 73,
+        production: &[ParseType::N(39)],
     },
-    // 34 - Value: Array;
+    // 35 - Value: Array;
     Production {
-        lhs: 55,
+        lhs: 73,
         production: &[ParseType::N(0)],
     },
-    // 35 - Value: Integer;
+    // 36 - Value: Tuple;
     Production {
-        lhs: 55,
+        lhs: 73,
+        production: &[ParseType::N(62)],
+    },
+    // 37 - Value: Integer;
+    Production {
+        lhs: 73,
+        production: &[ParseType::N(30)],
+    },
+    // 38 - Value: Float;
+    Production {
+        lhs: 73,
         production: &[ParseType::N(25)],
     },
-    // 36 - Value: Boolean;
+    // 39 - Value: FloatName;
     Production {
-        lhs: 55,
-        production: &[ParseType::N(12)],
+        lhs: 73,
+        production: &[ParseType::N(26)],
     },
-    // 37 - Value: Null;
+    // 40 - Value: Boolean;
     Production {
-        lhs: 55,
-        production: &[ParseType::N(33)],
+        lhs: 73,
+        production: &[ParseType::N(15)],
     },
-    // 38 - Value: StrContinues;
+    // 41 - Value: Null;
     Production {
-        lhs: 55,
-        production: &[ParseType::N(43)],
+        lhs: 73,
+        production: &[ParseType::N(38)],
     },
-    // 39 - Value: TypedStr;
+    // 42 - Value: StrContinues;
     Production {
-        lhs: 55,
-        production: &[ParseType::N(54)],
+        lhs: 73,
+        production: &[ParseType::N(51)],
     },
-    // 40 - Value: Hole;
+    // 43 - Value: TypedStr;
     Production {
-        lhs: 55,
-        production: &[ParseType::N(22)],
+        lhs: 73,
+        production: &[ParseType::N(71)],
     },
-    // 41 - Value: CodeBlock;
+    // 44 - Value: Hole;
     Production {
-        lhs: 55,
-        production: &[ParseType::N(14)],
+        lhs: 73,
+        production: &[ParseType::N(27)],
     },
-    // 42 - Value: NamedCode;
+    // 45 - Value: CodeBlock;
     Production {
-        lhs: 55,
-        production: &[ParseType::N(31)],
+        lhs: 73,
+        production: &[ParseType::N(17)],
     },
-    // 43 - Value: Code;
+    // 46 - Value: NamedCode;
     Production {
-        lhs: 55,
-        production: &[ParseType::N(13)],
+        lhs: 73,
+        production: &[ParseType::N(36)],
     },
-    // 44 - Object: Begin ObjectList /* Vec */ End;
+    // 47 - Value: Code;
     Production {
-        lhs: 34,
-        production: &[ParseType::N(18), ParseType::N(35), ParseType::N(8)],
+        lhs: 73,
+        production: &[ParseType::N(16)],
     },
-    // 45 - ObjectList: Key Bind Value ObjectOpt /* Option */ ObjectList;
+    // 48 - Value: Path;
     Production {
-        lhs: 35,
+        lhs: 73,
+        production: &[ParseType::N(42)],
+    },
+    // 49 - Object: Begin ObjectList /* Vec */ End;
+    Production {
+        lhs: 39,
+        production: &[ParseType::N(21), ParseType::N(40), ParseType::N(11)],
+    },
+    // 50 - ObjectList: Key Bind Value ObjectOpt /* Option */ ObjectList;
+    Production {
+        lhs: 40,
         production: &[
-            ParseType::N(35),
-            ParseType::N(36),
-            ParseType::N(55),
-            ParseType::N(9),
-            ParseType::N(26),
+            ParseType::N(40),
+            ParseType::N(41),
+            ParseType::N(73),
+            ParseType::N(12),
+            ParseType::N(31),
         ],
     },
-    // 46 - ObjectList: ;
+    // 51 - ObjectList: ;
     Production {
-        lhs: 35,
+        lhs: 40,
         production: &[],
     },
-    // 47 - ObjectOpt: Comma;
+    // 52 - ObjectOpt: Comma;
     Production {
-        lhs: 36,
-        production: &[ParseType::N(15)],
+        lhs: 41,
+        production: &[ParseType::N(18)],
     },
-    // 48 - ObjectOpt: ;
+    // 53 - ObjectOpt: ;
     Production {
-        lhs: 36,
+        lhs: 41,
         production: &[],
     },
-    // 49 - Array: ArrayBegin ArrayList /* Vec */ ArrayEnd;
+    // 54 - Array: ArrayBegin ArrayOpt /* Option */ ArrayEnd;
     Production {
         lhs: 0,
-        production: &[ParseType::N(2), ParseType::N(3), ParseType::N(1)],
+        production: &[ParseType::N(6), ParseType::N(9), ParseType::N(1)],
+    },
+    // 55 - ArrayOpt: ArrayElements;
+    Production {
+        lhs: 9,
+        production: &[ParseType::N(2)],
+    },
+    // 56 - ArrayOpt: ;
+    Production {
+        lhs: 9,
+        production: &[],
+    },
+    // 57 - ArrayElements: Value ArrayElementsOpt /* Option */;
+    Production {
+        lhs: 2,
+        production: &[ParseType::N(3), ParseType::N(73)],
     },
-    // 50 - ArrayList: Value ArrayOpt /* Option */ ArrayList;
+    // 58 - ArrayElementsOpt: ArrayElementsTail;
     Production {
         lhs: 3,
-        production: &[ParseType::N(3), ParseType::N(6), ParseType::N(55)],
+        production: &[ParseType::N(4)],
     },
-    // 51 - ArrayList: ;
+    // 59 - ArrayElementsOpt: ;
     Production {
         lhs: 3,
         production: &[],
     },
-    // 52 - ArrayOpt: Comma;
+    // 60 - ArrayElementsTail: Comma ArrayElementsTailOpt /* Option */;
     Production {
-        lhs: 6,
-        production: &[ParseType::N(15)],
+        lhs: 4,
+        production: &[ParseType::N(5), ParseType::N(18)],
     },
-    // 53 - ArrayOpt: ;
+    // 61 - ArrayElementsTailOpt: ArrayElements;
     Production {
-        lhs: 6,
+        lhs: 5,
+        production: &[ParseType::N(2)],
+    },
+    // 62 - ArrayElementsTailOpt: ;
+    Production {
+        lhs: 5,
         production: &[],
     },
-    // 54 - Integer: /\d[\d_]*/;
+    // 63 - Tuple: TupleBegin TupleOpt /* Option */ TupleEnd;
     Production {
-        lhs: 25,
-        production: &[ParseType::T(5)],
+        lhs: 62,
+        production: &[ParseType::N(68), ParseType::N(69), ParseType::N(63)],
     },
-    // 55 - Boolean: True;
+    // 64 - TupleOpt: TupleElements;
     Production {
-        lhs: 12,
-        production: &[ParseType::N(52)],
+        lhs: 69,
+        production: &[ParseType::N(64)],
     },
-    // 56 - Boolean: False;
+    // 65 - TupleOpt: ;
     Production {
-        lhs: 12,
-        production: &[ParseType::N(21)],
+        lhs: 69,
+        production: &[],
     },
-    // 57 - True: 'true';
+    // 66 - TupleElements: Value TupleElementsOpt /* Option */;
     Production {
-        lhs: 52,
+        lhs: 64,
+        production: &[ParseType::N(65), ParseType::N(73)],
+    },
+    // 67 - TupleElementsOpt: TupleElementsTail;
+    Production {
+        lhs: 65,
+        production: &[ParseType::N(66)],
+    },
+    // 68 - TupleElementsOpt: ;
+    Production {
+        lhs: 65,
+        production: &[],
+    },
+    // 69 - TupleElementsTail: Comma TupleElementsTailOpt /* Option */;
+    Production {
+        lhs: 66,
+        production: &[ParseType::N(67), ParseType::N(18)],
+    },
+    // 70 - TupleElementsTailOpt: TupleElements;
+    Production {
+        lhs: 67,
+        production: &[ParseType::N(64)],
+    },
+    // 71 - TupleElementsTailOpt: ;
+    Production {
+        lhs: 67,
+        production: &[],
+    },
+    // 72 - Integer: /[+-]?(0x[0-9a-fA-F][0-9a-fA-F_]*|0o[0-7][0-7_]*|0b[01][01_]*|\d[\d_]*)/;
+    Production {
+        lhs: 30,
+        production: &[ParseType::T(5)],
+    },
+    // 73 - Float: /[+-]?(\d[\d_]*\.\d[\d_]*([eE][+-]?\d[\d_]*)?|\d[\d_]*[eE][+-]?\d[\d_]*)|[+-](inf|nan)/;
+    Production {
+        lhs: 25,
         production: &[ParseType::T(6)],
     },
-    // 58 - False: 'false';
+    // 74 - FloatName: Ident;
     Production {
-        lhs: 21,
+        lhs: 26,
+        production: &[ParseType::N(28)],
+    },
+    // 75 - Boolean: True;
+    Production {
+        lhs: 15,
+        production: &[ParseType::N(61)],
+    },
+    // 76 - Boolean: False;
+    Production {
+        lhs: 15,
+        production: &[ParseType::N(24)],
+    },
+    // 77 - True: 'true';
+    Production {
+        lhs: 61,
         production: &[ParseType::T(7)],
     },
-    // 59 - Null: 'null';
+    // 78 - False: 'false';
     Production {
-        lhs: 33,
+        lhs: 24,
         production: &[ParseType::T(8)],
     },
-    // 60 - Hole: '!';
+    // 79 - Null: 'null';
     Production {
-        lhs: 22,
+        lhs: 38,
         production: &[ParseType::T(9)],
     },
-    // 61 - StrContinues: Str StrContinuesList /* Vec */;
+    // 80 - Hole: '!';
     Production {
-        lhs: 43,
-        production: &[ParseType::N(44), ParseType::N(42)],
+        lhs: 27,
+        production: &[ParseType::T(10)],
+    },
+    // 81 - Path: Dot PathHead PathList /* Vec */;
+    Production {
+        lhs: 42,
+        production: &[ParseType::N(44), ParseType::N(43), ParseType::N(20)],
     },
-    // 62 - StrContinuesList: Continue Str StrContinuesList;
+    // 82 - PathList: Dot Key PathList;
     Production {
         lhs: 44,
-        production: &[ParseType::N(44), ParseType::N(42), ParseType::N(16)],
+        production: &[ParseType::N(44), ParseType::N(31), ParseType::N(20)],
     },
-    // 63 - StrContinuesList: ;
+    // 83 - PathList: ;
     Production {
         lhs: 44,
         production: &[],
     },
-    // 64 - Str: Quote InStr Quote;
+    // 84 - PathHead: Key;
     Production {
-        lhs: 42,
-        production: &[ParseType::N(37), ParseType::N(24), ParseType::N(37)],
+        lhs: 43,
+        production: &[ParseType::N(31)],
     },
-    // 65 - TypedStr: TypedQuote InStr Quote;
+    // 85 - PathHead: ArrayMarker;
     Production {
-        lhs: 54,
-        production: &[ParseType::N(37), ParseType::N(24), ParseType::N(53)],
+        lhs: 43,
+        production: &[ParseType::N(7)],
     },
-    // 66 - Quote: '"';
+    // 86 - StrContinues: Str StrContinuesList /* Vec */;
     Production {
-        lhs: 37,
-        production: &[ParseType::T(10)],
+        lhs: 51,
+        production: &[ParseType::N(52), ParseType::N(50)],
+    },
+    // 87 - StrContinuesList: Continue Str StrContinuesList;
+    Production {
+        lhs: 52,
+        production: &[ParseType::N(52), ParseType::N(50), ParseType::N(19)],
     },
-    // 67 - TypedQuote: /[a-zA-Z0-9-_]+"/;
+    // 88 - StrContinuesList: ;
+    Production {
+        lhs: 52,
+        production: &[],
+    },
+    // 89 - Str: Quote StrOpt /* Option */ Quote;
+    Production {
+        lhs: 50,
+        production: &[ParseType::N(45), ParseType::N(53), ParseType::N(45)],
+    },
+    // 90 - StrOpt: InStr;
+    Production {
+        lhs: 53,
+        production: &[ParseType::N(29)],
+    },
+    // 91 - StrOpt: ;
     Production {
         lhs: 53,
+        production: &[],
+    },
+    // 92 - TypedStr: TypedQuote TypedStrOpt /* Option */ Quote;
+    Production {
+        lhs: 71,
+        production: &[ParseType::N(45), ParseType::N(72), ParseType::N(70)],
+    },
+    // 93 - TypedStrOpt: InStr;
+    Production {
+        lhs: 72,
+        production: &[ParseType::N(29)],
+    },
+    // 94 - TypedStrOpt: ;
+    Production {
+        lhs: 72,
+        production: &[],
+    },
+    // 95 - Quote: '"';
+    Production {
+        lhs: 45,
         production: &[ParseType::T(11)],
     },
-    // 68 - InStr: /(\\[nrt\\"0]|[^\\"\r\n])*/;
+    // 96 - TypedQuote: /[a-zA-Z0-9-_]+"/;
     Production {
-        lhs: 24,
+        lhs: 70,
         production: &[ParseType::T(12)],
     },
-    // 69 - Text: /[^\r\n]*/;
+    // 97 - InStr: /(\\[nrt\\"0]|[^\\"\r\n])+/;
     Production {
-        lhs: 48,
+        lhs: 29,
         production: &[ParseType::T(13)],
     },
-    // 70 - CodeBlock: /```[a-zA-Z0-9-_]*(\r\n|\r|\n)([^`]|[`]{1,2})*```/;
+    // 98 - Text: /[^\r\n]*/;
     Production {
-        lhs: 14,
+        lhs: 57,
         production: &[ParseType::T(14)],
     },
-    // 71 - NamedCode: /[a-zA-Z0-9-_]+`([^`\r\n]|\\`)*`/;
+    // 99 - CodeBlock: /```[a-zA-Z0-9-_]*(\r\n|\r|\n)([^`]|`[^`]|``[^`])*```/;
     Production {
-        lhs: 31,
+        lhs: 17,
         production: &[ParseType::T(15)],
     },
-    // 72 - Code: /`([^`\r\n]|\\`)*`/;
+    // 100 - NamedCode: /[a-zA-Z0-9-_]+`([^`\r\n]|\\`)*`/;
     Production {
-        lhs: 13,
+        lhs: 36,
         production: &[ParseType::T(16)],
     },
-    // 73 - Newline: /\r\n|\r|\n/;
+    // 101 - Code: /`([^`\r\n]|\\`)*`/;
     Production {
-        lhs: 32,
+        lhs: 16,
         production: &[ParseType::T(17)],
     },
-    // 74 - Ws: /[\s--\r\n]+/;
+    // 102 - Newline: /\r\n|\r|\n/;
     Production {
-        lhs: 57,
+        lhs: 37,
         production: &[ParseType::T(18)],
     },
-    // 75 - At: '@';
+    // 103 - Ws: /[\s--\r\n]+/;
     Production {
-        lhs: 7,
+        lhs: 75,
         production: &[ParseType::T(19)],
     },
-    // 76 - Ext: '$';
+    // 104 - At: '@';
     Production {
-        lhs: 19,
+        lhs: 10,
         production: &[ParseType::T(20)],
     },
-    // 77 - Dot: '.';
+    // 105 - Ext: '$';
     Production {
-        lhs: 17,
+        lhs: 22,
         production: &[ParseType::T(21)],
     },
-    // 78 - Begin: '{';
+    // 106 - Dot: '.';
     Production {
-        lhs: 8,
+        lhs: 20,
         production: &[ParseType::T(22)],
     },
-    // 79 - End: '}';
+    // 107 - Begin: '{';
     Production {
-        lhs: 18,
+        lhs: 11,
         production: &[ParseType::T(23)],
     },
-    // 80 - ArrayBegin: '[';
+    // 108 - End: '}';
     Production {
-        lhs: 1,
+        lhs: 21,
         production: &[ParseType::T(24)],
     },
-    // 81 - ArrayEnd: ']';
+    // 109 - ArrayBegin: '[';
     Production {
-        lhs: 2,
+        lhs: 1,
         production: &[ParseType::T(25)],
     },
-    // 82 - Bind: '=';
+    // 110 - ArrayEnd: ']';
     Production {
-        lhs: 9,
+        lhs: 6,
         production: &[ParseType::T(26)],
     },
-    // 83 - Comma: ',';
+    // 111 - TupleBegin: '(';
     Production {
-        lhs: 15,
+        lhs: 63,
         production: &[ParseType::T(27)],
     },
-    // 84 - Continue: '\\';
+    // 112 - TupleEnd: ')';
     Production {
-        lhs: 16,
+        lhs: 68,
         production: &[ParseType::T(28)],
     },
-    // 85 - TextStart: ":";
+    // 113 - Bind: '=';
     Production {
-        lhs: 51,
+        lhs: 12,
         production: &[ParseType::T(29)],
     },
-    // 86 - Ident: /\p{XID_Start}[\p{XID_Continue}-]*/;
+    // 114 - Comma: ',';
     Production {
-        lhs: 23,
+        lhs: 18,
         production: &[ParseType::T(30)],
     },
+    // 115 - Continue: '\\';
+    Production {
+        lhs: 19,
+        production: &[ParseType::T(31)],
+    },
+    // 116 - TextStart: ":";
+    Production {
+        lhs: 60,
+        production: &[ParseType::T(32)],
+    },
+    // 117 - Ident: /\p{XID_Start}[\p{XID_Continue}-]*/;
+    Production {
+        lhs: 28,
+        production: &[ParseType::T(33)],
+    },
 ];
 
 static SCANNERS: Lazy<Vec<ScannerConfig>> = Lazy::new(|| {
@@ -1148,20 +1520,20 @@ static SCANNERS: Lazy<Vec<ScannerConfig>> = Lazy::new(|| {
             "INITIAL",
             Tokenizer::build(TERMINALS, SCANNER_0.0, SCANNER_0.1).unwrap(),
             &[
-                (10 /* Quote */, 1 /* Str */),
-                (11 /* TypedQuote */, 1 /* Str */),
-                (29 /* TextStart */, 2 /* Text */),
+                (11 /* Quote */, 1 /* Str */),
+                (12 /* TypedQuote */, 1 /* Str */),
+                (32 /* TextStart */, 2 /* Text */),
             ],
         ),
         ScannerConfig::new(
             "Str",
             Tokenizer::build(TERMINALS, SCANNER_1.0, SCANNER_1.1).unwrap(),
-            &[(10 /* Quote */, 0 /* INITIAL */)],
+            &[(11 /* Quote */, 0 /* INITIAL */)],
         ),
         ScannerConfig::new(
             "Text",
             Tokenizer::build(TERMINALS, SCANNER_2.0, SCANNER_2.1).unwrap(),
-            &[(17 /* Newline */, 0 /* INITIAL */)],
+            &[(18 /* Newline */, 0 /* INITIAL */)],
         ),
     ]
 });
@@ -1192,7 +1564,7 @@ where
     ParolError: From<T::Error>,
 {
     let mut llk_parser = LLKParser::new(
-        45,
+        54,
         LOOKAHEAD_AUTOMATA,
         PRODUCTIONS,
         TERMINAL_NAMES,
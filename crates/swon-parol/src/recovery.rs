@@ -0,0 +1,614 @@
+//! Error recovery by restarting the parser after syntax errors.
+//!
+//! The input is parsed once from the start. At a syntax error, the broken binding is skipped to
+//! the end of the line of the error, and a broken section header up to the next `@`. The parser
+//! restarts after the skipped region as if the rest were a new document, and the trees of the
+//! parts are joined into one tree where each skipped region is a terminal with
+//! [TerminalData::Error].
+//!
+//! A binding that is still open at the start of the line of the error, like `a = [1, 2` followed
+//! by other bindings, is skipped alone, so that the following lines are parsed as bindings.
+
+use std::path::Path;
+
+use parol_runtime::{Location, ParolError, ParserError, SyntaxError};
+use swon_tree::{
+    Cst,
+    node_kind::{NonTerminalKind, TerminalKind},
+    tree::{CstNodeData, CstNodeId, InputSpan, NonTerminalData, TerminalData},
+};
+
+use crate::{ParseError, grammar, parser, tree::CstBuilder};
+
+type OpenNode = (CstNodeId, NonTerminalKind, InputSpan);
+
+pub(crate) fn parse(input: &str, file_name: &Path) -> Result<Cst, Box<ParseError>> {
+    let mut builder = CstBuilder::new();
+    let mut parts = Vec::new();
+    let mut entries = Vec::new();
+    let mut lines = Lines::default();
+    let mut offset = 0;
+    let error = loop {
+        let mut actions = grammar::Grammar::new();
+        let result = parser::parse_into(&input[offset..], &mut builder, file_name, &mut actions);
+        let open = builder.open_nodes();
+        let (skipped, error) = match result {
+            Ok(()) => (None, None),
+            Err(ParolError::ParserError(ParserError::SyntaxErrors { entries: new }))
+                if !new.is_empty() =>
+            {
+                let mut entry = new.into_iter().next().expect("entries are not empty");
+                shift_entry(&mut entry, lines.base(input, offset));
+                let at = (entry.error_location.start as usize).min(input.len());
+                let skipped = skip(input, &open, at);
+                if skipped.open_before {
+                    // Report the error at the end of the binding rather than on the next line
+                    let base = lines.base(input, skipped.region.start as usize);
+                    if let Some(unit) = parse_unit(input, skipped.region, file_name, base) {
+                        entry = unit;
+                    }
+                }
+                entries.push(entry);
+                (Some(skipped), None)
+            }
+            Err(mut error) => {
+                // Not a syntax error, so give up and skip the rest of the input
+                shift_error(&mut error, lines.base(input, offset));
+                let parsed = open.iter().map(|(_, _, span)| span.end as usize).max();
+                let mut skipped = skip(input, &open, parsed.unwrap_or(0).max(offset));
+                skipped.region.end = input.len() as u32;
+                (Some(skipped), Some(error))
+            }
+        };
+        let next = skipped
+            .as_ref()
+            .map_or(input.len(), |skipped| skipped.region.end as usize);
+        parts.push(Part {
+            root: builder.restart(next as u32),
+            open,
+            skipped,
+        });
+        if error.is_some() || next >= input.len() {
+            break error;
+        }
+        offset = next;
+    };
+    let mut cst = builder.build_tree_with_root(parts[0].root);
+    if parts.len() > 1 || parts[0].skipped.is_some() {
+        join(&mut cst, parts);
+    }
+    match error {
+        Some(error) => Err(Box::new(ParseError { cst, error })),
+        None if entries.is_empty() => Ok(cst),
+        None => Err(Box::new(ParseError {
+            cst,
+            error: ParserError::SyntaxErrors { entries }.into(),
+        })),
+    }
+}
+
+/// The syntax error of a skipped binding parsed on its own.
+fn parse_unit(input: &str, region: InputSpan, file_name: &Path, base: Base) -> Option<SyntaxError> {
+    let mut actions = grammar::Grammar::new();
+    let mut builder = CstBuilder::new();
+    match parser::parse_into(region.as_str(input), &mut builder, file_name, &mut actions) {
+        Err(ParolError::ParserError(ParserError::SyntaxErrors { entries })) => {
+            let mut entry = entries.into_iter().next()?;
+            shift_entry(&mut entry, base);
+            Some(entry)
+        }
+        _ => None,
+    }
+}
+
+/// A part of the input parsed from a restart point up to a syntax error or the end of input.
+struct Part {
+    root: CstNodeId,
+    /// The non-terminals left open by the syntax error, from the root.
+    open: Vec<OpenNode>,
+    skipped: Option<Skipped>,
+}
+
+struct Skipped {
+    region: InputSpan,
+    /// The binding or section that is replaced by the region.
+    broken: Option<CstNodeId>,
+    /// Whether the broken binding is still open at the start of the line of the error.
+    open_before: bool,
+}
+
+enum Broken {
+    /// A binding of the document root or of a top-level section.
+    Binding(CstNodeId, u32),
+    /// A top-level section with a broken header or `{ }` body.
+    Section(CstNodeId, u32),
+    /// The error is at a token that cannot start a binding or section. `bindings` is false if
+    /// only sections can follow, like after a section with a `{ }` body.
+    None { bindings: bool },
+}
+
+fn broken(open: &[OpenNode]) -> Broken {
+    let mut section = None;
+    let mut bindings = false;
+    for &(node, kind, span) in open {
+        match kind {
+            NonTerminalKind::Root | NonTerminalKind::SectionBody => {}
+            NonTerminalKind::Swon | NonTerminalKind::SwonList => bindings = true,
+            NonTerminalKind::SwonList0 => bindings = false,
+            NonTerminalKind::Section => section = Some((node, span.start)),
+            NonTerminalKind::SectionBodyList => {
+                section = None;
+                bindings = true;
+            }
+            NonTerminalKind::Binding => return Broken::Binding(node, span.start),
+            _ => break,
+        }
+    }
+    match section {
+        Some((node, start)) => Broken::Section(node, start),
+        None => Broken::None { bindings },
+    }
+}
+
+/// What to skip for a syntax error at `at`.
+fn skip(input: &str, open: &[OpenNode], at: usize) -> Skipped {
+    let (start, end, broken, open_before) = match broken(open) {
+        Broken::Binding(node, start) => {
+            let start = skip_trivia(input, start as usize);
+            let line = line_start(input, at);
+            let open_before = line > start && input[line..at].trim().is_empty();
+            let end = if open_before {
+                line
+            } else {
+                line_end(input, at)
+            };
+            (start, end, Some(node), open_before)
+        }
+        Broken::Section(node, start) => {
+            let start = skip_trivia(input, start as usize);
+            (start, next_section(input, at), Some(node), false)
+        }
+        Broken::None { bindings: true } => (at, line_end(input, at), None, false),
+        Broken::None { bindings: false } => (at, next_section(input, at), None, false),
+    };
+    Skipped {
+        region: InputSpan {
+            start: start as u32,
+            end: (start + input[start..end].trim_end().len()) as u32,
+        },
+        broken,
+        open_before,
+    }
+}
+
+fn line_start(input: &str, offset: usize) -> usize {
+    input[..offset].rfind('\n').map_or(0, |i| i + 1)
+}
+
+fn line_end(input: &str, offset: usize) -> usize {
+    input[offset..]
+        .find('\n')
+        .map_or(input.len(), |i| offset + i)
+}
+
+/// The offset of the first token from `offset`, after whitespace and comments.
+fn skip_trivia(input: &str, mut offset: usize) -> usize {
+    loop {
+        let rest = &input[offset..];
+        offset += rest.len() - rest.trim_start().len();
+        if !input[offset..].starts_with('#') {
+            return offset;
+        }
+        offset = line_end(input, offset);
+    }
+}
+
+/// The end of the line before the next line that starts with `@`.
+fn next_section(input: &str, offset: usize) -> usize {
+    let mut end = line_end(input, offset);
+    while end < input.len() && !input[end + 1..].trim_start().starts_with('@') {
+        end = line_end(input, end + 1);
+    }
+    end
+}
+
+/// Where a part of the input starts, to move the locations of its errors into the whole input.
+#[derive(Debug, Clone, Copy)]
+struct Base {
+    offset: u32,
+    /// Line breaks before `offset`.
+    lines: u32,
+    /// Characters between the start of the line and `offset`.
+    column: u32,
+}
+
+/// Counts line breaks up to offsets that only move forward.
+#[derive(Default)]
+struct Lines {
+    offset: usize,
+    lines: u32,
+}
+
+impl Lines {
+    fn base(&mut self, input: &str, offset: usize) -> Base {
+        self.lines += input[self.offset..offset].matches('\n').count() as u32;
+        self.offset = offset;
+        Base {
+            offset: offset as u32,
+            lines: self.lines,
+            column: input[line_start(input, offset)..offset].chars().count() as u32,
+        }
+    }
+}
+
+fn shift_location(location: &mut Location, base: Base) {
+    location.start += base.offset;
+    location.end += base.offset;
+    if location.start_line == 1 {
+        location.start_column += base.column;
+    }
+    if location.end_line == 1 {
+        location.end_column += base.column;
+    }
+    location.start_line += base.lines;
+    location.end_line += base.lines;
+}
+
+fn shift_entry(entry: &mut SyntaxError, base: Base) {
+    shift_location(&mut entry.error_location, base);
+    for token in &mut entry.unexpected_tokens {
+        shift_location(&mut token.token, base);
+    }
+}
+
+fn shift_error(error: &mut ParolError, base: Base) {
+    match error {
+        ParolError::ParserError(ParserError::SyntaxErrors { entries }) => {
+            for entry in entries {
+                shift_entry(entry, base);
+            }
+        }
+        ParolError::ParserError(ParserError::UnprocessedInput { last_token, .. }) => {
+            shift_location(last_token, base)
+        }
+        _ => {}
+    }
+}
+
+/// Join the trees of `parts` into the tree of the first part.
+fn join(cst: &mut Cst, parts: Vec<Part>) {
+    let root = cst.root();
+    let swon = child(cst, root, NonTerminalKind::Swon).expect("the root has Swon");
+    // Trivia and skipped regions that go before the content of the next part
+    let mut pending = Vec::new();
+    let mut trailing = Vec::new();
+    let mut tails = None;
+    for part in parts {
+        let skipped = part
+            .skipped
+            .as_ref()
+            .map(|skipped| cut(cst, &part, skipped))
+            .unwrap_or_default();
+        let Some((bindings_tail, sections_tail)) = tails else {
+            tails = Some(self::tails(cst, swon));
+            pending = skipped;
+            continue;
+        };
+        let part_swon = child(cst, part.root, NonTerminalKind::Swon).expect("the root has Swon");
+        if !pending.is_empty()
+            && let Some((parent, index)) = first_terminal(cst, part_swon)
+        {
+            let mut children = cst.children(parent).collect::<Vec<_>>();
+            children.splice(index..index, pending.drain(..));
+            cst.update_children(parent, children);
+        }
+        let bindings = child(cst, part_swon, NonTerminalKind::SwonList)
+            .filter(|&list| child(cst, list, NonTerminalKind::Binding).is_some());
+        let sections = child(cst, part_swon, NonTerminalKind::SwonList0)
+            .filter(|&list| child(cst, list, NonTerminalKind::Section).is_some());
+        trailing.extend(cst.children(part.root).filter(|&node| node != part_swon));
+        cst.update_children(part.root, []);
+        cst.update_children(part_swon, []);
+
+        let mut new_tails = (bindings_tail, sections_tail);
+        if let Some(bindings) = bindings {
+            let tail = bindings_tail.expect("bindings only follow a region where they are allowed");
+            let kind = non_terminal_kind(cst, tail);
+            if kind == NonTerminalKind::SectionBodyList {
+                retag(cst, bindings, NonTerminalKind::SwonList, kind);
+            }
+            replace(cst, tail, bindings);
+            new_tails.0 = Some(chain(cst, bindings, kind, NonTerminalKind::Binding).0);
+        }
+        if let Some(sections) = sections {
+            replace(cst, sections_tail, sections);
+            let (tail, last) = chain(
+                cst,
+                sections,
+                NonTerminalKind::SwonList0,
+                NonTerminalKind::Section,
+            );
+            new_tails = (last.and_then(|section| section_tail(cst, section)), tail);
+        }
+        tails = Some(new_tails);
+        pending.extend(skipped);
+    }
+
+    let mut others = cst
+        .children(root)
+        .filter(|&node| node != swon)
+        .chain(trailing)
+        .chain(pending)
+        .collect::<Vec<_>>();
+    others.sort_by_key(|&node| span(cst, node).map_or(u32::MAX, |span| span.start));
+    cst.update_children(root, std::iter::once(swon).chain(others));
+    update_spans(cst, root);
+}
+
+/// Remove the broken binding or section and everything after the start of the skipped region
+/// from the tree of `part`, and close the lists left open. Returns the nodes moved out of the
+/// tree, ending with the error terminal of the region.
+fn cut(cst: &mut Cst, part: &Part, skipped: &Skipped) -> Vec<CstNodeId> {
+    let mut moved = Vec::new();
+    if let Some(broken) = skipped.broken {
+        // Keep the trivia before the broken binding
+        leading_trivia(cst, broken, skipped.region.start, &mut moved);
+        for node in &moved {
+            detach(cst, *node);
+        }
+        detach(cst, broken);
+    }
+    truncate(cst, part.root, skipped.region.start);
+    for &(node, kind, _) in part
+        .open
+        .iter()
+        .take_while(|(node, ..)| Some(*node) != skipped.broken)
+    {
+        close(cst, node, kind, &mut moved);
+    }
+    moved.sort_by_key(|&node| span(cst, node).map_or(u32::MAX, |span| span.start));
+    moved.push(cst.add_node(CstNodeData::Terminal {
+        kind: TerminalKind::Whitespace,
+        data: TerminalData::Error(skipped.region),
+    }));
+    moved
+}
+
+fn leading_trivia(cst: &Cst, node: CstNodeId, end: u32, out: &mut Vec<CstNodeId>) {
+    for child in cst.children(node) {
+        match cst.node_data(child) {
+            Some(CstNodeData::Terminal {
+                data: TerminalData::Input(span),
+                ..
+            }) if span.end <= end => out.push(child),
+            Some(CstNodeData::NonTerminal { .. }) => leading_trivia(cst, child, end, out),
+            _ => {}
+        }
+    }
+}
+
+/// Remove the terminals from `at` under `node`.
+fn truncate(cst: &mut Cst, node: CstNodeId, at: u32) {
+    let children = cst.children(node).collect::<Vec<_>>();
+    let mut kept = Vec::with_capacity(children.len());
+    for &child in &children {
+        match cst.node_data(child) {
+            Some(CstNodeData::Terminal {
+                data: TerminalData::Input(span),
+                ..
+            }) if span.start >= at => {}
+            Some(CstNodeData::NonTerminal { .. }) => {
+                truncate(cst, child, at);
+                kept.push(child);
+            }
+            _ => kept.push(child),
+        }
+    }
+    if kept.len() != children.len() {
+        cst.update_children(node, kept);
+    }
+}
+
+/// Complete a non-terminal left open by a syntax error, so that views can be built from it.
+/// Trivia in lists without elements is moved to `moved`.
+fn close(cst: &mut Cst, node: CstNodeId, kind: NonTerminalKind, moved: &mut Vec<CstNodeId>) {
+    let mut children = cst.children(node).collect::<Vec<_>>();
+    let has = |cst: &Cst, children: &[CstNodeId], kind| {
+        children.iter().any(|&child| is(cst, child, kind))
+    };
+    let before = children.len();
+    match kind {
+        NonTerminalKind::Root => {
+            let swon = children
+                .iter()
+                .position(|&child| is(cst, child, NonTerminalKind::Swon))
+                .map_or(0, |i| i + 1);
+            moved.extend(children.drain(swon..));
+        }
+        NonTerminalKind::Swon => {
+            for list in [NonTerminalKind::SwonList, NonTerminalKind::SwonList0] {
+                if !has(cst, &children, list) {
+                    children.push(empty(cst, list));
+                }
+            }
+        }
+        NonTerminalKind::SwonList
+        | NonTerminalKind::SwonList0
+        | NonTerminalKind::SectionBodyList => {
+            let element = match kind {
+                NonTerminalKind::SwonList0 => NonTerminalKind::Section,
+                _ => NonTerminalKind::Binding,
+            };
+            if !has(cst, &children, element) {
+                moved.append(&mut children);
+            } else if !has(cst, &children, kind) {
+                children.push(empty(cst, kind));
+            }
+        }
+        NonTerminalKind::SectionBody if children.is_empty() => {
+            children.push(empty(cst, NonTerminalKind::SectionBodyList));
+        }
+        _ => {}
+    }
+    if children.len() != before {
+        cst.update_children(node, children);
+    }
+}
+
+fn empty(cst: &mut Cst, kind: NonTerminalKind) -> CstNodeId {
+    cst.add_node(CstNodeData::NonTerminal {
+        kind,
+        data: NonTerminalData::Input(InputSpan::EMPTY),
+    })
+}
+
+/// The empty lists where the bindings and the sections after `swon` go.
+fn tails(cst: &Cst, swon: CstNodeId) -> (Option<CstNodeId>, CstNodeId) {
+    let bindings = child(cst, swon, NonTerminalKind::SwonList).expect("Swon has SwonList");
+    let sections = child(cst, swon, NonTerminalKind::SwonList0).expect("Swon has SwonList0");
+    let (bindings, _) = chain(
+        cst,
+        bindings,
+        NonTerminalKind::SwonList,
+        NonTerminalKind::Binding,
+    );
+    let (sections, last) = chain(
+        cst,
+        sections,
+        NonTerminalKind::SwonList0,
+        NonTerminalKind::Section,
+    );
+    match last {
+        Some(section) => (section_tail(cst, section), sections),
+        None => (Some(bindings), sections),
+    }
+}
+
+/// The empty list at the end of the bindings of `section`, or `None` if it has a `{ }` body.
+fn section_tail(cst: &Cst, section: CstNodeId) -> Option<CstNodeId> {
+    let body = child(cst, section, NonTerminalKind::SectionBody)?;
+    let list = child(cst, body, NonTerminalKind::SectionBodyList)?;
+    Some(
+        chain(
+            cst,
+            list,
+            NonTerminalKind::SectionBodyList,
+            NonTerminalKind::Binding,
+        )
+        .0,
+    )
+}
+
+/// The empty list at the end of the list `list`, and the last element.
+fn chain(
+    cst: &Cst,
+    mut list: CstNodeId,
+    kind: NonTerminalKind,
+    element: NonTerminalKind,
+) -> (CstNodeId, Option<CstNodeId>) {
+    let mut last = None;
+    while let Some(next) = child(cst, list, kind) {
+        last = child(cst, list, element).or(last);
+        list = next;
+    }
+    (list, last)
+}
+
+/// Change the kind of the nodes of the list `list`, to move bindings into a section.
+fn retag(cst: &mut Cst, list: CstNodeId, from: NonTerminalKind, to: NonTerminalKind) {
+    let mut list = Some(list);
+    while let Some(node) = list {
+        list = child(cst, node, from);
+        if let Some(CstNodeData::NonTerminal { data, .. }) = cst.node_data(node) {
+            cst.update_node(node, CstNodeData::NonTerminal { kind: to, data });
+        }
+    }
+}
+
+fn replace(cst: &mut Cst, old: CstNodeId, new: CstNodeId) {
+    let parent = cst.parent(old).expect("a list has a parent");
+    let children = cst
+        .children(parent)
+        .map(|node| if node == old { new } else { node })
+        .collect::<Vec<_>>();
+    cst.update_children(parent, children);
+}
+
+fn detach(cst: &mut Cst, node: CstNodeId) {
+    if let Some(parent) = cst.parent(node) {
+        let children = cst
+            .children(parent)
+            .filter(|&child| child != node)
+            .collect::<Vec<_>>();
+        cst.update_children(parent, children);
+    }
+}
+
+/// The parent and index of the first terminal under `node`.
+fn first_terminal(cst: &Cst, node: CstNodeId) -> Option<(CstNodeId, usize)> {
+    for (index, child) in cst.children(node).enumerate() {
+        match cst.node_data(child)? {
+            CstNodeData::Terminal { .. } => return Some((node, index)),
+            CstNodeData::NonTerminal { .. } => {
+                if let Some(found) = first_terminal(cst, child) {
+                    return Some(found);
+                }
+            }
+        }
+    }
+    None
+}
+
+fn child(cst: &Cst, node: CstNodeId, kind: NonTerminalKind) -> Option<CstNodeId> {
+    cst.children(node).find(|&child| is(cst, child, kind))
+}
+
+fn is(cst: &Cst, node: CstNodeId, kind: NonTerminalKind) -> bool {
+    matches!(cst.node_data(node), Some(CstNodeData::NonTerminal { kind: k, .. }) if k == kind)
+}
+
+fn non_terminal_kind(cst: &Cst, node: CstNodeId) -> NonTerminalKind {
+    match cst.node_data(node) {
+        Some(CstNodeData::NonTerminal { kind, .. }) => kind,
+        _ => panic!("the node must be a non-terminal"),
+    }
+}
+
+fn span(cst: &Cst, node: CstNodeId) -> Option<InputSpan> {
+    match cst.node_data(node)? {
+        CstNodeData::Terminal {
+            data: TerminalData::Input(span) | TerminalData::Error(span),
+            ..
+        }
+        | CstNodeData::NonTerminal {
+            data: NonTerminalData::Input(span),
+            ..
+        } => Some(span),
+        _ => None,
+    }
+}
+
+/// Recompute the spans of the non-terminals under `node` from their terminals.
+fn update_spans(cst: &mut Cst, node: CstNodeId) -> Option<InputSpan> {
+    match cst.node_data(node)? {
+        CstNodeData::NonTerminal {
+            kind,
+            data: NonTerminalData::Input(_),
+        } => {
+            let children = cst.children(node).collect::<Vec<_>>();
+            let span = children
+                .into_iter()
+                .filter_map(|child| update_spans(cst, child))
+                .fold(InputSpan::EMPTY, InputSpan::merge);
+            cst.update_node(
+                node,
+                CstNodeData::NonTerminal {
+                    kind,
+                    data: NonTerminalData::Input(span),
+                },
+            );
+            Some(span)
+        }
+        _ => span(cst, node),
+    }
+}
@@ -13,6 +13,8 @@ pub struct CstBuilder {
     tree: DiGraph<Option<CstNodeData<TerminalKind, NonTerminalKind>>, ()>,
     node_stack: Vec<NodeStackItem>,
     root_node: Option<CstNodeId>,
+    /// Added to the offsets of the tokens, for input that starts in the middle of a document.
+    offset: u32,
 }
 
 #[derive(Debug, Clone)]
@@ -34,9 +36,43 @@ impl CstBuilder {
             tree: DiGraph::new(),
             node_stack: Vec::new(),
             root_node: None,
+            offset: 0,
         }
     }
 
+    /// A builder for input that starts at `offset` of a document.
+    pub fn with_offset(offset: u32) -> Self {
+        Self {
+            offset,
+            ..Self::new()
+        }
+    }
+
+    /// The non-terminals that are not closed yet, from the root.
+    pub(crate) fn open_nodes(&self) -> Vec<(CstNodeId, NonTerminalKind, InputSpan)> {
+        self.node_stack
+            .iter()
+            .map(|item| {
+                let Some(Some(CstNodeData::NonTerminal { kind, .. })) =
+                    self.tree.node_weight(item.node.0)
+                else {
+                    panic!("this node must be created as NonTerminal");
+                };
+                (item.node, *kind, item.span)
+            })
+            .collect()
+    }
+
+    /// Close the current tree and return its root, so that the input from `offset` can be built
+    /// into the same arena.
+    pub(crate) fn restart(&mut self, offset: u32) -> CstNodeId {
+        while !self.node_stack.is_empty() {
+            self.close_non_terminal_node();
+        }
+        self.offset = offset;
+        self.root_node.take().expect("Root node always provided")
+    }
+
     // Adds a terminal node to the current non-terminal in the stack
     fn add_terminal_node(&mut self, kind: TerminalKind, span: InputSpan) -> NodeIndex {
         let node = self.tree.add_node(Some(CstNodeData::Terminal {
@@ -102,13 +138,13 @@ impl CstBuilder {
 
     /// Builds the tree
     pub fn build_tree(mut self) -> Cst {
-        while !self.node_stack.is_empty() {
-            self.close_non_terminal_node();
-        }
-        ConcreteSyntaxTree::new(
-            self.root_node.expect("Root node always provided"),
-            self.tree,
-        )
+        let root = self.restart(0);
+        self.build_tree_with_root(root)
+    }
+
+    /// Builds the tree of `root`, one of the roots returned by [Self::restart].
+    pub(crate) fn build_tree_with_root(self, root: CstNodeId) -> Cst {
+        ConcreteSyntaxTree::new(root, self.tree)
     }
 }
 
@@ -134,8 +170,8 @@ impl<'t> TreeConstruct<'t> for CstBuilder {
     fn add_token(&mut self, token: &Token<'t>) -> Result<(), Self::Error> {
         let kind = TerminalKind::from_terminal_index(token.token_type);
         let span = InputSpan {
-            start: token.location.start,
-            end: token.location.end,
+            start: self.offset + token.location.start,
+            end: self.offset + token.location.end,
         };
         self.add_terminal_node(kind, span);
         Ok(())
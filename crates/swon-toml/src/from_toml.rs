@@ -0,0 +1,316 @@
+use swon_fmt::FmtError;
+use swon_tree::notation::{escape, is_text, key_notation};
+use thiserror::Error;
+use toml_edit::{Decor, DocumentMut, InlineTable, Item, Key, RawString, Table, TomlError, Value};
+
+use crate::DATETIME;
+
+#[derive(Debug, Error)]
+pub enum FromTomlError {
+    #[error(transparent)]
+    TomlError(#[from] TomlError),
+    #[error(transparent)]
+    FmtError(#[from] FmtError),
+    /// The converted text does not parse, which is a bug of the conversion.
+    #[error("The converted document is not valid SWON: {0}")]
+    InvalidSwon(Box<swon_parol::ParseError>),
+}
+
+/// Parse a TOML document and convert it to SWON. See [from_toml].
+pub fn from_toml_str(input: &str) -> Result<String, FromTomlError> {
+    from_toml(&input.parse()?)
+}
+
+/// Convert a TOML document to a formatted SWON document.
+///
+/// | TOML | SWON |
+/// |------|------|
+/// | table `[a.b]` | section `@ a.b`, unless the table is only created by its subtables |
+/// | array of tables `[[a]]` | section `@ a[]`, and `@ a[0].b` for its subtables |
+/// | dotted key `a.b = 1` | the same |
+/// | string | text binding `key: text` if it is one line without surrounding whitespace, else a string |
+/// | integer, float, boolean, array, inline table | the same |
+/// | datetime | typed string `datetime"1979-05-27T07:32:00Z"` |
+///
+/// Comments on the lines before a key or a table header, and at the end of their line, are kept.
+/// Comments inside arrays and inline tables are dropped.
+pub fn from_toml(document: &DocumentMut) -> Result<String, FromTomlError> {
+    let mut writer = Writer::default();
+    writer.bindings(document.as_table(), &[]);
+    writer.sections(document.as_table(), &[]);
+    writer.comments(Some(document.trailing()));
+
+    let input = writer.output;
+    let mut cst = swon_parol::parse(&input, "toml.swon").map_err(FromTomlError::InvalidSwon)?;
+    swon_fmt::fmt(&input, &mut cst)?;
+    let mut output = String::new();
+    cst.write(&input, &mut output)
+        .expect("writing to a string never fails");
+    Ok(output)
+}
+
+/// A key of a section, with the index of the element if it is in an array of tables.
+type Segment<'a> = (&'a str, Option<usize>);
+
+/// Writes SWON text, leaving the layout to the formatter.
+#[derive(Default)]
+struct Writer {
+    output: String,
+}
+
+impl Writer {
+    /// Write the key-values of `table`, and the ones in its dotted tables after `prefix`.
+    fn bindings(&mut self, table: &Table, prefix: &[&str]) {
+        for (key, item) in table.iter() {
+            let decor = table.key(key).map(Key::leaf_decor);
+            self.comments(decor.and_then(Decor::prefix));
+            let keys = [prefix, &[key]].concat();
+            match item {
+                Item::Value(value) => self.binding(&keys, value),
+                Item::Table(table) if table.is_dotted() => self.bindings(table, &keys),
+                _ => {}
+            }
+        }
+    }
+
+    fn binding(&mut self, keys: &[&str], value: &Value) {
+        let keys = keys.iter().map(|key| key_notation(key)).collect::<Vec<_>>();
+        self.output.push_str(&keys.join("."));
+        let comment = trailing_comment(value.decor());
+        match value {
+            Value::String(text) if comment.is_none() && is_text(text.value()) => {
+                self.output.push_str(": ");
+                self.output.push_str(text.value());
+            }
+            value => {
+                self.output.push_str(" = ");
+                self.value(value);
+            }
+        }
+        if let Some(comment) = comment {
+            self.output.push(' ');
+            self.output.push_str(comment);
+        }
+        self.output.push('\n');
+    }
+
+    /// Write the tables and arrays of tables in `table` at `path` as sections.
+    fn sections(&mut self, table: &Table, path: &[Segment]) {
+        for (key, item) in table.iter() {
+            let mut path = path.to_vec();
+            path.push((key, None));
+            match item {
+                Item::Table(table) if !table.is_dotted() => {
+                    if !table.is_implicit() || has_values(table) {
+                        self.header(table.decor(), &path, false);
+                        self.bindings(table, &[]);
+                    }
+                    self.sections(table, &path);
+                }
+                Item::ArrayOfTables(array) => {
+                    for (index, table) in array.iter().enumerate() {
+                        self.header(table.decor(), &path, true);
+                        self.bindings(table, &[]);
+                        let last = path.len() - 1;
+                        path[last].1 = Some(index);
+                        self.sections(table, &path);
+                        path[last].1 = None;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn header(&mut self, decor: &Decor, path: &[Segment], push: bool) {
+        self.comments(decor.prefix());
+        self.output.push_str("@ ");
+        let keys = path
+            .iter()
+            .map(|(key, index)| match index {
+                Some(index) => format!("{}[{index}]", key_notation(key)),
+                None => key_notation(key),
+            })
+            .collect::<Vec<_>>();
+        self.output.push_str(&keys.join("."));
+        if push {
+            self.output.push_str("[]");
+        }
+        if let Some(comment) = trailing_comment(decor) {
+            self.output.push(' ');
+            self.output.push_str(comment);
+        }
+        self.output.push('\n');
+    }
+
+    /// Write the comment lines of `raw`, keeping a blank line before them.
+    fn comments(&mut self, raw: Option<&RawString>) {
+        let Some(raw) = raw.and_then(RawString::as_str) else {
+            return;
+        };
+        let mut lines = raw.split('\n').collect::<Vec<_>>();
+        // The last line is the indentation of the next token, unless it ends the document
+        if lines.last().is_some_and(|last| last.trim().is_empty()) {
+            lines.pop();
+        }
+        let mut blank = false;
+        for line in lines {
+            let line = line.trim();
+            if line.is_empty() {
+                blank = true;
+            } else if line.starts_with('#') {
+                if std::mem::take(&mut blank) {
+                    self.output.push('\n');
+                }
+                self.output.push_str(line);
+                self.output.push('\n');
+            }
+        }
+        if blank {
+            self.output.push('\n');
+        }
+    }
+
+    fn value(&mut self, value: &Value) {
+        match value {
+            Value::String(text) => self.str(text.value()),
+            Value::Integer(integer) => self.output.push_str(&integer.value().to_string()),
+            Value::Float(float) => {
+                let float = *float.value();
+                let text = if float.is_nan() {
+                    "nan".to_string()
+                } else if float.is_infinite() {
+                    if float > 0.0 { "inf" } else { "-inf" }.to_string()
+                } else {
+                    // `{:?}` keeps a fractional part or an exponent, so `1.0` stays a float
+                    format!("{float:?}")
+                };
+                self.output.push_str(&text);
+            }
+            Value::Boolean(boolean) => self.output.push_str(&boolean.value().to_string()),
+            Value::Datetime(datetime) => {
+                self.output.push_str(DATETIME);
+                self.str(&datetime.value().to_string());
+            }
+            Value::Array(array) => {
+                self.output.push('[');
+                for (index, element) in array.iter().enumerate() {
+                    if index > 0 {
+                        self.output.push_str(", ");
+                    }
+                    self.value(element);
+                }
+                self.output.push(']');
+            }
+            Value::InlineTable(table) => self.inline_table(table),
+        }
+    }
+
+    fn inline_table(&mut self, table: &InlineTable) {
+        self.output.push('{');
+        for (index, (key, value)) in table.iter().enumerate() {
+            if index > 0 {
+                self.output.push(',');
+            }
+            self.output.push(' ');
+            self.output.push_str(&key_notation(key));
+            self.output.push_str(" = ");
+            self.value(value);
+        }
+        if !table.is_empty() {
+            self.output.push(' ');
+        }
+        self.output.push('}');
+    }
+
+    fn str(&mut self, text: &str) {
+        self.output.push('"');
+        self.output.push_str(&escape(text));
+        self.output.push('"');
+    }
+}
+
+/// Whether `table` has key-values of its own, so it needs a section.
+fn has_values(table: &Table) -> bool {
+    table.iter().any(|(_, item)| match item {
+        Item::Value(_) => true,
+        Item::Table(table) => table.is_dotted(),
+        _ => false,
+    })
+}
+
+/// The comment at the end of the line of a value or a table header.
+fn trailing_comment(decor: &Decor) -> Option<&str> {
+    let suffix = decor.suffix()?.as_str()?;
+    let start = suffix.find('#')?;
+    Some(suffix[start..].trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_toml() {
+        let input = r#"# The package
+[package]
+name = "swon" # the name
+version = "0.1.0"
+description = " padded "
+keywords = ["a", "b"]
+
+# Dependencies
+
+[dependencies]
+serde = { version = "1", features = ["derive"] }
+a.b = 1
+"not ident" = 1.5
+true = inf
+
+[target.'cfg(unix)'.dependencies]
+libc = "0.2"
+
+[[bin]] # first
+name = "swon"
+
+[bin.meta]
+released = 1979-05-27T07:32:00Z
+
+[[bin]]
+name = "other"
+empty = {}
+"#;
+        assert_eq!(
+            from_toml_str(input).unwrap(),
+            r#"# The package
+@ package
+name = "swon" # the name
+version: 0.1.0
+description = " padded "
+keywords = ["a", "b"]
+
+# Dependencies
+
+@ dependencies
+serde = { version = "1", features = ["derive"] }
+a.b = 1
+"not ident" = 1.5
+"true" = inf
+
+@ target."cfg(unix)".dependencies
+libc: 0.2
+
+@ bin[] # first
+name: swon
+
+@ bin[0].meta
+released = datetime"1979-05-27T07:32:00Z"
+
+@ bin[]
+name: other
+empty = {}
+"#
+        );
+        assert_eq!(from_toml_str("a = 1\n# end").unwrap(), "a = 1\n# end\n");
+    }
+}
@@ -1,14 +1,10 @@
-pub fn add(left: usize, right: usize) -> usize {
-    left + right
-}
+//! Conversion between SWON and TOML.
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+mod from_toml;
+mod to_toml;
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
+pub use from_toml::{FromTomlError, from_toml, from_toml_str};
+pub use to_toml::{Loss, LossKind, ToTomlError, ToTomlErrorKind, Toml, to_toml};
+
+/// The type name of typed strings that hold TOML datetimes, like `datetime"1979-05-27"`.
+pub const DATETIME: &str = "datetime";
@@ -0,0 +1,613 @@
+use std::convert::Infallible;
+
+use indexmap::map::Slice;
+use swon_tree::{prelude::*, spanned::*, tree::InputSpan};
+use swon_value::{
+    identifier::Identifier,
+    value::{KeyCmpValue, Path, PathSegment, Value, pop_element, push_element},
+};
+use thiserror::Error;
+use toml_edit::{
+    Array, ArrayOfTables, Datetime, DocumentMut, InlineTable, Item, Table, Value as TomlValue,
+};
+
+use crate::DATETIME;
+
+/// A converted document with the information that TOML could not hold.
+#[derive(Debug, Clone)]
+pub struct Toml {
+    pub document: DocumentMut,
+    /// In the order the values are converted
+    pub losses: Vec<Loss>,
+}
+
+/// Information dropped while converting the value at `path`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Loss {
+    pub path: Path,
+    pub kind: LossKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LossKind {
+    #[error("Type name `{0}` of a typed string is dropped")]
+    TypeName(String),
+    #[error("Language `{0}` of code is dropped")]
+    Language(String),
+    #[error("Tuple is converted to an array")]
+    Tuple,
+    #[error("Tag `{0}` of a variant is dropped")]
+    VariantTag(String),
+    #[error("Key {0} is converted to a string")]
+    NonStringKey(String),
+    #[error("Extension `${0}` is dropped")]
+    Extension(Identifier),
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+#[error("{kind}")]
+pub struct ToTomlError {
+    /// The value that can't be converted
+    pub path: Path,
+    pub kind: ToTomlErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ToTomlErrorKind {
+    #[error("TOML has no null")]
+    Null,
+    #[error("TOML has no unit")]
+    Unit,
+    #[error("TOML has no paths")]
+    Path,
+    #[error("Integer {0} is out of the range of TOML integers")]
+    IntegerOutOfRange(u64),
+    #[error("Invalid datetime {0:?}")]
+    InvalidDatetime(String),
+    #[error("Key {0} can't be converted to a TOML key")]
+    UnsupportedKey(String),
+    #[error("Key {0:?} appears twice after converting keys to strings")]
+    DuplicateKey(String),
+}
+
+/// Convert a document to TOML. `document` must be the values of `cst`, from
+/// [Cst::spanned_values].
+///
+/// | SWON | TOML |
+/// |------|------|
+/// | section `@ a.b`, block `a { ... }` or a map only created by keys | table `[a.b]` |
+/// | sections `@ a[]` | array of tables `[[a]]` |
+/// | inline object | inline table |
+/// | boolean, integer, float, string, array | the same |
+/// | typed string `datetime"..."` | datetime |
+/// | other typed strings and code | string, dropping the type name or the language |
+/// | tuple | array |
+/// | variant | table, dropping the tag |
+/// | integer or boolean key | its notation as a string |
+/// | extension | dropped |
+///
+/// null, unit and paths have no TOML representation. Every conversion that drops information is
+/// reported in [Toml::losses].
+///
+/// Comments on the lines just before a key and at the end of its line are kept on the TOML key or
+/// table header. Comments inside values are dropped.
+pub fn to_toml(input: &str, cst: &Cst, document: &SpannedDocument) -> Result<Toml, ToTomlError> {
+    let mut comments = Comments {
+        input,
+        cst,
+        comments: vec![],
+        used: vec![],
+    };
+    let Ok(_) = comments.visit_root_handle(cst.root_handle(), cst);
+    comments.used = vec![false; comments.comments.len()];
+    let mut converter = Converter {
+        cst,
+        comments,
+        path: vec![],
+        losses: vec![],
+    };
+    let mut document_mut = DocumentMut::new();
+    converter.table(&document.root, document_mut.as_table_mut())?;
+    if !document_mut.iter().any(|(_, item)| item.is_value()) {
+        trim_first_header(document_mut.as_table_mut());
+    }
+    Ok(Toml {
+        document: document_mut,
+        losses: converter.losses,
+    })
+}
+
+struct Converter<'a> {
+    cst: &'a Cst,
+    comments: Comments<'a>,
+    path: Vec<PathSegment>,
+    losses: Vec<Loss>,
+}
+
+impl Converter<'_> {
+    /// Fill `table` with the entries of the map `value`.
+    fn table(&mut self, value: &SpannedValue, table: &mut Table) -> Result<(), ToTomlError> {
+        for (key, entry) in self.entries(value) {
+            self.path.push(PathSegment::Value(key.clone().into()));
+            let name = self.key(key)?;
+            if table.contains_key(&name) {
+                return Err(self.error(ToTomlErrorKind::DuplicateKey(name)));
+            }
+            let item = self.item(entry)?;
+            let is_value = item.is_value();
+            table.insert(&name, item);
+            if is_value {
+                let leading = self.comments.leading(entry.key);
+                let trailing = self.comments.trailing(entry.key);
+                if let (Some(mut key), false) = (table.key_mut(&name), leading.is_empty()) {
+                    key.leaf_decor_mut().set_prefix(leading);
+                }
+                if let (Some(value), Some(trailing)) =
+                    (table[name.as_str()].as_value_mut(), trailing)
+                {
+                    value.decor_mut().set_suffix(format!(" {trailing}"));
+                }
+            }
+            self.path.pop();
+        }
+        Ok(())
+    }
+
+    /// Attach the comments around the key of `value` to the header of `table`.
+    fn header_comments(&mut self, value: &SpannedValue, table: &mut Table) {
+        let leading = self.comments.leading(value.key);
+        if !leading.is_empty() {
+            // Keep the blank line before the header
+            table.decor_mut().set_prefix(format!("\n{leading}"));
+        }
+        if let Some(trailing) = self.comments.trailing(value.key) {
+            table.decor_mut().set_suffix(format!(" {trailing}"));
+        }
+    }
+
+    /// The entries of a map or a variant, reporting the tag and the extensions that are dropped.
+    fn entries<'v>(&mut self, value: &'v SpannedValue) -> &'v Slice<KeyCmpValue, SpannedValue> {
+        self.extensions(value);
+        match &value.content {
+            SpannedContent::Map(entries) => entries.as_slice(),
+            SpannedContent::Variant(variant) => {
+                self.loss(LossKind::VariantTag(variant.tag.to_string()));
+                self.extensions(&variant.content);
+                match &variant.content.content {
+                    SpannedContent::Map(entries) => entries.as_slice(),
+                    _ => Slice::new(),
+                }
+            }
+            _ => Slice::new(),
+        }
+    }
+
+    fn item(&mut self, value: &SpannedValue) -> Result<Item, ToTomlError> {
+        match &value.content {
+            SpannedContent::Map(_) | SpannedContent::Variant(_) if !self.is_inline(value) => {
+                let mut table = Table::new();
+                if self.is_dotted(value) {
+                    table.set_dotted(true);
+                } else {
+                    // Only created by the keys of other tables
+                    table.set_implicit(value.value.is_none());
+                }
+                if value.value.is_some() {
+                    self.header_comments(value, &mut table);
+                }
+                self.table(value, &mut table)?;
+                Ok(Item::Table(table))
+            }
+            SpannedContent::Array(elements)
+                if !elements.is_empty()
+                    && !self.is_inline(value)
+                    && elements.iter().all(|element| self.is_table(element)) =>
+            {
+                self.extensions(value);
+                let mut array = ArrayOfTables::new();
+                for (index, element) in elements.iter().enumerate() {
+                    push_element(&mut self.path, index);
+                    let mut table = Table::new();
+                    self.header_comments(element, &mut table);
+                    self.table(element, &mut table)?;
+                    array.push(table);
+                    pop_element(&mut self.path);
+                }
+                Ok(Item::ArrayOfTables(array))
+            }
+            _ => Ok(Item::Value(self.value(value)?)),
+        }
+    }
+
+    /// Whether the map `value` is only created by the dotted keys of bindings, like `a` of
+    /// `a.b = 1`, rather than by a section or a block.
+    fn is_dotted(&self, value: &SpannedValue) -> bool {
+        let (None, Some(key)) = (value.value, value.key) else {
+            return false;
+        };
+        let mut node = key.node;
+        while let Some(parent) = self.cst.parent(node) {
+            match self.cst.node_data(parent) {
+                Some(CstNode::NonTerminal {
+                    kind: NonTerminalKind::Binding,
+                    ..
+                }) => return true,
+                Some(CstNode::NonTerminal {
+                    kind: NonTerminalKind::Section,
+                    ..
+                }) => return false,
+                _ => node = parent,
+            }
+        }
+        false
+    }
+
+    fn value(&mut self, value: &SpannedValue) -> Result<TomlValue, ToTomlError> {
+        let value = match &value.content {
+            SpannedContent::Map(_) | SpannedContent::Variant(_) => {
+                let mut table = InlineTable::new();
+                for (key, entry) in self.entries(value) {
+                    self.path.push(PathSegment::Value(key.clone().into()));
+                    let name = self.key(key)?;
+                    if table.contains_key(&name) {
+                        return Err(self.error(ToTomlErrorKind::DuplicateKey(name)));
+                    }
+                    let value = self.value(entry)?;
+                    table.insert(&name, value);
+                    self.path.pop();
+                }
+                TomlValue::InlineTable(table)
+            }
+            SpannedContent::Array(elements) => {
+                self.extensions(value);
+                let mut array = Array::new();
+                for (index, element) in elements.iter().enumerate() {
+                    push_element(&mut self.path, index);
+                    array.push(self.value(element)?);
+                    pop_element(&mut self.path);
+                }
+                TomlValue::Array(array)
+            }
+            SpannedContent::Tuple(elements) => {
+                self.extensions(value);
+                self.loss(LossKind::Tuple);
+                let mut array = Array::new();
+                for (index, element) in elements.iter().enumerate() {
+                    self.path.push(PathSegment::TupleIndex(index as u8));
+                    array.push(self.value(element)?);
+                    self.path.pop();
+                }
+                TomlValue::Array(array)
+            }
+            SpannedContent::Value(scalar) => {
+                self.extensions(value);
+                self.scalar(scalar)?
+            }
+        };
+        Ok(value)
+    }
+
+    fn scalar(&mut self, value: &Value) -> Result<TomlValue, ToTomlError> {
+        let value = match value {
+            Value::Null => return Err(self.error(ToTomlErrorKind::Null)),
+            Value::Unit => return Err(self.error(ToTomlErrorKind::Unit)),
+            Value::Path(_) => return Err(self.error(ToTomlErrorKind::Path)),
+            Value::Bool(value) => TomlValue::from(*value),
+            Value::I64(value) => TomlValue::from(*value),
+            Value::U64(value) => match i64::try_from(*value) {
+                Ok(value) => TomlValue::from(value),
+                Err(_) => return Err(self.error(ToTomlErrorKind::IntegerOutOfRange(*value))),
+            },
+            Value::F32(value) => TomlValue::from(*value as f64),
+            Value::F64(value) => TomlValue::from(*value),
+            Value::String(value) => TomlValue::from(value.as_str()),
+            Value::TypedString(typed) if typed.type_name == DATETIME => {
+                match typed.value.parse::<Datetime>() {
+                    Ok(datetime) => TomlValue::from(datetime),
+                    Err(_) => {
+                        let kind = ToTomlErrorKind::InvalidDatetime(typed.value.clone());
+                        return Err(self.error(kind));
+                    }
+                }
+            }
+            Value::TypedString(typed) => {
+                self.loss(LossKind::TypeName(typed.type_name.clone()));
+                TomlValue::from(typed.value.as_str())
+            }
+            Value::Code(code) => {
+                self.loss(LossKind::Language(code.language.clone()));
+                TomlValue::from(code.content.as_str())
+            }
+            Value::Array(_) | Value::Tuple(_) | Value::Map(_) | Value::Variant(_) => {
+                unreachable!("the spanned document splits containers out of values")
+            }
+        };
+        Ok(value)
+    }
+
+    fn key(&mut self, key: &KeyCmpValue) -> Result<String, ToTomlError> {
+        let notation = match key {
+            KeyCmpValue::String(key) => return Ok(key.clone()),
+            KeyCmpValue::Bool(key) => key.to_string(),
+            KeyCmpValue::I64(key) => key.to_string(),
+            KeyCmpValue::U64(key) => key.to_string(),
+            KeyCmpValue::Null => {
+                return Err(self.error(ToTomlErrorKind::UnsupportedKey("null".into())));
+            }
+            KeyCmpValue::Tuple(_) | KeyCmpValue::Unit => {
+                let kind = ToTomlErrorKind::UnsupportedKey(format!("{key:?}"));
+                return Err(self.error(kind));
+            }
+        };
+        // The loss belongs to the map, not the entry
+        let entry = self.path.pop();
+        self.loss(LossKind::NonStringKey(notation.clone()));
+        self.path.extend(entry);
+        Ok(notation)
+    }
+
+    fn extensions(&mut self, value: &SpannedValue) {
+        for (name, _) in &value.extensions {
+            self.loss(LossKind::Extension(name.clone()));
+        }
+    }
+
+    /// Whether `value` is written as a value rather than by sections or keys.
+    fn is_inline(&self, value: &SpannedValue) -> bool {
+        value.value.is_some_and(|location| {
+            matches!(
+                self.cst.node_data(location.node),
+                Some(CstNode::NonTerminal {
+                    kind: NonTerminalKind::Value,
+                    ..
+                })
+            )
+        })
+    }
+
+    fn is_table(&self, value: &SpannedValue) -> bool {
+        matches!(
+            value.content,
+            SpannedContent::Map(_) | SpannedContent::Variant(_)
+        ) && !self.is_inline(value)
+    }
+
+    fn loss(&mut self, kind: LossKind) {
+        self.losses.push(Loss {
+            path: Path(self.path.clone()),
+            kind,
+        });
+    }
+
+    fn error(&self, kind: ToTomlErrorKind) -> ToTomlError {
+        ToTomlError {
+            path: Path(self.path.clone()),
+            kind,
+        }
+    }
+}
+
+/// Remove the blank line before the first header of a document without key-values.
+fn trim_first_header(table: &mut Table) {
+    let Some((_, item)) = table.iter_mut().next() else {
+        return;
+    };
+    let table = match item {
+        Item::Table(table) if table.is_implicit() => return trim_first_header(table),
+        Item::Table(table) => table,
+        Item::ArrayOfTables(array) => match array.get_mut(0) {
+            Some(table) => table,
+            None => return,
+        },
+        _ => return,
+    };
+    if let Some(prefix) = table.decor().prefix().and_then(|prefix| prefix.as_str()) {
+        let prefix = prefix.trim_start_matches('\n').to_string();
+        table.decor_mut().set_prefix(prefix);
+    }
+}
+
+/// The line comments of a document. Each one is attached to at most one key.
+struct Comments<'a> {
+    input: &'a str,
+    cst: &'a Cst,
+    comments: Vec<InputSpan>,
+    used: Vec<bool>,
+}
+
+impl Comments<'_> {
+    /// The comment lines just before the line of `key`, each ending with a new line.
+    fn leading(&mut self, key: Option<Location>) -> String {
+        let Some(key) = key.and_then(|key| key.token_start(self.cst)) else {
+            return String::new();
+        };
+        let mut start = self.line_start(key);
+        let mut lines = vec![];
+        while let Some(index) = self.comments.iter().rposition(|comment| {
+            comment.end as usize <= start
+                && self.input[comment.end as usize..start].trim().is_empty()
+        }) {
+            let comment = self.comments[index];
+            let line_start = self.line_start(comment.start as usize);
+            if self.used[index]
+                || !self.input[line_start..comment.start as usize]
+                    .trim()
+                    .is_empty()
+            {
+                break;
+            }
+            // A comment ends with its new line, so anything more is a blank line
+            if comment.end as usize != start {
+                break;
+            }
+            lines.push(self.text(comment).to_string());
+            self.used[index] = true;
+            start = line_start;
+        }
+        lines.iter().rev().map(|line| format!("{line}\n")).collect()
+    }
+
+    /// The comment at the end of the line of `key`.
+    fn trailing(&mut self, key: Option<Location>) -> Option<String> {
+        let start = key?.token_start(self.cst)?;
+        let line_end = self.input[start..]
+            .find('\n')
+            .map_or(self.input.len(), |end| start + end);
+        let index = self
+            .comments
+            .iter()
+            .position(|comment| (start..line_end).contains(&(comment.start as usize)))?;
+        if std::mem::replace(&mut self.used[index], true) {
+            return None;
+        }
+        Some(self.text(self.comments[index]).to_string())
+    }
+
+    fn text(&self, comment: InputSpan) -> &str {
+        self.input[comment.start as usize..comment.end as usize].trim_end()
+    }
+
+    fn line_start(&self, offset: usize) -> usize {
+        self.input[..offset]
+            .rfind('\n')
+            .map_or(0, |index| index + 1)
+    }
+}
+
+impl<F: CstFacade> CstVisitor<F> for Comments<'_> {
+    type Error = Infallible;
+
+    fn visit_terminal(
+        &mut self,
+        _id: CstNodeId,
+        kind: TerminalKind,
+        data: TerminalData,
+        _tree: &F,
+    ) -> Result<(), Self::Error> {
+        if let (TerminalKind::LineComment, TerminalData::Input(span)) = (kind, data) {
+            self.comments.push(span);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_toml_str;
+
+    fn convert(input: &str) -> Result<Toml, ToTomlError> {
+        let cst = swon_parol::parse(input, "test.swon").unwrap();
+        to_toml(input, &cst, &cst.spanned_values(input).unwrap())
+    }
+
+    #[test]
+    fn test_to_toml() {
+        let input = r#"# The title
+title: SWON
+point = { x = 1, y = 2 }
+url = url"https://swon.dev"
+pair = (1, "a")
+dotted.key = true
+
+# The owner
+@ owner # owner
+name: Tom
+born = datetime"1979-05-27T07:32:00-08:00"
+@ owner.address
+city: Tokyo
+
+@ servers[]
+name: alpha
+@ servers[0].ports
+http = 80
+@ servers[]
+# The second
+name: beta
+"#;
+        let toml = convert(input).unwrap();
+        assert_eq!(
+            toml.document.to_string(),
+            r#"# The title
+title = "SWON"
+point = { x = 1, y = 2 }
+url = "https://swon.dev"
+pair = [1, "a"]
+dotted.key = true
+
+# The owner
+[owner] # owner
+name = "Tom"
+born = 1979-05-27T07:32:00-08:00
+
+[owner.address]
+city = "Tokyo"
+
+[[servers]]
+name = "alpha"
+
+[servers.ports]
+http = 80
+
+[[servers]]
+# The second
+name = "beta"
+"#
+        );
+        assert_eq!(
+            toml.losses
+                .iter()
+                .map(|loss| loss.kind.clone())
+                .collect::<Vec<_>>(),
+            [LossKind::TypeName("url".into()), LossKind::Tuple]
+        );
+    }
+
+    #[test]
+    fn test_to_toml_errors() {
+        let error = convert("a.b = null\n").unwrap_err();
+        assert_eq!(error.kind, ToTomlErrorKind::Null);
+        assert_eq!(
+            error.path,
+            Path(vec![
+                PathSegment::Value(Value::String("a".into())),
+                PathSegment::Value(Value::String("b".into())),
+            ])
+        );
+        let error = convert("a = datetime\"yesterday\"\n").unwrap_err();
+        assert_eq!(
+            error.kind,
+            ToTomlErrorKind::InvalidDatetime("yesterday".into())
+        );
+    }
+
+    #[test]
+    fn test_toml_round_trip() {
+        let input = r#"# The package
+[package]
+name = "swon" # the name
+edition = "2024"
+keywords = ["a", "b"]
+
+[dependencies]
+serde = { version = "1", features = ["derive"] }
+swon.workspace = true
+
+[target."cfg(unix)".dependencies]
+libc = "0.2"
+
+[[bin]] # first
+name = "swon"
+
+[bin.meta]
+released = 1979-05-27T07:32:00Z
+
+[[bin]]
+name = "other"
+"#;
+        let swon = from_toml_str(input).unwrap();
+        let toml = convert(&swon).unwrap();
+        assert_eq!(toml.document.to_string(), input);
+        assert!(toml.losses.is_empty());
+    }
+}
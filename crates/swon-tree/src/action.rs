@@ -62,6 +62,14 @@ impl CstCommands {
         id
     }
 
+    /// Move a node under `parent`, as its first child
+    pub fn change_parent(&mut self, id: impl Into<NodeTarget>, parent: impl Into<NodeTarget>) {
+        self.commands.push(Command::ChangeParent {
+            id: id.into(),
+            parent: parent.into(),
+        });
+    }
+
     pub fn update_node(&mut self, id: impl Into<NodeTarget>, data: CstNode) {
         self.commands.push(Command::Update {
             id: id.into(),
@@ -69,6 +77,18 @@ impl CstCommands {
         });
     }
 
+    /// Replace the children of a node. The new children are detached from their old parents.
+    pub fn update_children(
+        &mut self,
+        id: impl Into<NodeTarget>,
+        children: impl IntoIterator<Item = impl Into<NodeTarget>>,
+    ) {
+        self.commands.push(Command::UpdateChildren {
+            id: id.into(),
+            children: children.into_iter().map(|child| child.into()).collect(),
+        });
+    }
+
     /// Add nodes before the target child node
     pub fn add_nodes_before(
         &mut self,
@@ -82,12 +102,28 @@ impl CstCommands {
             data: data.into_iter().map(|d| d.into()).collect(),
         });
     }
+
+    /// Add nodes after the target child node
+    pub fn add_nodes_after(
+        &mut self,
+        id: impl Into<NodeTarget>,
+        after: impl Into<NodeTarget>,
+        data: impl IntoIterator<Item = impl Into<NodeTarget>>,
+    ) {
+        self.commands.push(Command::AddNodesAfter {
+            id: id.into(),
+            after: after.into(),
+            data: data.into_iter().map(|d| d.into()).collect(),
+        });
+    }
 }
 
 #[derive(Debug, Error)]
 pub enum CommandApplyError {
     #[error("before node not found")]
     BeforeNodeNotFound { id: CstNodeId, before: CstNodeId },
+    #[error("after node not found")]
+    AfterNodeNotFound { id: CstNodeId, after: CstNodeId },
 }
 
 impl CstCommands {
@@ -117,6 +153,16 @@ impl CstCommands {
                 Command::Update { id, data } => {
                     tree.update_node(to_id(&inserted, id), data);
                 }
+                Command::UpdateChildren { id, children } => {
+                    let children = children
+                        .into_iter()
+                        .map(|child| to_id(&inserted, child))
+                        .collect::<Vec<_>>();
+                    for &child in &children {
+                        tree.delete_node(child);
+                    }
+                    tree.update_children(to_id(&inserted, id), children);
+                }
                 Command::AddNodesBefore { id, before, data } => {
                     let mut children = tree.children(to_id(&inserted, id)).collect::<Vec<_>>();
                     let Some(before_index) =
@@ -133,6 +179,22 @@ impl CstCommands {
                     );
                     tree.update_children(to_id(&inserted, id), children);
                 }
+                Command::AddNodesAfter { id, after, data } => {
+                    let mut children = tree.children(to_id(&inserted, id)).collect::<Vec<_>>();
+                    let Some(after_index) =
+                        children.iter().position(|c| to_id(&inserted, after) == *c)
+                    else {
+                        return Err(CommandApplyError::AfterNodeNotFound {
+                            id: to_id(&inserted, id),
+                            after: to_id(&inserted, after),
+                        });
+                    };
+                    children.splice(
+                        after_index + 1..after_index + 1,
+                        data.into_iter().map(|d| to_id(&inserted, d)),
+                    );
+                    tree.update_children(to_id(&inserted, id), children);
+                }
                 Command::InsertDynamicTerminal { kind, data } => {
                     let token_id = tree.insert_dynamic_terminal(data);
                     let node_id = tree.add_node(CstNode::Terminal {
@@ -163,11 +225,20 @@ pub enum Command {
         id: NodeTarget,
         data: CstNode,
     },
+    UpdateChildren {
+        id: NodeTarget,
+        children: Vec<NodeTarget>,
+    },
     AddNodesBefore {
         id: NodeTarget,
         before: NodeTarget,
         data: Vec<NodeTarget>,
     },
+    AddNodesAfter {
+        id: NodeTarget,
+        after: NodeTarget,
+        data: Vec<NodeTarget>,
+    },
     InsertDynamicTerminal {
         kind: TerminalKind,
         data: String,
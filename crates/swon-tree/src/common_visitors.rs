@@ -1,20 +1,15 @@
 use thiserror::Error;
 
-use crate::{prelude::*, tree::LineNumbers};
+use crate::prelude::*;
 
 pub struct FormatVisitor<'f, 't> {
     input: &'t str,
-    line_numbers: LineNumbers<'t>,
     f: &'f mut dyn std::fmt::Write,
 }
 
 impl<'f, 't> FormatVisitor<'f, 't> {
     pub fn new(input: &'t str, f: &'f mut dyn std::fmt::Write) -> Self {
-        Self {
-            input,
-            line_numbers: LineNumbers::new(input),
-            f,
-        }
+        Self { input, f }
     }
 }
 
@@ -51,7 +46,7 @@ impl<F: CstFacade> CstVisitor<F> for FormatVisitor<'_, '_> {
         tree: &F,
     ) -> Result<(), Self::Error> {
         match terminal {
-            TerminalData::Input(input_span) => {
+            TerminalData::Input(input_span) | TerminalData::Error(input_span) => {
                 write!(
                     self.f,
                     "{}",
@@ -116,6 +111,15 @@ impl<F: CstFacade> CstVisitor<F> for InspectVisitor<'_, '_> {
                     .replace(" ", "_"),
                 kind,
             )?,
+            TerminalData::Error(input_span) => writeln!(
+                self.f,
+                "{}{} (Error)",
+                " ".repeat(self.indent),
+                &self.input[input_span.start as usize..input_span.end as usize]
+                    .replace("\n", "\\n")
+                    .replace("\t", "\\t")
+                    .replace(" ", "_"),
+            )?,
             TerminalData::Dynamic(token_id) => writeln!(
                 self.f,
                 "{}{:?} ({:?})",
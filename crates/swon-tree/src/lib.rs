@@ -7,6 +7,8 @@ pub mod action;
 pub mod common_visitors;
 pub mod node_kind;
 pub mod nodes;
+pub mod notation;
+pub mod spanned;
 pub mod tree;
 pub mod value_visitor;
 pub mod visitor;
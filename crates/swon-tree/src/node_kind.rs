@@ -3,8 +3,11 @@
 pub enum NonTerminalKind {
     Array,
     ArrayBegin,
+    ArrayElements,
+    ArrayElementsOpt,
+    ArrayElementsTail,
+    ArrayElementsTailOpt,
     ArrayEnd,
-    ArrayList,
     ArrayMarker,
     ArrayMarkerOpt,
     ArrayOpt,
@@ -23,6 +26,8 @@ pub enum NonTerminalKind {
     Ext,
     ExtensionNameSpace,
     False,
+    Float,
+    FloatName,
     Hole,
     Ident,
     InStr,
@@ -38,6 +43,9 @@ pub enum NonTerminalKind {
     Object,
     ObjectList,
     ObjectOpt,
+    Path,
+    PathHead,
+    PathList,
     Quote,
     Section,
     SectionBinding,
@@ -46,6 +54,7 @@ pub enum NonTerminalKind {
     Str,
     StrContinues,
     StrContinuesList,
+    StrOpt,
     Swon,
     SwonList,
     SwonList0,
@@ -54,8 +63,17 @@ pub enum NonTerminalKind {
     TextBindingOpt,
     TextStart,
     True,
+    Tuple,
+    TupleBegin,
+    TupleElements,
+    TupleElementsOpt,
+    TupleElementsTail,
+    TupleElementsTailOpt,
+    TupleEnd,
+    TupleOpt,
     TypedQuote,
     TypedStr,
+    TypedStrOpt,
     Value,
     ValueBinding,
     Ws,
@@ -69,6 +87,7 @@ pub enum TerminalKind {
     LineComment,
     BlockComment,
     Integer,
+    Float,
     True,
     False,
     Null,
@@ -89,6 +108,8 @@ pub enum TerminalKind {
     RBrace,
     LBracket,
     RBracket,
+    LParen,
+    RParen,
     Bind,
     Comma,
     Esc,
@@ -104,31 +125,34 @@ impl TerminalKind {
             3 => Self::LineComment,
             4 => Self::BlockComment,
             5 => Self::Integer,
-            6 => Self::True,
-            7 => Self::False,
-            8 => Self::Null,
-            9 => Self::Hole,
-            10 => Self::Quote,
-            11 => Self::TypedQuote,
-            12 => Self::InStr,
-            13 => Self::Text,
-            14 => Self::CodeBlock,
-            15 => Self::NamedCode,
-            16 => Self::Code,
-            17 => Self::Newline,
-            18 => Self::Ws,
-            19 => Self::At,
-            20 => Self::Dollar,
-            21 => Self::Dot,
-            22 => Self::LBrace,
-            23 => Self::RBrace,
-            24 => Self::LBracket,
-            25 => Self::RBracket,
-            26 => Self::Bind,
-            27 => Self::Comma,
-            28 => Self::Esc,
-            29 => Self::TextStart,
-            30 => Self::Ident,
+            6 => Self::Float,
+            7 => Self::True,
+            8 => Self::False,
+            9 => Self::Null,
+            10 => Self::Hole,
+            11 => Self::Quote,
+            12 => Self::TypedQuote,
+            13 => Self::InStr,
+            14 => Self::Text,
+            15 => Self::CodeBlock,
+            16 => Self::NamedCode,
+            17 => Self::Code,
+            18 => Self::Newline,
+            19 => Self::Ws,
+            20 => Self::At,
+            21 => Self::Dollar,
+            22 => Self::Dot,
+            23 => Self::LBrace,
+            24 => Self::RBrace,
+            25 => Self::LBracket,
+            26 => Self::RBracket,
+            27 => Self::LParen,
+            28 => Self::RParen,
+            29 => Self::Bind,
+            30 => Self::Comma,
+            31 => Self::Esc,
+            32 => Self::TextStart,
+            33 => Self::Ident,
             _ => panic!("Invalid terminal index: {}", index),
         }
     }
@@ -161,8 +185,11 @@ impl NonTerminalKind {
         match name {
             "Array" => Self::Array,
             "ArrayBegin" => Self::ArrayBegin,
+            "ArrayElements" => Self::ArrayElements,
+            "ArrayElementsOpt" => Self::ArrayElementsOpt,
+            "ArrayElementsTail" => Self::ArrayElementsTail,
+            "ArrayElementsTailOpt" => Self::ArrayElementsTailOpt,
             "ArrayEnd" => Self::ArrayEnd,
-            "ArrayList" => Self::ArrayList,
             "ArrayMarker" => Self::ArrayMarker,
             "ArrayMarkerOpt" => Self::ArrayMarkerOpt,
             "ArrayOpt" => Self::ArrayOpt,
@@ -181,6 +208,8 @@ impl NonTerminalKind {
             "Ext" => Self::Ext,
             "ExtensionNameSpace" => Self::ExtensionNameSpace,
             "False" => Self::False,
+            "Float" => Self::Float,
+            "FloatName" => Self::FloatName,
             "Hole" => Self::Hole,
             "Ident" => Self::Ident,
             "InStr" => Self::InStr,
@@ -196,6 +225,9 @@ impl NonTerminalKind {
             "Object" => Self::Object,
             "ObjectList" => Self::ObjectList,
             "ObjectOpt" => Self::ObjectOpt,
+            "Path" => Self::Path,
+            "PathHead" => Self::PathHead,
+            "PathList" => Self::PathList,
             "Quote" => Self::Quote,
             "Section" => Self::Section,
             "SectionBinding" => Self::SectionBinding,
@@ -204,6 +236,7 @@ impl NonTerminalKind {
             "Str" => Self::Str,
             "StrContinues" => Self::StrContinues,
             "StrContinuesList" => Self::StrContinuesList,
+            "StrOpt" => Self::StrOpt,
             "Swon" => Self::Swon,
             "SwonList" => Self::SwonList,
             "SwonList0" => Self::SwonList0,
@@ -212,8 +245,17 @@ impl NonTerminalKind {
             "TextBindingOpt" => Self::TextBindingOpt,
             "TextStart" => Self::TextStart,
             "True" => Self::True,
+            "Tuple" => Self::Tuple,
+            "TupleBegin" => Self::TupleBegin,
+            "TupleElements" => Self::TupleElements,
+            "TupleElementsOpt" => Self::TupleElementsOpt,
+            "TupleElementsTail" => Self::TupleElementsTail,
+            "TupleElementsTailOpt" => Self::TupleElementsTailOpt,
+            "TupleEnd" => Self::TupleEnd,
+            "TupleOpt" => Self::TupleOpt,
             "TypedQuote" => Self::TypedQuote,
             "TypedStr" => Self::TypedStr,
+            "TypedStrOpt" => Self::TypedStrOpt,
             "Value" => Self::Value,
             "ValueBinding" => Self::ValueBinding,
             "Ws" => Self::Ws,
@@ -230,6 +272,7 @@ impl std::fmt::Display for TerminalKind {
             Self::LineComment => write!(f, stringify!(LineComment)),
             Self::BlockComment => write!(f, stringify!(BlockComment)),
             Self::Integer => write!(f, stringify!(Integer)),
+            Self::Float => write!(f, stringify!(Float)),
             Self::True => write!(f, stringify!(True)),
             Self::False => write!(f, stringify!(False)),
             Self::Null => write!(f, stringify!(Null)),
@@ -250,6 +293,8 @@ impl std::fmt::Display for TerminalKind {
             Self::RBrace => write!(f, stringify!(RBrace)),
             Self::LBracket => write!(f, stringify!(LBracket)),
             Self::RBracket => write!(f, stringify!(RBracket)),
+            Self::LParen => write!(f, stringify!(LParen)),
+            Self::RParen => write!(f, stringify!(RParen)),
             Self::Bind => write!(f, stringify!(Bind)),
             Self::Comma => write!(f, stringify!(Comma)),
             Self::Esc => write!(f, stringify!(Esc)),
@@ -264,8 +309,11 @@ impl std::fmt::Display for NonTerminalKind {
         match self {
             Self::Array => write!(f, stringify!(Array)),
             Self::ArrayBegin => write!(f, stringify!(ArrayBegin)),
+            Self::ArrayElements => write!(f, stringify!(ArrayElements)),
+            Self::ArrayElementsOpt => write!(f, stringify!(ArrayElementsOpt)),
+            Self::ArrayElementsTail => write!(f, stringify!(ArrayElementsTail)),
+            Self::ArrayElementsTailOpt => write!(f, stringify!(ArrayElementsTailOpt)),
             Self::ArrayEnd => write!(f, stringify!(ArrayEnd)),
-            Self::ArrayList => write!(f, stringify!(ArrayList)),
             Self::ArrayMarker => write!(f, stringify!(ArrayMarker)),
             Self::ArrayMarkerOpt => write!(f, stringify!(ArrayMarkerOpt)),
             Self::ArrayOpt => write!(f, stringify!(ArrayOpt)),
@@ -284,6 +332,8 @@ impl std::fmt::Display for NonTerminalKind {
             Self::Ext => write!(f, stringify!(Ext)),
             Self::ExtensionNameSpace => write!(f, stringify!(ExtensionNameSpace)),
             Self::False => write!(f, stringify!(False)),
+            Self::Float => write!(f, stringify!(Float)),
+            Self::FloatName => write!(f, stringify!(FloatName)),
             Self::Hole => write!(f, stringify!(Hole)),
             Self::Ident => write!(f, stringify!(Ident)),
             Self::InStr => write!(f, stringify!(InStr)),
@@ -299,6 +349,9 @@ impl std::fmt::Display for NonTerminalKind {
             Self::Object => write!(f, stringify!(Object)),
             Self::ObjectList => write!(f, stringify!(ObjectList)),
             Self::ObjectOpt => write!(f, stringify!(ObjectOpt)),
+            Self::Path => write!(f, stringify!(Path)),
+            Self::PathHead => write!(f, stringify!(PathHead)),
+            Self::PathList => write!(f, stringify!(PathList)),
             Self::Quote => write!(f, stringify!(Quote)),
             Self::Section => write!(f, stringify!(Section)),
             Self::SectionBinding => write!(f, stringify!(SectionBinding)),
@@ -307,6 +360,7 @@ impl std::fmt::Display for NonTerminalKind {
             Self::Str => write!(f, stringify!(Str)),
             Self::StrContinues => write!(f, stringify!(StrContinues)),
             Self::StrContinuesList => write!(f, stringify!(StrContinuesList)),
+            Self::StrOpt => write!(f, stringify!(StrOpt)),
             Self::Swon => write!(f, stringify!(Swon)),
             Self::SwonList => write!(f, stringify!(SwonList)),
             Self::SwonList0 => write!(f, stringify!(SwonList0)),
@@ -315,8 +369,17 @@ impl std::fmt::Display for NonTerminalKind {
             Self::TextBindingOpt => write!(f, stringify!(TextBindingOpt)),
             Self::TextStart => write!(f, stringify!(TextStart)),
             Self::True => write!(f, stringify!(True)),
+            Self::Tuple => write!(f, stringify!(Tuple)),
+            Self::TupleBegin => write!(f, stringify!(TupleBegin)),
+            Self::TupleElements => write!(f, stringify!(TupleElements)),
+            Self::TupleElementsOpt => write!(f, stringify!(TupleElementsOpt)),
+            Self::TupleElementsTail => write!(f, stringify!(TupleElementsTail)),
+            Self::TupleElementsTailOpt => write!(f, stringify!(TupleElementsTailOpt)),
+            Self::TupleEnd => write!(f, stringify!(TupleEnd)),
+            Self::TupleOpt => write!(f, stringify!(TupleOpt)),
             Self::TypedQuote => write!(f, stringify!(TypedQuote)),
             Self::TypedStr => write!(f, stringify!(TypedStr)),
+            Self::TypedStrOpt => write!(f, stringify!(TypedStrOpt)),
             Self::Value => write!(f, stringify!(Value)),
             Self::ValueBinding => write!(f, stringify!(ValueBinding)),
             Self::Ws => write!(f, stringify!(Ws)),
@@ -38,14 +38,14 @@ impl NonTerminalHandle for ArrayHandle {
             self.0,
             [
                 NodeKind::NonTerminal(NonTerminalKind::ArrayBegin),
-                NodeKind::NonTerminal(NonTerminalKind::ArrayList),
+                NodeKind::NonTerminal(NonTerminalKind::ArrayOpt),
                 NodeKind::NonTerminal(NonTerminalKind::ArrayEnd),
             ],
-            |[array_begin, array_list, array_end], visit_ignored| Ok(
+            |[array_begin, array_opt, array_end], visit_ignored| Ok(
                 visit(
                     ArrayView {
                         array_begin: ArrayBeginHandle(array_begin),
-                        array_list: ArrayListHandle(array_list),
+                        array_opt: ArrayOptHandle(array_opt),
                         array_end: ArrayEndHandle(array_end),
                     },
                     visit_ignored,
@@ -58,7 +58,7 @@ impl NonTerminalHandle for ArrayHandle {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ArrayView {
     pub array_begin: ArrayBeginHandle,
-    pub array_list: ArrayListHandle,
+    pub array_opt: ArrayOptHandle,
     pub array_end: ArrayEndHandle,
 }
 impl ArrayView {}
@@ -111,9 +111,9 @@ pub struct ArrayBeginView {
 }
 impl ArrayBeginView {}
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct ArrayEndHandle(pub(crate) super::tree::CstNodeId);
-impl NonTerminalHandle for ArrayEndHandle {
-    type View = ArrayEndView;
+pub struct ArrayElementsHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for ArrayElementsHandle {
+    type View = ArrayElementsView;
     fn node_id(&self) -> CstNodeId {
         self.0
     }
@@ -124,13 +124,13 @@ impl NonTerminalHandle for ArrayEndHandle {
     ) -> Result<Self, CstConstructError<E>> {
         tree.collect_nodes(
             index,
-            [NodeKind::NonTerminal(NonTerminalKind::ArrayEnd)],
+            [NodeKind::NonTerminal(NonTerminalKind::ArrayElements)],
             |[index], visit| Ok((Self(index), visit)),
             visit_ignored,
         )
     }
     fn kind(&self) -> NonTerminalKind {
-        NonTerminalKind::ArrayEnd
+        NonTerminalKind::ArrayElements
     }
     fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
         &self,
@@ -140,11 +140,15 @@ impl NonTerminalHandle for ArrayEndHandle {
     ) -> Result<O, CstConstructError<E>> {
         tree.collect_nodes(
             self.0,
-            [NodeKind::Terminal(TerminalKind::RBracket)],
-            |[r_bracket], visit_ignored| Ok(
+            [
+                NodeKind::NonTerminal(NonTerminalKind::Value),
+                NodeKind::NonTerminal(NonTerminalKind::ArrayElementsOpt),
+            ],
+            |[value, array_elements_opt], visit_ignored| Ok(
                 visit(
-                    ArrayEndView {
-                        r_bracket: RBracket(r_bracket),
+                    ArrayElementsView {
+                        value: ValueHandle(value),
+                        array_elements_opt: ArrayElementsOptHandle(array_elements_opt),
                     },
                     visit_ignored,
                 ),
@@ -154,14 +158,15 @@ impl NonTerminalHandle for ArrayEndHandle {
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct ArrayEndView {
-    pub r_bracket: RBracket,
+pub struct ArrayElementsView {
+    pub value: ValueHandle,
+    pub array_elements_opt: ArrayElementsOptHandle,
 }
-impl ArrayEndView {}
+impl ArrayElementsView {}
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct ArrayListHandle(pub(crate) super::tree::CstNodeId);
-impl NonTerminalHandle for ArrayListHandle {
-    type View = Option<ArrayListView>;
+pub struct ArrayElementsOptHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for ArrayElementsOptHandle {
+    type View = Option<ArrayElementsTailHandle>;
     fn node_id(&self) -> CstNodeId {
         self.0
     }
@@ -172,13 +177,13 @@ impl NonTerminalHandle for ArrayListHandle {
     ) -> Result<Self, CstConstructError<E>> {
         tree.collect_nodes(
             index,
-            [NodeKind::NonTerminal(NonTerminalKind::ArrayList)],
+            [NodeKind::NonTerminal(NonTerminalKind::ArrayElementsOpt)],
             |[index], visit| Ok((Self(index), visit)),
             visit_ignored,
         )
     }
     fn kind(&self) -> NonTerminalKind {
-        NonTerminalKind::ArrayList
+        NonTerminalKind::ArrayElementsOpt
     }
     fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
         &self,
@@ -189,20 +194,58 @@ impl NonTerminalHandle for ArrayListHandle {
         if tree.has_no_children(self.0) {
             return Ok(visit(None, visit_ignored).0);
         }
+        tree.collect_nodes(
+            self.0,
+            [NodeKind::NonTerminal(NonTerminalKind::ArrayElementsTail)],
+            |[child], visit_ignored| Ok(
+                visit(Some(ArrayElementsTailHandle(child)), visit_ignored),
+            ),
+            visit_ignored,
+        )
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArrayElementsTailHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for ArrayElementsTailHandle {
+    type View = ArrayElementsTailView;
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn new_with_visit<F: CstFacade, E>(
+        index: CstNodeId,
+        tree: &F,
+        visit_ignored: &mut impl BuiltinTerminalVisitor<E, F>,
+    ) -> Result<Self, CstConstructError<E>> {
+        tree.collect_nodes(
+            index,
+            [NodeKind::NonTerminal(NonTerminalKind::ArrayElementsTail)],
+            |[index], visit| Ok((Self(index), visit)),
+            visit_ignored,
+        )
+    }
+    fn kind(&self) -> NonTerminalKind {
+        NonTerminalKind::ArrayElementsTail
+    }
+    fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
+        &self,
+        tree: &F,
+        mut visit: impl FnMut(Self::View, &'v mut V) -> (O, &'v mut V),
+        visit_ignored: &'v mut V,
+    ) -> Result<O, CstConstructError<E>> {
         tree.collect_nodes(
             self.0,
             [
-                NodeKind::NonTerminal(NonTerminalKind::Value),
-                NodeKind::NonTerminal(NonTerminalKind::ArrayOpt),
-                NodeKind::NonTerminal(NonTerminalKind::ArrayList),
+                NodeKind::NonTerminal(NonTerminalKind::Comma),
+                NodeKind::NonTerminal(NonTerminalKind::ArrayElementsTailOpt),
             ],
-            |[value, array_opt, array_list], visit_ignored| Ok(
+            |[comma, array_elements_tail_opt], visit_ignored| Ok(
                 visit(
-                    Some(ArrayListView {
-                        value: ValueHandle(value),
-                        array_opt: ArrayOptHandle(array_opt),
-                        array_list: ArrayListHandle(array_list),
-                    }),
+                    ArrayElementsTailView {
+                        comma: CommaHandle(comma),
+                        array_elements_tail_opt: ArrayElementsTailOptHandle(
+                            array_elements_tail_opt,
+                        ),
+                    },
                     visit_ignored,
                 ),
             ),
@@ -211,41 +254,100 @@ impl NonTerminalHandle for ArrayListHandle {
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct ArrayListView {
-    pub value: ValueHandle,
-    pub array_opt: ArrayOptHandle,
-    pub array_list: ArrayListHandle,
+pub struct ArrayElementsTailView {
+    pub comma: CommaHandle,
+    pub array_elements_tail_opt: ArrayElementsTailOptHandle,
 }
-impl<F: CstFacade> RecursiveView<F> for ArrayListView {
-    type Item = ArrayListItem;
-    fn get_all_with_visit<E>(
+impl ArrayElementsTailView {}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArrayElementsTailOptHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for ArrayElementsTailOptHandle {
+    type View = Option<ArrayElementsHandle>;
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn new_with_visit<F: CstFacade, E>(
+        index: CstNodeId,
+        tree: &F,
+        visit_ignored: &mut impl BuiltinTerminalVisitor<E, F>,
+    ) -> Result<Self, CstConstructError<E>> {
+        tree.collect_nodes(
+            index,
+            [NodeKind::NonTerminal(NonTerminalKind::ArrayElementsTailOpt)],
+            |[index], visit| Ok((Self(index), visit)),
+            visit_ignored,
+        )
+    }
+    fn kind(&self) -> NonTerminalKind {
+        NonTerminalKind::ArrayElementsTailOpt
+    }
+    fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
         &self,
         tree: &F,
+        mut visit: impl FnMut(Self::View, &'v mut V) -> (O, &'v mut V),
+        visit_ignored: &'v mut V,
+    ) -> Result<O, CstConstructError<E>> {
+        if tree.has_no_children(self.0) {
+            return Ok(visit(None, visit_ignored).0);
+        }
+        tree.collect_nodes(
+            self.0,
+            [NodeKind::NonTerminal(NonTerminalKind::ArrayElements)],
+            |[child], visit_ignored| Ok(
+                visit(Some(ArrayElementsHandle(child)), visit_ignored),
+            ),
+            visit_ignored,
+        )
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArrayEndHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for ArrayEndHandle {
+    type View = ArrayEndView;
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn new_with_visit<F: CstFacade, E>(
+        index: CstNodeId,
+        tree: &F,
         visit_ignored: &mut impl BuiltinTerminalVisitor<E, F>,
-    ) -> Result<Vec<Self::Item>, CstConstructError<E>> {
-        let mut items = Vec::new();
-        let mut current_view = Some(*self);
-        while let Some(item) = current_view {
-            let Self { value, array_opt, .. } = item;
-            items.push(ArrayListItem { value, array_opt });
-            item.array_list
-                .get_view_with_visit(
-                    tree,
-                    |view, visit_ignored| {
-                        current_view = view;
-                        ((), visit_ignored)
+    ) -> Result<Self, CstConstructError<E>> {
+        tree.collect_nodes(
+            index,
+            [NodeKind::NonTerminal(NonTerminalKind::ArrayEnd)],
+            |[index], visit| Ok((Self(index), visit)),
+            visit_ignored,
+        )
+    }
+    fn kind(&self) -> NonTerminalKind {
+        NonTerminalKind::ArrayEnd
+    }
+    fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
+        &self,
+        tree: &F,
+        mut visit: impl FnMut(Self::View, &'v mut V) -> (O, &'v mut V),
+        visit_ignored: &'v mut V,
+    ) -> Result<O, CstConstructError<E>> {
+        tree.collect_nodes(
+            self.0,
+            [NodeKind::Terminal(TerminalKind::RBracket)],
+            |[r_bracket], visit_ignored| Ok(
+                visit(
+                    ArrayEndView {
+                        r_bracket: RBracket(r_bracket),
                     },
                     visit_ignored,
-                )?;
-        }
-        Ok(items)
+                ),
+            ),
+            visit_ignored,
+        )
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct ArrayListItem {
-    pub value: ValueHandle,
-    pub array_opt: ArrayOptHandle,
+pub struct ArrayEndView {
+    pub r_bracket: RBracket,
 }
+impl ArrayEndView {}
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ArrayMarkerHandle(pub(crate) super::tree::CstNodeId);
 impl NonTerminalHandle for ArrayMarkerHandle {
@@ -337,10 +439,7 @@ impl NonTerminalHandle for ArrayMarkerOptHandle {
             self.0,
             [NodeKind::NonTerminal(NonTerminalKind::Integer)],
             |[child], visit_ignored| Ok(
-                visit(
-                    Some(IntegerHandle::new_with_visit(child, tree, visit_ignored)?),
-                    visit_ignored,
-                ),
+                visit(Some(IntegerHandle(child)), visit_ignored),
             ),
             visit_ignored,
         )
@@ -349,7 +448,7 @@ impl NonTerminalHandle for ArrayMarkerOptHandle {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ArrayOptHandle(pub(crate) super::tree::CstNodeId);
 impl NonTerminalHandle for ArrayOptHandle {
-    type View = Option<CommaHandle>;
+    type View = Option<ArrayElementsHandle>;
     fn node_id(&self) -> CstNodeId {
         self.0
     }
@@ -379,12 +478,9 @@ impl NonTerminalHandle for ArrayOptHandle {
         }
         tree.collect_nodes(
             self.0,
-            [NodeKind::NonTerminal(NonTerminalKind::Comma)],
+            [NodeKind::NonTerminal(NonTerminalKind::ArrayElements)],
             |[child], visit_ignored| Ok(
-                visit(
-                    Some(CommaHandle::new_with_visit(child, tree, visit_ignored)?),
-                    visit_ignored,
-                ),
+                visit(Some(ArrayElementsHandle(child)), visit_ignored),
             ),
             visit_ignored,
         )
@@ -1128,6 +1224,97 @@ pub struct FalseView {
 }
 impl FalseView {}
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FloatHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for FloatHandle {
+    type View = FloatView;
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn new_with_visit<F: CstFacade, E>(
+        index: CstNodeId,
+        tree: &F,
+        visit_ignored: &mut impl BuiltinTerminalVisitor<E, F>,
+    ) -> Result<Self, CstConstructError<E>> {
+        tree.collect_nodes(
+            index,
+            [NodeKind::NonTerminal(NonTerminalKind::Float)],
+            |[index], visit| Ok((Self(index), visit)),
+            visit_ignored,
+        )
+    }
+    fn kind(&self) -> NonTerminalKind {
+        NonTerminalKind::Float
+    }
+    fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
+        &self,
+        tree: &F,
+        mut visit: impl FnMut(Self::View, &'v mut V) -> (O, &'v mut V),
+        visit_ignored: &'v mut V,
+    ) -> Result<O, CstConstructError<E>> {
+        tree.collect_nodes(
+            self.0,
+            [NodeKind::Terminal(TerminalKind::Float)],
+            |[float], visit_ignored| Ok(
+                visit(FloatView { float: Float(float) }, visit_ignored),
+            ),
+            visit_ignored,
+        )
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FloatView {
+    pub float: Float,
+}
+impl FloatView {}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FloatNameHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for FloatNameHandle {
+    type View = FloatNameView;
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn new_with_visit<F: CstFacade, E>(
+        index: CstNodeId,
+        tree: &F,
+        visit_ignored: &mut impl BuiltinTerminalVisitor<E, F>,
+    ) -> Result<Self, CstConstructError<E>> {
+        tree.collect_nodes(
+            index,
+            [NodeKind::NonTerminal(NonTerminalKind::FloatName)],
+            |[index], visit| Ok((Self(index), visit)),
+            visit_ignored,
+        )
+    }
+    fn kind(&self) -> NonTerminalKind {
+        NonTerminalKind::FloatName
+    }
+    fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
+        &self,
+        tree: &F,
+        mut visit: impl FnMut(Self::View, &'v mut V) -> (O, &'v mut V),
+        visit_ignored: &'v mut V,
+    ) -> Result<O, CstConstructError<E>> {
+        tree.collect_nodes(
+            self.0,
+            [NodeKind::NonTerminal(NonTerminalKind::Ident)],
+            |[ident], visit_ignored| Ok(
+                visit(
+                    FloatNameView {
+                        ident: IdentHandle(ident),
+                    },
+                    visit_ignored,
+                ),
+            ),
+            visit_ignored,
+        )
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FloatNameView {
+    pub ident: IdentHandle,
+}
+impl FloatNameView {}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct HoleHandle(pub(crate) super::tree::CstNodeId);
 impl NonTerminalHandle for HoleHandle {
     type View = HoleView;
@@ -1409,6 +1596,9 @@ impl NonTerminalHandle for KeyBaseHandle {
             NodeKind::NonTerminal(NonTerminalKind::Integer) => {
                 KeyBaseView::Integer(IntegerHandle(child))
             }
+            NodeKind::NonTerminal(NonTerminalKind::Float) => {
+                KeyBaseView::Float(FloatHandle(child))
+            }
             _ => {
                 return Err(ViewConstructionError::UnexpectedNode {
                     node: child,
@@ -1432,6 +1622,7 @@ pub enum KeyBaseView {
     ExtensionNameSpace(ExtensionNameSpaceHandle),
     Str(StrHandle),
     Integer(IntegerHandle),
+    Float(FloatHandle),
 }
 impl KeyBaseView {}
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -1469,10 +1660,7 @@ impl NonTerminalHandle for KeyOptHandle {
             self.0,
             [NodeKind::NonTerminal(NonTerminalKind::ArrayMarker)],
             |[child], visit_ignored| Ok(
-                visit(
-                    Some(ArrayMarkerHandle::new_with_visit(child, tree, visit_ignored)?),
-                    visit_ignored,
-                ),
+                visit(Some(ArrayMarkerHandle(child)), visit_ignored),
             ),
             visit_ignored,
         )
@@ -1950,20 +2138,15 @@ impl NonTerminalHandle for ObjectOptHandle {
         tree.collect_nodes(
             self.0,
             [NodeKind::NonTerminal(NonTerminalKind::Comma)],
-            |[child], visit_ignored| Ok(
-                visit(
-                    Some(CommaHandle::new_with_visit(child, tree, visit_ignored)?),
-                    visit_ignored,
-                ),
-            ),
+            |[child], visit_ignored| Ok(visit(Some(CommaHandle(child)), visit_ignored)),
             visit_ignored,
         )
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct QuoteHandle(pub(crate) super::tree::CstNodeId);
-impl NonTerminalHandle for QuoteHandle {
-    type View = QuoteView;
+pub struct PathHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for PathHandle {
+    type View = PathView;
     fn node_id(&self) -> CstNodeId {
         self.0
     }
@@ -1974,13 +2157,13 @@ impl NonTerminalHandle for QuoteHandle {
     ) -> Result<Self, CstConstructError<E>> {
         tree.collect_nodes(
             index,
-            [NodeKind::NonTerminal(NonTerminalKind::Quote)],
+            [NodeKind::NonTerminal(NonTerminalKind::Path)],
             |[index], visit| Ok((Self(index), visit)),
             visit_ignored,
         )
     }
     fn kind(&self) -> NonTerminalKind {
-        NonTerminalKind::Quote
+        NonTerminalKind::Path
     }
     fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
         &self,
@@ -1990,19 +2173,232 @@ impl NonTerminalHandle for QuoteHandle {
     ) -> Result<O, CstConstructError<E>> {
         tree.collect_nodes(
             self.0,
-            [NodeKind::Terminal(TerminalKind::Quote)],
-            |[quote], visit_ignored| Ok(
-                visit(QuoteView { quote: Quote(quote) }, visit_ignored),
+            [
+                NodeKind::NonTerminal(NonTerminalKind::Dot),
+                NodeKind::NonTerminal(NonTerminalKind::PathHead),
+                NodeKind::NonTerminal(NonTerminalKind::PathList),
+            ],
+            |[dot, path_head, path_list], visit_ignored| Ok(
+                visit(
+                    PathView {
+                        dot: DotHandle(dot),
+                        path_head: PathHeadHandle(path_head),
+                        path_list: PathListHandle(path_list),
+                    },
+                    visit_ignored,
+                ),
             ),
             visit_ignored,
         )
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct QuoteView {
-    pub quote: Quote,
+pub struct PathView {
+    pub dot: DotHandle,
+    pub path_head: PathHeadHandle,
+    pub path_list: PathListHandle,
 }
-impl QuoteView {}
+impl PathView {}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PathHeadHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for PathHeadHandle {
+    type View = PathHeadView;
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn new_with_visit<F: CstFacade, E>(
+        index: CstNodeId,
+        tree: &F,
+        visit_ignored: &mut impl BuiltinTerminalVisitor<E, F>,
+    ) -> Result<Self, CstConstructError<E>> {
+        tree.collect_nodes(
+            index,
+            [NodeKind::NonTerminal(NonTerminalKind::PathHead)],
+            |[index], visit| Ok((Self(index), visit)),
+            visit_ignored,
+        )
+    }
+    fn kind(&self) -> NonTerminalKind {
+        NonTerminalKind::PathHead
+    }
+    fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
+        &self,
+        tree: &F,
+        mut visit: impl FnMut(Self::View, &'v mut V) -> (O, &'v mut V),
+        visit_ignored: &'v mut V,
+    ) -> Result<O, CstConstructError<E>> {
+        let mut children = tree.children(self.0);
+        let Some(child) = children.next() else {
+            return Err(ViewConstructionError::UnexpectedEndOfChildren {
+                parent: self.0,
+            });
+        };
+        let Some(child_data) = tree.node_data(child) else {
+            return Err(ViewConstructionError::NodeIdNotFound {
+                node: child,
+            });
+        };
+        let variant = match child_data.node_kind() {
+            NodeKind::NonTerminal(NonTerminalKind::Key) => {
+                PathHeadView::Key(KeyHandle(child))
+            }
+            NodeKind::NonTerminal(NonTerminalKind::ArrayMarker) => {
+                PathHeadView::ArrayMarker(ArrayMarkerHandle(child))
+            }
+            _ => {
+                return Err(ViewConstructionError::UnexpectedNode {
+                    node: child,
+                    data: child_data,
+                    expected_kind: child_data.node_kind(),
+                });
+            }
+        };
+        let (result, _visit) = visit(variant, visit_ignored);
+        if let Some(child) = children.next() {
+            return Err(ViewConstructionError::UnexpectedExtraNode {
+                node: child,
+            });
+        }
+        Ok(result)
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathHeadView {
+    Key(KeyHandle),
+    ArrayMarker(ArrayMarkerHandle),
+}
+impl PathHeadView {}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PathListHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for PathListHandle {
+    type View = Option<PathListView>;
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn new_with_visit<F: CstFacade, E>(
+        index: CstNodeId,
+        tree: &F,
+        visit_ignored: &mut impl BuiltinTerminalVisitor<E, F>,
+    ) -> Result<Self, CstConstructError<E>> {
+        tree.collect_nodes(
+            index,
+            [NodeKind::NonTerminal(NonTerminalKind::PathList)],
+            |[index], visit| Ok((Self(index), visit)),
+            visit_ignored,
+        )
+    }
+    fn kind(&self) -> NonTerminalKind {
+        NonTerminalKind::PathList
+    }
+    fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
+        &self,
+        tree: &F,
+        mut visit: impl FnMut(Self::View, &'v mut V) -> (O, &'v mut V),
+        visit_ignored: &'v mut V,
+    ) -> Result<O, CstConstructError<E>> {
+        if tree.has_no_children(self.0) {
+            return Ok(visit(None, visit_ignored).0);
+        }
+        tree.collect_nodes(
+            self.0,
+            [
+                NodeKind::NonTerminal(NonTerminalKind::Dot),
+                NodeKind::NonTerminal(NonTerminalKind::Key),
+                NodeKind::NonTerminal(NonTerminalKind::PathList),
+            ],
+            |[dot, key, path_list], visit_ignored| Ok(
+                visit(
+                    Some(PathListView {
+                        dot: DotHandle(dot),
+                        key: KeyHandle(key),
+                        path_list: PathListHandle(path_list),
+                    }),
+                    visit_ignored,
+                ),
+            ),
+            visit_ignored,
+        )
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathListView {
+    pub dot: DotHandle,
+    pub key: KeyHandle,
+    pub path_list: PathListHandle,
+}
+impl<F: CstFacade> RecursiveView<F> for PathListView {
+    type Item = PathListItem;
+    fn get_all_with_visit<E>(
+        &self,
+        tree: &F,
+        visit_ignored: &mut impl BuiltinTerminalVisitor<E, F>,
+    ) -> Result<Vec<Self::Item>, CstConstructError<E>> {
+        let mut items = Vec::new();
+        let mut current_view = Some(*self);
+        while let Some(item) = current_view {
+            let Self { dot, key, .. } = item;
+            items.push(PathListItem { dot, key });
+            item.path_list
+                .get_view_with_visit(
+                    tree,
+                    |view, visit_ignored| {
+                        current_view = view;
+                        ((), visit_ignored)
+                    },
+                    visit_ignored,
+                )?;
+        }
+        Ok(items)
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathListItem {
+    pub dot: DotHandle,
+    pub key: KeyHandle,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct QuoteHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for QuoteHandle {
+    type View = QuoteView;
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn new_with_visit<F: CstFacade, E>(
+        index: CstNodeId,
+        tree: &F,
+        visit_ignored: &mut impl BuiltinTerminalVisitor<E, F>,
+    ) -> Result<Self, CstConstructError<E>> {
+        tree.collect_nodes(
+            index,
+            [NodeKind::NonTerminal(NonTerminalKind::Quote)],
+            |[index], visit| Ok((Self(index), visit)),
+            visit_ignored,
+        )
+    }
+    fn kind(&self) -> NonTerminalKind {
+        NonTerminalKind::Quote
+    }
+    fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
+        &self,
+        tree: &F,
+        mut visit: impl FnMut(Self::View, &'v mut V) -> (O, &'v mut V),
+        visit_ignored: &'v mut V,
+    ) -> Result<O, CstConstructError<E>> {
+        tree.collect_nodes(
+            self.0,
+            [NodeKind::Terminal(TerminalKind::Quote)],
+            |[quote], visit_ignored| Ok(
+                visit(QuoteView { quote: Quote(quote) }, visit_ignored),
+            ),
+            visit_ignored,
+        )
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuoteView {
+    pub quote: Quote,
+}
+impl QuoteView {}
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SectionHandle(pub(crate) super::tree::CstNodeId);
 impl NonTerminalHandle for SectionHandle {
@@ -2296,14 +2692,14 @@ impl NonTerminalHandle for StrHandle {
             self.0,
             [
                 NodeKind::NonTerminal(NonTerminalKind::Quote),
-                NodeKind::NonTerminal(NonTerminalKind::InStr),
+                NodeKind::NonTerminal(NonTerminalKind::StrOpt),
                 NodeKind::NonTerminal(NonTerminalKind::Quote),
             ],
-            |[quote, in_str, quote2], visit_ignored| Ok(
+            |[quote, str_opt, quote2], visit_ignored| Ok(
                 visit(
                     StrView {
                         quote: QuoteHandle(quote),
-                        in_str: InStrHandle(in_str),
+                        str_opt: StrOptHandle(str_opt),
                         quote2: QuoteHandle(quote2),
                     },
                     visit_ignored,
@@ -2316,7 +2712,7 @@ impl NonTerminalHandle for StrHandle {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct StrView {
     pub quote: QuoteHandle,
-    pub in_str: InStrHandle,
+    pub str_opt: StrOptHandle,
     pub quote2: QuoteHandle,
 }
 impl StrView {}
@@ -2466,6 +2862,45 @@ pub struct StrContinuesListItem {
     pub str: StrHandle,
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StrOptHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for StrOptHandle {
+    type View = Option<InStrHandle>;
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn new_with_visit<F: CstFacade, E>(
+        index: CstNodeId,
+        tree: &F,
+        visit_ignored: &mut impl BuiltinTerminalVisitor<E, F>,
+    ) -> Result<Self, CstConstructError<E>> {
+        tree.collect_nodes(
+            index,
+            [NodeKind::NonTerminal(NonTerminalKind::StrOpt)],
+            |[index], visit| Ok((Self(index), visit)),
+            visit_ignored,
+        )
+    }
+    fn kind(&self) -> NonTerminalKind {
+        NonTerminalKind::StrOpt
+    }
+    fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
+        &self,
+        tree: &F,
+        mut visit: impl FnMut(Self::View, &'v mut V) -> (O, &'v mut V),
+        visit_ignored: &'v mut V,
+    ) -> Result<O, CstConstructError<E>> {
+        if tree.has_no_children(self.0) {
+            return Ok(visit(None, visit_ignored).0);
+        }
+        tree.collect_nodes(
+            self.0,
+            [NodeKind::NonTerminal(NonTerminalKind::InStr)],
+            |[child], visit_ignored| Ok(visit(Some(InStrHandle(child)), visit_ignored)),
+            visit_ignored,
+        )
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SwonHandle(pub(crate) super::tree::CstNodeId);
 impl NonTerminalHandle for SwonHandle {
     type View = SwonView;
@@ -2814,12 +3249,7 @@ impl NonTerminalHandle for TextBindingOptHandle {
         tree.collect_nodes(
             self.0,
             [NodeKind::NonTerminal(NonTerminalKind::Ws)],
-            |[child], visit_ignored| Ok(
-                visit(
-                    Some(WsHandle::new_with_visit(child, tree, visit_ignored)?),
-                    visit_ignored,
-                ),
-            ),
+            |[child], visit_ignored| Ok(visit(Some(WsHandle(child)), visit_ignored)),
             visit_ignored,
         )
     }
@@ -2916,9 +3346,9 @@ pub struct TrueView {
 }
 impl TrueView {}
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct TypedQuoteHandle(pub(crate) super::tree::CstNodeId);
-impl NonTerminalHandle for TypedQuoteHandle {
-    type View = TypedQuoteView;
+pub struct TupleHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for TupleHandle {
+    type View = TupleView;
     fn node_id(&self) -> CstNodeId {
         self.0
     }
@@ -2929,13 +3359,13 @@ impl NonTerminalHandle for TypedQuoteHandle {
     ) -> Result<Self, CstConstructError<E>> {
         tree.collect_nodes(
             index,
-            [NodeKind::NonTerminal(NonTerminalKind::TypedQuote)],
+            [NodeKind::NonTerminal(NonTerminalKind::Tuple)],
             |[index], visit| Ok((Self(index), visit)),
             visit_ignored,
         )
     }
     fn kind(&self) -> NonTerminalKind {
-        NonTerminalKind::TypedQuote
+        NonTerminalKind::Tuple
     }
     fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
         &self,
@@ -2945,11 +3375,17 @@ impl NonTerminalHandle for TypedQuoteHandle {
     ) -> Result<O, CstConstructError<E>> {
         tree.collect_nodes(
             self.0,
-            [NodeKind::Terminal(TerminalKind::TypedQuote)],
-            |[typed_quote], visit_ignored| Ok(
+            [
+                NodeKind::NonTerminal(NonTerminalKind::TupleBegin),
+                NodeKind::NonTerminal(NonTerminalKind::TupleOpt),
+                NodeKind::NonTerminal(NonTerminalKind::TupleEnd),
+            ],
+            |[tuple_begin, tuple_opt, tuple_end], visit_ignored| Ok(
                 visit(
-                    TypedQuoteView {
-                        typed_quote: TypedQuote(typed_quote),
+                    TupleView {
+                        tuple_begin: TupleBeginHandle(tuple_begin),
+                        tuple_opt: TupleOptHandle(tuple_opt),
+                        tuple_end: TupleEndHandle(tuple_end),
                     },
                     visit_ignored,
                 ),
@@ -2959,14 +3395,16 @@ impl NonTerminalHandle for TypedQuoteHandle {
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct TypedQuoteView {
-    pub typed_quote: TypedQuote,
+pub struct TupleView {
+    pub tuple_begin: TupleBeginHandle,
+    pub tuple_opt: TupleOptHandle,
+    pub tuple_end: TupleEndHandle,
 }
-impl TypedQuoteView {}
+impl TupleView {}
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct TypedStrHandle(pub(crate) super::tree::CstNodeId);
-impl NonTerminalHandle for TypedStrHandle {
-    type View = TypedStrView;
+pub struct TupleBeginHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for TupleBeginHandle {
+    type View = TupleBeginView;
     fn node_id(&self) -> CstNodeId {
         self.0
     }
@@ -2977,13 +3415,13 @@ impl NonTerminalHandle for TypedStrHandle {
     ) -> Result<Self, CstConstructError<E>> {
         tree.collect_nodes(
             index,
-            [NodeKind::NonTerminal(NonTerminalKind::TypedStr)],
+            [NodeKind::NonTerminal(NonTerminalKind::TupleBegin)],
             |[index], visit| Ok((Self(index), visit)),
             visit_ignored,
         )
     }
     fn kind(&self) -> NonTerminalKind {
-        NonTerminalKind::TypedStr
+        NonTerminalKind::TupleBegin
     }
     fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
         &self,
@@ -2993,17 +3431,11 @@ impl NonTerminalHandle for TypedStrHandle {
     ) -> Result<O, CstConstructError<E>> {
         tree.collect_nodes(
             self.0,
-            [
-                NodeKind::NonTerminal(NonTerminalKind::TypedQuote),
-                NodeKind::NonTerminal(NonTerminalKind::InStr),
-                NodeKind::NonTerminal(NonTerminalKind::Quote),
-            ],
-            |[typed_quote, in_str, quote], visit_ignored| Ok(
+            [NodeKind::Terminal(TerminalKind::LParen)],
+            |[l_paren], visit_ignored| Ok(
                 visit(
-                    TypedStrView {
-                        typed_quote: TypedQuoteHandle(typed_quote),
-                        in_str: InStrHandle(in_str),
-                        quote: QuoteHandle(quote),
+                    TupleBeginView {
+                        l_paren: LParen(l_paren),
                     },
                     visit_ignored,
                 ),
@@ -3013,16 +3445,14 @@ impl NonTerminalHandle for TypedStrHandle {
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct TypedStrView {
-    pub typed_quote: TypedQuoteHandle,
-    pub in_str: InStrHandle,
-    pub quote: QuoteHandle,
+pub struct TupleBeginView {
+    pub l_paren: LParen,
 }
-impl TypedStrView {}
+impl TupleBeginView {}
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct ValueHandle(pub(crate) super::tree::CstNodeId);
-impl NonTerminalHandle for ValueHandle {
-    type View = ValueView;
+pub struct TupleElementsHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for TupleElementsHandle {
+    type View = TupleElementsView;
     fn node_id(&self) -> CstNodeId {
         self.0
     }
@@ -3033,13 +3463,13 @@ impl NonTerminalHandle for ValueHandle {
     ) -> Result<Self, CstConstructError<E>> {
         tree.collect_nodes(
             index,
-            [NodeKind::NonTerminal(NonTerminalKind::Value)],
+            [NodeKind::NonTerminal(NonTerminalKind::TupleElements)],
             |[index], visit| Ok((Self(index), visit)),
             visit_ignored,
         )
     }
     fn kind(&self) -> NonTerminalKind {
-        NonTerminalKind::Value
+        NonTerminalKind::TupleElements
     }
     fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
         &self,
@@ -3047,27 +3477,458 @@ impl NonTerminalHandle for ValueHandle {
         mut visit: impl FnMut(Self::View, &'v mut V) -> (O, &'v mut V),
         visit_ignored: &'v mut V,
     ) -> Result<O, CstConstructError<E>> {
-        let mut children = tree.children(self.0);
-        let Some(child) = children.next() else {
-            return Err(ViewConstructionError::UnexpectedEndOfChildren {
-                parent: self.0,
-            });
-        };
-        let Some(child_data) = tree.node_data(child) else {
-            return Err(ViewConstructionError::NodeIdNotFound {
-                node: child,
-            });
-        };
-        let variant = match child_data.node_kind() {
+        tree.collect_nodes(
+            self.0,
+            [
+                NodeKind::NonTerminal(NonTerminalKind::Value),
+                NodeKind::NonTerminal(NonTerminalKind::TupleElementsOpt),
+            ],
+            |[value, tuple_elements_opt], visit_ignored| Ok(
+                visit(
+                    TupleElementsView {
+                        value: ValueHandle(value),
+                        tuple_elements_opt: TupleElementsOptHandle(tuple_elements_opt),
+                    },
+                    visit_ignored,
+                ),
+            ),
+            visit_ignored,
+        )
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TupleElementsView {
+    pub value: ValueHandle,
+    pub tuple_elements_opt: TupleElementsOptHandle,
+}
+impl TupleElementsView {}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TupleElementsOptHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for TupleElementsOptHandle {
+    type View = Option<TupleElementsTailHandle>;
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn new_with_visit<F: CstFacade, E>(
+        index: CstNodeId,
+        tree: &F,
+        visit_ignored: &mut impl BuiltinTerminalVisitor<E, F>,
+    ) -> Result<Self, CstConstructError<E>> {
+        tree.collect_nodes(
+            index,
+            [NodeKind::NonTerminal(NonTerminalKind::TupleElementsOpt)],
+            |[index], visit| Ok((Self(index), visit)),
+            visit_ignored,
+        )
+    }
+    fn kind(&self) -> NonTerminalKind {
+        NonTerminalKind::TupleElementsOpt
+    }
+    fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
+        &self,
+        tree: &F,
+        mut visit: impl FnMut(Self::View, &'v mut V) -> (O, &'v mut V),
+        visit_ignored: &'v mut V,
+    ) -> Result<O, CstConstructError<E>> {
+        if tree.has_no_children(self.0) {
+            return Ok(visit(None, visit_ignored).0);
+        }
+        tree.collect_nodes(
+            self.0,
+            [NodeKind::NonTerminal(NonTerminalKind::TupleElementsTail)],
+            |[child], visit_ignored| Ok(
+                visit(Some(TupleElementsTailHandle(child)), visit_ignored),
+            ),
+            visit_ignored,
+        )
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TupleElementsTailHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for TupleElementsTailHandle {
+    type View = TupleElementsTailView;
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn new_with_visit<F: CstFacade, E>(
+        index: CstNodeId,
+        tree: &F,
+        visit_ignored: &mut impl BuiltinTerminalVisitor<E, F>,
+    ) -> Result<Self, CstConstructError<E>> {
+        tree.collect_nodes(
+            index,
+            [NodeKind::NonTerminal(NonTerminalKind::TupleElementsTail)],
+            |[index], visit| Ok((Self(index), visit)),
+            visit_ignored,
+        )
+    }
+    fn kind(&self) -> NonTerminalKind {
+        NonTerminalKind::TupleElementsTail
+    }
+    fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
+        &self,
+        tree: &F,
+        mut visit: impl FnMut(Self::View, &'v mut V) -> (O, &'v mut V),
+        visit_ignored: &'v mut V,
+    ) -> Result<O, CstConstructError<E>> {
+        tree.collect_nodes(
+            self.0,
+            [
+                NodeKind::NonTerminal(NonTerminalKind::Comma),
+                NodeKind::NonTerminal(NonTerminalKind::TupleElementsTailOpt),
+            ],
+            |[comma, tuple_elements_tail_opt], visit_ignored| Ok(
+                visit(
+                    TupleElementsTailView {
+                        comma: CommaHandle(comma),
+                        tuple_elements_tail_opt: TupleElementsTailOptHandle(
+                            tuple_elements_tail_opt,
+                        ),
+                    },
+                    visit_ignored,
+                ),
+            ),
+            visit_ignored,
+        )
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TupleElementsTailView {
+    pub comma: CommaHandle,
+    pub tuple_elements_tail_opt: TupleElementsTailOptHandle,
+}
+impl TupleElementsTailView {}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TupleElementsTailOptHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for TupleElementsTailOptHandle {
+    type View = Option<TupleElementsHandle>;
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn new_with_visit<F: CstFacade, E>(
+        index: CstNodeId,
+        tree: &F,
+        visit_ignored: &mut impl BuiltinTerminalVisitor<E, F>,
+    ) -> Result<Self, CstConstructError<E>> {
+        tree.collect_nodes(
+            index,
+            [NodeKind::NonTerminal(NonTerminalKind::TupleElementsTailOpt)],
+            |[index], visit| Ok((Self(index), visit)),
+            visit_ignored,
+        )
+    }
+    fn kind(&self) -> NonTerminalKind {
+        NonTerminalKind::TupleElementsTailOpt
+    }
+    fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
+        &self,
+        tree: &F,
+        mut visit: impl FnMut(Self::View, &'v mut V) -> (O, &'v mut V),
+        visit_ignored: &'v mut V,
+    ) -> Result<O, CstConstructError<E>> {
+        if tree.has_no_children(self.0) {
+            return Ok(visit(None, visit_ignored).0);
+        }
+        tree.collect_nodes(
+            self.0,
+            [NodeKind::NonTerminal(NonTerminalKind::TupleElements)],
+            |[child], visit_ignored| Ok(
+                visit(Some(TupleElementsHandle(child)), visit_ignored),
+            ),
+            visit_ignored,
+        )
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TupleEndHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for TupleEndHandle {
+    type View = TupleEndView;
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn new_with_visit<F: CstFacade, E>(
+        index: CstNodeId,
+        tree: &F,
+        visit_ignored: &mut impl BuiltinTerminalVisitor<E, F>,
+    ) -> Result<Self, CstConstructError<E>> {
+        tree.collect_nodes(
+            index,
+            [NodeKind::NonTerminal(NonTerminalKind::TupleEnd)],
+            |[index], visit| Ok((Self(index), visit)),
+            visit_ignored,
+        )
+    }
+    fn kind(&self) -> NonTerminalKind {
+        NonTerminalKind::TupleEnd
+    }
+    fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
+        &self,
+        tree: &F,
+        mut visit: impl FnMut(Self::View, &'v mut V) -> (O, &'v mut V),
+        visit_ignored: &'v mut V,
+    ) -> Result<O, CstConstructError<E>> {
+        tree.collect_nodes(
+            self.0,
+            [NodeKind::Terminal(TerminalKind::RParen)],
+            |[r_paren], visit_ignored| Ok(
+                visit(
+                    TupleEndView {
+                        r_paren: RParen(r_paren),
+                    },
+                    visit_ignored,
+                ),
+            ),
+            visit_ignored,
+        )
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TupleEndView {
+    pub r_paren: RParen,
+}
+impl TupleEndView {}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TupleOptHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for TupleOptHandle {
+    type View = Option<TupleElementsHandle>;
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn new_with_visit<F: CstFacade, E>(
+        index: CstNodeId,
+        tree: &F,
+        visit_ignored: &mut impl BuiltinTerminalVisitor<E, F>,
+    ) -> Result<Self, CstConstructError<E>> {
+        tree.collect_nodes(
+            index,
+            [NodeKind::NonTerminal(NonTerminalKind::TupleOpt)],
+            |[index], visit| Ok((Self(index), visit)),
+            visit_ignored,
+        )
+    }
+    fn kind(&self) -> NonTerminalKind {
+        NonTerminalKind::TupleOpt
+    }
+    fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
+        &self,
+        tree: &F,
+        mut visit: impl FnMut(Self::View, &'v mut V) -> (O, &'v mut V),
+        visit_ignored: &'v mut V,
+    ) -> Result<O, CstConstructError<E>> {
+        if tree.has_no_children(self.0) {
+            return Ok(visit(None, visit_ignored).0);
+        }
+        tree.collect_nodes(
+            self.0,
+            [NodeKind::NonTerminal(NonTerminalKind::TupleElements)],
+            |[child], visit_ignored| Ok(
+                visit(Some(TupleElementsHandle(child)), visit_ignored),
+            ),
+            visit_ignored,
+        )
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TypedQuoteHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for TypedQuoteHandle {
+    type View = TypedQuoteView;
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn new_with_visit<F: CstFacade, E>(
+        index: CstNodeId,
+        tree: &F,
+        visit_ignored: &mut impl BuiltinTerminalVisitor<E, F>,
+    ) -> Result<Self, CstConstructError<E>> {
+        tree.collect_nodes(
+            index,
+            [NodeKind::NonTerminal(NonTerminalKind::TypedQuote)],
+            |[index], visit| Ok((Self(index), visit)),
+            visit_ignored,
+        )
+    }
+    fn kind(&self) -> NonTerminalKind {
+        NonTerminalKind::TypedQuote
+    }
+    fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
+        &self,
+        tree: &F,
+        mut visit: impl FnMut(Self::View, &'v mut V) -> (O, &'v mut V),
+        visit_ignored: &'v mut V,
+    ) -> Result<O, CstConstructError<E>> {
+        tree.collect_nodes(
+            self.0,
+            [NodeKind::Terminal(TerminalKind::TypedQuote)],
+            |[typed_quote], visit_ignored| Ok(
+                visit(
+                    TypedQuoteView {
+                        typed_quote: TypedQuote(typed_quote),
+                    },
+                    visit_ignored,
+                ),
+            ),
+            visit_ignored,
+        )
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypedQuoteView {
+    pub typed_quote: TypedQuote,
+}
+impl TypedQuoteView {}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TypedStrHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for TypedStrHandle {
+    type View = TypedStrView;
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn new_with_visit<F: CstFacade, E>(
+        index: CstNodeId,
+        tree: &F,
+        visit_ignored: &mut impl BuiltinTerminalVisitor<E, F>,
+    ) -> Result<Self, CstConstructError<E>> {
+        tree.collect_nodes(
+            index,
+            [NodeKind::NonTerminal(NonTerminalKind::TypedStr)],
+            |[index], visit| Ok((Self(index), visit)),
+            visit_ignored,
+        )
+    }
+    fn kind(&self) -> NonTerminalKind {
+        NonTerminalKind::TypedStr
+    }
+    fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
+        &self,
+        tree: &F,
+        mut visit: impl FnMut(Self::View, &'v mut V) -> (O, &'v mut V),
+        visit_ignored: &'v mut V,
+    ) -> Result<O, CstConstructError<E>> {
+        tree.collect_nodes(
+            self.0,
+            [
+                NodeKind::NonTerminal(NonTerminalKind::TypedQuote),
+                NodeKind::NonTerminal(NonTerminalKind::TypedStrOpt),
+                NodeKind::NonTerminal(NonTerminalKind::Quote),
+            ],
+            |[typed_quote, typed_str_opt, quote], visit_ignored| Ok(
+                visit(
+                    TypedStrView {
+                        typed_quote: TypedQuoteHandle(typed_quote),
+                        typed_str_opt: TypedStrOptHandle(typed_str_opt),
+                        quote: QuoteHandle(quote),
+                    },
+                    visit_ignored,
+                ),
+            ),
+            visit_ignored,
+        )
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypedStrView {
+    pub typed_quote: TypedQuoteHandle,
+    pub typed_str_opt: TypedStrOptHandle,
+    pub quote: QuoteHandle,
+}
+impl TypedStrView {}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TypedStrOptHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for TypedStrOptHandle {
+    type View = Option<InStrHandle>;
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn new_with_visit<F: CstFacade, E>(
+        index: CstNodeId,
+        tree: &F,
+        visit_ignored: &mut impl BuiltinTerminalVisitor<E, F>,
+    ) -> Result<Self, CstConstructError<E>> {
+        tree.collect_nodes(
+            index,
+            [NodeKind::NonTerminal(NonTerminalKind::TypedStrOpt)],
+            |[index], visit| Ok((Self(index), visit)),
+            visit_ignored,
+        )
+    }
+    fn kind(&self) -> NonTerminalKind {
+        NonTerminalKind::TypedStrOpt
+    }
+    fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
+        &self,
+        tree: &F,
+        mut visit: impl FnMut(Self::View, &'v mut V) -> (O, &'v mut V),
+        visit_ignored: &'v mut V,
+    ) -> Result<O, CstConstructError<E>> {
+        if tree.has_no_children(self.0) {
+            return Ok(visit(None, visit_ignored).0);
+        }
+        tree.collect_nodes(
+            self.0,
+            [NodeKind::NonTerminal(NonTerminalKind::InStr)],
+            |[child], visit_ignored| Ok(visit(Some(InStrHandle(child)), visit_ignored)),
+            visit_ignored,
+        )
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ValueHandle(pub(crate) super::tree::CstNodeId);
+impl NonTerminalHandle for ValueHandle {
+    type View = ValueView;
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn new_with_visit<F: CstFacade, E>(
+        index: CstNodeId,
+        tree: &F,
+        visit_ignored: &mut impl BuiltinTerminalVisitor<E, F>,
+    ) -> Result<Self, CstConstructError<E>> {
+        tree.collect_nodes(
+            index,
+            [NodeKind::NonTerminal(NonTerminalKind::Value)],
+            |[index], visit| Ok((Self(index), visit)),
+            visit_ignored,
+        )
+    }
+    fn kind(&self) -> NonTerminalKind {
+        NonTerminalKind::Value
+    }
+    fn get_view_with_visit<'v, F: CstFacade, V: BuiltinTerminalVisitor<E, F>, O, E>(
+        &self,
+        tree: &F,
+        mut visit: impl FnMut(Self::View, &'v mut V) -> (O, &'v mut V),
+        visit_ignored: &'v mut V,
+    ) -> Result<O, CstConstructError<E>> {
+        let mut children = tree.children(self.0);
+        let Some(child) = children.next() else {
+            return Err(ViewConstructionError::UnexpectedEndOfChildren {
+                parent: self.0,
+            });
+        };
+        let Some(child_data) = tree.node_data(child) else {
+            return Err(ViewConstructionError::NodeIdNotFound {
+                node: child,
+            });
+        };
+        let variant = match child_data.node_kind() {
             NodeKind::NonTerminal(NonTerminalKind::Object) => {
                 ValueView::Object(ObjectHandle(child))
             }
             NodeKind::NonTerminal(NonTerminalKind::Array) => {
                 ValueView::Array(ArrayHandle(child))
             }
+            NodeKind::NonTerminal(NonTerminalKind::Tuple) => {
+                ValueView::Tuple(TupleHandle(child))
+            }
             NodeKind::NonTerminal(NonTerminalKind::Integer) => {
                 ValueView::Integer(IntegerHandle(child))
             }
+            NodeKind::NonTerminal(NonTerminalKind::Float) => {
+                ValueView::Float(FloatHandle(child))
+            }
+            NodeKind::NonTerminal(NonTerminalKind::FloatName) => {
+                ValueView::FloatName(FloatNameHandle(child))
+            }
             NodeKind::NonTerminal(NonTerminalKind::Boolean) => {
                 ValueView::Boolean(BooleanHandle(child))
             }
@@ -3092,6 +3953,9 @@ impl NonTerminalHandle for ValueHandle {
             NodeKind::NonTerminal(NonTerminalKind::Code) => {
                 ValueView::Code(CodeHandle(child))
             }
+            NodeKind::NonTerminal(NonTerminalKind::Path) => {
+                ValueView::Path(PathHandle(child))
+            }
             _ => {
                 return Err(ViewConstructionError::UnexpectedNode {
                     node: child,
@@ -3113,7 +3977,10 @@ impl NonTerminalHandle for ValueHandle {
 pub enum ValueView {
     Object(ObjectHandle),
     Array(ArrayHandle),
+    Tuple(TupleHandle),
     Integer(IntegerHandle),
+    Float(FloatHandle),
+    FloatName(FloatNameHandle),
     Boolean(BooleanHandle),
     Null(NullHandle),
     StrContinues(StrContinuesHandle),
@@ -3122,6 +3989,7 @@ pub enum ValueView {
     CodeBlock(CodeBlockHandle),
     NamedCode(NamedCodeHandle),
     Code(CodeHandle),
+    Path(PathHandle),
 }
 impl ValueView {}
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -3312,6 +4180,16 @@ impl TerminalHandle for Integer {
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Float(pub(crate) super::tree::CstNodeId);
+impl TerminalHandle for Float {
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn kind(&self) -> TerminalKind {
+        TerminalKind::Float
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct True(pub(crate) super::tree::CstNodeId);
 impl TerminalHandle for True {
     fn node_id(&self) -> CstNodeId {
@@ -3512,6 +4390,26 @@ impl TerminalHandle for RBracket {
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LParen(pub(crate) super::tree::CstNodeId);
+impl TerminalHandle for LParen {
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn kind(&self) -> TerminalKind {
+        TerminalKind::LParen
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RParen(pub(crate) super::tree::CstNodeId);
+impl TerminalHandle for RParen {
+    fn node_id(&self) -> CstNodeId {
+        self.0
+    }
+    fn kind(&self) -> TerminalKind {
+        TerminalKind::RParen
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Bind(pub(crate) super::tree::CstNodeId);
 impl TerminalHandle for Bind {
     fn node_id(&self) -> CstNodeId {
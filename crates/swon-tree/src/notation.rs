@@ -0,0 +1,45 @@
+//! How strings are written in SWON, for code that writes documents as text.
+
+use std::str::FromStr;
+
+use swon_value::identifier::Identifier;
+
+/// Identifiers that are lexed as other tokens.
+const KEYWORDS: [&str; 3] = ["true", "false", "null"];
+
+/// Whether a string key can be written without quotes. Escape sequences are never identifiers.
+pub fn is_bare_key(text: &str) -> bool {
+    Identifier::from_str(text).is_ok() && !KEYWORDS.contains(&text)
+}
+
+/// Escape `text` for the inside of a quoted string.
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            '\0' => escaped.push_str("\\0"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// A string key as it is written, quoted unless it is a bare key.
+pub fn key_notation(key: &str) -> String {
+    if is_bare_key(key) {
+        key.to_string()
+    } else {
+        format!("\"{}\"", escape(key))
+    }
+}
+
+/// Whether `text` reads back the same from a text binding, which takes the rest of the line
+/// without its surrounding whitespace.
+pub fn is_text(text: &str) -> bool {
+    !text.is_empty() && !text.contains(['\n', '\r']) && text.trim() == text
+}
@@ -0,0 +1,204 @@
+use indexmap::IndexMap;
+use swon_value::{
+    identifier::Identifier,
+    value::{
+        Array, KeyCmpValue, Path, PathSegment, Tuple, Value, Variant, array_index, pop_element,
+        push_element,
+    },
+};
+
+use crate::{
+    Cst, CstNode,
+    tree::{CstNodeId, InputSpan, TerminalData},
+    value_visitor::{Extension, Values},
+};
+
+/// Location of a key or a value in the source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub node: CstNodeId,
+    /// [InputSpan::EMPTY] if the node is not from the input.
+    pub span: InputSpan,
+}
+
+impl Location {
+    /// The start of the first token of the node, after the trivia it holds. Unlike
+    /// [Location::span], this is where a key or a value is written.
+    pub fn token_start(&self, cst: &Cst) -> Option<usize> {
+        token_start(cst, self.node)
+    }
+}
+
+pub(crate) fn token_start(cst: &Cst, node: CstNodeId) -> Option<usize> {
+    match cst.node_data(node)? {
+        CstNode::Terminal { kind, data } => match data {
+            TerminalData::Input(span) if !kind.is_builtin_terminal() => Some(span.start as usize),
+            _ => None,
+        },
+        CstNode::NonTerminal { .. } => cst.children(node).find_map(|child| token_start(cst, child)),
+    }
+}
+
+/// The SWON data model with the source location of every value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpannedDocument {
+    /// The document root. The content is always a [SpannedContent::Map].
+    pub root: SpannedValue,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpannedValue {
+    /// The key that assigned this value, or that created it if it is never assigned directly.
+    /// `None` for the document root and elements of inline arrays.
+    pub key: Option<Location>,
+    /// The value, text or section that gives this value. `None` if the value is only created
+    /// implicitly by keys, like `a` in `a.b = 1`.
+    pub value: Option<Location>,
+    pub content: SpannedContent,
+    pub extensions: Vec<(Identifier, SpannedValue)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpannedContent {
+    /// Entries in the order of their first key in the source.
+    Map(IndexMap<KeyCmpValue, SpannedValue>),
+    Array(Vec<SpannedValue>),
+    Tuple(Vec<SpannedValue>),
+    /// A map with a `$variant` extension.
+    Variant(SpannedVariant),
+    Value(Value),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpannedVariant {
+    pub tag: Identifier,
+    /// The `$variant` key.
+    pub tag_location: Location,
+    /// The map without `$variant`. Extensions of the map are on the variant itself.
+    pub content: Box<SpannedValue>,
+}
+
+impl SpannedDocument {
+    /// Get the value at `path`, using the same paths as
+    /// [ExtensionMap](crate::value_visitor::ExtensionMap).
+    pub fn get(&self, path: &[PathSegment]) -> Option<&SpannedValue> {
+        self.root.get(path)
+    }
+
+    /// Drop the locations and split extensions out of the data.
+    pub fn into_values(self) -> Values {
+        let mut path = Vec::new();
+        let mut extensions = Vec::new();
+        let root = self.root.into_value(&mut path, &mut extensions);
+        Values {
+            root,
+            extensions: extensions.into_iter().collect(),
+        }
+    }
+}
+
+impl From<SpannedDocument> for Values {
+    fn from(document: SpannedDocument) -> Self {
+        document.into_values()
+    }
+}
+
+impl SpannedValue {
+    /// Get the value at `path` relative to this value.
+    pub fn get(&self, path: &[PathSegment]) -> Option<&SpannedValue> {
+        let Some((segment, rest)) = path.split_first() else {
+            return Some(self);
+        };
+        let child = match segment {
+            PathSegment::Extension(name) => self
+                .extensions
+                .iter()
+                .find(|(ext, _)| ext == name)
+                .map(|(_, value)| value)?,
+            PathSegment::Value(key) => match &self.content {
+                SpannedContent::Array(elements) => elements.get(array_index(key)?)?,
+                _ => self.entry(key)?,
+            },
+            PathSegment::TupleIndex(index) => match &self.content {
+                SpannedContent::Tuple(elements) => elements.get(*index as usize)?,
+                _ => return None,
+            },
+            PathSegment::Array { key, index } => {
+                let array = self.entry(key)?;
+                match (index, &array.content) {
+                    (None, _) => array,
+                    (Some(index), SpannedContent::Array(elements)) => {
+                        elements.get(array_index(index)?)?
+                    }
+                    _ => return None,
+                }
+            }
+        };
+        child.get(rest)
+    }
+
+    fn entry(&self, key: &Value) -> Option<&SpannedValue> {
+        let entries = match &self.content {
+            SpannedContent::Map(entries) => entries,
+            // Variants are transparent to paths
+            SpannedContent::Variant(variant) => return variant.content.entry(key),
+            _ => return None,
+        };
+        entries.get(&KeyCmpValue::try_from(key.clone()).ok()?)
+    }
+
+    fn into_value(self, path: &mut Vec<PathSegment>, extensions: &mut Vec<Extension>) -> Value {
+        for (name, node) in self.extensions {
+            path.push(PathSegment::Extension(name.clone()));
+            let value = node.into_value(path, extensions);
+            path.pop();
+            extensions.push(Extension {
+                path: Path(path.clone()),
+                name,
+                value,
+            });
+        }
+        match self.content {
+            SpannedContent::Map(entries) => Value::Map(
+                entries
+                    .into_iter()
+                    .map(|(key, node)| {
+                        path.push(PathSegment::Value(key.clone().into()));
+                        let value = node.into_value(path, extensions);
+                        path.pop();
+                        (key, value)
+                    })
+                    .collect(),
+            ),
+            SpannedContent::Array(elements) => Value::Array(Array(
+                elements
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, node)| {
+                        push_element(path, index);
+                        let value = node.into_value(path, extensions);
+                        pop_element(path);
+                        value
+                    })
+                    .collect(),
+            )),
+            SpannedContent::Tuple(elements) => Value::Tuple(Tuple(
+                elements
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, node)| {
+                        path.push(PathSegment::TupleIndex(index as u8));
+                        let value = node.into_value(path, extensions);
+                        path.pop();
+                        value
+                    })
+                    .collect(),
+            )),
+            SpannedContent::Variant(variant) => Value::Variant(Variant {
+                tag: variant.tag.to_string(),
+                content: Box::new(variant.content.into_value(path, extensions)),
+            }),
+            SpannedContent::Value(value) => value,
+        }
+    }
+}
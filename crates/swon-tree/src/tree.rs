@@ -1,5 +1,6 @@
 mod span;
 
+use ahash::{AHashMap, AHashSet};
 use petgraph::{
     Direction,
     graph::{DiGraph, NodeIndex},
@@ -15,6 +16,8 @@ use crate::{
     common_visitors::{FormatVisitor, FormatVisitorError, InspectVisitor},
     node_kind::{NodeKind, NonTerminalKind, TerminalKind},
     nodes::{BlockComment, LineComment, NewLine, RootHandle, Whitespace},
+    spanned::SpannedDocument,
+    value_visitor::{ValueVisitor, ValueVisitorError, Values},
     visitor::{BuiltinTerminalVisitor, CstVisitor, CstVisitorSuper as _},
 };
 
@@ -52,6 +55,9 @@ impl<T, Nt> CstNodeData<T, Nt> {
 pub enum TerminalData {
     Input(InputSpan),
     Dynamic(DynamicTokenId),
+    /// Input skipped by error recovery. These terminals have the [TerminalKind::Whitespace]
+    /// kind, so views and visitors pass over them as trivia.
+    Error(InputSpan),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -124,15 +130,73 @@ pub struct ConcreteSyntaxTree<T, Nt> {
     dynamic_tokens: BTreeMap<DynamicTokenId, String>,
     next_dynamic_token_id: u32,
     root: CstNodeId,
+    /// Removed nodes, reused by [Self::add_node].
+    free: Vec<CstNodeId>,
+    /// Error terminals, so that [Self::has_errors] doesn't walk the tree.
+    errors: AHashSet<CstNodeId>,
+    /// The offsets of the subtrees moved by [Self::shift_subtree], indexed by anchor. The spans
+    /// of their nodes are stored relative to these. Anchor 0 is for the other nodes.
+    offsets: Vec<i64>,
+    /// The anchor of each node by index. Nodes past the end have anchor 0.
+    anchors: Vec<u32>,
+    /// The roots of the subtrees that have an anchor of their own.
+    anchored: AHashMap<CstNodeId, u32>,
+}
+
+fn span_mut<T, Nt>(data: &mut CstNodeData<T, Nt>) -> Option<&mut InputSpan> {
+    match data {
+        CstNodeData::Terminal {
+            data: TerminalData::Input(span) | TerminalData::Error(span),
+            ..
+        }
+        | CstNodeData::NonTerminal {
+            data: NonTerminalData::Input(span),
+            ..
+        } => Some(span),
+        _ => None,
+    }
+}
+
+/// Move the span of `data` by `delta`, except the empty span of a node without input.
+fn shift<T, Nt>(data: &mut CstNodeData<T, Nt>, delta: i64) {
+    if let Some(span) = span_mut(data)
+        && *span != InputSpan::EMPTY
+    {
+        let moved = |offset: u32| (offset as i64 + delta) as u32;
+        *span = InputSpan {
+            start: moved(span.start),
+            end: moved(span.end),
+        };
+    }
+}
+
+fn is_error<T, Nt>(data: &CstNodeData<T, Nt>) -> bool {
+    matches!(
+        data,
+        CstNodeData::Terminal {
+            data: TerminalData::Error(_),
+            ..
+        }
+    )
 }
 
 impl<T, Nt> ConcreteSyntaxTree<T, Nt> {
     pub fn new(root: CstNodeId, graph: DiGraph<Option<CstNodeData<T, Nt>>, ()>) -> Self {
+        let errors = graph
+            .node_indices()
+            .filter(|&node| graph[node].as_ref().is_some_and(is_error))
+            .map(CstNodeId)
+            .collect();
         Self {
             graph,
             root,
             dynamic_tokens: BTreeMap::new(),
             next_dynamic_token_id: 0,
+            free: Vec::new(),
+            errors,
+            offsets: vec![0],
+            anchors: Vec::new(),
+            anchored: AHashMap::new(),
         }
     }
 
@@ -149,12 +213,102 @@ impl<T, Nt> ConcreteSyntaxTree<T, Nt> {
         {
             self.graph.remove_edge(edge);
         }
-        self.graph.add_edge(parent.0, id.0, ());
+        self.add_edge(parent, id);
     }
 
     pub fn add_node(&mut self, data: CstNodeData<T, Nt>) -> CstNodeId {
-        let node = self.graph.add_node(Some(data));
-        CstNodeId(node)
+        let error = is_error(&data);
+        let node = match self.free.pop() {
+            Some(node) => {
+                self.graph[node.0] = Some(data);
+                node
+            }
+            None => CstNodeId(self.graph.add_node(Some(data))),
+        };
+        if error {
+            self.errors.insert(node);
+        }
+        node
+    }
+
+    /// Remove `id` and its descendants from the tree. Their ids are reused for new nodes.
+    pub fn remove_subtree(&mut self, id: CstNodeId) {
+        if let Some(parent) = self.parent(id) {
+            let children = self.children(parent).filter(|&child| child != id);
+            let children = children.collect::<Vec<_>>();
+            self.update_children(parent, children);
+        }
+        let mut stack = vec![id];
+        while let Some(node) = stack.pop() {
+            stack.extend(self.children(node));
+            self.update_children(node, []);
+            self.graph[node.0] = None;
+            self.errors.remove(&node);
+            self.anchored.remove(&node);
+            self.set_anchor(node, 0);
+            self.free.push(node);
+        }
+    }
+
+    /// Whether the tree holds terminals of input skipped by error recovery.
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+
+    /// The number of nodes in the arena, including removed nodes that are not reused yet.
+    pub fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    /// Move the spans of `id` and its descendants by `delta`. The first shift gives the subtree
+    /// an offset of its own, and later shifts only change that offset.
+    pub fn shift_subtree(&mut self, id: CstNodeId, delta: i64) {
+        let anchor = match self.anchored.get(&id) {
+            Some(&anchor) => anchor,
+            None => {
+                let anchor = self.offsets.len() as u32;
+                self.offsets.push(0);
+                self.anchor_subtree(id, anchor);
+                self.anchored.insert(id, anchor);
+                anchor
+            }
+        };
+        self.offsets[anchor as usize] += delta;
+    }
+
+    fn anchor(&self, id: CstNodeId) -> u32 {
+        self.anchors.get(id.0.index()).copied().unwrap_or(0)
+    }
+
+    fn offset(&self, id: CstNodeId) -> i64 {
+        self.offsets[self.anchor(id) as usize]
+    }
+
+    fn set_anchor(&mut self, id: CstNodeId, anchor: u32) {
+        let index = id.0.index();
+        if index >= self.anchors.len() {
+            if anchor == 0 {
+                return;
+            }
+            self.anchors.resize(index + 1, 0);
+        }
+        self.anchors[index] = anchor;
+    }
+
+    /// Store the spans of the subtree of `id` relative to the offset of `anchor`.
+    fn anchor_subtree(&mut self, id: CstNodeId, anchor: u32) {
+        let offset = self.offsets[anchor as usize];
+        let mut stack = vec![id];
+        while let Some(node) = stack.pop() {
+            stack.extend(self.children(node));
+            let delta = self.offset(node) - offset;
+            if let Some(Some(data)) = self.graph.node_weight_mut(node.0) {
+                shift(data, delta);
+            }
+            // A subtree inside is moved with this one from now on
+            self.anchored.remove(&node);
+            self.set_anchor(node, anchor);
+        }
     }
 
     pub fn add_node_with_parent(
@@ -163,11 +317,16 @@ impl<T, Nt> ConcreteSyntaxTree<T, Nt> {
         parent: CstNodeId,
     ) -> CstNodeId {
         let node = self.add_node(data);
-        self.graph.add_edge(parent.0, node.0, ());
+        self.add_edge(parent, node);
         node
     }
 
     pub fn add_edge(&mut self, from: CstNodeId, to: CstNodeId) {
+        // A node added to a shifted subtree moves with it
+        let anchor = self.anchor(from);
+        if anchor != 0 && self.anchor(to) != anchor {
+            self.anchor_subtree(to, anchor);
+        }
         self.graph.add_edge(from.0, to.0, ());
     }
 
@@ -197,7 +356,7 @@ impl<T, Nt> ConcreteSyntaxTree<T, Nt> {
         input: &'b str,
     ) -> Option<&'c str> {
         match terminal {
-            TerminalData::Input(span) => Some(span.as_str(input)),
+            TerminalData::Input(span) | TerminalData::Error(span) => Some(span.as_str(input)),
             TerminalData::Dynamic(id) => self.dynamic_token(id),
         }
     }
@@ -209,29 +368,42 @@ impl<T, Nt> ConcreteSyntaxTree<T, Nt> {
     pub fn update_node(
         &mut self,
         id: CstNodeId,
-        data: CstNodeData<T, Nt>,
+        mut data: CstNodeData<T, Nt>,
     ) -> Option<CstNodeData<T, Nt>> {
-        if let Some(Some(node_data)) = self.graph.node_weight_mut(id.0) {
-            Some(std::mem::replace(node_data, data))
+        let offset = self.offset(id);
+        let error = is_error(&data);
+        shift(&mut data, -offset);
+        let Some(Some(node_data)) = self.graph.node_weight_mut(id.0) else {
+            return None;
+        };
+        let mut previous = std::mem::replace(node_data, data);
+        shift(&mut previous, offset);
+        if error {
+            self.errors.insert(id);
         } else {
-            None
+            self.errors.remove(&id);
         }
+        Some(previous)
     }
 
+    /// Replace the children of `id`, in the given order.
     pub fn update_children(
         &mut self,
         id: CstNodeId,
         children: impl IntoIterator<Item = CstNodeId>,
     ) {
-        for edge in self
+        // Removing an edge moves the last edge to its index, so edge ids can't be collected first
+        while let Some(edge) = self
             .graph
             .edges_directed(id.0, Direction::Outgoing)
+            .next()
             .map(|edge| edge.id())
-            .collect::<Vec<_>>()
         {
             self.graph.remove_edge(edge);
         }
-        for child in children {
+        // Children are iterated from the last added edge
+        let children = children.into_iter().collect::<Vec<_>>();
+        for child in children.into_iter().rev() {
             self.add_edge(id, child);
         }
     }
@@ -250,7 +422,9 @@ where
     Nt: Copy,
 {
     pub fn node_data(&self, node: CstNodeId) -> Option<CstNodeData<T, Nt>> {
-        self.graph.node_weight(node.0).copied().flatten()
+        let mut data = self.graph.node_weight(node.0).copied().flatten()?;
+        shift(&mut data, self.offset(node));
+        Some(data)
     }
 
     /// Delete a node but keep its children
@@ -482,6 +656,20 @@ impl ConcreteSyntaxTree<TerminalKind, NonTerminalKind> {
         Ok(())
     }
 
+    /// Lower this tree into the SWON data model.
+    pub fn values(&self, input: &str) -> Result<Values, ValueVisitorError> {
+        let mut visitor = ValueVisitor::new(input);
+        visitor.visit_root_handle(self.root_handle(), self)?;
+        visitor.finish()
+    }
+
+    /// Lower this tree into the SWON data model, keeping the location of every value.
+    pub fn spanned_values(&self, input: &str) -> Result<SpannedDocument, ValueVisitorError> {
+        let mut visitor = ValueVisitor::new(input);
+        visitor.visit_root_handle(self.root_handle(), self)?;
+        visitor.finish_spanned()
+    }
+
     pub fn visit_from_root<V: CstVisitor<Self>>(&self, visitor: &mut V) -> Result<(), V::Error> {
         visitor.visit_root_handle(self.root_handle(), self)
     }
@@ -1,78 +1,1208 @@
+use std::{
+    hash::{DefaultHasher, Hash as _, Hasher as _},
+    mem,
+};
+
 use ahash::AHashMap;
-use swon_value::{identifier::Identifier, value::PathSegment};
+use indexmap::IndexMap;
+use swon_value::{
+    identifier::{Identifier, IdentifierError},
+    value::{Code, KeyCmpValue, Path, PathError, PathSegment, TypedString, Value, resolve_path},
+};
 use thiserror::Error;
 
-use crate::{prelude::*, tree::CstFacade};
+use crate::{
+    prelude::*,
+    spanned::{
+        Location, SpannedContent, SpannedDocument, SpannedValue, SpannedVariant, token_start,
+    },
+    tree::{CstFacade, InputSpan, NonTerminalHandle, RecursiveView as _, TerminalHandle},
+};
 
+/// The SWON data model lowered from a [Cst](crate::Cst).
+#[derive(Debug, Clone, PartialEq)]
 pub struct Values {
-    ident_handles: AHashMap<IdentHandle, Identifier>,
-    key_handles: AHashMap<KeyHandle, PathSegment>,
-    keys_handles: AHashMap<KeysHandle, Vec<KeyHandle>>,
+    /// The document root. This is always a [Value::Map].
+    pub root: Value,
+    /// Extensions found in the document, which are not part of the data.
+    pub extensions: ExtensionMap,
+}
+
+impl Values {
+    /// Evaluate `path` against the document. Unlike [Path::resolve], extension segments are
+    /// looked up in [Values::extensions].
+    pub fn resolve(&self, path: &Path) -> Result<&Value, PathError> {
+        let mut current = &self.root;
+        let mut start = 0;
+        for (index, segment) in path.0.iter().enumerate() {
+            let PathSegment::Extension(name) = segment else {
+                continue;
+            };
+            current = self
+                .extensions
+                .get(&path.0[..index], name.as_ref())
+                .ok_or(PathError::NotFound { index })?;
+            start = index + 1;
+        }
+        resolve_path(current, &path.0[start..]).map_err(|error| error.offset(start))
+    }
+}
+
+/// Extension values keyed by the path of the value they are attached to.
+#[derive(Debug, Clone, Default)]
+pub struct ExtensionMap {
+    extensions: Vec<Extension>,
+    /// Indices into `extensions` by the hash of their path.
+    index: AHashMap<u64, Vec<usize>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Extension {
+    /// Path of the value this extension is attached to. Empty for the document root.
+    pub path: Path,
+    pub name: Identifier,
+    pub value: Value,
+}
+
+impl ExtensionMap {
+    /// Get the value of the extension `name` attached to the value at `path`.
+    pub fn get(&self, path: &[PathSegment], name: &str) -> Option<&Value> {
+        self.at(path)
+            .find(|extension| extension.name.as_ref() == name)
+            .map(|extension| &extension.value)
+    }
+
+    /// Iterate over the extensions attached to the value at `path`.
+    pub fn at<'a>(&'a self, path: &[PathSegment]) -> impl Iterator<Item = &'a Extension> {
+        self.index
+            .get(&path_hash(path))
+            .into_iter()
+            .flatten()
+            .map(|&index| &self.extensions[index])
+            .filter(move |extension| extension.path.0 == path)
+    }
+
+    /// Iterate over all extensions, in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = &Extension> {
+        self.extensions.iter()
+    }
+
+    pub fn push(&mut self, extension: Extension) {
+        self.index
+            .entry(path_hash(&extension.path.0))
+            .or_default()
+            .push(self.extensions.len());
+        self.extensions.push(extension);
+    }
+}
+
+impl PartialEq for ExtensionMap {
+    fn eq(&self, other: &Self) -> bool {
+        self.extensions == other.extensions
+    }
 }
 
+impl FromIterator<Extension> for ExtensionMap {
+    fn from_iter<I: IntoIterator<Item = Extension>>(iter: I) -> Self {
+        let mut map = Self::default();
+        for extension in iter {
+            map.push(extension);
+        }
+        map
+    }
+}
+
+/// A hash of `path` that agrees with its `PartialEq`. Only the values that can be keys are
+/// hashed by their content.
+fn path_hash(path: &[PathSegment]) -> u64 {
+    fn value(value: &Value, hasher: &mut DefaultHasher) {
+        mem::discriminant(value).hash(hasher);
+        match value {
+            Value::Bool(value) => value.hash(hasher),
+            Value::I64(value) => value.hash(hasher),
+            Value::U64(value) => value.hash(hasher),
+            Value::String(value) => value.hash(hasher),
+            _ => {}
+        }
+    }
+    let mut hasher = DefaultHasher::new();
+    for segment in path {
+        mem::discriminant(segment).hash(&mut hasher);
+        match segment {
+            PathSegment::Extension(name) => name.as_ref().hash(&mut hasher),
+            PathSegment::Value(key) => value(key, &mut hasher),
+            PathSegment::TupleIndex(index) => index.hash(&mut hasher),
+            PathSegment::Array { key, index } => {
+                value(key, &mut hasher);
+                if let Some(index) = index {
+                    value(index, &mut hasher);
+                }
+            }
+        }
+    }
+    hasher.finish()
+}
+
+/// Lowers a [Cst](crate::Cst) into [Values].
 pub struct ValueVisitor<'a> {
     input: &'a str,
-    values: &'a mut Values,
-    current_keys: Vec<KeyHandle>,
+    root: Node,
+    /// Resolved paths that bindings are relative to. The last one is the innermost.
+    scopes: Vec<Vec<Step>>,
 }
 
 #[derive(Debug, Error)]
 pub enum ValueVisitorError {
     #[error(transparent)]
     CstError(#[from] CstConstructError),
+    #[error("Dynamic token not found: {id:?}")]
+    DynamicTokenNotFound { id: DynamicTokenId },
+    #[error("Invalid identifier: {source}")]
+    InvalidIdentifier {
+        node: CstNodeId,
+        #[source]
+        source: IdentifierError,
+    },
+    #[error("Integer out of range: {text}")]
+    IntegerOutOfRange { node: CstNodeId, text: String },
+    #[error("Invalid float: {text}")]
+    InvalidFloat { node: CstNodeId, text: String },
+    /// An identifier as a value, which can only be `inf` or `nan`.
+    #[error("Unknown value: {text}, expected `inf` or `nan`")]
+    UnknownFloatName { node: CstNodeId, text: String },
+    /// Only floats of the form `0.1` are allowed in keys, which are read as two tuple indices.
+    #[error("Float cannot be used as a key: {text}")]
+    FloatKey { node: CstNodeId, text: String },
+    #[error("Holes cannot be converted to a value")]
+    Hole { node: CstNodeId },
+    /// The same key is assigned twice.
+    #[error("Key is already assigned")]
+    DuplicateKey {
+        original: Location,
+        conflicting: Location,
+    },
+    /// A key is used as a different kind of value than where it was defined, or a key goes into
+    /// an already assigned value.
+    #[error("Key conflicts with the previous definition")]
+    ConflictingKey {
+        original: Location,
+        conflicting: Location,
+    },
+    /// `[n]` is used before the elements preceding it exist.
+    #[error("Array index {index} is out of order, the array has {len} elements")]
+    ArrayIndexOutOfOrder {
+        /// Where the array was defined. `None` if it does not exist yet.
+        original: Option<Location>,
+        conflicting: Location,
+        index: usize,
+        len: usize,
+    },
+    /// A tuple index like `.1` is used before the elements preceding it exist.
+    #[error("Tuple index {index} is out of order, the tuple has {len} elements")]
+    TupleIndexOutOfOrder {
+        /// Where the tuple was defined. `None` if it does not exist yet.
+        original: Option<Location>,
+        conflicting: Location,
+        index: u8,
+        len: usize,
+    },
+    /// An integer key is a tuple index, which must fit in `u8`.
+    #[error("Tuple index must be between 0 and 255: {text}")]
+    TupleIndexOutOfRange { location: Location, text: String },
+    /// An integer key directly under the document root, which is always a map.
+    #[error("Tuple index is not allowed on the document root")]
+    TupleIndexOnRoot { location: Location },
+    #[error("Tuple has more than 256 elements")]
+    TooManyTupleElements { node: CstNodeId },
+    /// The tag of `$variant` must be an identifier.
+    #[error("Variant tag must be an identifier")]
+    InvalidVariantTag { location: Location },
+    /// `$variant` is only allowed on a map other than the document root.
+    #[error("Variant is not allowed here")]
+    VariantNotAllowed { location: Location },
+    /// `[]` appends to an array, so it cannot point to a value.
+    #[error("`[]` cannot be used in a path")]
+    ArrayPushInPath { node: CstNodeId },
 }
 
-impl<'a, F: CstFacade> CstVisitor<F> for ValueVisitor<'a> {
-    type Error = ValueVisitorError;
+impl ValueVisitorError {
+    /// The node of the key or the value the error is about, like the second of duplicate keys.
+    /// `None` for errors of the tree itself.
+    pub fn node(&self) -> Option<CstNodeId> {
+        match self {
+            ValueVisitorError::CstError(_) | ValueVisitorError::DynamicTokenNotFound { .. } => None,
+            ValueVisitorError::InvalidIdentifier { node, .. }
+            | ValueVisitorError::IntegerOutOfRange { node, .. }
+            | ValueVisitorError::InvalidFloat { node, .. }
+            | ValueVisitorError::UnknownFloatName { node, .. }
+            | ValueVisitorError::FloatKey { node, .. }
+            | ValueVisitorError::Hole { node }
+            | ValueVisitorError::TooManyTupleElements { node }
+            | ValueVisitorError::ArrayPushInPath { node } => Some(*node),
+            ValueVisitorError::DuplicateKey { conflicting, .. }
+            | ValueVisitorError::ConflictingKey { conflicting, .. }
+            | ValueVisitorError::ArrayIndexOutOfOrder { conflicting, .. }
+            | ValueVisitorError::TupleIndexOutOfOrder { conflicting, .. } => Some(conflicting.node),
+            ValueVisitorError::TupleIndexOutOfRange { location, .. }
+            | ValueVisitorError::TupleIndexOnRoot { location }
+            | ValueVisitorError::InvalidVariantTag { location }
+            | ValueVisitorError::VariantNotAllowed { location } => Some(location.node),
+        }
+    }
 
-    fn visit_keys(
-        &mut self,
+    /// The start of the first token of [Self::node] in the input.
+    pub fn token_start(&self, cst: &Cst) -> Option<usize> {
+        token_start(cst, self.node()?)
+    }
+}
+
+impl<'a> ValueVisitor<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            root: Node {
+                content: Content::Map(MapNode::default()),
+                ..Default::default()
+            },
+            scopes: vec![vec![]],
+        }
+    }
+
+    /// Finish lowering and returns the collected values.
+    pub fn finish(self) -> Result<Values, ValueVisitorError> {
+        Ok(self.finish_spanned()?.into_values())
+    }
+
+    /// Finish lowering and returns the collected values with their locations.
+    pub fn finish_spanned(self) -> Result<SpannedDocument, ValueVisitorError> {
+        // The root is always a map
+        if let Some(variant) = self.root.extension_node(VARIANT) {
+            return Err(ValueVisitorError::VariantNotAllowed {
+                location: variant.defined_at(),
+            });
+        }
+        Ok(SpannedDocument {
+            root: self.root.into_spanned()?,
+        })
+    }
+
+    fn location<F: CstFacade>(
+        &self,
+        handle: impl NonTerminalHandle,
+        tree: &F,
+    ) -> Result<Location, ValueVisitorError> {
+        let span = match tree.get_non_terminal(handle.node_id(), handle.kind())? {
+            NonTerminalData::Input(span) => span,
+            NonTerminalData::Dynamic => InputSpan::EMPTY,
+        };
+        Ok(Location {
+            node: handle.node_id(),
+            span,
+        })
+    }
+
+    fn terminal_text<F: CstFacade>(
+        &self,
+        terminal: impl TerminalHandle,
+        tree: &F,
+    ) -> Result<String, ValueVisitorError> {
+        match terminal.get_data(tree)? {
+            TerminalData::Input(span) | TerminalData::Error(span) => {
+                Ok(span.as_str(self.input).to_string())
+            }
+            TerminalData::Dynamic(id) => tree
+                .dynamic_token(id)
+                .map(str::to_string)
+                .ok_or(ValueVisitorError::DynamicTokenNotFound { id }),
+        }
+    }
+
+    fn ident<F: CstFacade>(
+        &self,
+        handle: IdentHandle,
+        tree: &F,
+    ) -> Result<Identifier, ValueVisitorError> {
+        let view = handle.get_view(tree)?;
+        let text = self.terminal_text(view.ident, tree)?;
+        text.parse()
+            .map_err(|source| ValueVisitorError::InvalidIdentifier {
+                node: handle.node_id(),
+                source,
+            })
+    }
+
+    fn integer<F: CstFacade>(
+        &self,
+        handle: IntegerHandle,
+        tree: &F,
+    ) -> Result<KeyCmpValue, ValueVisitorError> {
+        let view = handle.get_view(tree)?;
+        let text = self.terminal_text(view.integer, tree)?;
+        parse_integer(&text).ok_or(ValueVisitorError::IntegerOutOfRange {
+            node: handle.node_id(),
+            text,
+        })
+    }
+
+    fn float<F: CstFacade>(&self, handle: FloatHandle, tree: &F) -> Result<f64, ValueVisitorError> {
+        let view = handle.get_view(tree)?;
+        let text = self.terminal_text(view.float, tree)?;
+        text.replace('_', "")
+            .parse()
+            .map_err(|_| ValueVisitorError::InvalidFloat {
+                node: handle.node_id(),
+                text,
+            })
+    }
+
+    /// `inf` or `nan` written without a sign.
+    fn float_name<F: CstFacade>(
+        &self,
+        handle: FloatNameHandle,
+        tree: &F,
+    ) -> Result<f64, ValueVisitorError> {
+        let ident = handle.get_view(tree)?.ident;
+        let text = self.terminal_text(ident.get_view(tree)?.ident, tree)?;
+        match text.as_str() {
+            "inf" => Ok(f64::INFINITY),
+            "nan" => Ok(f64::NAN),
+            _ => Err(ValueVisitorError::UnknownFloatName {
+                node: handle.node_id(),
+                text,
+            }),
+        }
+    }
+
+    /// The content of a string, which is empty without `InStr`.
+    fn in_str<F: CstFacade>(
+        &self,
+        handle: Option<InStrHandle>,
+        tree: &F,
+    ) -> Result<String, ValueVisitorError> {
+        let Some(handle) = handle else {
+            return Ok(String::new());
+        };
+        let view = handle.get_view(tree)?;
+        Ok(unescape(&self.terminal_text(view.in_str, tree)?))
+    }
+
+    fn str<F: CstFacade>(&self, handle: StrHandle, tree: &F) -> Result<String, ValueVisitorError> {
+        let in_str = handle.get_view(tree)?.str_opt.get_view(tree)?;
+        self.in_str(in_str, tree)
+    }
+
+    fn keys<F: CstFacade>(
+        &self,
         handle: KeysHandle,
-        view: KeysView,
         tree: &F,
-    ) -> Result<(), Self::Error> {
-        assert_eq!(self.current_keys.len(), 0);
-        self.visit_keys_super(handle, view, tree)?;
-        self.values
-            .keys_handles
-            .insert(handle, std::mem::take(&mut self.current_keys));
-        Ok(())
+    ) -> Result<Vec<KeySegment>, ValueVisitorError> {
+        let view = handle.get_view(tree)?;
+        let mut keys = self.key(view.key, tree)?;
+        if let Some(list) = view.keys_list.get_view(tree)? {
+            for item in list.get_all(tree)? {
+                keys.extend(self.key(item.key, tree)?);
+            }
+        }
+        Ok(keys)
     }
 
-    fn visit_key(&mut self, handle: KeyHandle, view: KeyView, tree: &F) -> Result<(), Self::Error> {
-        self.visit_key_super(handle, view, tree)?;
-        Ok(())
+    /// Usually one segment, but a float key like `0.1` gives two.
+    fn key<F: CstFacade>(
+        &self,
+        handle: KeyHandle,
+        tree: &F,
+    ) -> Result<Vec<KeySegment>, ValueVisitorError> {
+        let view = handle.get_view(tree)?;
+        let location = self.location(handle, tree)?;
+        let mut keys = Vec::new();
+        let base = match view.key_base.get_view(tree)? {
+            KeyBaseView::Ident(ident) => {
+                KeyBase::Key(KeyCmpValue::String(self.ident(ident, tree)?.to_string()))
+            }
+            KeyBaseView::ExtensionNameSpace(ext) => {
+                KeyBase::Extension(self.ident(ext.get_view(tree)?.ident, tree)?)
+            }
+            KeyBaseView::Str(str) => KeyBase::Key(KeyCmpValue::String(self.str(str, tree)?)),
+            KeyBaseView::Integer(integer) => {
+                let text = self.terminal_text(integer.get_view(tree)?.integer, tree)?;
+                KeyBase::TupleIndex(tuple_index(text, location)?)
+            }
+            KeyBaseView::Float(float) => {
+                let text = self.terminal_text(float.get_view(tree)?.float, tree)?;
+                let Some((first, second)) = text
+                    .split_once('.')
+                    .filter(|(first, second)| is_digits(first) && is_digits(second))
+                else {
+                    return Err(ValueVisitorError::FloatKey {
+                        node: float.node_id(),
+                        text,
+                    });
+                };
+                keys.push(KeySegment {
+                    location,
+                    base: KeyBase::TupleIndex(tuple_index(first.to_string(), location)?),
+                    array: None,
+                });
+                KeyBase::TupleIndex(tuple_index(second.to_string(), location)?)
+            }
+        };
+        let array = match view.key_opt.get_view(tree)? {
+            Some(marker) => Some(self.array_marker(marker, tree)?),
+            None => None,
+        };
+        keys.push(KeySegment {
+            location,
+            base,
+            array,
+        });
+        Ok(keys)
+    }
+
+    fn array_marker<F: CstFacade>(
+        &self,
+        handle: ArrayMarkerHandle,
+        tree: &F,
+    ) -> Result<ArrayMarker, ValueVisitorError> {
+        let Some(index) = handle.get_view(tree)?.array_marker_opt.get_view(tree)? else {
+            return Ok(ArrayMarker::Push);
+        };
+        let value = match self.integer(index, tree)? {
+            KeyCmpValue::I64(value) => usize::try_from(value).ok(),
+            KeyCmpValue::U64(value) => usize::try_from(value).ok(),
+            _ => None,
+        };
+        let Some(value) = value else {
+            return Err(ValueVisitorError::IntegerOutOfRange {
+                node: index.node_id(),
+                text: self.terminal_text(index.get_view(tree)?.integer, tree)?,
+            });
+        };
+        Ok(ArrayMarker::Index(value))
+    }
+
+    fn path<F: CstFacade>(&self, handle: PathHandle, tree: &F) -> Result<Path, ValueVisitorError> {
+        let view = handle.get_view(tree)?;
+        let mut keys = Vec::new();
+        let mut segments = Vec::new();
+        match view.path_head.get_view(tree)? {
+            PathHeadView::Key(key) => keys.extend(self.key(key, tree)?),
+            PathHeadView::ArrayMarker(marker) => match self.array_marker(marker, tree)? {
+                ArrayMarker::Index(index) => {
+                    segments.push(PathSegment::Value(Value::U64(index as u64)))
+                }
+                ArrayMarker::Push => {
+                    return Err(ValueVisitorError::ArrayPushInPath {
+                        node: marker.node_id(),
+                    });
+                }
+            },
+        }
+        if let Some(list) = view.path_list.get_view(tree)? {
+            for item in list.get_all(tree)? {
+                keys.extend(self.key(item.key, tree)?);
+            }
+        }
+        for key in keys {
+            let index = match key.array {
+                Some(ArrayMarker::Index(index)) => Some(Value::U64(index as u64)),
+                Some(ArrayMarker::Push) => {
+                    return Err(ValueVisitorError::ArrayPushInPath {
+                        node: key.location.node,
+                    });
+                }
+                None => None,
+            };
+            match (key.base, index) {
+                (KeyBase::Key(key), None) => segments.push(PathSegment::Value(key.into())),
+                (KeyBase::Key(key), index) => segments.push(PathSegment::Array {
+                    key: key.into(),
+                    index,
+                }),
+                (KeyBase::Extension(name), index) => {
+                    segments.push(PathSegment::Extension(name));
+                    segments.extend(index.map(PathSegment::Value));
+                }
+                (KeyBase::TupleIndex(tuple_index), index) => {
+                    segments.push(PathSegment::TupleIndex(tuple_index));
+                    segments.extend(index.map(PathSegment::Value));
+                }
+            }
+        }
+        Ok(Path(segments))
+    }
+
+    fn elements<F: CstFacade>(
+        &self,
+        mut handle: Option<ArrayElementsHandle>,
+        tree: &F,
+    ) -> Result<Vec<Node>, ValueVisitorError> {
+        let mut elements = Vec::new();
+        while let Some(current) = handle {
+            let view = current.get_view(tree)?;
+            elements.push(self.value(view.value, tree)?);
+            handle = match view.array_elements_opt.get_view(tree)? {
+                Some(tail) => tail
+                    .get_view(tree)?
+                    .array_elements_tail_opt
+                    .get_view(tree)?,
+                None => None,
+            };
+        }
+        Ok(elements)
+    }
+
+    fn tuple_elements<F: CstFacade>(
+        &self,
+        mut handle: Option<TupleElementsHandle>,
+        tree: &F,
+    ) -> Result<Vec<Node>, ValueVisitorError> {
+        let mut elements = Vec::new();
+        while let Some(current) = handle {
+            let view = current.get_view(tree)?;
+            elements.push(self.value(view.value, tree)?);
+            handle = match view.tuple_elements_opt.get_view(tree)? {
+                Some(tail) => tail
+                    .get_view(tree)?
+                    .tuple_elements_tail_opt
+                    .get_view(tree)?,
+                None => None,
+            };
+        }
+        Ok(elements)
     }
 
-    fn visit_key_base(
+    fn value<F: CstFacade>(
+        &self,
+        handle: ValueHandle,
+        tree: &F,
+    ) -> Result<Node, ValueVisitorError> {
+        let location = Some(self.location(handle, tree)?);
+        let value = match handle.get_view(tree)? {
+            ValueView::Object(object) => {
+                let mut node = Node {
+                    content: Content::Map(MapNode::default()),
+                    value: location,
+                    ..Default::default()
+                };
+                if let Some(list) = object.get_view(tree)?.object_list.get_view(tree)? {
+                    for item in list.get_all(tree)? {
+                        let keys = self.key(item.key, tree)?;
+                        let value = self.value(item.value, tree)?;
+                        node.resolve(&[], &keys)?
+                            .1
+                            .assign(last_location(&keys), value)?;
+                    }
+                }
+                return Ok(node);
+            }
+            ValueView::Array(array) => {
+                let elements =
+                    self.elements(array.get_view(tree)?.array_opt.get_view(tree)?, tree)?;
+                return Ok(Node {
+                    content: Content::Array(elements),
+                    value: location,
+                    ..Default::default()
+                });
+            }
+            ValueView::Tuple(tuple) => {
+                let elements =
+                    self.tuple_elements(tuple.get_view(tree)?.tuple_opt.get_view(tree)?, tree)?;
+                if elements.len() > u8::MAX as usize + 1 {
+                    return Err(ValueVisitorError::TooManyTupleElements {
+                        node: tuple.node_id(),
+                    });
+                }
+                // `()` is the unit value
+                let content = if elements.is_empty() {
+                    Content::Value(Value::Unit)
+                } else {
+                    Content::Tuple(elements)
+                };
+                return Ok(Node {
+                    content,
+                    value: location,
+                    ..Default::default()
+                });
+            }
+            ValueView::Integer(integer) => self.integer(integer, tree)?.into(),
+            ValueView::Float(float) => Value::F64(self.float(float, tree)?),
+            ValueView::FloatName(name) => Value::F64(self.float_name(name, tree)?),
+            ValueView::Path(path) => Value::Path(self.path(path, tree)?),
+            ValueView::Boolean(boolean) => match boolean.get_view(tree)? {
+                BooleanView::True(_) => Value::Bool(true),
+                BooleanView::False(_) => Value::Bool(false),
+            },
+            ValueView::Null(_) => Value::Null,
+            ValueView::StrContinues(strs) => {
+                let view = strs.get_view(tree)?;
+                let mut string = self.str(view.str, tree)?;
+                if let Some(list) = view.str_continues_list.get_view(tree)? {
+                    for item in list.get_all(tree)? {
+                        string.push_str(&self.str(item.str, tree)?);
+                    }
+                }
+                Value::String(string)
+            }
+            ValueView::TypedStr(typed_str) => {
+                let view = typed_str.get_view(tree)?;
+                let quote =
+                    self.terminal_text(view.typed_quote.get_view(tree)?.typed_quote, tree)?;
+                Value::TypedString(TypedString {
+                    type_name: quote.trim_end_matches('"').to_string(),
+                    value: self.in_str(view.typed_str_opt.get_view(tree)?, tree)?,
+                })
+            }
+            ValueView::Hole(hole) => {
+                return Err(ValueVisitorError::Hole {
+                    node: hole.node_id(),
+                });
+            }
+            ValueView::CodeBlock(code_block) => {
+                let text = self.terminal_text(code_block.get_view(tree)?.code_block, tree)?;
+                Value::Code(code_block_value(&text))
+            }
+            ValueView::NamedCode(named_code) => {
+                let text = self.terminal_text(named_code.get_view(tree)?.named_code, tree)?;
+                let (language, code) = text.split_once('`').unwrap_or_default();
+                Value::Code(Code {
+                    language: language.to_string(),
+                    content: inline_code_content(code),
+                })
+            }
+            ValueView::Code(code) => {
+                let text = self.terminal_text(code.get_view(tree)?.code, tree)?;
+                Value::Code(Code {
+                    language: String::new(),
+                    content: inline_code_content(text.trim_start_matches('`')),
+                })
+            }
+        };
+        Ok(Node {
+            content: Content::Value(value),
+            value: location,
+            ..Default::default()
+        })
+    }
+
+    fn scope(&self) -> &[Step] {
+        self.scopes.last().map(Vec::as_slice).unwrap_or_default()
+    }
+}
+
+impl<F: CstFacade> CstVisitor<F> for ValueVisitor<'_> {
+    type Error = ValueVisitorError;
+
+    fn visit_binding(
         &mut self,
-        handle: KeyBaseHandle,
-        view: KeyBaseView,
+        _handle: BindingHandle,
+        view: BindingView,
         tree: &F,
     ) -> Result<(), Self::Error> {
-        self.visit_key_base_super(handle, view, tree)?;
+        let keys = self.keys(view.keys, tree)?;
+        let scope = self.scope().to_vec();
+        match view.binding_rhs.get_view(tree)? {
+            BindingRhsView::ValueBinding(binding) => {
+                let value = self.value(binding.get_view(tree)?.value, tree)?;
+                let (_, target) = self.root.resolve(&scope, &keys)?;
+                target.assign(last_location(&keys), value)?;
+            }
+            BindingRhsView::TextBinding(binding) => {
+                let text_handle = binding.get_view(tree)?.text;
+                let location = Some(self.location(text_handle, tree)?);
+                let text = self.terminal_text(text_handle.get_view(tree)?.text, tree)?;
+                // The text token also captures the whitespace around it
+                let text = text.trim().to_string();
+                let (_, target) = self.root.resolve(&scope, &keys)?;
+                target.assign(
+                    last_location(&keys),
+                    Node {
+                        content: Content::Value(Value::String(text)),
+                        value: location,
+                        ..Default::default()
+                    },
+                )?;
+            }
+            BindingRhsView::SectionBinding(binding) => {
+                let location = self.location(binding, tree)?;
+                let (path, target) = self.root.resolve(&scope, &keys)?;
+                target.open_map(last_location(&keys))?;
+                target.value.get_or_insert(location);
+                self.scopes.push(path);
+                let result = self.visit_section_binding_handle(binding, tree);
+                self.scopes.pop();
+                result?;
+            }
+        }
         Ok(())
     }
 
-    fn visit_ident(
+    fn visit_section(
         &mut self,
-        handle: IdentHandle,
-        view: IdentView,
+        handle: SectionHandle,
+        view: SectionView,
         tree: &F,
     ) -> Result<(), Self::Error> {
-        let data = view.ident.get_data(tree)?;
-        let text = tree.get_str(data, self.input).unwrap();
-        self.values.ident_handles.insert(handle, todo!());
-        Ok(())
+        let keys = self.keys(view.keys, tree)?;
+        let scope = self.scope().to_vec();
+        let location = self.location(handle, tree)?;
+        let (path, target) = self.root.resolve(&scope, &keys)?;
+        target.open_map(last_location(&keys))?;
+        target.value.get_or_insert(location);
+        self.scopes.push(path);
+        let result = self.visit_section_super(handle, view, tree);
+        self.scopes.pop();
+        result
     }
 
-    fn visit_key_opt(
+    fn then_construct_error(
         &mut self,
-        handle: KeyOptHandle,
-        view: ArrayMarkerHandle,
-        tree: &F,
+        _node_data: Option<CstNode>,
+        _parent: CstNodeId,
+        _kind: NodeKind,
+        error: CstConstructError,
+        _tree: &F,
     ) -> Result<(), Self::Error> {
-        self.visit_key_opt_super(handle, view, tree)?;
+        Err(error.into())
+    }
+}
+
+/// A key segment as written in the source.
+struct KeySegment {
+    location: Location,
+    base: KeyBase,
+    array: Option<ArrayMarker>,
+}
+
+enum KeyBase {
+    Key(KeyCmpValue),
+    Extension(Identifier),
+    /// An integer key like `.0`
+    TupleIndex(u8),
+}
+
+enum ArrayMarker {
+    /// `[]`
+    Push,
+    /// `[n]`
+    Index(usize),
+}
+
+/// A resolved step from a node to its child.
+#[derive(Debug, Clone)]
+enum Step {
+    Key(KeyCmpValue),
+    Extension(Identifier),
+    Index(usize),
+    TupleIndex(u8),
+}
+
+/// The extension that turns a map into a [Value::Variant]. See `swon::extensions::CoreExtension`.
+const VARIANT: &str = "variant";
+
+fn last_location(keys: &[KeySegment]) -> Location {
+    keys.last().expect("keys are never empty").location
+}
+
+#[derive(Debug, Default)]
+struct Node {
+    content: Content,
+    extensions: Vec<(Identifier, Node)>,
+    /// The key that created this node. `None` only for the root.
+    origin: Option<Location>,
+    /// The key that assigned a value to this node.
+    assigned: Option<Location>,
+    /// The value, text or section that gives this node.
+    value: Option<Location>,
+}
+
+#[derive(Debug, Default)]
+enum Content {
+    /// Not assigned yet. Becomes an empty map if nothing is assigned.
+    #[default]
+    Unset,
+    Map(MapNode),
+    Array(Vec<Node>),
+    Tuple(Vec<Node>),
+    Value(Value),
+}
+
+#[derive(Debug, Default)]
+struct MapNode {
+    entries: Vec<(KeyCmpValue, Node)>,
+    index: AHashMap<KeyCmpValue, usize>,
+}
+
+impl Node {
+    fn child(&mut self, step: &Step) -> Option<&mut Node> {
+        match (step, &mut self.content) {
+            (Step::Key(key), Content::Map(map)) => {
+                let index = *map.index.get(key)?;
+                Some(&mut map.entries[index].1)
+            }
+            (Step::Index(index), Content::Array(elements)) => elements.get_mut(*index),
+            (Step::TupleIndex(index), Content::Tuple(elements)) => {
+                elements.get_mut(*index as usize)
+            }
+            (Step::Extension(name), _) => self
+                .extensions
+                .iter_mut()
+                .find(|(ext, _)| ext == name)
+                .map(|(_, node)| node),
+            _ => None,
+        }
+    }
+
+    /// Where this node was defined, preferring the assignment over the implicit creation. An
+    /// inline object has neither before it is assigned, so it is defined at its value.
+    fn defined_at(&self) -> Location {
+        self.assigned
+            .or(self.origin)
+            .or(self.value)
+            .expect("the root is never assigned nor conflicts")
+    }
+
+    fn conflict(&self, location: Location) -> ValueVisitorError {
+        match self.assigned.or(self.origin).or(self.value) {
+            Some(original) => ValueVisitorError::ConflictingKey {
+                original,
+                conflicting: location,
+            },
+            // Only a tuple index can conflict with the root map
+            None => ValueVisitorError::TupleIndexOnRoot { location },
+        }
+    }
+
+    /// Ensure this node is a map that can be extended by `location`.
+    fn open_map(&mut self, location: Location) -> Result<&mut MapNode, ValueVisitorError> {
+        if self.assigned.is_some() || !matches!(self.content, Content::Unset | Content::Map(_)) {
+            return Err(self.conflict(location));
+        }
+        if let Content::Unset = self.content {
+            self.content = Content::Map(MapNode::default());
+        }
+        let Content::Map(map) = &mut self.content else {
+            unreachable!()
+        };
+        Ok(map)
+    }
+
+    fn entry(
+        &mut self,
+        location: Location,
+        key: KeyCmpValue,
+    ) -> Result<&mut Node, ValueVisitorError> {
+        let map = self.open_map(location)?;
+        let index = *map.index.entry(key.clone()).or_insert_with(|| {
+            map.entries.push((key, Node::new(location)));
+            map.entries.len() - 1
+        });
+        Ok(&mut map.entries[index].1)
+    }
+
+    fn new(origin: Location) -> Self {
+        Self {
+            origin: Some(origin),
+            ..Default::default()
+        }
+    }
+
+    /// Extensions are not a part of the data, so they can be attached to assigned values too.
+    fn extension(&mut self, location: Location, name: Identifier) -> &mut Node {
+        let index = match self.extensions.iter().position(|(ext, _)| *ext == name) {
+            Some(index) => index,
+            None => {
+                self.extensions.push((name, Node::new(location)));
+                self.extensions.len() - 1
+            }
+        };
+        &mut self.extensions[index].1
+    }
+
+    fn element(
+        &mut self,
+        location: Location,
+        marker: &ArrayMarker,
+    ) -> Result<(usize, &mut Node), ValueVisitorError> {
+        let len = match &self.content {
+            _ if self.assigned.is_some() => return Err(self.conflict(location)),
+            Content::Unset => 0,
+            Content::Array(elements) => elements.len(),
+            _ => return Err(self.conflict(location)),
+        };
+        let index = match *marker {
+            ArrayMarker::Push => len,
+            ArrayMarker::Index(index) if index <= len => index,
+            ArrayMarker::Index(index) => {
+                return Err(ValueVisitorError::ArrayIndexOutOfOrder {
+                    original: (len > 0).then(|| self.defined_at()),
+                    conflicting: location,
+                    index,
+                    len,
+                });
+            }
+        };
+        if let Content::Unset = self.content {
+            self.content = Content::Array(Vec::new());
+        }
+        let Content::Array(elements) = &mut self.content else {
+            unreachable!()
+        };
+        if index == elements.len() {
+            elements.push(Node::new(location));
+        }
+        Ok((index, &mut elements[index]))
+    }
+
+    fn tuple_element(
+        &mut self,
+        location: Location,
+        index: u8,
+    ) -> Result<&mut Node, ValueVisitorError> {
+        let len = match &self.content {
+            _ if self.assigned.is_some() => return Err(self.conflict(location)),
+            Content::Unset => 0,
+            Content::Tuple(elements) => elements.len(),
+            _ => return Err(self.conflict(location)),
+        };
+        if index as usize > len {
+            return Err(ValueVisitorError::TupleIndexOutOfOrder {
+                original: (len > 0).then(|| self.defined_at()),
+                conflicting: location,
+                index,
+                len,
+            });
+        }
+        if let Content::Unset = self.content {
+            self.content = Content::Tuple(Vec::new());
+        }
+        let Content::Tuple(elements) = &mut self.content else {
+            unreachable!()
+        };
+        if index as usize == elements.len() {
+            elements.push(Node::new(location));
+        }
+        Ok(&mut elements[index as usize])
+    }
+
+    /// Walk `scope` and then `keys` from this node, creating nodes as needed.
+    fn resolve(
+        &mut self,
+        scope: &[Step],
+        keys: &[KeySegment],
+    ) -> Result<(Vec<Step>, &mut Node), ValueVisitorError> {
+        let mut path = scope.to_vec();
+        let mut current = self;
+        for step in scope {
+            current = current
+                .child(step)
+                .expect("scope must be resolved before bindings");
+        }
+        for key in keys {
+            current = match &key.base {
+                KeyBase::Key(name) => {
+                    path.push(Step::Key(name.clone()));
+                    current.entry(key.location, name.clone())?
+                }
+                KeyBase::Extension(name) => {
+                    path.push(Step::Extension(name.clone()));
+                    current.extension(key.location, name.clone())
+                }
+                KeyBase::TupleIndex(index) => {
+                    path.push(Step::TupleIndex(*index));
+                    current.tuple_element(key.location, *index)?
+                }
+            };
+            if let Some(marker) = &key.array {
+                let (index, element) = current.element(key.location, marker)?;
+                path.push(Step::Index(index));
+                current = element;
+            }
+        }
+        Ok((path, current))
+    }
+
+    fn assign(&mut self, location: Location, value: Node) -> Result<(), ValueVisitorError> {
+        if self.assigned.is_some() || !matches!(self.content, Content::Unset) {
+            return Err(ValueVisitorError::DuplicateKey {
+                original: self.defined_at(),
+                conflicting: location,
+            });
+        }
+        self.assigned = Some(location);
+        self.value = value.value;
+        self.content = value.content;
+        self.extensions.extend(value.extensions);
         Ok(())
     }
+
+    fn extension_node(&self, name: &str) -> Option<&Node> {
+        self.extensions
+            .iter()
+            .find(|(ext, _)| ext.as_ref() == name)
+            .map(|(_, node)| node)
+    }
+
+    fn into_spanned(mut self) -> Result<SpannedValue, ValueVisitorError> {
+        let variant = match self
+            .extensions
+            .iter()
+            .position(|(ext, _)| ext.as_ref() == VARIANT)
+        {
+            Some(index) => Some(self.extensions.remove(index).1),
+            None => None,
+        };
+        let content = match self.content {
+            Content::Unset => SpannedContent::Map(IndexMap::new()),
+            Content::Map(map) => SpannedContent::Map(
+                map.entries
+                    .into_iter()
+                    .map(|(key, node)| Ok((key, node.into_spanned()?)))
+                    .collect::<Result<_, ValueVisitorError>>()?,
+            ),
+            Content::Array(elements) => SpannedContent::Array(
+                elements
+                    .into_iter()
+                    .map(Node::into_spanned)
+                    .collect::<Result<_, _>>()?,
+            ),
+            Content::Tuple(elements) => SpannedContent::Tuple(
+                elements
+                    .into_iter()
+                    .map(Node::into_spanned)
+                    .collect::<Result<_, _>>()?,
+            ),
+            Content::Value(value) => SpannedContent::Value(value),
+        };
+        let content = match variant {
+            Some(variant) => {
+                let location = variant.defined_at();
+                if !matches!(content, SpannedContent::Map(_)) {
+                    return Err(ValueVisitorError::VariantNotAllowed { location });
+                }
+                let tag = match &variant.content {
+                    Content::Value(Value::String(tag)) => tag.parse().ok(),
+                    _ => None,
+                };
+                let Some(tag) = tag else {
+                    return Err(ValueVisitorError::InvalidVariantTag { location });
+                };
+                SpannedContent::Variant(SpannedVariant {
+                    tag,
+                    tag_location: location,
+                    content: Box::new(SpannedValue {
+                        key: None,
+                        value: None,
+                        content,
+                        extensions: Vec::new(),
+                    }),
+                })
+            }
+            None => content,
+        };
+        Ok(SpannedValue {
+            key: self.assigned.or(self.origin),
+            value: self.value,
+            content,
+            extensions: self
+                .extensions
+                .into_iter()
+                .map(|(name, node)| Ok((name, node.into_spanned()?)))
+                .collect::<Result<_, ValueVisitorError>>()?,
+        })
+    }
+}
+
+/// Parse a signed decimal, `0x`, `0o` or `0b` integer with `_` separators. Values that fit in
+/// `i64` are [KeyCmpValue::I64] and larger ones are [KeyCmpValue::U64].
+fn parse_integer(text: &str) -> Option<KeyCmpValue> {
+    let (negative, unsigned) = match text.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let (radix, digits) = match unsigned.get(..2) {
+        Some("0x") => (16, &unsigned[2..]),
+        Some("0o") => (8, &unsigned[2..]),
+        Some("0b") => (2, &unsigned[2..]),
+        _ => (10, unsigned),
+    };
+    let magnitude = u64::from_str_radix(&digits.replace('_', ""), radix).ok()?;
+    if negative {
+        0i64.checked_sub_unsigned(magnitude).map(KeyCmpValue::I64)
+    } else {
+        Some(
+            i64::try_from(magnitude)
+                .map(KeyCmpValue::I64)
+                .unwrap_or(KeyCmpValue::U64(magnitude)),
+        )
+    }
+}
+
+/// Read an integer key as a tuple index.
+fn tuple_index(text: String, location: Location) -> Result<u8, ValueVisitorError> {
+    match parse_integer(&text) {
+        Some(KeyCmpValue::I64(index)) => u8::try_from(index).ok(),
+        _ => None,
+    }
+    .ok_or(ValueVisitorError::TupleIndexOutOfRange { location, text })
+}
+
+fn is_digits(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit() || b == b'_')
+}
+
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('t') => out.push('\t'),
+            Some('0') => out.push('\0'),
+            Some(c) => out.push(c),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// Content of `` `code` `` without the surrounding backquotes.
+fn inline_code_content(code: &str) -> String {
+    code.strip_suffix('`').unwrap_or(code).replace("\\`", "`")
+}
+
+/// Splits a fenced code block into its language and content.
+/// The content always ends with a newline.
+fn code_block_value(text: &str) -> Code {
+    let text = text.trim_start_matches('`');
+    let text = text.strip_suffix("```").unwrap_or(text);
+    let (language, content) = match text.find(['\r', '\n']) {
+        Some(index) => {
+            let rest = &text[index..];
+            let rest = rest
+                .strip_prefix("\r\n")
+                .or_else(|| rest.strip_prefix('\n'))
+                .or_else(|| rest.strip_prefix('\r'))
+                .unwrap_or(rest);
+            (&text[..index], rest)
+        }
+        None => (text, ""),
+    };
+    // Indentation before the closing fence is not a part of the content
+    let content = match content.rfind('\n') {
+        Some(index) if content[index + 1..].trim().is_empty() => &content[..index + 1],
+        _ => content,
+    };
+    let mut content = content.to_string();
+    if !content.ends_with('\n') {
+        content.push('\n');
+    }
+    Code {
+        language: language.to_string(),
+        content,
+    }
 }
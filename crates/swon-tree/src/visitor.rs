@@ -24,21 +24,45 @@ pub trait CstVisitor<F: CstFacade>: CstVisitorSuper<F, Self::Error> {
     ) -> Result<(), Self::Error> {
         self.visit_array_begin_super(handle, view, tree)
     }
-    fn visit_array_end(
+    fn visit_array_elements(
         &mut self,
-        handle: ArrayEndHandle,
-        view: ArrayEndView,
+        handle: ArrayElementsHandle,
+        view: ArrayElementsView,
         tree: &F,
     ) -> Result<(), Self::Error> {
-        self.visit_array_end_super(handle, view, tree)
+        self.visit_array_elements_super(handle, view, tree)
+    }
+    fn visit_array_elements_opt(
+        &mut self,
+        handle: ArrayElementsOptHandle,
+        view: ArrayElementsTailHandle,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_array_elements_opt_super(handle, view, tree)
+    }
+    fn visit_array_elements_tail(
+        &mut self,
+        handle: ArrayElementsTailHandle,
+        view: ArrayElementsTailView,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_array_elements_tail_super(handle, view, tree)
+    }
+    fn visit_array_elements_tail_opt(
+        &mut self,
+        handle: ArrayElementsTailOptHandle,
+        view: ArrayElementsHandle,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_array_elements_tail_opt_super(handle, view, tree)
     }
-    fn visit_array_list(
+    fn visit_array_end(
         &mut self,
-        handle: ArrayListHandle,
-        view: ArrayListView,
+        handle: ArrayEndHandle,
+        view: ArrayEndView,
         tree: &F,
     ) -> Result<(), Self::Error> {
-        self.visit_array_list_super(handle, view, tree)
+        self.visit_array_end_super(handle, view, tree)
     }
     fn visit_array_marker(
         &mut self,
@@ -59,7 +83,7 @@ pub trait CstVisitor<F: CstFacade>: CstVisitorSuper<F, Self::Error> {
     fn visit_array_opt(
         &mut self,
         handle: ArrayOptHandle,
-        view: CommaHandle,
+        view: ArrayElementsHandle,
         tree: &F,
     ) -> Result<(), Self::Error> {
         self.visit_array_opt_super(handle, view, tree)
@@ -184,6 +208,22 @@ pub trait CstVisitor<F: CstFacade>: CstVisitorSuper<F, Self::Error> {
     ) -> Result<(), Self::Error> {
         self.visit_false_super(handle, view, tree)
     }
+    fn visit_float(
+        &mut self,
+        handle: FloatHandle,
+        view: FloatView,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_float_super(handle, view, tree)
+    }
+    fn visit_float_name(
+        &mut self,
+        handle: FloatNameHandle,
+        view: FloatNameView,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_float_name_super(handle, view, tree)
+    }
     fn visit_hole(
         &mut self,
         handle: HoleHandle,
@@ -304,6 +344,30 @@ pub trait CstVisitor<F: CstFacade>: CstVisitorSuper<F, Self::Error> {
     ) -> Result<(), Self::Error> {
         self.visit_object_opt_super(handle, view, tree)
     }
+    fn visit_path(
+        &mut self,
+        handle: PathHandle,
+        view: PathView,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_path_super(handle, view, tree)
+    }
+    fn visit_path_head(
+        &mut self,
+        handle: PathHeadHandle,
+        view: PathHeadView,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_path_head_super(handle, view, tree)
+    }
+    fn visit_path_list(
+        &mut self,
+        handle: PathListHandle,
+        view: PathListView,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_path_list_super(handle, view, tree)
+    }
     fn visit_quote(
         &mut self,
         handle: QuoteHandle,
@@ -368,6 +432,14 @@ pub trait CstVisitor<F: CstFacade>: CstVisitorSuper<F, Self::Error> {
     ) -> Result<(), Self::Error> {
         self.visit_str_continues_list_super(handle, view, tree)
     }
+    fn visit_str_opt(
+        &mut self,
+        handle: StrOptHandle,
+        view: InStrHandle,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_str_opt_super(handle, view, tree)
+    }
     fn visit_swon(
         &mut self,
         handle: SwonHandle,
@@ -432,6 +504,70 @@ pub trait CstVisitor<F: CstFacade>: CstVisitorSuper<F, Self::Error> {
     ) -> Result<(), Self::Error> {
         self.visit_true_super(handle, view, tree)
     }
+    fn visit_tuple(
+        &mut self,
+        handle: TupleHandle,
+        view: TupleView,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_tuple_super(handle, view, tree)
+    }
+    fn visit_tuple_begin(
+        &mut self,
+        handle: TupleBeginHandle,
+        view: TupleBeginView,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_tuple_begin_super(handle, view, tree)
+    }
+    fn visit_tuple_elements(
+        &mut self,
+        handle: TupleElementsHandle,
+        view: TupleElementsView,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_tuple_elements_super(handle, view, tree)
+    }
+    fn visit_tuple_elements_opt(
+        &mut self,
+        handle: TupleElementsOptHandle,
+        view: TupleElementsTailHandle,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_tuple_elements_opt_super(handle, view, tree)
+    }
+    fn visit_tuple_elements_tail(
+        &mut self,
+        handle: TupleElementsTailHandle,
+        view: TupleElementsTailView,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_tuple_elements_tail_super(handle, view, tree)
+    }
+    fn visit_tuple_elements_tail_opt(
+        &mut self,
+        handle: TupleElementsTailOptHandle,
+        view: TupleElementsHandle,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_tuple_elements_tail_opt_super(handle, view, tree)
+    }
+    fn visit_tuple_end(
+        &mut self,
+        handle: TupleEndHandle,
+        view: TupleEndView,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_tuple_end_super(handle, view, tree)
+    }
+    fn visit_tuple_opt(
+        &mut self,
+        handle: TupleOptHandle,
+        view: TupleElementsHandle,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_tuple_opt_super(handle, view, tree)
+    }
     fn visit_typed_quote(
         &mut self,
         handle: TypedQuoteHandle,
@@ -448,6 +584,14 @@ pub trait CstVisitor<F: CstFacade>: CstVisitorSuper<F, Self::Error> {
     ) -> Result<(), Self::Error> {
         self.visit_typed_str_super(handle, view, tree)
     }
+    fn visit_typed_str_opt(
+        &mut self,
+        handle: TypedStrOptHandle,
+        view: InStrHandle,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_typed_str_opt_super(handle, view, tree)
+    }
     fn visit_value(
         &mut self,
         handle: ValueHandle,
@@ -520,6 +664,14 @@ pub trait CstVisitor<F: CstFacade>: CstVisitorSuper<F, Self::Error> {
     ) -> Result<(), Self::Error> {
         self.visit_integer_terminal_super(terminal, data, tree)
     }
+    fn visit_float_terminal(
+        &mut self,
+        terminal: Float,
+        data: TerminalData,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_float_terminal_super(terminal, data, tree)
+    }
     fn visit_true_terminal(
         &mut self,
         terminal: True,
@@ -680,6 +832,22 @@ pub trait CstVisitor<F: CstFacade>: CstVisitorSuper<F, Self::Error> {
     ) -> Result<(), Self::Error> {
         self.visit_r_bracket_terminal_super(terminal, data, tree)
     }
+    fn visit_l_paren_terminal(
+        &mut self,
+        terminal: LParen,
+        data: TerminalData,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_l_paren_terminal_super(terminal, data, tree)
+    }
+    fn visit_r_paren_terminal(
+        &mut self,
+        terminal: RParen,
+        data: TerminalData,
+        tree: &F,
+    ) -> Result<(), Self::Error> {
+        self.visit_r_paren_terminal_super(terminal, data, tree)
+    }
     fn visit_bind_terminal(
         &mut self,
         terminal: Bind,
@@ -783,26 +951,59 @@ pub trait CstVisitorSuper<F: CstFacade, E>: private::Sealed<F> {
         view: ArrayBeginView,
         tree: &F,
     ) -> Result<(), E>;
-    fn visit_array_end_handle(
+    fn visit_array_elements_handle(
         &mut self,
-        handle: ArrayEndHandle,
+        handle: ArrayElementsHandle,
         tree: &F,
     ) -> Result<(), E>;
-    fn visit_array_end_super(
+    fn visit_array_elements_super(
         &mut self,
-        handle: ArrayEndHandle,
-        view: ArrayEndView,
+        handle: ArrayElementsHandle,
+        view: ArrayElementsView,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_array_elements_opt_handle(
+        &mut self,
+        handle: ArrayElementsOptHandle,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_array_elements_opt_super(
+        &mut self,
+        handle: ArrayElementsOptHandle,
+        view: ArrayElementsTailHandle,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_array_elements_tail_handle(
+        &mut self,
+        handle: ArrayElementsTailHandle,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_array_elements_tail_super(
+        &mut self,
+        handle: ArrayElementsTailHandle,
+        view: ArrayElementsTailView,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_array_elements_tail_opt_handle(
+        &mut self,
+        handle: ArrayElementsTailOptHandle,
         tree: &F,
     ) -> Result<(), E>;
-    fn visit_array_list_handle(
+    fn visit_array_elements_tail_opt_super(
+        &mut self,
+        handle: ArrayElementsTailOptHandle,
+        view: ArrayElementsHandle,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_array_end_handle(
         &mut self,
-        handle: ArrayListHandle,
+        handle: ArrayEndHandle,
         tree: &F,
     ) -> Result<(), E>;
-    fn visit_array_list_super(
+    fn visit_array_end_super(
         &mut self,
-        handle: ArrayListHandle,
-        view: ArrayListView,
+        handle: ArrayEndHandle,
+        view: ArrayEndView,
         tree: &F,
     ) -> Result<(), E>;
     fn visit_array_marker_handle(
@@ -835,7 +1036,7 @@ pub trait CstVisitorSuper<F: CstFacade, E>: private::Sealed<F> {
     fn visit_array_opt_super(
         &mut self,
         handle: ArrayOptHandle,
-        view: CommaHandle,
+        view: ArrayElementsHandle,
         tree: &F,
     ) -> Result<(), E>;
     fn visit_at_handle(&mut self, handle: AtHandle, tree: &F) -> Result<(), E>;
@@ -959,6 +1160,24 @@ pub trait CstVisitorSuper<F: CstFacade, E>: private::Sealed<F> {
         view: FalseView,
         tree: &F,
     ) -> Result<(), E>;
+    fn visit_float_handle(&mut self, handle: FloatHandle, tree: &F) -> Result<(), E>;
+    fn visit_float_super(
+        &mut self,
+        handle: FloatHandle,
+        view: FloatView,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_float_name_handle(
+        &mut self,
+        handle: FloatNameHandle,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_float_name_super(
+        &mut self,
+        handle: FloatNameHandle,
+        view: FloatNameView,
+        tree: &F,
+    ) -> Result<(), E>;
     fn visit_hole_handle(&mut self, handle: HoleHandle, tree: &F) -> Result<(), E>;
     fn visit_hole_super(
         &mut self,
@@ -1084,6 +1303,35 @@ pub trait CstVisitorSuper<F: CstFacade, E>: private::Sealed<F> {
         view: CommaHandle,
         tree: &F,
     ) -> Result<(), E>;
+    fn visit_path_handle(&mut self, handle: PathHandle, tree: &F) -> Result<(), E>;
+    fn visit_path_super(
+        &mut self,
+        handle: PathHandle,
+        view: PathView,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_path_head_handle(
+        &mut self,
+        handle: PathHeadHandle,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_path_head_super(
+        &mut self,
+        handle: PathHeadHandle,
+        view: PathHeadView,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_path_list_handle(
+        &mut self,
+        handle: PathListHandle,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_path_list_super(
+        &mut self,
+        handle: PathListHandle,
+        view: PathListView,
+        tree: &F,
+    ) -> Result<(), E>;
     fn visit_quote_handle(&mut self, handle: QuoteHandle, tree: &F) -> Result<(), E>;
     fn visit_quote_super(
         &mut self,
@@ -1160,6 +1408,13 @@ pub trait CstVisitorSuper<F: CstFacade, E>: private::Sealed<F> {
         view: StrContinuesListView,
         tree: &F,
     ) -> Result<(), E>;
+    fn visit_str_opt_handle(&mut self, handle: StrOptHandle, tree: &F) -> Result<(), E>;
+    fn visit_str_opt_super(
+        &mut self,
+        handle: StrOptHandle,
+        view: InStrHandle,
+        tree: &F,
+    ) -> Result<(), E>;
     fn visit_swon_handle(&mut self, handle: SwonHandle, tree: &F) -> Result<(), E>;
     fn visit_swon_super(
         &mut self,
@@ -1236,6 +1491,90 @@ pub trait CstVisitorSuper<F: CstFacade, E>: private::Sealed<F> {
         view: TrueView,
         tree: &F,
     ) -> Result<(), E>;
+    fn visit_tuple_handle(&mut self, handle: TupleHandle, tree: &F) -> Result<(), E>;
+    fn visit_tuple_super(
+        &mut self,
+        handle: TupleHandle,
+        view: TupleView,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_tuple_begin_handle(
+        &mut self,
+        handle: TupleBeginHandle,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_tuple_begin_super(
+        &mut self,
+        handle: TupleBeginHandle,
+        view: TupleBeginView,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_tuple_elements_handle(
+        &mut self,
+        handle: TupleElementsHandle,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_tuple_elements_super(
+        &mut self,
+        handle: TupleElementsHandle,
+        view: TupleElementsView,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_tuple_elements_opt_handle(
+        &mut self,
+        handle: TupleElementsOptHandle,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_tuple_elements_opt_super(
+        &mut self,
+        handle: TupleElementsOptHandle,
+        view: TupleElementsTailHandle,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_tuple_elements_tail_handle(
+        &mut self,
+        handle: TupleElementsTailHandle,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_tuple_elements_tail_super(
+        &mut self,
+        handle: TupleElementsTailHandle,
+        view: TupleElementsTailView,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_tuple_elements_tail_opt_handle(
+        &mut self,
+        handle: TupleElementsTailOptHandle,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_tuple_elements_tail_opt_super(
+        &mut self,
+        handle: TupleElementsTailOptHandle,
+        view: TupleElementsHandle,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_tuple_end_handle(
+        &mut self,
+        handle: TupleEndHandle,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_tuple_end_super(
+        &mut self,
+        handle: TupleEndHandle,
+        view: TupleEndView,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_tuple_opt_handle(
+        &mut self,
+        handle: TupleOptHandle,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_tuple_opt_super(
+        &mut self,
+        handle: TupleOptHandle,
+        view: TupleElementsHandle,
+        tree: &F,
+    ) -> Result<(), E>;
     fn visit_typed_quote_handle(
         &mut self,
         handle: TypedQuoteHandle,
@@ -1258,6 +1597,17 @@ pub trait CstVisitorSuper<F: CstFacade, E>: private::Sealed<F> {
         view: TypedStrView,
         tree: &F,
     ) -> Result<(), E>;
+    fn visit_typed_str_opt_handle(
+        &mut self,
+        handle: TypedStrOptHandle,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_typed_str_opt_super(
+        &mut self,
+        handle: TypedStrOptHandle,
+        view: InStrHandle,
+        tree: &F,
+    ) -> Result<(), E>;
     fn visit_value_handle(&mut self, handle: ValueHandle, tree: &F) -> Result<(), E>;
     fn visit_value_super(
         &mut self,
@@ -1320,6 +1670,12 @@ pub trait CstVisitorSuper<F: CstFacade, E>: private::Sealed<F> {
         data: TerminalData,
         tree: &F,
     ) -> Result<(), E>;
+    fn visit_float_terminal_super(
+        &mut self,
+        terminal: Float,
+        data: TerminalData,
+        tree: &F,
+    ) -> Result<(), E>;
     fn visit_true_terminal_super(
         &mut self,
         terminal: True,
@@ -1440,6 +1796,18 @@ pub trait CstVisitorSuper<F: CstFacade, E>: private::Sealed<F> {
         data: TerminalData,
         tree: &F,
     ) -> Result<(), E>;
+    fn visit_l_paren_terminal_super(
+        &mut self,
+        terminal: LParen,
+        data: TerminalData,
+        tree: &F,
+    ) -> Result<(), E>;
+    fn visit_r_paren_terminal_super(
+        &mut self,
+        terminal: RParen,
+        data: TerminalData,
+        tree: &F,
+    ) -> Result<(), E>;
     fn visit_bind_terminal_super(
         &mut self,
         terminal: Bind,
@@ -1592,9 +1960,9 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
         result
     }
-    fn visit_array_end_handle(
+    fn visit_array_elements_handle(
         &mut self,
-        handle: ArrayEndHandle,
+        handle: ArrayElementsHandle,
         tree: &F,
     ) -> Result<(), V::Error> {
         let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
@@ -1615,7 +1983,7 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
             .get_view_with_visit(
                 tree,
                 |view, visit: &mut Self| (
-                    visit.visit_array_end(handle, view, tree),
+                    visit.visit_array_elements(handle, view, tree),
                     visit,
                 ),
                 self,
@@ -1638,9 +2006,9 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
         result
     }
-    fn visit_array_list_handle(
+    fn visit_array_elements_opt_handle(
         &mut self,
-        handle: ArrayListHandle,
+        handle: ArrayElementsOptHandle,
         tree: &F,
     ) -> Result<(), V::Error> {
         let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
@@ -1662,7 +2030,7 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
                 tree,
                 |view, visit: &mut Self| (
                     if let Some(view) = view {
-                        visit.visit_array_list(handle, view, tree)
+                        visit.visit_array_elements_opt(handle, view, tree)
                     } else {
                         Ok(())
                     },
@@ -1688,9 +2056,9 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
         result
     }
-    fn visit_array_marker_handle(
+    fn visit_array_elements_tail_handle(
         &mut self,
-        handle: ArrayMarkerHandle,
+        handle: ArrayElementsTailHandle,
         tree: &F,
     ) -> Result<(), V::Error> {
         let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
@@ -1711,7 +2079,7 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
             .get_view_with_visit(
                 tree,
                 |view, visit: &mut Self| (
-                    visit.visit_array_marker(handle, view, tree),
+                    visit.visit_array_elements_tail(handle, view, tree),
                     visit,
                 ),
                 self,
@@ -1734,9 +2102,9 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
         result
     }
-    fn visit_array_marker_opt_handle(
+    fn visit_array_elements_tail_opt_handle(
         &mut self,
-        handle: ArrayMarkerOptHandle,
+        handle: ArrayElementsTailOptHandle,
         tree: &F,
     ) -> Result<(), V::Error> {
         let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
@@ -1758,7 +2126,7 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
                 tree,
                 |view, visit: &mut Self| (
                     if let Some(view) = view {
-                        visit.visit_array_marker_opt(handle, view, tree)
+                        visit.visit_array_elements_tail_opt(handle, view, tree)
                     } else {
                         Ok(())
                     },
@@ -1784,9 +2152,9 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
         result
     }
-    fn visit_array_opt_handle(
+    fn visit_array_end_handle(
         &mut self,
-        handle: ArrayOptHandle,
+        handle: ArrayEndHandle,
         tree: &F,
     ) -> Result<(), V::Error> {
         let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
@@ -1807,11 +2175,7 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
             .get_view_with_visit(
                 tree,
                 |view, visit: &mut Self| (
-                    if let Some(view) = view {
-                        visit.visit_array_opt(handle, view, tree)
-                    } else {
-                        Ok(())
-                    },
+                    visit.visit_array_end(handle, view, tree),
                     visit,
                 ),
                 self,
@@ -1834,7 +2198,11 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
         result
     }
-    fn visit_at_handle(&mut self, handle: AtHandle, tree: &F) -> Result<(), V::Error> {
+    fn visit_array_marker_handle(
+        &mut self,
+        handle: ArrayMarkerHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
         let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
             Ok(nt_data) => nt_data,
             Err(error) => {
@@ -1852,7 +2220,10 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         let result = match handle
             .get_view_with_visit(
                 tree,
-                |view, visit: &mut Self| (visit.visit_at(handle, view, tree), visit),
+                |view, visit: &mut Self| (
+                    visit.visit_array_marker(handle, view, tree),
+                    visit,
+                ),
                 self,
             )
             .map_err(|e| e.extract_error())
@@ -1873,9 +2244,9 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
         result
     }
-    fn visit_begin_handle(
+    fn visit_array_marker_opt_handle(
         &mut self,
-        handle: BeginHandle,
+        handle: ArrayMarkerOptHandle,
         tree: &F,
     ) -> Result<(), V::Error> {
         let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
@@ -1895,13 +2266,152 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         let result = match handle
             .get_view_with_visit(
                 tree,
-                |view, visit: &mut Self| (visit.visit_begin(handle, view, tree), visit),
-                self,
-            )
-            .map_err(|e| e.extract_error())
-        {
-            Ok(Ok(())) => Ok(()),
-            Ok(Err(e)) => Err(e),
+                |view, visit: &mut Self| (
+                    if let Some(view) = view {
+                        visit.visit_array_marker_opt(handle, view, tree)
+                    } else {
+                        Ok(())
+                    },
+                    visit,
+                ),
+                self,
+            )
+            .map_err(|e| e.extract_error())
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(e),
+            Err(Ok(e)) => Err(e),
+            Err(Err(e)) => {
+                self.then_construct_error(
+                    Some(CstNode::new_non_terminal(handle.kind(), nt_data)),
+                    handle.node_id(),
+                    NodeKind::NonTerminal(handle.kind()),
+                    e,
+                    tree,
+                )
+            }
+        };
+        self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
+        result
+    }
+    fn visit_array_opt_handle(
+        &mut self,
+        handle: ArrayOptHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
+            Ok(nt_data) => nt_data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        handle.node_id(),
+                        NodeKind::NonTerminal(handle.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_non_terminal(handle.node_id(), handle.kind(), nt_data, tree)?;
+        let result = match handle
+            .get_view_with_visit(
+                tree,
+                |view, visit: &mut Self| (
+                    if let Some(view) = view {
+                        visit.visit_array_opt(handle, view, tree)
+                    } else {
+                        Ok(())
+                    },
+                    visit,
+                ),
+                self,
+            )
+            .map_err(|e| e.extract_error())
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(e),
+            Err(Ok(e)) => Err(e),
+            Err(Err(e)) => {
+                self.then_construct_error(
+                    Some(CstNode::new_non_terminal(handle.kind(), nt_data)),
+                    handle.node_id(),
+                    NodeKind::NonTerminal(handle.kind()),
+                    e,
+                    tree,
+                )
+            }
+        };
+        self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
+        result
+    }
+    fn visit_at_handle(&mut self, handle: AtHandle, tree: &F) -> Result<(), V::Error> {
+        let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
+            Ok(nt_data) => nt_data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        handle.node_id(),
+                        NodeKind::NonTerminal(handle.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_non_terminal(handle.node_id(), handle.kind(), nt_data, tree)?;
+        let result = match handle
+            .get_view_with_visit(
+                tree,
+                |view, visit: &mut Self| (visit.visit_at(handle, view, tree), visit),
+                self,
+            )
+            .map_err(|e| e.extract_error())
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(e),
+            Err(Ok(e)) => Err(e),
+            Err(Err(e)) => {
+                self.then_construct_error(
+                    Some(CstNode::new_non_terminal(handle.kind(), nt_data)),
+                    handle.node_id(),
+                    NodeKind::NonTerminal(handle.kind()),
+                    e,
+                    tree,
+                )
+            }
+        };
+        self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
+        result
+    }
+    fn visit_begin_handle(
+        &mut self,
+        handle: BeginHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
+            Ok(nt_data) => nt_data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        handle.node_id(),
+                        NodeKind::NonTerminal(handle.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_non_terminal(handle.node_id(), handle.kind(), nt_data, tree)?;
+        let result = match handle
+            .get_view_with_visit(
+                tree,
+                |view, visit: &mut Self| (visit.visit_begin(handle, view, tree), visit),
+                self,
+            )
+            .map_err(|e| e.extract_error())
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(e),
             Err(Ok(e)) => Err(e),
             Err(Err(e)) => {
                 self.then_construct_error(
@@ -2481,6 +2991,95 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
         result
     }
+    fn visit_float_handle(
+        &mut self,
+        handle: FloatHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
+            Ok(nt_data) => nt_data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        handle.node_id(),
+                        NodeKind::NonTerminal(handle.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_non_terminal(handle.node_id(), handle.kind(), nt_data, tree)?;
+        let result = match handle
+            .get_view_with_visit(
+                tree,
+                |view, visit: &mut Self| (visit.visit_float(handle, view, tree), visit),
+                self,
+            )
+            .map_err(|e| e.extract_error())
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(e),
+            Err(Ok(e)) => Err(e),
+            Err(Err(e)) => {
+                self.then_construct_error(
+                    Some(CstNode::new_non_terminal(handle.kind(), nt_data)),
+                    handle.node_id(),
+                    NodeKind::NonTerminal(handle.kind()),
+                    e,
+                    tree,
+                )
+            }
+        };
+        self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
+        result
+    }
+    fn visit_float_name_handle(
+        &mut self,
+        handle: FloatNameHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
+            Ok(nt_data) => nt_data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        handle.node_id(),
+                        NodeKind::NonTerminal(handle.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_non_terminal(handle.node_id(), handle.kind(), nt_data, tree)?;
+        let result = match handle
+            .get_view_with_visit(
+                tree,
+                |view, visit: &mut Self| (
+                    visit.visit_float_name(handle, view, tree),
+                    visit,
+                ),
+                self,
+            )
+            .map_err(|e| e.extract_error())
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(e),
+            Err(Ok(e)) => Err(e),
+            Err(Err(e)) => {
+                self.then_construct_error(
+                    Some(CstNode::new_non_terminal(handle.kind(), nt_data)),
+                    handle.node_id(),
+                    NodeKind::NonTerminal(handle.kind()),
+                    e,
+                    tree,
+                )
+            }
+        };
+        self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
+        result
+    }
     fn visit_hole_handle(
         &mut self,
         handle: HoleHandle,
@@ -3162,9 +3761,9 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
         result
     }
-    fn visit_quote_handle(
+    fn visit_path_handle(
         &mut self,
-        handle: QuoteHandle,
+        handle: PathHandle,
         tree: &F,
     ) -> Result<(), V::Error> {
         let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
@@ -3184,7 +3783,7 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         let result = match handle
             .get_view_with_visit(
                 tree,
-                |view, visit: &mut Self| (visit.visit_quote(handle, view, tree), visit),
+                |view, visit: &mut Self| (visit.visit_path(handle, view, tree), visit),
                 self,
             )
             .map_err(|e| e.extract_error())
@@ -3205,9 +3804,9 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
         result
     }
-    fn visit_section_handle(
+    fn visit_path_head_handle(
         &mut self,
-        handle: SectionHandle,
+        handle: PathHeadHandle,
         tree: &F,
     ) -> Result<(), V::Error> {
         let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
@@ -3228,7 +3827,7 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
             .get_view_with_visit(
                 tree,
                 |view, visit: &mut Self| (
-                    visit.visit_section(handle, view, tree),
+                    visit.visit_path_head(handle, view, tree),
                     visit,
                 ),
                 self,
@@ -3251,9 +3850,9 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
         result
     }
-    fn visit_section_binding_handle(
+    fn visit_path_list_handle(
         &mut self,
-        handle: SectionBindingHandle,
+        handle: PathListHandle,
         tree: &F,
     ) -> Result<(), V::Error> {
         let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
@@ -3274,7 +3873,11 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
             .get_view_with_visit(
                 tree,
                 |view, visit: &mut Self| (
-                    visit.visit_section_binding(handle, view, tree),
+                    if let Some(view) = view {
+                        visit.visit_path_list(handle, view, tree)
+                    } else {
+                        Ok(())
+                    },
                     visit,
                 ),
                 self,
@@ -3297,9 +3900,9 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
         result
     }
-    fn visit_section_body_handle(
+    fn visit_quote_handle(
         &mut self,
-        handle: SectionBodyHandle,
+        handle: QuoteHandle,
         tree: &F,
     ) -> Result<(), V::Error> {
         let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
@@ -3319,10 +3922,7 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         let result = match handle
             .get_view_with_visit(
                 tree,
-                |view, visit: &mut Self| (
-                    visit.visit_section_body(handle, view, tree),
-                    visit,
-                ),
+                |view, visit: &mut Self| (visit.visit_quote(handle, view, tree), visit),
                 self,
             )
             .map_err(|e| e.extract_error())
@@ -3343,9 +3943,9 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
         result
     }
-    fn visit_section_body_list_handle(
+    fn visit_section_handle(
         &mut self,
-        handle: SectionBodyListHandle,
+        handle: SectionHandle,
         tree: &F,
     ) -> Result<(), V::Error> {
         let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
@@ -3366,7 +3966,145 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
             .get_view_with_visit(
                 tree,
                 |view, visit: &mut Self| (
-                    if let Some(view) = view {
+                    visit.visit_section(handle, view, tree),
+                    visit,
+                ),
+                self,
+            )
+            .map_err(|e| e.extract_error())
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(e),
+            Err(Ok(e)) => Err(e),
+            Err(Err(e)) => {
+                self.then_construct_error(
+                    Some(CstNode::new_non_terminal(handle.kind(), nt_data)),
+                    handle.node_id(),
+                    NodeKind::NonTerminal(handle.kind()),
+                    e,
+                    tree,
+                )
+            }
+        };
+        self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
+        result
+    }
+    fn visit_section_binding_handle(
+        &mut self,
+        handle: SectionBindingHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
+            Ok(nt_data) => nt_data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        handle.node_id(),
+                        NodeKind::NonTerminal(handle.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_non_terminal(handle.node_id(), handle.kind(), nt_data, tree)?;
+        let result = match handle
+            .get_view_with_visit(
+                tree,
+                |view, visit: &mut Self| (
+                    visit.visit_section_binding(handle, view, tree),
+                    visit,
+                ),
+                self,
+            )
+            .map_err(|e| e.extract_error())
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(e),
+            Err(Ok(e)) => Err(e),
+            Err(Err(e)) => {
+                self.then_construct_error(
+                    Some(CstNode::new_non_terminal(handle.kind(), nt_data)),
+                    handle.node_id(),
+                    NodeKind::NonTerminal(handle.kind()),
+                    e,
+                    tree,
+                )
+            }
+        };
+        self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
+        result
+    }
+    fn visit_section_body_handle(
+        &mut self,
+        handle: SectionBodyHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
+            Ok(nt_data) => nt_data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        handle.node_id(),
+                        NodeKind::NonTerminal(handle.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_non_terminal(handle.node_id(), handle.kind(), nt_data, tree)?;
+        let result = match handle
+            .get_view_with_visit(
+                tree,
+                |view, visit: &mut Self| (
+                    visit.visit_section_body(handle, view, tree),
+                    visit,
+                ),
+                self,
+            )
+            .map_err(|e| e.extract_error())
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(e),
+            Err(Ok(e)) => Err(e),
+            Err(Err(e)) => {
+                self.then_construct_error(
+                    Some(CstNode::new_non_terminal(handle.kind(), nt_data)),
+                    handle.node_id(),
+                    NodeKind::NonTerminal(handle.kind()),
+                    e,
+                    tree,
+                )
+            }
+        };
+        self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
+        result
+    }
+    fn visit_section_body_list_handle(
+        &mut self,
+        handle: SectionBodyListHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
+            Ok(nt_data) => nt_data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        handle.node_id(),
+                        NodeKind::NonTerminal(handle.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_non_terminal(handle.node_id(), handle.kind(), nt_data, tree)?;
+        let result = match handle
+            .get_view_with_visit(
+                tree,
+                |view, visit: &mut Self| (
+                    if let Some(view) = view {
                         visit.visit_section_body_list(handle, view, tree)
                     } else {
                         Ok(())
@@ -3528,6 +4266,56 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
         result
     }
+    fn visit_str_opt_handle(
+        &mut self,
+        handle: StrOptHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
+            Ok(nt_data) => nt_data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        handle.node_id(),
+                        NodeKind::NonTerminal(handle.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_non_terminal(handle.node_id(), handle.kind(), nt_data, tree)?;
+        let result = match handle
+            .get_view_with_visit(
+                tree,
+                |view, visit: &mut Self| (
+                    if let Some(view) = view {
+                        visit.visit_str_opt(handle, view, tree)
+                    } else {
+                        Ok(())
+                    },
+                    visit,
+                ),
+                self,
+            )
+            .map_err(|e| e.extract_error())
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(e),
+            Err(Ok(e)) => Err(e),
+            Err(Err(e)) => {
+                self.then_construct_error(
+                    Some(CstNode::new_non_terminal(handle.kind(), nt_data)),
+                    handle.node_id(),
+                    NodeKind::NonTerminal(handle.kind()),
+                    e,
+                    tree,
+                )
+            }
+        };
+        self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
+        result
+    }
     fn visit_swon_handle(
         &mut self,
         handle: SwonHandle,
@@ -3716,7 +4504,423 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
     }
     fn visit_text_binding_handle(
         &mut self,
-        handle: TextBindingHandle,
+        handle: TextBindingHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
+            Ok(nt_data) => nt_data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        handle.node_id(),
+                        NodeKind::NonTerminal(handle.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_non_terminal(handle.node_id(), handle.kind(), nt_data, tree)?;
+        let result = match handle
+            .get_view_with_visit(
+                tree,
+                |view, visit: &mut Self| (
+                    visit.visit_text_binding(handle, view, tree),
+                    visit,
+                ),
+                self,
+            )
+            .map_err(|e| e.extract_error())
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(e),
+            Err(Ok(e)) => Err(e),
+            Err(Err(e)) => {
+                self.then_construct_error(
+                    Some(CstNode::new_non_terminal(handle.kind(), nt_data)),
+                    handle.node_id(),
+                    NodeKind::NonTerminal(handle.kind()),
+                    e,
+                    tree,
+                )
+            }
+        };
+        self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
+        result
+    }
+    fn visit_text_binding_opt_handle(
+        &mut self,
+        handle: TextBindingOptHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
+            Ok(nt_data) => nt_data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        handle.node_id(),
+                        NodeKind::NonTerminal(handle.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_non_terminal(handle.node_id(), handle.kind(), nt_data, tree)?;
+        let result = match handle
+            .get_view_with_visit(
+                tree,
+                |view, visit: &mut Self| (
+                    if let Some(view) = view {
+                        visit.visit_text_binding_opt(handle, view, tree)
+                    } else {
+                        Ok(())
+                    },
+                    visit,
+                ),
+                self,
+            )
+            .map_err(|e| e.extract_error())
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(e),
+            Err(Ok(e)) => Err(e),
+            Err(Err(e)) => {
+                self.then_construct_error(
+                    Some(CstNode::new_non_terminal(handle.kind(), nt_data)),
+                    handle.node_id(),
+                    NodeKind::NonTerminal(handle.kind()),
+                    e,
+                    tree,
+                )
+            }
+        };
+        self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
+        result
+    }
+    fn visit_text_start_handle(
+        &mut self,
+        handle: TextStartHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
+            Ok(nt_data) => nt_data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        handle.node_id(),
+                        NodeKind::NonTerminal(handle.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_non_terminal(handle.node_id(), handle.kind(), nt_data, tree)?;
+        let result = match handle
+            .get_view_with_visit(
+                tree,
+                |view, visit: &mut Self| (
+                    visit.visit_text_start(handle, view, tree),
+                    visit,
+                ),
+                self,
+            )
+            .map_err(|e| e.extract_error())
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(e),
+            Err(Ok(e)) => Err(e),
+            Err(Err(e)) => {
+                self.then_construct_error(
+                    Some(CstNode::new_non_terminal(handle.kind(), nt_data)),
+                    handle.node_id(),
+                    NodeKind::NonTerminal(handle.kind()),
+                    e,
+                    tree,
+                )
+            }
+        };
+        self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
+        result
+    }
+    fn visit_true_handle(
+        &mut self,
+        handle: TrueHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
+            Ok(nt_data) => nt_data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        handle.node_id(),
+                        NodeKind::NonTerminal(handle.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_non_terminal(handle.node_id(), handle.kind(), nt_data, tree)?;
+        let result = match handle
+            .get_view_with_visit(
+                tree,
+                |view, visit: &mut Self| (visit.visit_true(handle, view, tree), visit),
+                self,
+            )
+            .map_err(|e| e.extract_error())
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(e),
+            Err(Ok(e)) => Err(e),
+            Err(Err(e)) => {
+                self.then_construct_error(
+                    Some(CstNode::new_non_terminal(handle.kind(), nt_data)),
+                    handle.node_id(),
+                    NodeKind::NonTerminal(handle.kind()),
+                    e,
+                    tree,
+                )
+            }
+        };
+        self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
+        result
+    }
+    fn visit_tuple_handle(
+        &mut self,
+        handle: TupleHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
+            Ok(nt_data) => nt_data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        handle.node_id(),
+                        NodeKind::NonTerminal(handle.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_non_terminal(handle.node_id(), handle.kind(), nt_data, tree)?;
+        let result = match handle
+            .get_view_with_visit(
+                tree,
+                |view, visit: &mut Self| (visit.visit_tuple(handle, view, tree), visit),
+                self,
+            )
+            .map_err(|e| e.extract_error())
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(e),
+            Err(Ok(e)) => Err(e),
+            Err(Err(e)) => {
+                self.then_construct_error(
+                    Some(CstNode::new_non_terminal(handle.kind(), nt_data)),
+                    handle.node_id(),
+                    NodeKind::NonTerminal(handle.kind()),
+                    e,
+                    tree,
+                )
+            }
+        };
+        self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
+        result
+    }
+    fn visit_tuple_begin_handle(
+        &mut self,
+        handle: TupleBeginHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
+            Ok(nt_data) => nt_data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        handle.node_id(),
+                        NodeKind::NonTerminal(handle.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_non_terminal(handle.node_id(), handle.kind(), nt_data, tree)?;
+        let result = match handle
+            .get_view_with_visit(
+                tree,
+                |view, visit: &mut Self| (
+                    visit.visit_tuple_begin(handle, view, tree),
+                    visit,
+                ),
+                self,
+            )
+            .map_err(|e| e.extract_error())
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(e),
+            Err(Ok(e)) => Err(e),
+            Err(Err(e)) => {
+                self.then_construct_error(
+                    Some(CstNode::new_non_terminal(handle.kind(), nt_data)),
+                    handle.node_id(),
+                    NodeKind::NonTerminal(handle.kind()),
+                    e,
+                    tree,
+                )
+            }
+        };
+        self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
+        result
+    }
+    fn visit_tuple_elements_handle(
+        &mut self,
+        handle: TupleElementsHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
+            Ok(nt_data) => nt_data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        handle.node_id(),
+                        NodeKind::NonTerminal(handle.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_non_terminal(handle.node_id(), handle.kind(), nt_data, tree)?;
+        let result = match handle
+            .get_view_with_visit(
+                tree,
+                |view, visit: &mut Self| (
+                    visit.visit_tuple_elements(handle, view, tree),
+                    visit,
+                ),
+                self,
+            )
+            .map_err(|e| e.extract_error())
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(e),
+            Err(Ok(e)) => Err(e),
+            Err(Err(e)) => {
+                self.then_construct_error(
+                    Some(CstNode::new_non_terminal(handle.kind(), nt_data)),
+                    handle.node_id(),
+                    NodeKind::NonTerminal(handle.kind()),
+                    e,
+                    tree,
+                )
+            }
+        };
+        self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
+        result
+    }
+    fn visit_tuple_elements_opt_handle(
+        &mut self,
+        handle: TupleElementsOptHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
+            Ok(nt_data) => nt_data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        handle.node_id(),
+                        NodeKind::NonTerminal(handle.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_non_terminal(handle.node_id(), handle.kind(), nt_data, tree)?;
+        let result = match handle
+            .get_view_with_visit(
+                tree,
+                |view, visit: &mut Self| (
+                    if let Some(view) = view {
+                        visit.visit_tuple_elements_opt(handle, view, tree)
+                    } else {
+                        Ok(())
+                    },
+                    visit,
+                ),
+                self,
+            )
+            .map_err(|e| e.extract_error())
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(e),
+            Err(Ok(e)) => Err(e),
+            Err(Err(e)) => {
+                self.then_construct_error(
+                    Some(CstNode::new_non_terminal(handle.kind(), nt_data)),
+                    handle.node_id(),
+                    NodeKind::NonTerminal(handle.kind()),
+                    e,
+                    tree,
+                )
+            }
+        };
+        self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
+        result
+    }
+    fn visit_tuple_elements_tail_handle(
+        &mut self,
+        handle: TupleElementsTailHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
+            Ok(nt_data) => nt_data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        handle.node_id(),
+                        NodeKind::NonTerminal(handle.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_non_terminal(handle.node_id(), handle.kind(), nt_data, tree)?;
+        let result = match handle
+            .get_view_with_visit(
+                tree,
+                |view, visit: &mut Self| (
+                    visit.visit_tuple_elements_tail(handle, view, tree),
+                    visit,
+                ),
+                self,
+            )
+            .map_err(|e| e.extract_error())
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(e),
+            Err(Ok(e)) => Err(e),
+            Err(Err(e)) => {
+                self.then_construct_error(
+                    Some(CstNode::new_non_terminal(handle.kind(), nt_data)),
+                    handle.node_id(),
+                    NodeKind::NonTerminal(handle.kind()),
+                    e,
+                    tree,
+                )
+            }
+        };
+        self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
+        result
+    }
+    fn visit_tuple_elements_tail_opt_handle(
+        &mut self,
+        handle: TupleElementsTailOptHandle,
         tree: &F,
     ) -> Result<(), V::Error> {
         let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
@@ -3737,7 +4941,11 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
             .get_view_with_visit(
                 tree,
                 |view, visit: &mut Self| (
-                    visit.visit_text_binding(handle, view, tree),
+                    if let Some(view) = view {
+                        visit.visit_tuple_elements_tail_opt(handle, view, tree)
+                    } else {
+                        Ok(())
+                    },
                     visit,
                 ),
                 self,
@@ -3760,9 +4968,9 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
         result
     }
-    fn visit_text_binding_opt_handle(
+    fn visit_tuple_end_handle(
         &mut self,
-        handle: TextBindingOptHandle,
+        handle: TupleEndHandle,
         tree: &F,
     ) -> Result<(), V::Error> {
         let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
@@ -3783,11 +4991,7 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
             .get_view_with_visit(
                 tree,
                 |view, visit: &mut Self| (
-                    if let Some(view) = view {
-                        visit.visit_text_binding_opt(handle, view, tree)
-                    } else {
-                        Ok(())
-                    },
+                    visit.visit_tuple_end(handle, view, tree),
                     visit,
                 ),
                 self,
@@ -3810,9 +5014,9 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
         result
     }
-    fn visit_text_start_handle(
+    fn visit_tuple_opt_handle(
         &mut self,
-        handle: TextStartHandle,
+        handle: TupleOptHandle,
         tree: &F,
     ) -> Result<(), V::Error> {
         let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
@@ -3833,7 +5037,11 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
             .get_view_with_visit(
                 tree,
                 |view, visit: &mut Self| (
-                    visit.visit_text_start(handle, view, tree),
+                    if let Some(view) = view {
+                        visit.visit_tuple_opt(handle, view, tree)
+                    } else {
+                        Ok(())
+                    },
                     visit,
                 ),
                 self,
@@ -3856,9 +5064,9 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
         result
     }
-    fn visit_true_handle(
+    fn visit_typed_quote_handle(
         &mut self,
-        handle: TrueHandle,
+        handle: TypedQuoteHandle,
         tree: &F,
     ) -> Result<(), V::Error> {
         let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
@@ -3878,7 +5086,10 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         let result = match handle
             .get_view_with_visit(
                 tree,
-                |view, visit: &mut Self| (visit.visit_true(handle, view, tree), visit),
+                |view, visit: &mut Self| (
+                    visit.visit_typed_quote(handle, view, tree),
+                    visit,
+                ),
                 self,
             )
             .map_err(|e| e.extract_error())
@@ -3899,9 +5110,9 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
         result
     }
-    fn visit_typed_quote_handle(
+    fn visit_typed_str_handle(
         &mut self,
-        handle: TypedQuoteHandle,
+        handle: TypedStrHandle,
         tree: &F,
     ) -> Result<(), V::Error> {
         let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
@@ -3922,7 +5133,7 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
             .get_view_with_visit(
                 tree,
                 |view, visit: &mut Self| (
-                    visit.visit_typed_quote(handle, view, tree),
+                    visit.visit_typed_str(handle, view, tree),
                     visit,
                 ),
                 self,
@@ -3945,9 +5156,9 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_non_terminal_close(handle.node_id(), handle.kind(), nt_data, tree)?;
         result
     }
-    fn visit_typed_str_handle(
+    fn visit_typed_str_opt_handle(
         &mut self,
-        handle: TypedStrHandle,
+        handle: TypedStrOptHandle,
         tree: &F,
     ) -> Result<(), V::Error> {
         let nt_data = match tree.get_non_terminal(handle.node_id(), handle.kind()) {
@@ -3968,7 +5179,11 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
             .get_view_with_visit(
                 tree,
                 |view, visit: &mut Self| (
-                    visit.visit_typed_str(handle, view, tree),
+                    if let Some(view) = view {
+                        visit.visit_typed_str_opt(handle, view, tree)
+                    } else {
+                        Ok(())
+                    },
                     visit,
                 ),
                 self,
@@ -4169,9 +5384,9 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         tree: &F,
     ) -> Result<(), V::Error> {
         let _handle = handle;
-        let ArrayView { array_begin, array_list, array_end } = view_param;
+        let ArrayView { array_begin, array_opt, array_end } = view_param;
         self.visit_array_begin_handle(array_begin, tree)?;
-        self.visit_array_list_handle(array_list, tree)?;
+        self.visit_array_opt_handle(array_opt, tree)?;
         self.visit_array_end_handle(array_end, tree)?;
         Ok(())
     }
@@ -4199,6 +5414,50 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_l_bracket_terminal(l_bracket, data, tree)?;
         Ok(())
     }
+    fn visit_array_elements_super(
+        &mut self,
+        handle: ArrayElementsHandle,
+        view_param: ArrayElementsView,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let _handle = handle;
+        let ArrayElementsView { value, array_elements_opt } = view_param;
+        self.visit_value_handle(value, tree)?;
+        self.visit_array_elements_opt_handle(array_elements_opt, tree)?;
+        Ok(())
+    }
+    fn visit_array_elements_opt_super(
+        &mut self,
+        handle: ArrayElementsOptHandle,
+        view_param: ArrayElementsTailHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let _handle = handle;
+        self.visit_array_elements_tail_handle(view_param, tree)?;
+        Ok(())
+    }
+    fn visit_array_elements_tail_super(
+        &mut self,
+        handle: ArrayElementsTailHandle,
+        view_param: ArrayElementsTailView,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let _handle = handle;
+        let ArrayElementsTailView { comma, array_elements_tail_opt } = view_param;
+        self.visit_comma_handle(comma, tree)?;
+        self.visit_array_elements_tail_opt_handle(array_elements_tail_opt, tree)?;
+        Ok(())
+    }
+    fn visit_array_elements_tail_opt_super(
+        &mut self,
+        handle: ArrayElementsTailOptHandle,
+        view_param: ArrayElementsHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let _handle = handle;
+        self.visit_array_elements_handle(view_param, tree)?;
+        Ok(())
+    }
     fn visit_array_end_super(
         &mut self,
         handle: ArrayEndHandle,
@@ -4223,19 +5482,6 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_r_bracket_terminal(r_bracket, data, tree)?;
         Ok(())
     }
-    fn visit_array_list_super(
-        &mut self,
-        handle: ArrayListHandle,
-        view_param: ArrayListView,
-        tree: &F,
-    ) -> Result<(), V::Error> {
-        let _handle = handle;
-        let ArrayListView { value, array_opt, array_list } = view_param;
-        self.visit_value_handle(value, tree)?;
-        self.visit_array_opt_handle(array_opt, tree)?;
-        self.visit_array_list_handle(array_list, tree)?;
-        Ok(())
-    }
     fn visit_array_marker_super(
         &mut self,
         handle: ArrayMarkerHandle,
@@ -4262,11 +5508,11 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
     fn visit_array_opt_super(
         &mut self,
         handle: ArrayOptHandle,
-        view_param: CommaHandle,
+        view_param: ArrayElementsHandle,
         tree: &F,
     ) -> Result<(), V::Error> {
         let _handle = handle;
-        self.visit_comma_handle(view_param, tree)?;
+        self.visit_array_elements_handle(view_param, tree)?;
         Ok(())
     }
     fn visit_at_super(
@@ -4594,6 +5840,41 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_false_terminal(r#false, data, tree)?;
         Ok(())
     }
+    fn visit_float_super(
+        &mut self,
+        handle: FloatHandle,
+        view_param: FloatView,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let _handle = handle;
+        let FloatView { float } = view_param;
+        let data = match float.get_data(tree) {
+            Ok(data) => data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        float.0,
+                        NodeKind::Terminal(float.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_float_terminal(float, data, tree)?;
+        Ok(())
+    }
+    fn visit_float_name_super(
+        &mut self,
+        handle: FloatNameHandle,
+        view_param: FloatNameView,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let _handle = handle;
+        let FloatNameView { ident } = view_param;
+        self.visit_ident_handle(ident, tree)?;
+        Ok(())
+    }
     fn visit_hole_super(
         &mut self,
         handle: HoleHandle,
@@ -4722,6 +6003,9 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
             KeyBaseView::Integer(item) => {
                 self.visit_integer_handle(item, tree)?;
             }
+            KeyBaseView::Float(item) => {
+                self.visit_float_handle(item, tree)?;
+            }
         }
         Ok(())
     }
@@ -4870,6 +6154,49 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_comma_handle(view_param, tree)?;
         Ok(())
     }
+    fn visit_path_super(
+        &mut self,
+        handle: PathHandle,
+        view_param: PathView,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let _handle = handle;
+        let PathView { dot, path_head, path_list } = view_param;
+        self.visit_dot_handle(dot, tree)?;
+        self.visit_path_head_handle(path_head, tree)?;
+        self.visit_path_list_handle(path_list, tree)?;
+        Ok(())
+    }
+    fn visit_path_head_super(
+        &mut self,
+        handle: PathHeadHandle,
+        view_param: PathHeadView,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let _handle = handle;
+        match view_param {
+            PathHeadView::Key(item) => {
+                self.visit_key_handle(item, tree)?;
+            }
+            PathHeadView::ArrayMarker(item) => {
+                self.visit_array_marker_handle(item, tree)?;
+            }
+        }
+        Ok(())
+    }
+    fn visit_path_list_super(
+        &mut self,
+        handle: PathListHandle,
+        view_param: PathListView,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let _handle = handle;
+        let PathListView { dot, key, path_list } = view_param;
+        self.visit_dot_handle(dot, tree)?;
+        self.visit_key_handle(key, tree)?;
+        self.visit_path_list_handle(path_list, tree)?;
+        Ok(())
+    }
     fn visit_quote_super(
         &mut self,
         handle: QuoteHandle,
@@ -4956,9 +6283,9 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         tree: &F,
     ) -> Result<(), V::Error> {
         let _handle = handle;
-        let StrView { quote, in_str, quote2 } = view_param;
+        let StrView { quote, str_opt, quote2 } = view_param;
         self.visit_quote_handle(quote, tree)?;
-        self.visit_in_str_handle(in_str, tree)?;
+        self.visit_str_opt_handle(str_opt, tree)?;
         self.visit_quote_handle(quote2, tree)?;
         Ok(())
     }
@@ -4987,6 +6314,16 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_str_continues_list_handle(str_continues_list, tree)?;
         Ok(())
     }
+    fn visit_str_opt_super(
+        &mut self,
+        handle: StrOptHandle,
+        view_param: InStrHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let _handle = handle;
+        self.visit_in_str_handle(view_param, tree)?;
+        Ok(())
+    }
     fn visit_swon_super(
         &mut self,
         handle: SwonHandle,
@@ -5119,6 +6456,121 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_true_terminal(r#true, data, tree)?;
         Ok(())
     }
+    fn visit_tuple_super(
+        &mut self,
+        handle: TupleHandle,
+        view_param: TupleView,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let _handle = handle;
+        let TupleView { tuple_begin, tuple_opt, tuple_end } = view_param;
+        self.visit_tuple_begin_handle(tuple_begin, tree)?;
+        self.visit_tuple_opt_handle(tuple_opt, tree)?;
+        self.visit_tuple_end_handle(tuple_end, tree)?;
+        Ok(())
+    }
+    fn visit_tuple_begin_super(
+        &mut self,
+        handle: TupleBeginHandle,
+        view_param: TupleBeginView,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let _handle = handle;
+        let TupleBeginView { l_paren } = view_param;
+        let data = match l_paren.get_data(tree) {
+            Ok(data) => data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        l_paren.0,
+                        NodeKind::Terminal(l_paren.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_l_paren_terminal(l_paren, data, tree)?;
+        Ok(())
+    }
+    fn visit_tuple_elements_super(
+        &mut self,
+        handle: TupleElementsHandle,
+        view_param: TupleElementsView,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let _handle = handle;
+        let TupleElementsView { value, tuple_elements_opt } = view_param;
+        self.visit_value_handle(value, tree)?;
+        self.visit_tuple_elements_opt_handle(tuple_elements_opt, tree)?;
+        Ok(())
+    }
+    fn visit_tuple_elements_opt_super(
+        &mut self,
+        handle: TupleElementsOptHandle,
+        view_param: TupleElementsTailHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let _handle = handle;
+        self.visit_tuple_elements_tail_handle(view_param, tree)?;
+        Ok(())
+    }
+    fn visit_tuple_elements_tail_super(
+        &mut self,
+        handle: TupleElementsTailHandle,
+        view_param: TupleElementsTailView,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let _handle = handle;
+        let TupleElementsTailView { comma, tuple_elements_tail_opt } = view_param;
+        self.visit_comma_handle(comma, tree)?;
+        self.visit_tuple_elements_tail_opt_handle(tuple_elements_tail_opt, tree)?;
+        Ok(())
+    }
+    fn visit_tuple_elements_tail_opt_super(
+        &mut self,
+        handle: TupleElementsTailOptHandle,
+        view_param: TupleElementsHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let _handle = handle;
+        self.visit_tuple_elements_handle(view_param, tree)?;
+        Ok(())
+    }
+    fn visit_tuple_end_super(
+        &mut self,
+        handle: TupleEndHandle,
+        view_param: TupleEndView,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let _handle = handle;
+        let TupleEndView { r_paren } = view_param;
+        let data = match r_paren.get_data(tree) {
+            Ok(data) => data,
+            Err(error) => {
+                return self
+                    .then_construct_error(
+                        None,
+                        r_paren.0,
+                        NodeKind::Terminal(r_paren.kind()),
+                        error,
+                        tree,
+                    );
+            }
+        };
+        self.visit_r_paren_terminal(r_paren, data, tree)?;
+        Ok(())
+    }
+    fn visit_tuple_opt_super(
+        &mut self,
+        handle: TupleOptHandle,
+        view_param: TupleElementsHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let _handle = handle;
+        self.visit_tuple_elements_handle(view_param, tree)?;
+        Ok(())
+    }
     fn visit_typed_quote_super(
         &mut self,
         handle: TypedQuoteHandle,
@@ -5150,12 +6602,22 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         tree: &F,
     ) -> Result<(), V::Error> {
         let _handle = handle;
-        let TypedStrView { typed_quote, in_str, quote } = view_param;
+        let TypedStrView { typed_quote, typed_str_opt, quote } = view_param;
         self.visit_typed_quote_handle(typed_quote, tree)?;
-        self.visit_in_str_handle(in_str, tree)?;
+        self.visit_typed_str_opt_handle(typed_str_opt, tree)?;
         self.visit_quote_handle(quote, tree)?;
         Ok(())
     }
+    fn visit_typed_str_opt_super(
+        &mut self,
+        handle: TypedStrOptHandle,
+        view_param: InStrHandle,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        let _handle = handle;
+        self.visit_in_str_handle(view_param, tree)?;
+        Ok(())
+    }
     fn visit_value_super(
         &mut self,
         handle: ValueHandle,
@@ -5170,9 +6632,18 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
             ValueView::Array(item) => {
                 self.visit_array_handle(item, tree)?;
             }
+            ValueView::Tuple(item) => {
+                self.visit_tuple_handle(item, tree)?;
+            }
             ValueView::Integer(item) => {
                 self.visit_integer_handle(item, tree)?;
             }
+            ValueView::Float(item) => {
+                self.visit_float_handle(item, tree)?;
+            }
+            ValueView::FloatName(item) => {
+                self.visit_float_name_handle(item, tree)?;
+            }
             ValueView::Boolean(item) => {
                 self.visit_boolean_handle(item, tree)?;
             }
@@ -5197,6 +6668,9 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
             ValueView::Code(item) => {
                 self.visit_code_handle(item, tree)?;
             }
+            ValueView::Path(item) => {
+                self.visit_path_handle(item, tree)?;
+            }
         }
         Ok(())
     }
@@ -5292,6 +6766,15 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_terminal(terminal.0, terminal.kind(), data, tree)?;
         Ok(())
     }
+    fn visit_float_terminal_super(
+        &mut self,
+        terminal: Float,
+        data: TerminalData,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        self.visit_terminal(terminal.0, terminal.kind(), data, tree)?;
+        Ok(())
+    }
     fn visit_true_terminal_super(
         &mut self,
         terminal: True,
@@ -5472,6 +6955,24 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
         self.visit_terminal(terminal.0, terminal.kind(), data, tree)?;
         Ok(())
     }
+    fn visit_l_paren_terminal_super(
+        &mut self,
+        terminal: LParen,
+        data: TerminalData,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        self.visit_terminal(terminal.0, terminal.kind(), data, tree)?;
+        Ok(())
+    }
+    fn visit_r_paren_terminal_super(
+        &mut self,
+        terminal: RParen,
+        data: TerminalData,
+        tree: &F,
+    ) -> Result<(), V::Error> {
+        self.visit_terminal(terminal.0, terminal.kind(), data, tree)?;
+        Ok(())
+    }
     fn visit_bind_terminal_super(
         &mut self,
         terminal: Bind,
@@ -5582,14 +7083,26 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
                         let handle = ArrayBeginHandle(id);
                         self.visit_array_begin_handle(handle, tree)?;
                     }
+                    NonTerminalKind::ArrayElements => {
+                        let handle = ArrayElementsHandle(id);
+                        self.visit_array_elements_handle(handle, tree)?;
+                    }
+                    NonTerminalKind::ArrayElementsOpt => {
+                        let handle = ArrayElementsOptHandle(id);
+                        self.visit_array_elements_opt_handle(handle, tree)?;
+                    }
+                    NonTerminalKind::ArrayElementsTail => {
+                        let handle = ArrayElementsTailHandle(id);
+                        self.visit_array_elements_tail_handle(handle, tree)?;
+                    }
+                    NonTerminalKind::ArrayElementsTailOpt => {
+                        let handle = ArrayElementsTailOptHandle(id);
+                        self.visit_array_elements_tail_opt_handle(handle, tree)?;
+                    }
                     NonTerminalKind::ArrayEnd => {
                         let handle = ArrayEndHandle(id);
                         self.visit_array_end_handle(handle, tree)?;
                     }
-                    NonTerminalKind::ArrayList => {
-                        let handle = ArrayListHandle(id);
-                        self.visit_array_list_handle(handle, tree)?;
-                    }
                     NonTerminalKind::ArrayMarker => {
                         let handle = ArrayMarkerHandle(id);
                         self.visit_array_marker_handle(handle, tree)?;
@@ -5662,6 +7175,14 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
                         let handle = FalseHandle(id);
                         self.visit_false_handle(handle, tree)?;
                     }
+                    NonTerminalKind::Float => {
+                        let handle = FloatHandle(id);
+                        self.visit_float_handle(handle, tree)?;
+                    }
+                    NonTerminalKind::FloatName => {
+                        let handle = FloatNameHandle(id);
+                        self.visit_float_name_handle(handle, tree)?;
+                    }
                     NonTerminalKind::Hole => {
                         let handle = HoleHandle(id);
                         self.visit_hole_handle(handle, tree)?;
@@ -5722,6 +7243,18 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
                         let handle = ObjectOptHandle(id);
                         self.visit_object_opt_handle(handle, tree)?;
                     }
+                    NonTerminalKind::Path => {
+                        let handle = PathHandle(id);
+                        self.visit_path_handle(handle, tree)?;
+                    }
+                    NonTerminalKind::PathHead => {
+                        let handle = PathHeadHandle(id);
+                        self.visit_path_head_handle(handle, tree)?;
+                    }
+                    NonTerminalKind::PathList => {
+                        let handle = PathListHandle(id);
+                        self.visit_path_list_handle(handle, tree)?;
+                    }
                     NonTerminalKind::Quote => {
                         let handle = QuoteHandle(id);
                         self.visit_quote_handle(handle, tree)?;
@@ -5754,6 +7287,10 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
                         let handle = StrContinuesListHandle(id);
                         self.visit_str_continues_list_handle(handle, tree)?;
                     }
+                    NonTerminalKind::StrOpt => {
+                        let handle = StrOptHandle(id);
+                        self.visit_str_opt_handle(handle, tree)?;
+                    }
                     NonTerminalKind::Swon => {
                         let handle = SwonHandle(id);
                         self.visit_swon_handle(handle, tree)?;
@@ -5786,6 +7323,38 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
                         let handle = TrueHandle(id);
                         self.visit_true_handle(handle, tree)?;
                     }
+                    NonTerminalKind::Tuple => {
+                        let handle = TupleHandle(id);
+                        self.visit_tuple_handle(handle, tree)?;
+                    }
+                    NonTerminalKind::TupleBegin => {
+                        let handle = TupleBeginHandle(id);
+                        self.visit_tuple_begin_handle(handle, tree)?;
+                    }
+                    NonTerminalKind::TupleElements => {
+                        let handle = TupleElementsHandle(id);
+                        self.visit_tuple_elements_handle(handle, tree)?;
+                    }
+                    NonTerminalKind::TupleElementsOpt => {
+                        let handle = TupleElementsOptHandle(id);
+                        self.visit_tuple_elements_opt_handle(handle, tree)?;
+                    }
+                    NonTerminalKind::TupleElementsTail => {
+                        let handle = TupleElementsTailHandle(id);
+                        self.visit_tuple_elements_tail_handle(handle, tree)?;
+                    }
+                    NonTerminalKind::TupleElementsTailOpt => {
+                        let handle = TupleElementsTailOptHandle(id);
+                        self.visit_tuple_elements_tail_opt_handle(handle, tree)?;
+                    }
+                    NonTerminalKind::TupleEnd => {
+                        let handle = TupleEndHandle(id);
+                        self.visit_tuple_end_handle(handle, tree)?;
+                    }
+                    NonTerminalKind::TupleOpt => {
+                        let handle = TupleOptHandle(id);
+                        self.visit_tuple_opt_handle(handle, tree)?;
+                    }
                     NonTerminalKind::TypedQuote => {
                         let handle = TypedQuoteHandle(id);
                         self.visit_typed_quote_handle(handle, tree)?;
@@ -5794,6 +7363,10 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
                         let handle = TypedStrHandle(id);
                         self.visit_typed_str_handle(handle, tree)?;
                     }
+                    NonTerminalKind::TypedStrOpt => {
+                        let handle = TypedStrOptHandle(id);
+                        self.visit_typed_str_opt_handle(handle, tree)?;
+                    }
                     NonTerminalKind::Value => {
                         let handle = ValueHandle(id);
                         self.visit_value_handle(handle, tree)?;
@@ -5834,6 +7407,10 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
                         let terminal = Integer(id);
                         self.visit_integer_terminal(terminal, data, tree)?;
                     }
+                    TerminalKind::Float => {
+                        let terminal = Float(id);
+                        self.visit_float_terminal(terminal, data, tree)?;
+                    }
                     TerminalKind::True => {
                         let terminal = True(id);
                         self.visit_true_terminal(terminal, data, tree)?;
@@ -5914,6 +7491,14 @@ impl<V: CstVisitor<F>, F: CstFacade> CstVisitorSuper<F, V::Error> for V {
                         let terminal = RBracket(id);
                         self.visit_r_bracket_terminal(terminal, data, tree)?;
                     }
+                    TerminalKind::LParen => {
+                        let terminal = LParen(id);
+                        self.visit_l_paren_terminal(terminal, data, tree)?;
+                    }
+                    TerminalKind::RParen => {
+                        let terminal = RParen(id);
+                        self.visit_r_paren_terminal(terminal, data, tree)?;
+                    }
                     TerminalKind::Bind => {
                         let terminal = Bind(id);
                         self.visit_bind_terminal(terminal, data, tree)?;
@@ -38,7 +38,7 @@ impl IdentifierParser {
         } else {
             Err(IdentifierError::InvalidChar {
                 at: matches.end(),
-                invalid_char: s.chars().nth(matches.end()).unwrap(),
+                invalid_char: s[matches.end()..].chars().next().unwrap(),
             })
         }
     }
@@ -69,6 +69,12 @@ pub enum IdentifierError {
     },
 }
 
+impl AsRef<str> for Identifier {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
 impl Display for Identifier {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -123,6 +129,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_identifier_error_after_multibyte() {
+        assert_eq!(
+            Identifier::from_str("日本 key"),
+            Err(IdentifierError::InvalidChar {
+                at: 6,
+                invalid_char: ' ',
+            })
+        );
+    }
+
     #[test]
     fn test_identifier_invalid_first_char() {
         assert_eq!(
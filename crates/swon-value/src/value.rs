@@ -3,6 +3,7 @@ use alloc::string::String;
 use alloc::vec::Vec;
 
 use crate::identifier::Identifier;
+use thiserror::Error;
 use thisisplural::Plural;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -21,6 +22,8 @@ pub enum Value {
     Map(Map),
     Variant(Variant),
     Unit,
+    /// A path from the document root like `.a.b[0]`.
+    Path(Path),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -35,6 +38,46 @@ pub enum KeyCmpValue {
     Unit,
 }
 
+impl From<KeyCmpValue> for Value {
+    fn from(value: KeyCmpValue) -> Self {
+        match value {
+            KeyCmpValue::Null => Value::Null,
+            KeyCmpValue::Bool(b) => Value::Bool(b),
+            KeyCmpValue::I64(i) => Value::I64(i),
+            KeyCmpValue::U64(u) => Value::U64(u),
+            KeyCmpValue::String(s) => Value::String(s),
+            KeyCmpValue::Tuple(tuple) => {
+                Value::Tuple(Tuple(tuple.0.into_iter().map(Value::from).collect()))
+            }
+            KeyCmpValue::Unit => Value::Unit,
+        }
+    }
+}
+
+impl TryFrom<Value> for KeyCmpValue {
+    /// The value, or the element of a tuple, that cannot be a key.
+    type Error = Value;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Null => Ok(KeyCmpValue::Null),
+            Value::Bool(b) => Ok(KeyCmpValue::Bool(b)),
+            Value::I64(i) => Ok(KeyCmpValue::I64(i)),
+            Value::U64(u) => Ok(KeyCmpValue::U64(u)),
+            Value::String(s) => Ok(KeyCmpValue::String(s)),
+            Value::Tuple(tuple) => Ok(KeyCmpValue::Tuple(Tuple(
+                tuple
+                    .0
+                    .into_iter()
+                    .map(KeyCmpValue::try_from)
+                    .collect::<Result<_, _>>()?,
+            ))),
+            Value::Unit => Ok(KeyCmpValue::Unit),
+            value => Err(value),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Plural)]
 pub struct Path(pub Vec<PathSegment>);
 
@@ -42,7 +85,119 @@ pub struct Path(pub Vec<PathSegment>);
 pub enum PathSegment {
     Extension(Identifier),
     Value(Value),
-    Array { key: Value, index: Option<Value> },
+    /// An element of a tuple like `tuple.0`.
+    TupleIndex(u8),
+    Array {
+        key: Value,
+        index: Option<Value>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PathError {
+    #[error("No value found at segment {index} of the path")]
+    NotFound {
+        /// Index of the segment that could not be resolved.
+        index: usize,
+    },
+    #[error("Extension at segment {index} of the path cannot be resolved against a value")]
+    Extension { index: usize },
+}
+
+impl PathError {
+    /// Shift the segment index by `offset`, for resolving a path piece by piece.
+    pub fn offset(self, offset: usize) -> Self {
+        match self {
+            PathError::NotFound { index } => PathError::NotFound {
+                index: index + offset,
+            },
+            PathError::Extension { index } => PathError::Extension {
+                index: index + offset,
+            },
+        }
+    }
+}
+
+impl Path {
+    /// Evaluate this path against `value`, which is usually the document root.
+    ///
+    /// Extensions are not a part of [Value], so resolving an extension segment is an error.
+    pub fn resolve<'a>(&self, value: &'a Value) -> Result<&'a Value, PathError> {
+        resolve_path(value, &self.0)
+    }
+}
+
+/// Evaluate `segments` against `value`. See [Path::resolve].
+pub fn resolve_path<'a>(
+    value: &'a Value,
+    segments: &[PathSegment],
+) -> Result<&'a Value, PathError> {
+    let mut current = value;
+    for (index, segment) in segments.iter().enumerate() {
+        current = match segment {
+            PathSegment::Extension(_) => return Err(PathError::Extension { index }),
+            PathSegment::Value(key) => match current {
+                Value::Array(array) => array_index(key).and_then(|i| array.0.get(i)),
+                _ => map_entry(current, key),
+            },
+            PathSegment::TupleIndex(i) => match current {
+                Value::Tuple(tuple) => tuple.0.get(*i as usize),
+                _ => None,
+            },
+            PathSegment::Array { key, index: None } => map_entry(current, key),
+            PathSegment::Array {
+                key,
+                index: Some(i),
+            } => match map_entry(current, key) {
+                Some(Value::Array(array)) => array_index(i).and_then(|i| array.0.get(i)),
+                _ => None,
+            },
+        }
+        .ok_or(PathError::NotFound { index })?;
+    }
+    Ok(current)
+}
+
+fn map_entry<'a>(value: &'a Value, key: &Value) -> Option<&'a Value> {
+    let map = match value {
+        Value::Map(map) => map,
+        // Variants are transparent to paths
+        Value::Variant(variant) => return map_entry(&variant.content, key),
+        _ => return None,
+    };
+    map.0.get(&KeyCmpValue::try_from(key.clone()).ok()?)
+}
+
+/// Move `path` from an array to its element at `index`. Elements of an array under a map key are
+/// addressed as `key[index]`, and others as `[index]` after the path of the array.
+pub fn push_element(path: &mut Vec<PathSegment>, index: usize) {
+    let index = Value::U64(index as u64);
+    match path.pop() {
+        Some(PathSegment::Value(key)) => path.push(PathSegment::Array {
+            key,
+            index: Some(index),
+        }),
+        parent => {
+            path.extend(parent);
+            path.push(PathSegment::Value(index));
+        }
+    }
+}
+
+/// Move `path` back from an element to its array. See [push_element].
+pub fn pop_element(path: &mut Vec<PathSegment>) {
+    if let Some(PathSegment::Array { key, .. }) = path.pop() {
+        path.push(PathSegment::Value(key));
+    }
+}
+
+/// The index of an array element addressed by `value`, which must be a non-negative integer.
+pub fn array_index(value: &Value) -> Option<usize> {
+    match value {
+        Value::U64(index) => usize::try_from(*index).ok(),
+        Value::I64(index) => usize::try_from(*index).ok(),
+        _ => None,
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -0,0 +1,833 @@
+use std::collections::HashMap;
+use std::str::Chars;
+
+use swon_fmt::FmtError;
+use swon_tree::notation::{escape, is_text, key_notation as string_notation};
+use thiserror::Error;
+use yaml_rust2::{
+    Event, ScanError,
+    parser::{Parser, Tag},
+    scanner::{Marker, TScalarStyle},
+};
+
+#[derive(Debug, Clone)]
+pub struct FromYamlOptions {
+    /// Mappings nested up to this depth become sections, and deeper ones blocks or inline objects.
+    pub max_section_depth: usize,
+    /// How many nodes aliases may copy in total, so that aliases of aliases can't expand
+    /// exponentially.
+    pub max_alias_nodes: usize,
+}
+
+impl Default for FromYamlOptions {
+    fn default() -> Self {
+        Self {
+            max_section_depth: 2,
+            max_alias_nodes: 100_000,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum FromYamlError {
+    #[error(transparent)]
+    ScanError(#[from] ScanError),
+    #[error("{kind} at line {line} column {column}")]
+    Value {
+        line: usize,
+        column: usize,
+        kind: FromYamlErrorKind,
+    },
+    #[error(transparent)]
+    FmtError(#[from] FmtError),
+    /// The converted text does not parse, which is a bug of the conversion.
+    #[error("The converted document is not valid SWON: {0}")]
+    InvalidSwon(Box<swon_parol::ParseError>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum FromYamlErrorKind {
+    #[error("The root of a document must be a mapping")]
+    RootNotMapping,
+    #[error("Key {0} can't be a SWON key, which must be a string or an integer")]
+    NonStringKey(String),
+    #[error("Tag `{0}` has no SWON representation")]
+    UnsupportedTag(String),
+    #[error("{value:?} is not a valid `{tag}`")]
+    InvalidScalar { tag: String, value: String },
+    #[error("Aliases copy more than {0} nodes")]
+    TooManyAliasNodes(usize),
+    /// An event out of place in the stream, which the YAML parser doesn't produce.
+    #[error("Unexpected {0}")]
+    UnexpectedEvent(String),
+}
+
+/// Convert a YAML stream to formatted SWON documents, one for each YAML document.
+///
+/// | YAML | SWON |
+/// |------|------|
+/// | mapping | section `@ a.b` up to [FromYamlOptions::max_section_depth], else a block or an inline object |
+/// | sequence of mappings | sections `@ a[]` |
+/// | null, boolean, integer, float and string by the core schema | the same |
+/// | block scalar | text binding `key: text` if it is one line without surrounding whitespace, else inline code if it is one line, else a code block if it ends with a line break, else a string |
+/// | scalar with a local tag `!url` | typed string `url"..."`, or a code block in `url` for block scalars |
+/// | mapping with a local tag `!name` | variant with `$variant = "name"` |
+/// | alias `*a` | a copy of the anchored node |
+/// | `!!str`, `!!int`, `!!float`, `!!bool`, `!!null`, `!!map`, `!!seq` | as tagged |
+///
+/// Keys must be strings or integers, which become string keys because integer keys are tuple
+/// indices in SWON. Other keys, and other tags, have no SWON representation. Both are
+/// errors with the line and column of the node, as is an alias once aliases have copied more
+/// than [FromYamlOptions::max_alias_nodes] nodes. An empty document becomes an empty SWON
+/// document.
+pub fn from_yaml(input: &str, options: &FromYamlOptions) -> Result<Vec<String>, FromYamlError> {
+    let mut reader = Reader {
+        parser: Parser::new_from_str(input),
+        anchors: HashMap::new(),
+        max_alias_nodes: options.max_alias_nodes,
+        alias_nodes: 0,
+    };
+    let mut documents = vec![];
+    loop {
+        let (event, mark) = reader.next()?;
+        match event {
+            Event::DocumentStart => {
+                let (event, mark) = reader.next()?;
+                let root = reader.node(event, mark)?;
+                let mut writer = Writer {
+                    output: String::new(),
+                    options,
+                };
+                writer.document(&root)?;
+                documents.push(format(writer.output)?);
+            }
+            Event::StreamEnd => break,
+            Event::StreamStart | Event::DocumentEnd => {}
+            event => {
+                let kind = FromYamlErrorKind::UnexpectedEvent(format!("{event:?}"));
+                return Err(error(mark, kind));
+            }
+        }
+    }
+    Ok(documents)
+}
+
+fn format(input: String) -> Result<String, FromYamlError> {
+    let mut cst = swon_parol::parse(&input, "yaml.swon").map_err(FromYamlError::InvalidSwon)?;
+    swon_fmt::fmt(&input, &mut cst)?;
+    let mut output = String::new();
+    cst.write(&input, &mut output)
+        .expect("writing to a string never fails");
+    Ok(output)
+}
+
+/// A YAML node with its aliases expanded.
+#[derive(Debug, Clone)]
+enum Node {
+    Scalar {
+        value: String,
+        style: TScalarStyle,
+        tag: Option<Tag>,
+        mark: Marker,
+    },
+    Sequence {
+        elements: Vec<Node>,
+        tag: Option<Tag>,
+        mark: Marker,
+    },
+    Mapping {
+        entries: Vec<(Node, Node)>,
+        tag: Option<Tag>,
+        mark: Marker,
+    },
+}
+
+impl Node {
+    fn mark(&self) -> Marker {
+        match self {
+            Node::Scalar { mark, .. }
+            | Node::Sequence { mark, .. }
+            | Node::Mapping { mark, .. } => *mark,
+        }
+    }
+
+    /// Whether the node is a non-empty mapping, which can be written as a section or a block.
+    fn is_mapping(&self) -> bool {
+        matches!(self, Node::Mapping { entries, .. } if !entries.is_empty())
+    }
+
+    /// The number of nodes in this node, including itself.
+    fn size(&self) -> usize {
+        match self {
+            Node::Scalar { .. } => 1,
+            Node::Sequence { elements, .. } => 1 + elements.iter().map(Node::size).sum::<usize>(),
+            Node::Mapping { entries, .. } => {
+                let entries = entries.iter().map(|(key, value)| key.size() + value.size());
+                1 + entries.sum::<usize>()
+            }
+        }
+    }
+}
+
+/// Builds [Node]s from the events of a stream.
+struct Reader<'a> {
+    parser: Parser<Chars<'a>>,
+    /// Anchored nodes with their sizes.
+    anchors: HashMap<usize, (Node, usize)>,
+    max_alias_nodes: usize,
+    /// How many nodes aliases have copied so far.
+    alias_nodes: usize,
+}
+
+impl Reader<'_> {
+    fn next(&mut self) -> Result<(Event, Marker), ScanError> {
+        self.parser.next_token()
+    }
+
+    /// Read the node that starts with `event`.
+    fn node(&mut self, event: Event, mark: Marker) -> Result<Node, FromYamlError> {
+        let (node, anchor) = match event {
+            Event::Alias(id) => {
+                let Some((node, size)) = self.anchors.get(&id) else {
+                    return Err(ScanError::new(mark, "unknown anchor").into());
+                };
+                self.alias_nodes += size;
+                if self.alias_nodes > self.max_alias_nodes {
+                    let kind = FromYamlErrorKind::TooManyAliasNodes(self.max_alias_nodes);
+                    return Err(error(mark, kind));
+                }
+                return Ok(node.clone());
+            }
+            Event::Scalar(value, style, anchor, tag) => {
+                let node = Node::Scalar {
+                    value,
+                    style,
+                    tag,
+                    mark,
+                };
+                (node, anchor)
+            }
+            Event::SequenceStart(anchor, tag) => {
+                let mut elements = vec![];
+                loop {
+                    match self.next()? {
+                        (Event::SequenceEnd, _) => break,
+                        (event, mark) => elements.push(self.node(event, mark)?),
+                    }
+                }
+                let node = Node::Sequence {
+                    elements,
+                    tag,
+                    mark,
+                };
+                (node, anchor)
+            }
+            Event::MappingStart(anchor, tag) => {
+                let mut entries = vec![];
+                loop {
+                    let key = match self.next()? {
+                        (Event::MappingEnd, _) => break,
+                        (event, mark) => self.node(event, mark)?,
+                    };
+                    let (event, mark) = self.next()?;
+                    entries.push((key, self.node(event, mark)?));
+                }
+                let node = Node::Mapping { entries, tag, mark };
+                (node, anchor)
+            }
+            event => {
+                let kind = FromYamlErrorKind::UnexpectedEvent(format!("{event:?}"));
+                return Err(error(mark, kind));
+            }
+        };
+        // Anchor 0 means the node has none
+        if anchor > 0 {
+            self.anchors.insert(anchor, (node.clone(), node.size()));
+        }
+        Ok(node)
+    }
+}
+
+/// A resolved scalar.
+enum Scalar<'a> {
+    Null,
+    Bool(bool),
+    /// Decimal notation of an integer
+    Integer(String),
+    /// SWON notation of a float
+    Float(String),
+    String(&'a str),
+    Typed(&'a str, &'a str),
+    /// The content of a block scalar, with the language of its local tag
+    Block(&'a str, &'a str),
+}
+
+enum TagKind<'a> {
+    /// `!!name`
+    Core(&'a str),
+    /// `!name`, or the non-specific `!` that makes a scalar a string
+    Local(&'a str),
+    Other,
+}
+
+fn tag_kind(tag: &Tag) -> TagKind<'_> {
+    match tag.handle.as_str() {
+        "tag:yaml.org,2002:" | "!!" => TagKind::Core(&tag.suffix),
+        "!" => TagKind::Local(&tag.suffix),
+        _ => TagKind::Other,
+    }
+}
+
+fn tag_notation(tag: &Tag) -> String {
+    match tag.handle.as_str() {
+        "tag:yaml.org,2002:" => format!("!!{}", tag.suffix),
+        handle => format!("{handle}{}", tag.suffix),
+    }
+}
+
+/// Whether `name` can be the type name of a typed string or the language of a code block.
+fn is_type_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn scalar<'a>(
+    value: &'a str,
+    style: TScalarStyle,
+    tag: Option<&'a Tag>,
+    mark: Marker,
+) -> Result<Scalar<'a>, FromYamlError> {
+    let block = matches!(style, TScalarStyle::Literal | TScalarStyle::Folded);
+    let Some(tag) = tag else {
+        return Ok(match style {
+            TScalarStyle::Plain => resolve_plain(value),
+            _ if block => Scalar::Block("", value),
+            _ => Scalar::String(value),
+        });
+    };
+    let scalar = match tag_kind(tag) {
+        TagKind::Local("") => Scalar::String(value),
+        TagKind::Local(name) if is_type_name(name) && block => Scalar::Block(name, value),
+        TagKind::Local(name) if is_type_name(name) => Scalar::Typed(name, value),
+        TagKind::Core("str") if block => Scalar::Block("", value),
+        TagKind::Core("str") => Scalar::String(value),
+        TagKind::Core(core @ ("null" | "bool" | "int" | "float")) => {
+            let scalar = resolve_plain(value);
+            let valid = match (core, &scalar) {
+                ("null", Scalar::Null) | ("bool", Scalar::Bool(_)) => true,
+                ("int", Scalar::Integer(_)) | ("float", Scalar::Float(_)) => true,
+                ("float", Scalar::Integer(integer)) => {
+                    return Ok(Scalar::Float(format!("{integer}.0")));
+                }
+                _ => false,
+            };
+            if !valid {
+                let kind = FromYamlErrorKind::InvalidScalar {
+                    tag: tag_notation(tag),
+                    value: value.to_string(),
+                };
+                return Err(error(mark, kind));
+            }
+            scalar
+        }
+        _ => {
+            let kind = FromYamlErrorKind::UnsupportedTag(tag_notation(tag));
+            return Err(error(mark, kind));
+        }
+    };
+    Ok(scalar)
+}
+
+/// Resolve a plain scalar by the YAML 1.2 core schema.
+fn resolve_plain(value: &str) -> Scalar<'_> {
+    match value {
+        "" | "~" | "null" | "Null" | "NULL" => return Scalar::Null,
+        "true" | "True" | "TRUE" => return Scalar::Bool(true),
+        "false" | "False" | "FALSE" => return Scalar::Bool(false),
+        ".inf" | ".Inf" | ".INF" | "+.inf" | "+.Inf" | "+.INF" => {
+            return Scalar::Float("inf".into());
+        }
+        "-.inf" | "-.Inf" | "-.INF" => return Scalar::Float("-inf".into()),
+        ".nan" | ".NaN" | ".NAN" => return Scalar::Float("nan".into()),
+        _ => {}
+    }
+    if let Some(integer) = resolve_integer(value) {
+        return Scalar::Integer(integer);
+    }
+    let numeric = value
+        .chars()
+        .all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-'));
+    if numeric
+        && value.contains(|c: char| c.is_ascii_digit())
+        && let Ok(float) = value.parse::<f64>()
+    {
+        // `{:?}` keeps a fractional part or an exponent, so `1e3` stays a float
+        return Scalar::Float(format!("{float:?}"));
+    }
+    Scalar::String(value)
+}
+
+/// Whether a plain scalar of `text` is a string.
+pub(crate) fn resolves_to_string(text: &str) -> bool {
+    matches!(resolve_plain(text), Scalar::String(_))
+}
+
+fn resolve_integer(value: &str) -> Option<String> {
+    let (digits, radix) = if let Some(hex) = value.strip_prefix("0x") {
+        (hex, 16)
+    } else if let Some(octal) = value.strip_prefix("0o") {
+        (octal, 8)
+    } else {
+        let unsigned = value.strip_prefix(['-', '+']).unwrap_or(value);
+        if unsigned.is_empty() || !unsigned.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        (value, 10)
+    };
+    if digits.is_empty() || digits.starts_with(['-', '+']) {
+        return None;
+    }
+    i128::from_str_radix(digits, radix)
+        .ok()
+        .map(|integer| integer.to_string())
+}
+
+fn error(mark: Marker, kind: FromYamlErrorKind) -> FromYamlError {
+    FromYamlError::Value {
+        line: mark.line(),
+        column: mark.col() + 1,
+        kind,
+    }
+}
+
+/// A key of a section, and whether it pushes a new array element.
+type Segment = (String, bool);
+
+/// A section with the bindings directly in it.
+struct Section<'n> {
+    keys: Vec<Segment>,
+    variant: Option<&'n str>,
+    bindings: Vec<(String, &'n Node)>,
+}
+
+/// Writes SWON text, leaving the layout to the formatter.
+struct Writer<'o> {
+    output: String,
+    options: &'o FromYamlOptions,
+}
+
+impl Writer<'_> {
+    fn document(&mut self, root: &Node) -> Result<(), FromYamlError> {
+        let (entries, tag) = match root {
+            Node::Mapping { entries, tag, .. } => (entries, tag),
+            Node::Scalar {
+                value,
+                style: TScalarStyle::Plain,
+                tag: None,
+                ..
+            } if value.is_empty() => return Ok(()),
+            node => return Err(error(node.mark(), FromYamlErrorKind::RootNotMapping)),
+        };
+        self.variant(variant(tag.as_ref(), root.mark())?);
+        let mut sections = vec![];
+        let bindings = self.plan(entries, &[], 0, &mut sections)?;
+        for (key, node) in bindings {
+            self.binding(&key, node)?;
+        }
+        for section in sections {
+            self.output.push_str("\n@ ");
+            let keys = section
+                .keys
+                .iter()
+                .map(|(key, push)| {
+                    if *push {
+                        format!("{key}[]")
+                    } else {
+                        key.clone()
+                    }
+                })
+                .collect::<Vec<_>>();
+            self.output.push_str(&keys.join("."));
+            self.output.push('\n');
+            self.variant(section.variant);
+            for (key, node) in section.bindings {
+                self.binding(&key, node)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Split `entries` at `path` into the bindings written directly in it and `sections`.
+    fn plan<'n>(
+        &self,
+        entries: &'n [(Node, Node)],
+        path: &[Segment],
+        depth: usize,
+        sections: &mut Vec<Section<'n>>,
+    ) -> Result<Vec<(String, &'n Node)>, FromYamlError> {
+        let mut bindings = vec![];
+        for (key, node) in entries {
+            let key = key_notation(key)?;
+            if depth >= self.options.max_section_depth {
+                bindings.push((key, node));
+                continue;
+            }
+            match node {
+                Node::Mapping { entries, tag, mark } if node.is_mapping() => {
+                    let keys = [path, &[(key, false)]].concat();
+                    let variant = variant(tag.as_ref(), *mark)?;
+                    let mut nested = vec![];
+                    let body = self.plan(entries, &keys, depth + 1, &mut nested)?;
+                    if !body.is_empty() || variant.is_some() {
+                        sections.push(Section {
+                            keys,
+                            variant,
+                            bindings: body,
+                        });
+                    }
+                    sections.extend(nested);
+                }
+                Node::Sequence { elements, tag, .. }
+                    if is_plain_sequence(tag.as_ref())
+                        && !elements.is_empty()
+                        && elements.iter().all(Node::is_mapping) =>
+                {
+                    let keys = [path, &[(key, true)]].concat();
+                    for element in elements {
+                        let Node::Mapping { entries, tag, mark } = element else {
+                            unreachable!("all elements are mappings");
+                        };
+                        sections.push(Section {
+                            keys: keys.clone(),
+                            variant: variant(tag.as_ref(), *mark)?,
+                            bindings: bindings_of(entries)?,
+                        });
+                    }
+                }
+                _ => bindings.push((key, node)),
+            }
+        }
+        Ok(bindings)
+    }
+
+    fn binding(&mut self, key: &str, node: &Node) -> Result<(), FromYamlError> {
+        self.output.push_str(key);
+        match node {
+            Node::Mapping { entries, tag, mark } if node.is_mapping() => {
+                self.output.push_str(" {\n");
+                self.variant(variant(tag.as_ref(), *mark)?);
+                for (key, node) in bindings_of(entries)? {
+                    self.binding(&key, node)?;
+                }
+                self.output.push('}');
+            }
+            Node::Scalar {
+                value,
+                style,
+                tag,
+                mark,
+            } => match scalar(value, *style, tag.as_ref(), *mark)? {
+                Scalar::String(text) | Scalar::Block("", text) if is_text(text) => {
+                    self.output.push_str(": ");
+                    self.output.push_str(text);
+                }
+                scalar => {
+                    self.output.push_str(" = ");
+                    self.scalar(scalar);
+                }
+            },
+            node => {
+                self.output.push_str(" = ");
+                self.value(node)?;
+            }
+        }
+        self.output.push('\n');
+        Ok(())
+    }
+
+    fn variant(&mut self, variant: Option<&str>) {
+        if let Some(variant) = variant {
+            self.output.push_str("$variant = ");
+            self.str(variant);
+            self.output.push('\n');
+        }
+    }
+
+    fn value(&mut self, node: &Node) -> Result<(), FromYamlError> {
+        match node {
+            Node::Scalar {
+                value,
+                style,
+                tag,
+                mark,
+            } => self.scalar(scalar(value, *style, tag.as_ref(), *mark)?),
+            Node::Sequence {
+                elements,
+                tag,
+                mark,
+            } => {
+                if !is_plain_sequence(tag.as_ref()) {
+                    let tag = tag.as_ref().map(tag_notation).unwrap_or_default();
+                    return Err(error(*mark, FromYamlErrorKind::UnsupportedTag(tag)));
+                }
+                self.output.push('[');
+                for (index, element) in elements.iter().enumerate() {
+                    if index > 0 {
+                        self.output.push_str(", ");
+                    }
+                    self.value(element)?;
+                }
+                self.output.push(']');
+            }
+            Node::Mapping { entries, tag, mark } => {
+                self.output.push('{');
+                let mut separator = " ";
+                if let Some(variant) = variant(tag.as_ref(), *mark)? {
+                    self.output.push_str(" $variant = ");
+                    self.str(variant);
+                    separator = ", ";
+                }
+                for (key, node) in entries {
+                    self.output.push_str(separator);
+                    self.output.push_str(&key_notation(key)?);
+                    self.output.push_str(" = ");
+                    self.value(node)?;
+                    separator = ", ";
+                }
+                if separator == ", " {
+                    self.output.push(' ');
+                }
+                self.output.push('}');
+            }
+        }
+        Ok(())
+    }
+
+    fn scalar(&mut self, scalar: Scalar) {
+        match scalar {
+            Scalar::Null => self.output.push_str("null"),
+            Scalar::Bool(value) => self.output.push_str(&value.to_string()),
+            Scalar::Integer(text) | Scalar::Float(text) => self.output.push_str(&text),
+            Scalar::String(text) => self.str(text),
+            Scalar::Typed(name, text) => {
+                self.output.push_str(name);
+                self.str(text);
+            }
+            Scalar::Block(language, text)
+                if !text.is_empty() && !text.contains(['\n', '\r', '`']) =>
+            {
+                self.output.push_str(language);
+                self.output.push('`');
+                self.output.push_str(text);
+                self.output.push('`');
+            }
+            // A code block ends at the first fence, and its content ends with a line break
+            Scalar::Block(language, text) if text.contains("```") || !text.ends_with('\n') => {
+                self.output.push_str(language);
+                self.str(text);
+            }
+            Scalar::Block(language, text) => {
+                self.output.push_str("```");
+                self.output.push_str(language);
+                self.output.push('\n');
+                self.output.push_str(text);
+                self.output.push_str("```");
+            }
+        }
+    }
+
+    fn str(&mut self, text: &str) {
+        self.output.push('"');
+        self.output.push_str(&escape(text));
+        self.output.push('"');
+    }
+}
+
+fn bindings_of(entries: &[(Node, Node)]) -> Result<Vec<(String, &Node)>, FromYamlError> {
+    entries
+        .iter()
+        .map(|(key, node)| Ok((key_notation(key)?, node)))
+        .collect()
+}
+
+/// The variant name of a mapping from its local tag.
+fn variant(tag: Option<&Tag>, mark: Marker) -> Result<Option<&str>, FromYamlError> {
+    let Some(tag) = tag else {
+        return Ok(None);
+    };
+    match tag_kind(tag) {
+        TagKind::Core("map") | TagKind::Local("") => Ok(None),
+        TagKind::Local(name) => Ok(Some(name)),
+        _ => Err(error(
+            mark,
+            FromYamlErrorKind::UnsupportedTag(tag_notation(tag)),
+        )),
+    }
+}
+
+fn is_plain_sequence(tag: Option<&Tag>) -> bool {
+    tag.is_none_or(|tag| matches!(tag_kind(tag), TagKind::Core("seq") | TagKind::Local("")))
+}
+
+fn key_notation(key: &Node) -> Result<String, FromYamlError> {
+    let notation = match key {
+        Node::Scalar {
+            value,
+            style,
+            tag,
+            mark,
+        } => match scalar(value, *style, tag.as_ref(), *mark)? {
+            Scalar::String(key) => return Ok(string_notation(key)),
+            // An integer key in SWON is a tuple index, so integer keys of mappings are strings
+            Scalar::Integer(key) => return Ok(string_notation(&key)),
+            Scalar::Null => "null".to_string(),
+            Scalar::Bool(key) => key.to_string(),
+            Scalar::Float(key) => key,
+            Scalar::Typed(name, key) => format!("!{name} {key:?}"),
+            Scalar::Block(_, key) => format!("{key:?}"),
+        },
+        Node::Sequence { .. } => "of a sequence".to_string(),
+        Node::Mapping { .. } => "of a mapping".to_string(),
+    };
+    Err(error(key.mark(), FromYamlErrorKind::NonStringKey(notation)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(input: &str) -> Result<Vec<String>, FromYamlError> {
+        from_yaml(input, &FromYamlOptions::default())
+    }
+
+    #[test]
+    fn test_from_yaml() {
+        let input = r#"apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: web
+  labels: &labels
+    app: web
+spec:
+  replicas: 0x10
+  ratio: .inf
+  enabled: yes
+  selector:
+    matchLabels: *labels
+  containers:
+    - name: web
+      image: !image "nginx:1.25"
+    - name: sidecar
+      args: [--port, "8080", 8080]
+script: |
+  echo hello
+  exit 0
+summary: >-
+  folded
+  line
+config: !toml |
+  a = 1
+empty:
+1: one
+---
+second: !Point { x: 1, y: 2 }
+---
+"#;
+        let documents = convert(input).unwrap();
+        assert_eq!(
+            documents,
+            [
+                r#"apiVersion: apps/v1
+kind: Deployment
+script = ```
+echo hello
+exit 0
+```
+summary: folded line
+config = ```toml
+a = 1
+```
+empty = null
+"1": one
+
+@ metadata
+name: web
+
+@ metadata.labels
+app: web
+
+@ spec
+replicas = 16
+ratio = inf
+enabled: yes
+
+@ spec.selector
+matchLabels {
+  app: web
+}
+
+@ spec.containers[]
+name: web
+image = image"nginx:1.25"
+
+@ spec.containers[]
+name: sidecar
+args = ["--port", "8080", 8080]
+"#,
+                "@ second\n$variant = \"Point\"\nx = 1\ny = 2\n",
+                "",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_yaml_errors() {
+        let error = |input| match convert(input) {
+            Err(FromYamlError::Value { line, column, kind }) => (line, column, kind),
+            result => panic!("unexpected {result:?}"),
+        };
+        assert_eq!(
+            error("a: 1\nb:\n  ? [1, 2]\n  : c\n"),
+            (
+                3,
+                5,
+                FromYamlErrorKind::NonStringKey("of a sequence".into())
+            )
+        );
+        assert_eq!(
+            error("true: 1\n"),
+            (1, 1, FromYamlErrorKind::NonStringKey("true".into()))
+        );
+        assert_eq!(
+            error("a: !!binary aGk=\n"),
+            (1, 13, FromYamlErrorKind::UnsupportedTag("!!binary".into()))
+        );
+        assert_eq!(
+            error("a: !!int one\n"),
+            (
+                1,
+                10,
+                FromYamlErrorKind::InvalidScalar {
+                    tag: "!!int".into(),
+                    value: "one".into()
+                }
+            )
+        );
+        assert_eq!(error("- a\n"), (1, 1, FromYamlErrorKind::RootNotMapping));
+        let mut laughs = "a0: &a0 [lol, lol, lol, lol, lol, lol, lol, lol, lol, lol]\n".to_string();
+        for i in 1..10 {
+            let aliases = vec![format!("*a{}", i - 1); 10].join(", ");
+            laughs.push_str(&format!("a{i}: &a{i} [{aliases}]\n"));
+        }
+        assert_eq!(
+            error(&laughs),
+            (5, 45, FromYamlErrorKind::TooManyAliasNodes(100_000))
+        );
+        assert!(matches!(
+            convert("a: [1\n"),
+            Err(FromYamlError::ScanError(_))
+        ));
+    }
+}
@@ -1,14 +1,7 @@
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
-}
+//! Conversion between SWON and YAML.
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+mod from_yaml;
+mod to_yaml;
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
+pub use from_yaml::{FromYamlError, FromYamlErrorKind, FromYamlOptions, from_yaml};
+pub use to_yaml::{Loss, LossKind, ToYamlError, ToYamlErrorKind, Yaml, to_yaml};
@@ -0,0 +1,487 @@
+use swon_tree::value_visitor::Values;
+use swon_value::{
+    identifier::Identifier,
+    value::{Code, KeyCmpValue, Map, Path, PathSegment, Value, pop_element, push_element},
+};
+use thiserror::Error;
+
+use crate::from_yaml::resolves_to_string;
+
+/// A converted document with the information that YAML could not hold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Yaml {
+    pub text: String,
+    /// In the order the values are converted, with map entries sorted by key
+    pub losses: Vec<Loss>,
+}
+
+/// Information dropped while converting the value at `path`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Loss {
+    pub path: Path,
+    pub kind: LossKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LossKind {
+    #[error("Tuple is converted to a sequence")]
+    Tuple,
+    #[error("Unit is converted to null")]
+    Unit,
+    #[error("Tag `{0}` of a variant whose content is not a map is dropped")]
+    VariantTag(String),
+    #[error("Extension `${0}` is dropped")]
+    Extension(Identifier),
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+#[error("{kind}")]
+pub struct ToYamlError {
+    /// The value that can't be converted
+    pub path: Path,
+    pub kind: ToYamlErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ToYamlErrorKind {
+    #[error("YAML has no paths")]
+    Path,
+    #[error("Key {0} can't be converted to a YAML key")]
+    UnsupportedKey(String),
+}
+
+/// Convert a document to a YAML document in block style. The documents of a stream are converted
+/// one by one and joined with `---` lines.
+///
+/// | SWON | YAML |
+/// |------|------|
+/// | null, boolean, integer, float, string, array, map | the same, with map entries sorted by key |
+/// | typed string `url"..."` | tagged string `!url "..."` |
+/// | code | literal block scalar, tagged with its language like `!rust \|` |
+/// | variant of a map | tagged mapping `!Variant` |
+/// | variant of other values | its content, dropping the tag |
+/// | tuple | a sequence |
+/// | unit `()` | null |
+/// | extension | dropped |
+///
+/// Paths have no YAML representation, and tuples and unit can't be keys. Every conversion that
+/// drops information is reported in [Yaml::losses].
+pub fn to_yaml(values: &Values) -> Result<Yaml, ToYamlError> {
+    let mut converter = Converter {
+        values,
+        path: vec![],
+        losses: vec![],
+        output: String::new(),
+    };
+    let Value::Map(root) = &values.root else {
+        unreachable!("the document root is always a map");
+    };
+    converter.extensions();
+    if root.0.is_empty() {
+        converter.output.push_str("{}\n");
+    } else {
+        converter.entries(root, 0, false)?;
+    }
+    Ok(Yaml {
+        text: converter.output,
+        losses: converter.losses,
+    })
+}
+
+struct Converter<'a> {
+    values: &'a Values,
+    /// The path of the current value, as in [swon_tree::value_visitor::ExtensionMap]
+    path: Vec<PathSegment>,
+    losses: Vec<Loss>,
+    output: String,
+}
+
+impl Converter<'_> {
+    /// Write the entries of `map` at `indent`. `inline` continues the line of a sequence entry.
+    fn entries(&mut self, map: &Map, indent: usize, inline: bool) -> Result<(), ToYamlError> {
+        let mut entries = map.0.iter().collect::<Vec<_>>();
+        entries.sort_by_key(|(key, _)| *key);
+        for (index, (key, value)) in entries.into_iter().enumerate() {
+            if index > 0 || !inline {
+                self.indent(indent);
+            }
+            self.path.push(PathSegment::Value(key.clone().into()));
+            let written = self.key(key).and_then(|()| {
+                self.output.push(':');
+                self.node(value, indent, false)
+            });
+            self.path.pop();
+            written?;
+        }
+        Ok(())
+    }
+
+    /// Write the elements of a sequence at `indent`. `inline` continues the line of a sequence
+    /// entry.
+    fn elements(
+        &mut self,
+        elements: &[Value],
+        indent: usize,
+        inline: bool,
+        tuple: bool,
+    ) -> Result<(), ToYamlError> {
+        for (index, element) in elements.iter().enumerate() {
+            if index > 0 || !inline {
+                self.indent(indent);
+            }
+            self.output.push('-');
+            if tuple {
+                self.path.push(PathSegment::TupleIndex(index as u8));
+            } else {
+                push_element(&mut self.path, index);
+            }
+            let written = self.node(element, indent, true);
+            if tuple {
+                self.path.pop();
+            } else {
+                pop_element(&mut self.path);
+            }
+            written?;
+        }
+        Ok(())
+    }
+
+    /// Write `value` after a `key:` or a `-` of a sequence entry, ending the line.
+    fn node(&mut self, value: &Value, indent: usize, entry: bool) -> Result<(), ToYamlError> {
+        self.extensions();
+        let mut value = value;
+        if let Value::Variant(variant) = value {
+            match &*variant.content {
+                Value::Map(map) if !map.0.is_empty() => {
+                    self.output.push_str(" !");
+                    self.output.push_str(&variant.tag);
+                    self.output.push('\n');
+                    return self.entries(map, indent + 2, false);
+                }
+                content => {
+                    self.loss(LossKind::VariantTag(variant.tag.clone()));
+                    value = content;
+                }
+            }
+        }
+        // A collection in a sequence entry starts on the line of its `-`
+        let separator = if entry { ' ' } else { '\n' };
+        match value {
+            Value::Map(map) if !map.0.is_empty() => {
+                self.output.push(separator);
+                self.entries(map, indent + 2, entry)
+            }
+            Value::Array(array) if !array.0.is_empty() => {
+                self.output.push(separator);
+                self.elements(&array.0, indent + 2, entry, false)
+            }
+            Value::Tuple(tuple) if !tuple.0.is_empty() => {
+                self.loss(LossKind::Tuple);
+                self.output.push(separator);
+                self.elements(&tuple.0, indent + 2, entry, true)
+            }
+            Value::Code(code) if is_literal(&code.content) => {
+                self.output.push(' ');
+                self.literal(code, indent + 2);
+                Ok(())
+            }
+            value => {
+                self.output.push(' ');
+                self.scalar(value)?;
+                self.output.push('\n');
+                Ok(())
+            }
+        }
+    }
+
+    fn scalar(&mut self, value: &Value) -> Result<(), ToYamlError> {
+        match value {
+            Value::Null => self.output.push_str("null"),
+            Value::Unit => {
+                self.loss(LossKind::Unit);
+                self.output.push_str("null");
+            }
+            Value::Bool(value) => self.output.push_str(&value.to_string()),
+            Value::I64(value) => self.output.push_str(&value.to_string()),
+            Value::U64(value) => self.output.push_str(&value.to_string()),
+            // Through the shortest decimal, so that `1.1` stays `1.1`
+            Value::F32(value) => self.float(value.to_string().parse().unwrap_or(*value as f64)),
+            Value::F64(value) => self.float(*value),
+            Value::String(value) => self.str(value),
+            Value::TypedString(typed) => {
+                self.tag(&typed.type_name);
+                self.quoted(&typed.value);
+            }
+            Value::Code(code) => {
+                if !code.language.is_empty() {
+                    self.tag(&code.language);
+                }
+                self.quoted(&code.content);
+            }
+            Value::Map(_) => self.output.push_str("{}"),
+            Value::Array(_) => self.output.push_str("[]"),
+            Value::Tuple(_) => {
+                self.loss(LossKind::Tuple);
+                self.output.push_str("[]");
+            }
+            Value::Path(_) => return Err(self.error(ToYamlErrorKind::Path)),
+            Value::Variant(_) => unreachable!("variants are unwrapped by the caller"),
+        }
+        Ok(())
+    }
+
+    fn key(&mut self, key: &KeyCmpValue) -> Result<(), ToYamlError> {
+        match key {
+            KeyCmpValue::String(key) => self.str(key),
+            KeyCmpValue::Null => self.output.push_str("null"),
+            KeyCmpValue::Bool(key) => self.output.push_str(&key.to_string()),
+            KeyCmpValue::I64(key) => self.output.push_str(&key.to_string()),
+            KeyCmpValue::U64(key) => self.output.push_str(&key.to_string()),
+            KeyCmpValue::Tuple(_) | KeyCmpValue::Unit => {
+                let kind = ToYamlErrorKind::UnsupportedKey(format!("{key:?}"));
+                return Err(self.error(kind));
+            }
+        }
+        Ok(())
+    }
+
+    /// Write `code` as a literal block scalar with its content at `indent`.
+    fn literal(&mut self, code: &Code, indent: usize) {
+        if !code.language.is_empty() {
+            self.tag(&code.language);
+        }
+        self.output.push('|');
+        // Leading spaces or blank lines would be taken as the indentation
+        if code.content.starts_with([' ', '\n']) {
+            self.output.push('2');
+        }
+        let content = match code.content.strip_suffix('\n') {
+            Some(content) if content.ends_with('\n') => {
+                self.output.push('+');
+                content
+            }
+            Some(content) => content,
+            None => {
+                self.output.push('-');
+                &code.content
+            }
+        };
+        self.output.push('\n');
+        for line in content.split('\n') {
+            if !line.is_empty() {
+                self.indent(indent);
+                self.output.push_str(line);
+            }
+            self.output.push('\n');
+        }
+    }
+
+    fn float(&mut self, value: f64) {
+        let text = if value.is_nan() {
+            ".nan".to_string()
+        } else if value.is_infinite() {
+            if value > 0.0 { ".inf" } else { "-.inf" }.to_string()
+        } else {
+            // `{:?}` keeps a fractional part or an exponent, so `1.0` stays a float
+            format!("{value:?}")
+        };
+        self.output.push_str(&text);
+    }
+
+    /// Write a string, without quotes if it reads back as the same string.
+    fn str(&mut self, text: &str) {
+        if is_plain(text) {
+            self.output.push_str(text);
+        } else {
+            self.quoted(text);
+        }
+    }
+
+    fn quoted(&mut self, text: &str) {
+        self.output.push('"');
+        for c in text.chars() {
+            match c {
+                '\\' => self.output.push_str("\\\\"),
+                '"' => self.output.push_str("\\\""),
+                '\n' => self.output.push_str("\\n"),
+                '\r' => self.output.push_str("\\r"),
+                '\t' => self.output.push_str("\\t"),
+                '\0' => self.output.push_str("\\0"),
+                c if c.is_control() => self.output.push_str(&format!("\\u{:04X}", c as u32)),
+                c => self.output.push(c),
+            }
+        }
+        self.output.push('"');
+    }
+
+    fn tag(&mut self, name: &str) {
+        self.output.push('!');
+        self.output.push_str(name);
+        self.output.push(' ');
+    }
+
+    fn indent(&mut self, indent: usize) {
+        self.output.extend(std::iter::repeat_n(' ', indent));
+    }
+
+    fn extensions(&mut self) {
+        let names = self
+            .values
+            .extensions
+            .at(&self.path)
+            .map(|extension| extension.name.clone())
+            .collect::<Vec<_>>();
+        for name in names {
+            self.loss(LossKind::Extension(name));
+        }
+    }
+
+    fn loss(&mut self, kind: LossKind) {
+        self.losses.push(Loss {
+            path: Path(self.path.clone()),
+            kind,
+        });
+    }
+
+    fn error(&self, kind: ToYamlErrorKind) -> ToYamlError {
+        ToYamlError {
+            path: Path(self.path.clone()),
+            kind,
+        }
+    }
+}
+
+/// Whether `text` reads back as the same string from a plain scalar.
+fn is_plain(text: &str) -> bool {
+    !text.is_empty()
+        && text.trim() == text
+        && resolves_to_string(text)
+        // YAML 1.1 readers take these as booleans
+        && !matches!(
+            text.to_ascii_lowercase().as_str(),
+            "y" | "n" | "yes" | "no" | "on" | "off"
+        )
+        && !text.starts_with([
+            '-', '?', ':', ',', '[', ']', '{', '}', '#', '&', '*', '!', '|', '>', '\'', '"', '%',
+            '@', '`',
+        ])
+        && !text.contains(": ")
+        && !text.contains(" #")
+        && !text.ends_with(':')
+        && !text.contains(|c: char| c.is_control())
+}
+
+/// Whether `content` can be written as a literal block scalar, which normalizes line breaks.
+fn is_literal(content: &str) -> bool {
+    !content.contains(|c: char| c.is_control() && c != '\n' && c != '\t')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FromYamlOptions, from_yaml};
+
+    fn convert(input: &str) -> Result<Yaml, ToYamlError> {
+        let cst = swon_parol::parse(input, "test.swon").unwrap();
+        to_yaml(&cst.values(input).unwrap())
+    }
+
+    #[test]
+    fn test_to_yaml() {
+        let input = r#"
+$schema = "a.swon"
+name: web
+count = 3
+ratio = inf
+flags = [true, null, "yes", " padded", "a: b"]
+image = image"nginx:1.25"
+script = ```sh
+echo hello
+```
+empty = {}
+tuple = (1, "x")
+unit = ()
+
+@ metadata
+labels = { app = "web", tier = "front" }
+
+@ containers[]
+name: web
+ports = [[80, 443], []]
+
+@ shape
+$variant = "Circle"
+radius = 1.5
+"#;
+        let yaml = convert(input).unwrap();
+        assert_eq!(
+            yaml.text,
+            r#"containers:
+  - name: web
+    ports:
+      - - 80
+        - 443
+      - []
+count: 3
+empty: {}
+flags:
+  - true
+  - null
+  - "yes"
+  - " padded"
+  - "a: b"
+image: !image "nginx:1.25"
+metadata:
+  labels:
+    app: web
+    tier: front
+name: web
+ratio: .inf
+script: !sh |
+  echo hello
+shape: !Circle
+  radius: 1.5
+tuple:
+  - 1
+  - x
+unit: null
+"#
+        );
+        let losses = yaml
+            .losses
+            .iter()
+            .map(|loss| loss.kind.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            losses,
+            [
+                LossKind::Extension("schema".parse().unwrap()),
+                LossKind::Tuple,
+                LossKind::Unit,
+            ]
+        );
+        assert_eq!(convert("a = .b\n").unwrap_err().kind, ToYamlErrorKind::Path);
+    }
+
+    #[test]
+    fn test_yaml_round_trip() {
+        let input = r#"a = "x\ny"
+b = ```
+ indented
+
+```
+c = ```rust
+fn main() {}
+```
+e = rust`let a = 1;`
+d = [1, [2, 3], { e = "f" }]
+"#;
+        let yaml = convert(input).unwrap();
+        let swon = from_yaml(&yaml.text, &FromYamlOptions::default()).unwrap();
+        let values = |input: &str| {
+            let cst = swon_parol::parse(input, "test.swon").unwrap();
+            cst.values(input).unwrap().root
+        };
+        assert_eq!(values(&swon[0]), values(input));
+    }
+}
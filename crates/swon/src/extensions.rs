@@ -1,5 +1,7 @@
 //! Core extensions for SWON.
 
+use std::fmt;
+
 pub trait ExtensionNamespace {
     /// The name of the extension namespace.
     fn name(&self) -> &'static str;
@@ -16,9 +18,12 @@ pub trait ExtensionNamespace {
 // assert that ExtensionNamespace is dyn-compatible
 type _DynAssertion = Box<dyn ExtensionNamespace>;
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ExtensionType {
     Union(&'static [ExtensionType]),
+    /// A map with the listed keys. Every key is optional and no other keys are allowed.
     Map(&'static [(&'static str, ExtensionType)]),
+    /// A string, including typed strings like `url"..."`.
     String,
     Integer,
     Float,
@@ -28,7 +33,140 @@ pub enum ExtensionType {
     Tuple(&'static [ExtensionType]),
 }
 
+/// Writes the type in a notation close to SWON values, like `{ allow = string | boolean }`.
+impl fmt::Display for ExtensionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExtensionType::Union(types) => write_list(f, types, " | "),
+            ExtensionType::Map(fields) => {
+                write!(f, "{{")?;
+                for (i, (name, ty)) in fields.iter().enumerate() {
+                    let separator = if i == 0 { " " } else { ", " };
+                    write!(f, "{separator}{name} = {ty}")?;
+                }
+                write!(f, " }}")
+            }
+            ExtensionType::String => write!(f, "string"),
+            ExtensionType::Integer => write!(f, "integer"),
+            ExtensionType::Float => write!(f, "float"),
+            ExtensionType::Boolean => write!(f, "boolean"),
+            ExtensionType::Null => write!(f, "null"),
+            ExtensionType::Array(ty) => write!(f, "[{ty}]"),
+            ExtensionType::Tuple(types) => {
+                write!(f, "(")?;
+                write_list(f, types, ", ")?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+fn write_list(f: &mut fmt::Formatter<'_>, types: &[ExtensionType], separator: &str) -> fmt::Result {
+    for (i, ty) in types.iter().enumerate() {
+        if i > 0 {
+            write!(f, "{separator}")?;
+        }
+        write!(f, "{ty}")?;
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CoreExtension {
     Swon,
     Variant,
 }
+
+impl CoreExtension {
+    pub const ALL: [CoreExtension; 2] = [CoreExtension::Swon, CoreExtension::Variant];
+}
+
+impl ExtensionNamespace for CoreExtension {
+    fn name(&self) -> &'static str {
+        match self {
+            CoreExtension::Swon => "swon",
+            CoreExtension::Variant => "variant",
+        }
+    }
+
+    fn top_level_only(&self) -> bool {
+        match self {
+            CoreExtension::Swon => true,
+            CoreExtension::Variant => false,
+        }
+    }
+
+    fn extension_type(&self) -> ExtensionType {
+        match self {
+            CoreExtension::Swon => ExtensionType::Map(&[
+                ("version", ExtensionType::String),
+                ("schema", ExtensionType::String),
+                ("data-model", ExtensionType::String),
+            ]),
+            CoreExtension::Variant => ExtensionType::String,
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        CoreExtension::ALL.into_iter().find(|ext| ext.name() == s)
+    }
+}
+
+/// The set of extension namespaces known to a validator.
+pub struct ExtensionRegistry {
+    namespaces: Vec<Box<dyn ExtensionNamespace>>,
+}
+
+impl ExtensionRegistry {
+    /// A registry without any namespace, not even the core ones.
+    pub fn empty() -> Self {
+        Self {
+            namespaces: Vec::new(),
+        }
+    }
+
+    /// A registry with the [CoreExtension]s.
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        for ext in CoreExtension::ALL {
+            registry.register(ext);
+        }
+        registry
+    }
+
+    /// Register a namespace, returning the namespace previously registered with the same name.
+    pub fn register(
+        &mut self,
+        namespace: impl ExtensionNamespace + 'static,
+    ) -> Option<Box<dyn ExtensionNamespace>> {
+        let namespace: Box<dyn ExtensionNamespace> = Box::new(namespace);
+        match self
+            .namespaces
+            .iter_mut()
+            .find(|ns| ns.name() == namespace.name())
+        {
+            Some(registered) => Some(std::mem::replace(registered, namespace)),
+            None => {
+                self.namespaces.push(namespace);
+                None
+            }
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn ExtensionNamespace> {
+        self.namespaces
+            .iter()
+            .find(|ns| ns.name() == name)
+            .map(|ns| ns.as_ref())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn ExtensionNamespace> {
+        self.namespaces.iter().map(|ns| ns.as_ref())
+    }
+}
+
+impl Default for ExtensionRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1 +1,2 @@
 pub mod extensions;
+pub mod validate;
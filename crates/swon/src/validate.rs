@@ -0,0 +1,256 @@
+//! Validation of extension usage against an [ExtensionRegistry].
+
+use swon_tree::spanned::{Location, SpannedContent, SpannedDocument, SpannedValue};
+use swon_value::value::{KeyCmpValue, Value};
+use thiserror::Error;
+
+use crate::extensions::{CoreExtension, ExtensionNamespace as _, ExtensionRegistry, ExtensionType};
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ExtensionError {
+    #[error("Unknown extension namespace: ${name}")]
+    UnknownNamespace {
+        name: String,
+        location: Option<Location>,
+    },
+    #[error("Extension ${name} is only allowed at the top level")]
+    NotTopLevel {
+        name: String,
+        location: Option<Location>,
+    },
+    /// `location` and `expected` are of the innermost value that does not match. For a key that
+    /// the map type does not list, `location` is the key.
+    #[error("Extension ${name} expects {expected}")]
+    TypeMismatch {
+        name: String,
+        expected: ExtensionType,
+        location: Option<Location>,
+    },
+}
+
+impl ExtensionRegistry {
+    /// Check every extension in `document`, including `$variant` tags, against this registry.
+    pub fn validate(&self, document: &SpannedDocument) -> Vec<ExtensionError> {
+        let mut errors = Vec::new();
+        self.validate_value(&document.root, true, &mut errors);
+        errors
+    }
+
+    /// `top_level` is true for the root and for extensions of top-level values, so `$swon`
+    /// can be nested in other extensions of the document.
+    fn validate_value(
+        &self,
+        value: &SpannedValue,
+        top_level: bool,
+        errors: &mut Vec<ExtensionError>,
+    ) {
+        for (name, ext) in &value.extensions {
+            self.validate_extension(name.as_ref(), ext, top_level, errors);
+            self.validate_value(ext, top_level, errors);
+        }
+        match &value.content {
+            SpannedContent::Map(entries) => {
+                for (_, entry) in entries {
+                    self.validate_value(entry, false, errors);
+                }
+            }
+            SpannedContent::Array(elements) | SpannedContent::Tuple(elements) => {
+                for element in elements {
+                    self.validate_value(element, false, errors);
+                }
+            }
+            SpannedContent::Variant(variant) => {
+                let tag = SpannedValue {
+                    key: Some(variant.tag_location),
+                    value: None,
+                    content: SpannedContent::Value(Value::String(variant.tag.to_string())),
+                    extensions: Vec::new(),
+                };
+                self.validate_extension(CoreExtension::Variant.name(), &tag, top_level, errors);
+                self.validate_value(&variant.content, top_level, errors);
+            }
+            SpannedContent::Value(_) => {}
+        }
+    }
+
+    fn validate_extension(
+        &self,
+        name: &str,
+        value: &SpannedValue,
+        top_level: bool,
+        errors: &mut Vec<ExtensionError>,
+    ) {
+        let Some(namespace) = self.get(name) else {
+            errors.push(ExtensionError::UnknownNamespace {
+                name: name.to_string(),
+                location: value.key,
+            });
+            return;
+        };
+        if namespace.top_level_only() && !top_level {
+            errors.push(ExtensionError::NotTopLevel {
+                name: name.to_string(),
+                location: value.key,
+            });
+        }
+        if let Err((expected, location)) = check_type(&namespace.extension_type(), value) {
+            errors.push(ExtensionError::TypeMismatch {
+                name: name.to_string(),
+                expected: expected.clone(),
+                location,
+            });
+        }
+    }
+}
+
+/// On mismatch, returns the innermost type that does not match and where it fails.
+fn check_type<'a>(
+    ty: &'a ExtensionType,
+    value: &SpannedValue,
+) -> Result<(), (&'a ExtensionType, Option<Location>)> {
+    let mismatch = |ty| Err((ty, value.value.or(value.key)));
+    let matches = match (ty, &value.content) {
+        (ExtensionType::Union(types), _) => types.iter().any(|ty| check_type(ty, value).is_ok()),
+        (ExtensionType::Map(fields), SpannedContent::Map(entries)) => {
+            for (key, entry) in entries {
+                let field = match key {
+                    KeyCmpValue::String(key) => fields.iter().find(|(name, _)| name == key),
+                    _ => None,
+                };
+                match field {
+                    Some((_, ty)) => check_type(ty, entry)?,
+                    None => return Err((ty, entry.key.or(entry.value))),
+                }
+            }
+            true
+        }
+        (ExtensionType::Array(ty), SpannedContent::Array(elements)) => {
+            for element in elements {
+                check_type(ty, element)?;
+            }
+            true
+        }
+        (ExtensionType::Tuple(types), SpannedContent::Tuple(elements)) => {
+            if types.len() != elements.len() {
+                return mismatch(ty);
+            }
+            for (ty, element) in types.iter().zip(elements) {
+                check_type(ty, element)?;
+            }
+            true
+        }
+        (ty, SpannedContent::Value(value)) => matches!(
+            (ty, value),
+            (
+                ExtensionType::String,
+                Value::String(_) | Value::TypedString(_)
+            ) | (ExtensionType::Integer, Value::I64(_) | Value::U64(_))
+                | (ExtensionType::Float, Value::F32(_) | Value::F64(_))
+                | (ExtensionType::Boolean, Value::Bool(_))
+                | (ExtensionType::Null, Value::Null)
+        ),
+        _ => false,
+    };
+    if matches { Ok(()) } else { mismatch(ty) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lint;
+
+    impl crate::extensions::ExtensionNamespace for Lint {
+        fn name(&self) -> &'static str {
+            "lint"
+        }
+
+        fn top_level_only(&self) -> bool {
+            false
+        }
+
+        fn extension_type(&self) -> ExtensionType {
+            ExtensionType::Map(&[(
+                "allow",
+                ExtensionType::Union(&[ExtensionType::String, ExtensionType::Boolean]),
+            )])
+        }
+
+        fn parse(s: &str) -> Option<Self> {
+            (s == "lint").then_some(Lint)
+        }
+    }
+
+    fn errors(registry: &ExtensionRegistry, input: &str) -> Vec<(String, String)> {
+        let document = swon_parol::parse(input, "test.swon")
+            .unwrap()
+            .spanned_values(input)
+            .unwrap();
+        registry
+            .validate(&document)
+            .into_iter()
+            .map(|error| {
+                let (ExtensionError::UnknownNamespace { location, .. }
+                | ExtensionError::NotTopLevel { location, .. }
+                | ExtensionError::TypeMismatch { location, .. }) = &error;
+                let span = location.unwrap().span;
+                let text = input[span.start as usize..span.end as usize].trim();
+                (error.to_string(), text.to_string())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_validate_extensions() {
+        let mut registry = ExtensionRegistry::new();
+        assert!(registry.register(Lint).is_none());
+
+        let input = r#"$swon.version = "v1"
+$swon.schema = 1
+$other.$swon.schema = "s"
+$unknown = 1
+a.$swon.version = "v1"
+a.$lint.allow = true
+b.$lint.deny = true
+@ c
+$variant: x
+"#;
+        assert_eq!(
+            errors(&registry, input),
+            vec![
+                (
+                    "Extension $swon expects string".to_string(),
+                    "1".to_string()
+                ),
+                (
+                    "Unknown extension namespace: $other".to_string(),
+                    "$other".to_string()
+                ),
+                (
+                    "Unknown extension namespace: $unknown".to_string(),
+                    "$unknown".to_string()
+                ),
+                (
+                    "Extension $swon is only allowed at the top level".to_string(),
+                    "$swon".to_string()
+                ),
+                (
+                    "Extension $lint expects { allow = string | boolean }".to_string(),
+                    "deny".to_string()
+                ),
+            ]
+        );
+        assert_eq!(
+            errors(&ExtensionRegistry::empty(), "@ a\n$variant: x\n"),
+            vec![(
+                "Unknown extension namespace: $variant".to_string(),
+                "$variant".to_string()
+            )]
+        );
+        let ty = ExtensionType::Array(Box::new(ExtensionType::Tuple(&[
+            ExtensionType::Integer,
+            ExtensionType::Null,
+        ])));
+        assert_eq!(ty.to_string(), "[(integer, null)]");
+    }
+}